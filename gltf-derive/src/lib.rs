@@ -9,7 +9,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 use syn::DeriveInput;
 
-#[proc_macro_derive(Validate)]
+#[proc_macro_derive(Validate, attributes(gltf))]
 pub fn derive_validate(input: TokenStream) -> TokenStream {
     expand(&syn::parse_macro_input!(input as DeriveInput)).into()
 }
@@ -25,17 +25,27 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
     let ident = &ast.ident;
     let validations: Vec<TokenStream> = fields
         .iter()
-        .map(|f| f.ident.as_ref().unwrap())
-        .map(|ident| {
+        .map(|f| {
             use inflections::Inflect;
+            let ident = f.ident.as_ref().unwrap();
             let field = ident.to_string().to_camel_case();
-            quote!(
-                self.#ident.validate(
-                    _root,
-                    || _path().field(#field),
-                    _report,
-                )
-            )
+            match validator(f) {
+                Some(validator) => quote!(
+                    #validator(
+                        &self.#ident,
+                        _root,
+                        || _path().field(#field),
+                        _report,
+                    )
+                ),
+                None => quote!(
+                    self.#ident.validate(
+                        _root,
+                        || _path().field(#field),
+                        _report,
+                    )
+                ),
+            }
         })
         .collect();
     let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
@@ -59,3 +69,22 @@ fn expand(ast: &DeriveInput) -> proc_macro2::TokenStream {
         }
     )
 }
+
+/// Returns the function named by a `#[gltf(validate = "path")]` attribute,
+/// which validates the field in place of its `Validate` implementation.
+fn validator(field: &syn::Field) -> Option<syn::Path> {
+    use syn::{Lit, Meta, NestedMeta};
+
+    let attr = field.attrs.iter().find(|attr| attr.path.is_ident("gltf"))?;
+    let list = match attr.parse_meta() {
+        Ok(Meta::List(list)) => list,
+        _ => panic!("expected `#[gltf(validate = \"path\")]`"),
+    };
+    list.nested.iter().find_map(|nested| match nested {
+        NestedMeta::Meta(Meta::NameValue(x)) if x.path.is_ident("validate") => match &x.lit {
+            Lit::Str(path) => Some(path.parse().expect("expected a function path")),
+            _ => panic!("expected a string literal"),
+        },
+        _ => None,
+    })
+}
@@ -250,7 +250,64 @@ impl Validate for Accessor {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
     {
-        if self.sparse.is_none() && self.buffer_view.is_none() {
+        let index = root.accessors.iter().position(|x| std::ptr::eq(x, self));
+        let compressed = index.is_some_and(|index| draco_compressed(root)[index]);
+        self.validate_compressed(compressed, root, path, report);
+    }
+}
+
+/// Validates the accessors of the root, finding the accessors of primitives
+/// compressed with `KHR_draco_mesh_compression` once for all of them.
+pub(crate) fn validate_accessors<P, R>(
+    accessors: &[Accessor],
+    root: &Root,
+    path: P,
+    report: &mut R,
+) where
+    P: Fn() -> Path,
+    R: FnMut(&dyn Fn() -> Path, Error),
+{
+    let compressed = draco_compressed(root);
+    for (index, accessor) in accessors.iter().enumerate() {
+        let compressed = compressed.get(index).copied().unwrap_or(false);
+        accessor.validate_compressed(compressed, root, || path().index(index), report);
+    }
+}
+
+/// Returns whether each accessor of the root describes the decompressed data
+/// of a primitive compressed with `KHR_draco_mesh_compression`, which needs no
+/// buffer view.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+fn draco_compressed(root: &Root) -> Vec<bool> {
+    let mut compressed = vec![false; root.accessors.len()];
+    let primitives = root.meshes.iter().flat_map(|mesh| mesh.primitives.iter());
+    for primitive in primitives.filter(|primitive| {
+        let extensions = primitive.extensions.as_ref();
+        extensions.and_then(|x| x.khr_draco_mesh_compression.as_ref()).is_some()
+    }) {
+        let indices = primitive.attributes.values().chain(primitive.indices.iter());
+        for index in indices {
+            if let Some(flag) = compressed.get_mut(index.value()) {
+                *flag = true;
+            }
+        }
+    }
+    compressed
+}
+
+/// Returns that no accessor is compressed, without `KHR_draco_mesh_compression`.
+#[cfg(not(feature = "KHR_draco_mesh_compression"))]
+fn draco_compressed(root: &Root) -> Vec<bool> {
+    vec![false; root.accessors.len()]
+}
+
+impl Accessor {
+    /// Validates the accessor, where `compressed` tells whether it describes
+    /// the decompressed data of a Draco compressed primitive.
+    fn validate_compressed<P, R>(&self, compressed: bool, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        if self.sparse.is_none() && self.buffer_view.is_none() && !compressed {
             // If sparse is missing, then bufferView must be present. Report that bufferView is
             // missing since it is the more common one to require.
             report(&|| path().field("bufferView"), Error::Missing);
@@ -267,9 +324,56 @@ impl Validate for Accessor {
         self.max.validate(root, || path().field("max"), report);
         self.normalized.validate(root, || path().field("normalized"), report);
         self.sparse.validate(root, || path().field("sparse"), report);
+
+        let (component_type, type_) = match (self.component_type.as_ref(), self.type_.as_ref()) {
+            (Checked::Valid(x), Checked::Valid(y)) => (x.0, *y),
+            _ => return,
+        };
+        let size = element_size(component_type, type_);
+        if let Some(view) = self.buffer_view.and_then(|x| root.get(x)) {
+            let stride = view.byte_stride.filter(|&x| x > 0).map_or(size, u64::from);
+            let length = match self.count {
+                0 => 0,
+                count => stride * (u64::from(count) - 1) + size,
+            };
+            if u64::from(self.byte_offset) + length > u64::from(view.byte_length) {
+                report(&path, Error::ByteRangeOutOfBounds);
+            }
+        }
+        if let Some(sparse) = self.sparse.as_ref() {
+            let count = u64::from(sparse.count);
+            let indices = &sparse.indices;
+            let view = root.get(indices.buffer_view);
+            if let (Some(view), Checked::Valid(x)) = (view, &indices.component_type) {
+                let length = u64::from(indices.byte_offset) + count * x.0.size() as u64;
+                if length > u64::from(view.byte_length) {
+                    let path = || path().field("sparse").field("indices");
+                    report(&path, Error::ByteRangeOutOfBounds);
+                }
+            }
+            let values = &sparse.values;
+            if let Some(view) = root.get(values.buffer_view) {
+                if u64::from(values.byte_offset) + count * size > u64::from(view.byte_length) {
+                    report(&|| path().field("sparse").field("values"), Error::ByteRangeOutOfBounds);
+                }
+            }
+        }
     }
 }
 
+/// Returns the size in bytes of an element, including the padding that aligns
+/// each column of a matrix to four bytes.
+fn element_size(component_type: ComponentType, type_: Type) -> u64 {
+    let component_size = component_type.size();
+    let size = match type_ {
+        Type::Mat2 if component_size == 1 => 8,
+        Type::Mat3 if component_size == 1 => 12,
+        Type::Mat3 if component_size == 2 => 24,
+        _ => type_.multiplicity() * component_size,
+    };
+    size as u64
+}
+
 // Help serde avoid serializing this glTF 2.0 default value.
 fn is_normalized_default(b: &bool) -> bool {
     !*b
@@ -18,6 +18,8 @@ pub const VALID_PROPERTIES: &'static [&'static str] = &[
     "rotation",
     "scale",
     "weights",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "pointer",
 ];
 
 /// Specifies an interpolation algorithm.
@@ -62,6 +64,12 @@ pub enum Property {
 
     /// Weights of morph targets.
     MorphTargetWeights,
+
+    /// The property referenced by the JSON pointer of the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    Pointer,
 }
 
 /// A keyframe animation.
@@ -115,7 +123,7 @@ pub struct Channel {
 }
 
 /// The index of the node and TRS property that an animation channel targets.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Target {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -127,7 +135,11 @@ pub struct Target {
     pub extras: Extras,
     
     /// The index of the node to target.
-    pub node: Index<scene::Node>,
+    ///
+    /// Undefined when the target is defined by an extension, such as the
+    /// JSON pointer of `KHR_animation_pointer`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Index<scene::Node>>,
     
     /// The name of the node's property to modify or the 'weights' of the
     /// morph targets it instantiates.
@@ -164,12 +176,79 @@ impl Validate for Animation {
         R: FnMut(&dyn Fn() -> Path, Error),
     {
         self.samplers.validate(root, || path().field("samplers"), report);
+        for (index, sampler) in self.samplers.iter().enumerate() {
+            let input = match root.accessors.get(sampler.input.value()) {
+                Some(input) => input,
+                None => continue,
+            };
+            let path = || path().field("samplers").index(index).field("input");
+            // Keyframe times are scalar floats with declared, non-negative bounds.
+            let is_float = matches!(
+                input.component_type,
+                Checked::Valid(accessor::GenericComponentType(accessor::ComponentType::F32))
+            );
+            if input.type_ != Checked::Valid(accessor::Type::Scalar) || !is_float {
+                report(&path, Error::Invalid);
+            }
+            match (&input.min, &input.max) {
+                (Some(min), Some(_)) => {
+                    if matches!(min.get(0).and_then(|x| x.as_f64()), Some(x) if x < 0.0) {
+                        report(&path, Error::Invalid);
+                    }
+                },
+                _ => report(&path, Error::Missing),
+            }
+        }
         for (index, channel) in self.channels.iter().enumerate() {
             if channel.sampler.value() as usize >= self.samplers.len() {
                 let path = || path().field("channels").index(index).field("sampler");
                 report(&path, Error::IndexOutOfBounds);
             }
+            let path = || path().field("channels").index(index).field("target");
+            channel.target.validate(root, path, report);
+        }
+    }
+}
+
+impl Target {
+    /// Returns the JSON pointer of the `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn pointer(&self) -> Option<&str> {
+        let extension = self.extensions.as_ref()?.khr_animation_pointer.as_ref()?;
+        Some(&extension.pointer)
+    }
+}
+
+impl Validate for Target {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        #[cfg(feature = "KHR_animation_pointer")]
+        {
+            if self.path == Checked::Valid(Property::Pointer) {
+                if self.pointer().is_none() {
+                    report(&|| path().field("extensions"), Error::Missing);
+                }
+                if self.node.is_some() {
+                    report(&|| path().field("node"), Error::Invalid);
+                }
+            } else if self.node.is_none() {
+                report(&|| path().field("node"), Error::Missing);
+            }
+        }
+        #[cfg(not(feature = "KHR_animation_pointer"))]
+        {
+            if self.node.is_none() {
+                report(&|| path().field("node"), Error::Missing);
+            }
         }
+        self.node.validate(root, || path().field("node"), report);
+        self.path.validate(root, || path().field("path"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
     }
 }
 
@@ -243,6 +322,8 @@ impl<'de> de::Deserialize<'de> for Checked<Property> {
                     "rotation" => Valid(Rotation),
                     "scale" => Valid(Scale),
                     "weights" => Valid(MorphTargetWeights),
+                    #[cfg(feature = "KHR_animation_pointer")]
+                    "pointer" => Valid(Pointer),
                     _ => Invalid,
                 })
             }
@@ -261,6 +342,8 @@ impl ser::Serialize for Property {
             Property::Rotation => "rotation",
             Property::Scale => "scale",
             Property::MorphTargetWeights => "weights",
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => "pointer",
         })
     }
 }
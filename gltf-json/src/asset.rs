@@ -1,6 +1,8 @@
 use gltf_derive::Validate;
+use serde::de;
 use serde_derive::{Serialize, Deserialize};
-use crate::{extensions, Extras};
+use std::{fmt, io};
+use crate::{extensions, Error, Extras};
 
 /// Metadata about the glTF asset.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
@@ -44,3 +46,49 @@ impl Default for Asset {
     }
 }
 
+
+impl Asset {
+    /// Reads the `asset` object from a stream of glTF JSON without parsing the
+    /// rest of the document.
+    ///
+    /// Top-level properties preceding `asset` are skipped over and reading
+    /// stops as soon as `asset` has been parsed, so the remainder of the stream
+    /// is neither read nor checked. The stream is read byte by byte, so large
+    /// files should be wrapped in a `BufReader`.
+    pub fn from_root_reader<R>(reader: R) -> Result<Self, Error>
+        where R: io::Read
+    {
+        struct Visitor<'a>(&'a mut Option<Asset>);
+
+        impl<'a, 'de> de::Visitor<'de> for Visitor<'a> {
+            type Value = ();
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a glTF root object")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<(), A::Error>
+                where A: de::MapAccess<'de>
+            {
+                while let Some(key) = map.next_key::<String>()? {
+                    if key == "asset" {
+                        *self.0 = Some(map.next_value()?);
+                        // Abandon parsing; the caller discards this error.
+                        return Err(de::Error::custom("stop"));
+                    }
+                    map.next_value::<de::IgnoredAny>()?;
+                }
+                Err(de::Error::missing_field("asset"))
+            }
+        }
+
+        let mut asset = None;
+        let mut deserializer = serde_json::Deserializer::from_reader(reader);
+        let result = de::Deserializer::deserialize_map(&mut deserializer, Visitor(&mut asset));
+        match (asset, result) {
+            (Some(asset), _) => Ok(asset),
+            (None, Err(err)) => Err(err),
+            (None, Ok(())) => Err(de::Error::missing_field("asset")),
+        }
+    }
+}
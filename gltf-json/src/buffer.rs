@@ -2,8 +2,8 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use std::fmt;
-use crate::validation::Checked;
-use crate::{extensions, Extras, Index};
+use crate::validation::{self, Checked, Validate};
+use crate::{extensions, Extras, Index, Path, Root};
 
 /// Corresponds to `GL_ARRAY_BUFFER`.
 pub const ARRAY_BUFFER: u32 = 34_962;
@@ -75,7 +75,7 @@ pub struct Buffer {
 ///
 /// <https://github.com/KhronosGroup/glTF/tree/master/specification/2.0#reference-bufferview>
 ///
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct View {
     /// The parent `Buffer`.
     pub buffer: Index<Buffer>,
@@ -114,6 +114,49 @@ pub struct View {
     pub extras: Extras,
 }
 
+impl Validate for View {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, validation::Error)
+    {
+        self.buffer.validate(root, || path().field("buffer"), report);
+        self.byte_length.validate(root, || path().field("byteLength"), report);
+        self.byte_offset.validate(root, || path().field("byteOffset"), report);
+        self.byte_stride.validate(root, || path().field("byteStride"), report);
+        self.target.validate(root, || path().field("target"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+
+        if let Some(buffer) = root.get(self.buffer) {
+            let end = u64::from(self.byte_offset.unwrap_or(0)) + u64::from(self.byte_length);
+            if end > u64::from(buffer.byte_length) {
+                report(&path, validation::Error::ByteRangeOutOfBounds);
+            }
+        }
+
+        // Decoders allocate the decompressed data up front, so it must be
+        // known to fit in the view before anything is decoded.
+        #[cfg(feature = "EXT_meshopt_compression")]
+        if let Some(meshopt) = self
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_meshopt_compression.as_ref())
+        {
+            let fits = meshopt
+                .decompressed_length()
+                .map_or(true, |length| length <= self.byte_length);
+            if !fits {
+                let path = || {
+                    path()
+                        .field("extensions")
+                        .field("extMeshoptCompression")
+                        .field("count")
+                };
+                report(&path, validation::Error::ByteRangeOutOfBounds);
+            }
+        }
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Checked<Target> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: de::Deserializer<'de>
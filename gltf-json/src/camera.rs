@@ -1,9 +1,8 @@
-use gltf_derive::Validate;
 use serde::{de, ser};
 use serde_derive::{Serialize, Deserialize};
 use std::fmt;
 use crate::validation::{Checked, Error, Validate};
-use crate::{extensions, Extras, Root, Path};
+use crate::{extensions, scene, Extras, Index, Root, Path};
 
 /// All valid camera types.
 pub const VALID_CAMERA_TYPES: &'static [&'static str] = &[
@@ -57,7 +56,7 @@ pub struct Camera {
 }
 
 /// Values for an orthographic camera.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Orthographic {
     /// The horizontal magnification of the view.
     pub xmag: f32,
@@ -82,7 +81,7 @@ pub struct Orthographic {
 }
 
 /// Values for a perspective camera.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Perspective {
     /// Aspect ratio of the field of view.
     #[serde(rename = "aspectRatio")]
@@ -125,6 +124,229 @@ impl Validate for Camera {
     }
 }
 
+impl Validate for Orthographic {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        if self.xmag == 0.0 {
+            report(&|| path().field("xmag"), Error::Invalid);
+        }
+        if self.ymag == 0.0 {
+            report(&|| path().field("ymag"), Error::Invalid);
+        }
+        if self.znear < 0.0 {
+            report(&|| path().field("znear"), Error::Invalid);
+        }
+        if self.zfar <= 0.0 || self.zfar <= self.znear {
+            report(&|| path().field("zfar"), Error::Invalid);
+        }
+
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+    }
+}
+
+impl Validate for Perspective {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        if let Some(aspect_ratio) = self.aspect_ratio {
+            if aspect_ratio <= 0.0 {
+                report(&|| path().field("aspectRatio"), Error::Invalid);
+            }
+        }
+        if self.yfov <= 0.0 {
+            report(&|| path().field("yfov"), Error::Invalid);
+        }
+        if self.znear <= 0.0 {
+            report(&|| path().field("znear"), Error::Invalid);
+        }
+        if let Some(zfar) = self.zfar {
+            if zfar <= self.znear {
+                report(&|| path().field("zfar"), Error::Invalid);
+            }
+        }
+
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+    }
+}
+
+/// Helper for adding a camera, along with the node that instantiates it, to a
+/// `Root`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use gltf_json::camera::CameraBuilder;
+/// let mut root = gltf_json::Root::default();
+/// let node = CameraBuilder::perspective(0.8, 0.1, Some(100.0))
+///     .translation([0.0, 1.0, 5.0])
+///     .build(&mut root)
+///     .unwrap();
+/// assert_eq!(root.nodes[node.value()].camera.unwrap().value(), 0);
+/// ```
+#[derive(Clone, Debug)]
+pub struct CameraBuilder {
+    /// The camera under construction.
+    camera: Camera,
+
+    /// The node that will instantiate the camera.
+    node: scene::Node,
+
+    /// The node to attach the camera node to as a child.
+    parent: Option<Index<scene::Node>>,
+
+    /// The scene to attach the camera node to as a root node.
+    scene: Option<Index<scene::Scene>>,
+}
+
+impl CameraBuilder {
+    /// Creates a builder for a perspective camera.
+    ///
+    /// `zfar` may be `None` for an infinite projection.
+    pub fn perspective(yfov: f32, znear: f32, zfar: Option<f32>) -> Self {
+        Self::new(Type::Perspective, None, Some(Perspective {
+            aspect_ratio: None,
+            yfov,
+            zfar,
+            znear,
+            extensions: None,
+            extras: Default::default(),
+        }))
+    }
+
+    /// Creates a builder for an orthographic camera.
+    pub fn orthographic(xmag: f32, ymag: f32, znear: f32, zfar: f32) -> Self {
+        Self::new(Type::Orthographic, Some(Orthographic {
+            xmag,
+            ymag,
+            zfar,
+            znear,
+            extensions: None,
+            extras: Default::default(),
+        }), None)
+    }
+
+    fn new(
+        type_: Type,
+        orthographic: Option<Orthographic>,
+        perspective: Option<Perspective>,
+    ) -> Self {
+        Self {
+            camera: Camera {
+                #[cfg(feature = "names")]
+                name: None,
+                orthographic,
+                perspective,
+                type_: Checked::Valid(type_),
+                extensions: None,
+                extras: Default::default(),
+            },
+            node: scene::Node {
+                camera: None,
+                children: None,
+                extensions: None,
+                extras: Default::default(),
+                matrix: None,
+                mesh: None,
+                #[cfg(feature = "names")]
+                name: None,
+                rotation: None,
+                scale: None,
+                translation: None,
+                skin: None,
+                weights: None,
+            },
+            parent: None,
+            scene: None,
+        }
+    }
+
+    /// Sets the aspect ratio of a perspective camera.
+    ///
+    /// Has no effect on orthographic cameras.
+    pub fn aspect_ratio(mut self, aspect_ratio: f32) -> Self {
+        if let Some(perspective) = self.camera.perspective.as_mut() {
+            perspective.aspect_ratio = Some(aspect_ratio);
+        }
+        self
+    }
+
+    /// Sets the user-defined name of both the camera and its node.
+    #[cfg(feature = "names")]
+    pub fn name(mut self, name: &str) -> Self {
+        self.camera.name = Some(name.to_string());
+        self.node.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the translation of the camera node.
+    pub fn translation(mut self, translation: [f32; 3]) -> Self {
+        self.node.translation = Some(translation);
+        self
+    }
+
+    /// Sets the rotation of the camera node as an `[x, y, z, w]` unit quaternion.
+    pub fn rotation(mut self, rotation: [f32; 4]) -> Self {
+        self.node.rotation = Some(scene::UnitQuaternion(rotation));
+        self
+    }
+
+    /// Attaches the camera node as a child of the given node.
+    pub fn parent(mut self, parent: Index<scene::Node>) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Attaches the camera node as a root node of the given scene.
+    pub fn scene(mut self, scene: Index<scene::Scene>) -> Self {
+        self.scene = Some(scene);
+        self
+    }
+
+    /// Validates the camera and adds it to `root` along with its node, returning
+    /// the index of the new node.
+    ///
+    /// `root` is left unmodified if validation fails.
+    pub fn build(
+        mut self,
+        root: &mut Root,
+    ) -> Result<Index<scene::Node>, Vec<(Path, Error)>> {
+        let mut errors = Vec::new();
+        let camera_path = || Path::new().field("cameras").index(root.cameras.len());
+        self.camera.validate(root, camera_path, &mut |path, error| {
+            errors.push((path(), error))
+        });
+        if let Some(parent) = self.parent {
+            parent.validate(root, || Path::new().field("nodes"), &mut |path, error| {
+                errors.push((path(), error))
+            });
+        }
+        if let Some(scene) = self.scene {
+            scene.validate(root, || Path::new().field("scenes"), &mut |path, error| {
+                errors.push((path(), error))
+            });
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        self.node.camera = Some(root.push(self.camera));
+        let node = root.push(self.node);
+        if let Some(parent) = self.parent {
+            root.nodes[parent.value()]
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(node);
+        }
+        if let Some(scene) = self.scene {
+            root.scenes[scene.value()].nodes.push(node);
+        }
+        Ok(node)
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Checked<Type> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: de::Deserializer<'de>
@@ -3,6 +3,10 @@ use std::collections::HashMap;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::value::Value;
+#[cfg(feature = "KHR_animation_pointer")]
+use crate::validation::{Error, Validate};
+#[cfg(feature = "KHR_animation_pointer")]
+use crate::{Path, Root};
 
 /// A keyframe animation.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -21,13 +25,46 @@ pub struct Channel {
 /// The index of the node and TRS property that an animation channel targets.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Target {
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[serde(
+        default,
+        rename = "KHR_animation_pointer",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_animation_pointer: Option<AnimationPointer>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
 
+/// Targets an arbitrary property of the asset with a JSON pointer, such as
+/// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+///
+/// The target's `path` must be `"pointer"` and its `node` undefined.
+#[cfg(feature = "KHR_animation_pointer")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct AnimationPointer {
+    /// The JSON pointer to the animated property.
+    pub pointer: String,
+}
+
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Sampler {
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
+
+#[cfg(feature = "KHR_animation_pointer")]
+impl Validate for AnimationPointer {
+    fn validate<P, R>(&self, _: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        // JSON pointers into the asset are absolute.
+        if !self.pointer.starts_with('/') {
+            report(&|| path().field("pointer"), Error::Invalid);
+        }
+    }
+}
@@ -3,17 +3,255 @@ use std::collections::HashMap;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::value::Value;
+#[cfg(feature = "EXT_meshopt_compression")]
+use crate::validation::{Checked, Error, Validate};
+#[cfg(feature = "EXT_meshopt_compression")]
+use crate::{buffer, Index, Path, Root};
+#[cfg(feature = "EXT_meshopt_compression")]
+use serde::{de, ser};
+#[cfg(feature = "EXT_meshopt_compression")]
+use std::fmt;
+
+/// All valid meshopt compression modes.
+#[cfg(feature = "EXT_meshopt_compression")]
+pub const VALID_MESHOPT_MODES: &[&str] = &[
+    "ATTRIBUTES",
+    "TRIANGLES",
+    "INDICES",
+];
+
+/// All valid meshopt compression filters.
+#[cfg(feature = "EXT_meshopt_compression")]
+pub const VALID_MESHOPT_FILTERS: &[&str] = &[
+    "NONE",
+    "OCTAHEDRAL",
+    "QUATERNION",
+    "EXPONENTIAL",
+];
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Buffer {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_meshopt_compression: Option<MeshoptBuffer>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
 
+/// Marks a buffer that only holds the decompressed data of meshopt compressed
+/// buffer views.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct MeshoptBuffer {
+    /// Whether the buffer has no data of its own. A fallback buffer may have no
+    /// URI and is allocated by the importer.
+    #[serde(default)]
+    pub fallback: bool,
+}
+
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct View {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(
+        default,
+        rename = "EXT_meshopt_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_meshopt_compression: Option<MeshoptCompression>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
+
+/// Meshopt compressed data of a buffer view.
+///
+/// The buffer view itself describes the decompressed data.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeshoptCompression {
+    /// The buffer holding the compressed data.
+    pub buffer: Index<buffer::Buffer>,
+
+    /// The offset of the compressed data in bytes.
+    #[serde(default)]
+    pub byte_offset: u32,
+
+    /// The length of the compressed data in bytes.
+    pub byte_length: u32,
+
+    /// The stride of the decompressed elements in bytes.
+    pub byte_stride: u32,
+
+    /// The number of decompressed elements.
+    pub count: u32,
+
+    /// The compression mode.
+    pub mode: Checked<MeshoptMode>,
+
+    /// The filter applied to the decompressed elements.
+    #[serde(default)]
+    pub filter: Checked<MeshoptFilter>,
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl MeshoptCompression {
+    /// Returns the length of the decompressed data in bytes, or `None` if it
+    /// overflows.
+    pub fn decompressed_length(&self) -> Option<u32> {
+        self.count.checked_mul(self.byte_stride)
+    }
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl Validate for MeshoptCompression {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        self.buffer.validate(root, || path().field("buffer"), report);
+        self.mode.validate(root, || path().field("mode"), report);
+        self.filter.validate(root, || path().field("filter"), report);
+        if let Some(buffer) = root.get(self.buffer) {
+            let end = u64::from(self.byte_offset) + u64::from(self.byte_length);
+            if end > u64::from(buffer.byte_length) {
+                report(&path, Error::ByteRangeOutOfBounds);
+            }
+        }
+        // The decompressed length is checked against the length of the
+        // buffer view by `buffer::View::validate`.
+        if self.decompressed_length().is_none() {
+            report(&|| path().field("count"), Error::ByteRangeOutOfBounds);
+        }
+    }
+}
+
+/// Specifies how meshopt compressed data is encoded.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MeshoptMode {
+    /// Vertex attributes, or other interleaved elements.
+    Attributes = 1,
+
+    /// Triangle list indices.
+    Triangles,
+
+    /// Indices of other primitives.
+    Indices,
+}
+
+/// Specifies the filter applied to meshopt decompressed elements.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MeshoptFilter {
+    /// No filter.
+    #[default]
+    None = 1,
+
+    /// Octahedral encoding of unit vectors.
+    Octahedral,
+
+    /// Encoding of unit quaternions by their three smallest components.
+    Quaternion,
+
+    /// Exponential encoding of floating point values.
+    Exponential,
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl<'de> de::Deserialize<'de> for Checked<MeshoptMode> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: de::Deserializer<'de>
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Checked<MeshoptMode>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "any of: {:?}", VALID_MESHOPT_MODES)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where E: de::Error
+            {
+                use self::MeshoptMode::*;
+                use crate::validation::Checked::*;
+                Ok(match value {
+                    "ATTRIBUTES" => Valid(Attributes),
+                    "TRIANGLES" => Valid(Triangles),
+                    "INDICES" => Valid(Indices),
+                    _ => Invalid,
+                })
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl ser::Serialize for MeshoptMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer
+    {
+        serializer.serialize_str(match *self {
+            MeshoptMode::Attributes => "ATTRIBUTES",
+            MeshoptMode::Triangles => "TRIANGLES",
+            MeshoptMode::Indices => "INDICES",
+        })
+    }
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl<'de> de::Deserialize<'de> for Checked<MeshoptFilter> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where D: de::Deserializer<'de>
+    {
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Checked<MeshoptFilter>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "any of: {:?}", VALID_MESHOPT_FILTERS)
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                where E: de::Error
+            {
+                use self::MeshoptFilter::*;
+                use crate::validation::Checked::*;
+                Ok(match value {
+                    "NONE" => Valid(None),
+                    "OCTAHEDRAL" => Valid(Octahedral),
+                    "QUATERNION" => Valid(Quaternion),
+                    "EXPONENTIAL" => Valid(Exponential),
+                    _ => Invalid,
+                })
+            }
+        }
+        deserializer.deserialize_str(Visitor)
+    }
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl ser::Serialize for MeshoptFilter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer
+    {
+        serializer.serialize_str(match *self {
+            MeshoptFilter::None => "NONE",
+            MeshoptFilter::Octahedral => "OCTAHEDRAL",
+            MeshoptFilter::Quaternion => "QUATERNION",
+            MeshoptFilter::Exponential => "EXPONENTIAL",
+        })
+    }
+}
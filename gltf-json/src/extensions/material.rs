@@ -4,13 +4,18 @@ use std::collections::HashMap;
 use crate::material::StrengthFactor;
 #[cfg(any(
     feature = "KHR_materials_pbrSpecularGlossiness",
-    feature = "KHR_materials_transmission"
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_volume",
+    feature = "KHR_materials_specular"
 ))]
 use crate::texture;
 #[cfg(any(
     feature = "KHR_materials_pbrSpecularGlossiness",
     feature = "KHR_materials_transmission",
-    feature = "KHR_materials_ior"
+    feature = "KHR_materials_ior",
+    feature = "KHR_materials_volume",
+    feature = "KHR_materials_emissive_strength",
+    feature = "KHR_materials_specular"
 ))]
 use crate::{validation::Validate, Extras};
 use gltf_derive::Validate;
@@ -52,6 +57,30 @@ pub struct Material {
     )]
     pub ior: Option<Ior>,
 
+    #[cfg(feature = "KHR_materials_volume")]
+    #[serde(
+        default,
+        rename = "KHR_materials_volume",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub volume: Option<Volume>,
+
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[serde(
+        default,
+        rename = "KHR_materials_emissive_strength",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub emissive_strength: Option<EmissiveStrength>,
+
+    #[cfg(feature = "KHR_materials_specular")]
+    #[serde(
+        default,
+        rename = "KHR_materials_specular",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub specular: Option<Specular>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
@@ -117,11 +146,27 @@ pub struct PbrSpecularGlossiness {
 
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct NormalTexture {}
+pub struct NormalTexture {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(
+        default,
+        rename = "KHR_texture_transform",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_transform: Option<super::texture::TextureTransform>,
+}
 
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct OcclusionTexture {}
+pub struct OcclusionTexture {
+    #[cfg(feature = "KHR_texture_transform")]
+    #[serde(
+        default,
+        rename = "KHR_texture_transform",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_transform: Option<super::texture::TextureTransform>,
+}
 
 /// The diffuse factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -229,3 +274,146 @@ pub struct Ior {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     pub extras: Extras,
 }
+
+/// A non-negative number with a default value of 0.0.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct ThicknessFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Validate for ThicknessFactor {}
+
+/// An RGB color with a default value of `[1.0, 1.0, 1.0]`.
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct AttenuationColor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Default for AttenuationColor {
+    fn default() -> Self {
+        AttenuationColor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+impl Validate for AttenuationColor {}
+
+#[cfg(feature = "KHR_materials_volume")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Volume {
+    /// The thickness of the volume beneath the surface.
+    ///
+    /// The value is given in the coordinate space of the mesh. If the value is
+    /// 0 the material is thin-walled. Otherwise the material is a volume
+    /// boundary.
+    pub thickness_factor: ThicknessFactor,
+
+    /// A texture that defines the thickness, stored in the G channel.
+    ///
+    /// The value is multiplied by the thicknessFactor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thickness_texture: Option<texture::Info>,
+
+    /// The average distance that light travels in the medium before interacting
+    /// with a particle, in world space.
+    ///
+    /// `None` means that the distance is infinite.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attenuation_distance: Option<f32>,
+
+    /// The color that white light turns into due to absorption when reaching
+    /// the attenuation distance.
+    pub attenuation_color: AttenuationColor,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A non-negative number with a default value of 1.0.
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct EmissiveStrengthFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Default for EmissiveStrengthFactor {
+    fn default() -> Self {
+        EmissiveStrengthFactor(1.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Validate for EmissiveStrengthFactor {}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EmissiveStrength {
+    /// The strength adjustment to be multiplied with the material's emissive
+    /// value.
+    pub emissive_strength: EmissiveStrengthFactor,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 1.0.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct SpecularFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Default for SpecularFactor {
+    fn default() -> Self {
+        SpecularFactor(1.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Validate for SpecularFactor {}
+
+/// A linear RGB color with a default value of `[1.0, 1.0, 1.0]`.
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct SpecularColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Default for SpecularColorFactor {
+    fn default() -> Self {
+        SpecularColorFactor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+impl Validate for SpecularColorFactor {}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Specular {
+    /// The strength of the specular reflection.
+    pub specular_factor: SpecularFactor,
+
+    /// A texture that defines the strength of the specular reflection, stored
+    /// in the A channel.
+    ///
+    /// The value is multiplied by the specularFactor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specular_texture: Option<texture::Info>,
+
+    /// The F0 color of the specular reflection, in linear RGB.
+    pub specular_color_factor: SpecularColorFactor,
+
+    /// A texture that defines the F0 color of the specular reflection, stored
+    /// in the RGB channels and encoded in sRGB.
+    ///
+    /// The value is multiplied by the specularColorFactor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub specular_color_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
@@ -3,6 +3,10 @@ use std::collections::HashMap;
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::value::Value;
+#[cfg(feature = "KHR_draco_mesh_compression")]
+use crate::{buffer, mesh::Semantic, validation::Checked};
+#[cfg(any(feature = "KHR_draco_mesh_compression", feature = "KHR_materials_variants"))]
+use crate::Index;
 
 /// A set of primitives to be rendered.
 ///
@@ -17,6 +21,70 @@ pub struct Mesh {
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Primitive {
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[serde(
+        default,
+        rename = "KHR_draco_mesh_compression",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_draco_mesh_compression: Option<DracoMeshCompression>,
+
+    #[cfg(feature = "KHR_materials_variants")]
+    #[serde(
+        default,
+        rename = "KHR_materials_variants",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_materials_variants: Option<KhrMaterialsVariants>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
+
+/// Draco compressed geometry of a primitive.
+///
+/// The accessors of the primitive describe the decompressed data and have no
+/// buffer views of their own.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DracoMeshCompression {
+    /// The buffer view holding the compressed data.
+    pub buffer_view: Index<buffer::View>,
+
+    /// Maps each attribute semantic of the primitive to the unique id of the
+    /// attribute in the compressed data.
+    pub attributes: crate::OrderedMap<Checked<Semantic>, u32>,
+}
+
+/// The materials of a primitive for the variants of
+/// `extensions::root::KhrMaterialsVariants`.
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct KhrMaterialsVariants {
+    /// Maps variants to the material of the primitive.
+    ///
+    /// Primitives keep their default material for variants without a mapping.
+    pub mappings: Vec<Mapping>,
+}
+
+/// Assigns a material to a primitive for one or more variants.
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct Mapping {
+    /// The material of the primitive for these variants.
+    pub material: Index<crate::Material>,
+
+    /// The variants that use the material.
+    pub variants: Vec<Index<crate::extensions::root::Variant>>,
+
+    /// Optional user-defined name for this mapping.
+    #[cfg(feature = "names")]
+    #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+    pub name: Option<String>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: crate::Extras,
+}
@@ -50,6 +50,24 @@ pub const ENABLED_EXTENSIONS: &'static [&'static str] = &[
     "KHR_materials_transmission",
     #[cfg(feature = "KHR_materials_ior")]
     "KHR_materials_ior",
+    #[cfg(feature = "KHR_materials_volume")]
+    "KHR_materials_volume",
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    "KHR_materials_emissive_strength",
+    #[cfg(feature = "KHR_materials_specular")]
+    "KHR_materials_specular",
+    #[cfg(feature = "KHR_materials_variants")]
+    "KHR_materials_variants",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "KHR_texture_basisu",
+    #[cfg(feature = "EXT_meshopt_compression")]
+    "EXT_meshopt_compression",
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    "EXT_mesh_gpu_instancing",
+    #[cfg(feature = "EXT_texture_webp")]
+    "EXT_texture_webp",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "KHR_animation_pointer",
 ];
 
 /// Names of glTF 2.0 extensions supported by the library.
@@ -60,4 +78,13 @@ pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &[
     "KHR_texture_transform",
     "KHR_materials_transmission",
     "KHR_materials_ior",
+    "KHR_materials_volume",
+    "KHR_materials_emissive_strength",
+    "KHR_materials_specular",
+    "KHR_materials_variants",
+    "KHR_texture_basisu",
+    "EXT_meshopt_compression",
+    "EXT_mesh_gpu_instancing",
+    "EXT_texture_webp",
+    "KHR_animation_pointer",
 ];
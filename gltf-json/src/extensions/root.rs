@@ -15,6 +15,14 @@ pub struct Root {
     )]
     pub khr_lights_punctual: Option<KhrLightsPunctual>,
 
+    #[cfg(feature = "KHR_materials_variants")]
+    #[serde(
+        default,
+        rename = "KHR_materials_variants",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub khr_materials_variants: Option<KhrMaterialsVariants>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
@@ -43,3 +51,38 @@ impl crate::root::Get<crate::extensions::scene::khr_lights_punctual::Light> for
         }
     }
 }
+
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct KhrMaterialsVariants {
+    /// The material variants of the asset.
+    pub variants: Vec<Variant>,
+}
+
+/// A named set of material assignments, such as a colorway of a product.
+///
+/// Primitives select their material for a variant with the mappings of
+/// `extensions::mesh::KhrMaterialsVariants`.
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct Variant {
+    /// The name of the variant.
+    pub name: String,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: crate::Extras,
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+impl crate::root::Get<Variant> for crate::Root {
+    fn get(&self, id: crate::Index<Variant>) -> Option<&Variant> {
+        self.extensions
+            .as_ref()?
+            .khr_materials_variants
+            .as_ref()?
+            .variants
+            .get(id.value())
+    }
+}
@@ -24,6 +24,14 @@ pub struct Node {
     )]
     pub khr_lights_punctual: Option<khr_lights_punctual::KhrLightsPunctual>,
 
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[serde(
+        default,
+        rename = "EXT_mesh_gpu_instancing",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ext_mesh_gpu_instancing: Option<ext_mesh_gpu_instancing::ExtMeshGpuInstancing>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
@@ -205,6 +213,53 @@ pub mod khr_lights_punctual {
     }
 }
 
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing {
+    use crate::validation::{Error, Validate};
+    use crate::{Accessor, Extras, Index, Path, Root};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    /// Draws the mesh of a node once per instance, with one transform per
+    /// instance given by the instance attributes.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct ExtMeshGpuInstancing {
+        /// The accessors of the instance attributes, keyed by attribute name:
+        /// `TRANSLATION`, `ROTATION`, `SCALE`, or an application specific name
+        /// starting with an underscore.
+        pub attributes: HashMap<String, Index<Accessor>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for ExtMeshGpuInstancing {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            self.attributes.validate(root, || path().field("attributes"), report);
+
+            // Every instance attribute holds one element per instance.
+            let mut count = None;
+            for (name, index) in &self.attributes {
+                let accessor = match root.get(*index) {
+                    Some(accessor) => accessor,
+                    None => continue,
+                };
+                match count {
+                    Some(count) if count != accessor.count => {
+                        report(&|| path().field("attributes").key(name), Error::Invalid);
+                    },
+                    _ => count = Some(accessor.count),
+                }
+            }
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+}
+
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Scene {
@@ -5,6 +5,8 @@ use crate::{extras::Extras, validation::Validate};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 use serde_json::value::Value;
+#[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp"))]
+use crate::{image, Index};
 
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -16,10 +18,49 @@ pub struct Sampler {
 /// A texture and its sampler.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 pub struct Texture {
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[serde(
+        default,
+        rename = "KHR_texture_basisu",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_basisu: Option<TextureBasisu>,
+
+    #[cfg(feature = "EXT_texture_webp")]
+    #[serde(
+        default,
+        rename = "EXT_texture_webp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub texture_webp: Option<TextureWebp>,
+
     #[serde(default, flatten)]
     pub others: HashMap<String, Value>,
 }
 
+/// Supercompressed GPU textures in the KTX2 container with Basis Universal
+/// payloads.
+///
+/// Clients without support for the extension fall back to the image of the
+/// texture's `source` property, if any.
+#[cfg(feature = "KHR_texture_basisu")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureBasisu {
+    /// The index of the KTX2 image used by this texture.
+    pub source: Index<image::Image>,
+}
+
+/// Textures in the WebP image format.
+///
+/// Clients without support for the extension fall back to the image of the
+/// texture's `source` property, if any.
+#[cfg(feature = "EXT_texture_webp")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureWebp {
+    /// The index of the WebP image used by this texture.
+    pub source: Index<image::Image>,
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {
@@ -13,6 +13,267 @@ pub type Extras = Option<::std::boxed::Box<RawValue>>;
 #[cfg(not(feature = "extras"))]
 pub type Extras = Void;
 
+/// The key in `extras` under which `Taggable` objects record their tags.
+#[cfg(feature = "extras")]
+pub const TAGS_EXTRAS_KEY: &str = "tags";
+
+/// Objects that carry tags, recorded as an array of strings under the `"tags"`
+/// key of their `extras`.
+///
+/// Tags let pipeline stages pass flags such as `"collider"` or `"lod0"` between
+/// tools through the asset itself. Tags are unique and kept in the order they
+/// were added; any other data in `extras` is preserved.
+///
+/// ```
+/// use gltf_json::extras::Taggable;
+///
+/// let mut node: gltf_json::Node = gltf_json::deserialize::from_str("{}").unwrap();
+/// assert!(node.add_tag("collider").unwrap());
+/// assert!(!node.add_tag("collider").unwrap());
+/// assert!(node.has_tag("collider"));
+/// assert_eq!(node.extras.as_ref().unwrap().get(), r#"{"tags":["collider"]}"#);
+/// ```
+#[cfg(feature = "extras")]
+pub trait Taggable {
+    /// Returns the `extras` of the object.
+    fn extras(&self) -> &Extras;
+
+    /// Returns the `extras` of the object for modification.
+    fn extras_mut(&mut self) -> &mut Extras;
+
+    /// Returns the tags of the object.
+    ///
+    /// Returns no tags if `extras` is absent or holds no array of strings under
+    /// the `"tags"` key.
+    fn tags(&self) -> Vec<String> {
+        get(self.extras(), TAGS_EXTRAS_KEY)
+            .and_then(|tags| serde_json::from_value(tags).ok())
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the object carries the given tag.
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags().iter().any(|x| x == tag)
+    }
+
+    /// Adds a tag to the object, returning `false` if it was already present.
+    ///
+    /// Fails if `extras` is present but is not a JSON object.
+    fn add_tag(&mut self, tag: &str) -> Result<bool, crate::Error> {
+        let mut tags = self.tags();
+        if tags.iter().any(|x| x == tag) {
+            return Ok(false);
+        }
+        tags.push(tag.to_string());
+        let tags = if tags.is_empty() { None } else { Some(crate::Value::from(tags)) };
+        set(self.extras_mut(), TAGS_EXTRAS_KEY, tags)?;
+        Ok(true)
+    }
+
+    /// Removes a tag from the object, returning `false` if it was not present.
+    ///
+    /// The `"tags"` key is removed along with the last tag. Fails if `extras`
+    /// is present but is not a JSON object.
+    fn remove_tag(&mut self, tag: &str) -> Result<bool, crate::Error> {
+        let mut tags = self.tags();
+        let count = tags.len();
+        tags.retain(|x| x != tag);
+        if tags.len() == count {
+            return Ok(false);
+        }
+        let tags = if tags.is_empty() { None } else { Some(crate::Value::from(tags)) };
+        set(self.extras_mut(), TAGS_EXTRAS_KEY, tags)?;
+        Ok(true)
+    }
+}
+
+/// Returns the value under the given key of `extras`, if `extras` is a JSON
+/// object with that key.
+#[cfg(feature = "extras")]
+pub fn get(extras: &Extras, key: &str) -> Option<crate::Value> {
+    let mut value: crate::Value = serde_json::from_str(extras.as_ref()?.get()).ok()?;
+    Some(value.get_mut(key)?.take())
+}
+
+/// Records a value under the given key of `extras`, or removes the key if
+/// `value` is `None`.
+///
+/// Any other data in `extras` is preserved, and `extras` is cleared when no
+/// keys remain. Fails if `extras` is present but is not a JSON object.
+#[cfg(feature = "extras")]
+pub fn set(
+    extras: &mut Extras,
+    key: &str,
+    value: Option<crate::Value>,
+) -> Result<(), crate::Error> {
+    use serde::de::Error;
+    let mut map = match extras.as_ref() {
+        Some(extras) => match serde_json::from_str(extras.get())? {
+            crate::Value::Object(map) => map,
+            _ => return Err(crate::Error::custom("extras is not an object")),
+        },
+        None => serde_json::Map::new(),
+    };
+    match value {
+        Some(value) => map.insert(key.to_string(), value),
+        None => map.remove(key),
+    };
+    *extras = if map.is_empty() {
+        None
+    } else {
+        Some(serde_json::value::to_raw_value(&map)?)
+    };
+    Ok(())
+}
+
+#[cfg(feature = "extras")]
+impl Taggable for crate::Mesh {
+    fn extras(&self) -> &Extras {
+        &self.extras
+    }
+
+    fn extras_mut(&mut self) -> &mut Extras {
+        &mut self.extras
+    }
+}
+
+#[cfg(feature = "extras")]
+impl Taggable for crate::Node {
+    fn extras(&self) -> &Extras {
+        &self.extras
+    }
+
+    fn extras_mut(&mut self) -> &mut Extras {
+        &mut self.extras
+    }
+}
+
+/// The key in `extras` under which `assign_stable_ids` records the identifier
+/// of an object.
+#[cfg(feature = "extras")]
+pub const STABLE_ID_EXTRAS_KEY: &str = "stableId";
+
+/// Returns the identifier recorded under `STABLE_ID_EXTRAS_KEY`, if any.
+#[cfg(feature = "extras")]
+pub fn stable_id(extras: &Extras) -> Option<String> {
+    get(extras, STABLE_ID_EXTRAS_KEY)?.as_str().map(String::from)
+}
+
+/// Returns the FNV-1a hash of `bytes` from the given offset basis.
+#[cfg(feature = "extras")]
+fn fnv1a(basis: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(basis, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x100_0000_01b3))
+}
+
+/// Derives a UUID, of version 8 as its bits are not random, from the kind and
+/// key of an object and the number of objects of the same kind and key before
+/// it.
+#[cfg(feature = "extras")]
+fn derive_id(kind: &str, key: &str, occurrence: usize) -> String {
+    let bytes = format!("{}\0{}\0{}", kind, key, occurrence).into_bytes();
+    let high = fnv1a(0xcbf2_9ce4_8422_2325, &bytes);
+    let low = fnv1a(0x6c62_272e_07bb_0142, &bytes);
+    format!(
+        "{:08x}-{:04x}-8{:03x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        high & 0xfff,
+        0x8000 | (low >> 48) & 0x3fff,
+        low & 0xffff_ffff_ffff,
+    )
+}
+
+/// Records a stable identifier in the `extras` of every scene, node, mesh,
+/// material, texture, image, camera, skin, and animation that has none,
+/// returning the number of identifiers added.
+///
+/// Identifiers let engines track objects across re-exports of an asset, where
+/// indices change. Each identifier is a UUID derived from the kind of the
+/// object and its name, or if it has no name, its content other than `extras`.
+/// An object that keeps its name, or its content, is thus given the same
+/// identifier by every export, and identifiers already recorded, such as by
+/// the authoring tool, are kept as they are. Objects of the same kind and key
+/// are told apart by their order, and no identifier is given out twice.
+///
+/// Fails if the `extras` of an object are present but not a JSON object.
+///
+/// ```
+/// # use gltf_json as json;
+/// let mut root: json::Root = json::deserialize::from_str(r#"{
+///     "asset": {"version": "2.0"},
+///     "nodes": [{"name": "Hero"}, {"extras": {"stableId": "hero-camera"}}]
+/// }"#).unwrap();
+/// assert_eq!(json::extras::assign_stable_ids(&mut root).unwrap(), 1);
+/// let id = json::extras::stable_id(&root.nodes[0].extras).unwrap();
+/// assert_eq!(id.len(), 36);
+/// assert_eq!(json::extras::stable_id(&root.nodes[1].extras).unwrap(), "hero-camera");
+/// ```
+#[cfg(feature = "extras")]
+pub fn assign_stable_ids(root: &mut crate::Root) -> Result<usize, crate::Error> {
+    use std::collections::{HashMap, HashSet};
+
+    macro_rules! for_each_kind {
+        ($m:ident) => {
+            $m!(scenes, "scene");
+            $m!(nodes, "node");
+            $m!(meshes, "mesh");
+            $m!(materials, "material");
+            $m!(textures, "texture");
+            $m!(images, "image");
+            $m!(cameras, "camera");
+            $m!(skins, "skin");
+            $m!(animations, "animation");
+        };
+    }
+
+    let mut used = HashSet::new();
+    macro_rules! collect {
+        ($field:ident, $kind:expr) => {
+            used.extend(root.$field.iter().filter_map(|x| stable_id(&x.extras)));
+        };
+    }
+    for_each_kind!(collect);
+
+    let mut added = 0;
+    macro_rules! assign {
+        ($field:ident, $kind:expr) => {
+            let mut occurrences = HashMap::new();
+            for object in root.$field.iter_mut() {
+                if stable_id(&object.extras).is_some() {
+                    continue;
+                }
+                #[cfg(feature = "names")]
+                let name = object.name.clone();
+                #[cfg(not(feature = "names"))]
+                let name: Option<String> = None;
+                let key = match name {
+                    Some(name) => format!("name:{}", name),
+                    None => {
+                        let mut content = serde_json::to_value(&*object)?;
+                        if let Some(map) = content.as_object_mut() {
+                            map.remove("extras");
+                        }
+                        format!("content:{}", content)
+                    },
+                };
+                let occurrence = occurrences.entry(key.clone()).or_insert(0);
+                let mut id = derive_id($kind, &key, *occurrence);
+                while used.contains(&id) {
+                    *occurrence += 1;
+                    id = derive_id($kind, &key, *occurrence);
+                }
+                *occurrence += 1;
+                let value = Some(crate::Value::from(id.clone()));
+                set(&mut object.extras, STABLE_ID_EXTRAS_KEY, value)?;
+                used.insert(id);
+                added += 1;
+            }
+        };
+    }
+    for_each_kind!(assign);
+    Ok(added)
+}
+
 /// Type representing no user-defined data.
 #[derive(Clone, Default, Serialize, Deserialize, Validate)]
 pub struct Void {
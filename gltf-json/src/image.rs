@@ -7,6 +7,10 @@ use crate::{buffer, extensions, Extras, Index};
 pub const VALID_MIME_TYPES: &'static [&'static str] = &[
     "image/jpeg",
     "image/png",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "image/ktx2",
+    #[cfg(feature = "EXT_texture_webp")]
+    "image/webp",
 ];
 
 /// Image data used to create a texture.
@@ -17,12 +17,18 @@ pub mod camera;
 /// 2.0 extensions supported by the library.
 pub mod extensions;
 
-/// Contains `Extras`.
+/// Contains `Extras` and the `Taggable` tagging layer.
 pub mod extras;
 
 /// Contains `Image` and other related data structures.
 pub mod image;
 
+/// Contains `Limits` for guarding against untrusted JSON.
+pub mod limits;
+
+/// Contains `OrderedMap`, the map type of attributes.
+pub mod map;
+
 /// Contains `Material` and other related data structures.
 pub mod material;
 
@@ -35,9 +41,15 @@ pub mod path;
 /// Contains `Root`.
 pub mod root;
 
+/// Contains `sanitize` for removing identifying metadata.
+pub mod sanitize;
+
 /// Contains `Scene`, `Node`, and other related data structures.
 pub mod scene;
 
+/// Contains `Spans` for locating objects within JSON text.
+pub mod span;
+
 /// Contains `Skin` and other related data structures.
 pub mod skin;
 
@@ -74,6 +86,9 @@ pub use texture::Texture;
 
 #[doc(inline)]
 pub use self::extras::Extras;
+#[doc(inline)]
+pub use self::map::OrderedMap;
+
 #[doc(inline)]
 pub use self::path::Path;
 #[doc(inline)]
@@ -0,0 +1,142 @@
+use std::fmt;
+
+/// Limits on the structure of glTF JSON, for guarding against untrusted input.
+///
+/// The default limits match the nesting depth permitted by `serde_json` and
+/// place no bound on string or array lengths.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Limits {
+    /// The maximum nesting depth of arrays and objects.
+    pub max_depth: usize,
+
+    /// The maximum length of a string, including object keys, in bytes of
+    /// JSON text.
+    pub max_string_length: usize,
+
+    /// The maximum number of elements in an array.
+    pub max_array_length: usize,
+}
+
+/// Represents a breach of the configured `Limits`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// Arrays and objects are nested more deeply than `max_depth`.
+    Depth {
+        /// Byte offset of the container exceeding the limit.
+        offset: usize,
+    },
+
+    /// A string is longer than `max_string_length`.
+    StringLength {
+        /// Byte offset of the opening quote of the string.
+        offset: usize,
+    },
+
+    /// An array has more elements than `max_array_length`.
+    ArrayLength {
+        /// Byte offset of the first element exceeding the limit.
+        offset: usize,
+    },
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_depth: 128,
+            max_string_length: usize::MAX,
+            max_array_length: usize::MAX,
+        }
+    }
+}
+
+/// An array or object being scanned.
+struct Container {
+    /// Whether the container is an array.
+    array: bool,
+
+    /// The number of elements seen so far, if an array.
+    len: usize,
+
+    /// Whether the current array element has been counted.
+    counted: bool,
+}
+
+impl Limits {
+    /// Checks JSON text against the limits without deserializing it.
+    ///
+    /// Syntax errors are not reported here; they are left to the parser run
+    /// afterwards.
+    pub fn check(&self, json: &[u8]) -> Result<(), Error> {
+        let mut stack: Vec<Container> = Vec::new();
+        let mut string_start = None;
+        let mut escaped = false;
+        for (offset, &byte) in json.iter().enumerate() {
+            if let Some(start) = string_start {
+                if escaped {
+                    escaped = false;
+                } else if byte == b'\\' {
+                    escaped = true;
+                } else if byte == b'"' {
+                    string_start = None;
+                }
+                if string_start.is_some() && offset - start > self.max_string_length {
+                    return Err(Error::StringLength { offset: start });
+                }
+                continue;
+            }
+            match byte {
+                b' ' | b'\t' | b'\n' | b'\r' | b':' => continue,
+                b',' => {
+                    if let Some(top) = stack.last_mut() {
+                        top.counted = false;
+                    }
+                    continue;
+                },
+                b']' | b'}' => {
+                    stack.pop();
+                    continue;
+                },
+                _ => {},
+            }
+            // `byte` begins a value or an object key.
+            if let Some(top) = stack.last_mut() {
+                if top.array && !top.counted {
+                    top.counted = true;
+                    top.len += 1;
+                    if top.len > self.max_array_length {
+                        return Err(Error::ArrayLength { offset });
+                    }
+                }
+            }
+            match byte {
+                b'[' | b'{' => {
+                    if stack.len() >= self.max_depth {
+                        return Err(Error::Depth { offset });
+                    }
+                    stack.push(Container { array: byte == b'[', len: 0, counted: false });
+                },
+                b'"' => string_start = Some(offset),
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Depth { offset } => {
+                write!(f, "nesting depth limit exceeded at byte {}", offset)
+            },
+            Error::StringLength { offset } => {
+                write!(f, "string length limit exceeded by string at byte {}", offset)
+            },
+            Error::ArrayLength { offset } => {
+                write!(f, "array length limit exceeded at byte {}", offset)
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,387 @@
+use serde::{de, ser};
+use std::collections::hash_map::{Entry, HashMap};
+use std::hash::Hash;
+use std::{fmt, iter, marker, slice, vec};
+
+use crate::validation::{Error, Validate};
+use crate::{Path, Root};
+
+/// A map that keeps its entries in the order they were inserted, which for
+/// deserialized maps is the order they are declared in the JSON.
+///
+/// Serializing the map writes its entries in the same order, so reading and
+/// writing an asset never reorders its attributes. Lookups are linear, which
+/// suits the few entries of an attribute map.
+///
+/// # Examples
+///
+/// ```rust
+/// # use gltf_json::OrderedMap;
+/// let map: OrderedMap<String, u32> =
+///     gltf_json::deserialize::from_str(r#"{"b": 0, "a": 1}"#).unwrap();
+/// let keys: Vec<_> = map.keys().map(String::as_str).collect();
+/// assert_eq!(keys, ["b", "a"]);
+/// assert_eq!(gltf_json::serialize::to_string(&map).unwrap(), r#"{"b":0,"a":1}"#);
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct OrderedMap<K, V> {
+    /// The entries in insertion order.
+    entries: Vec<(K, V)>,
+}
+
+/// An `Iterator` that visits the entries of an `OrderedMap` in order.
+#[derive(Clone, Debug)]
+pub struct Iter<'a, K, V> {
+    /// The internal entry iterator.
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+/// An `Iterator` that visits the entries of an `OrderedMap` in order, with
+/// mutable values.
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    /// The internal entry iterator.
+    iter: slice::IterMut<'a, (K, V)>,
+}
+
+/// An `Iterator` that visits the keys of an `OrderedMap` in order.
+#[derive(Clone, Debug)]
+pub struct Keys<'a, K, V> {
+    /// The internal entry iterator.
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+/// An `Iterator` that visits the values of an `OrderedMap` in order.
+#[derive(Clone, Debug)]
+pub struct Values<'a, K, V> {
+    /// The internal entry iterator.
+    iter: slice::Iter<'a, (K, V)>,
+}
+
+/// An `Iterator` that visits the values of an `OrderedMap` in order, mutably.
+#[derive(Debug)]
+pub struct ValuesMut<'a, K, V> {
+    /// The internal entry iterator.
+    iter: slice::IterMut<'a, (K, V)>,
+}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an `Iterator` that visits the entries in order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter { iter: self.entries.iter() }
+    }
+
+    /// Returns an `Iterator` that visits the entries in order, with mutable
+    /// values.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut { iter: self.entries.iter_mut() }
+    }
+
+    /// Returns an `Iterator` that visits the keys in order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.entries.iter() }
+    }
+
+    /// Returns an `Iterator` that visits the values in order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.entries.iter() }
+    }
+
+    /// Returns an `Iterator` that visits the values in order, mutably.
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, V> {
+        ValuesMut { iter: self.entries.iter_mut() }
+    }
+
+    /// Keeps only the entries for which `f` returns `true`, in order.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        self.entries.retain_mut(|(key, value)| f(key, value));
+    }
+}
+
+impl<K: Eq, V> OrderedMap<K, V> {
+    /// Returns the position of the entry with the given key.
+    fn position(&self, key: &K) -> Option<usize> {
+        self.entries.iter().position(|(x, _)| x == key)
+    }
+
+    /// Returns the value of the given key.
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.entries.iter().find(|(x, _)| x == key).map(|(_, value)| value)
+    }
+
+    /// Returns the value of the given key, mutably.
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.entries.iter_mut().find(|(x, _)| x == key).map(|(_, value)| value)
+    }
+
+    /// Returns `true` if the map has an entry with the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.position(key).is_some()
+    }
+
+    /// Inserts an entry, returning the previous value of the key.
+    ///
+    /// A new key is appended; the value of an existing key is replaced in
+    /// place, keeping its position.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.position(&key) {
+            Some(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            None => {
+                self.entries.push((key, value));
+                None
+            },
+        }
+    }
+
+    /// Removes the entry with the given key, keeping the order of the others,
+    /// and returns its value.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let index = self.position(key)?;
+        Some(self.entries.remove(index).1)
+    }
+}
+
+impl<K, V> Default for OrderedMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for OrderedMap<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Eq, V> iter::FromIterator<(K, V)> for OrderedMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Eq, V> Extend<(K, V)> for OrderedMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V> IntoIterator for OrderedMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = vec::IntoIter<(K, V)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a OrderedMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a mut OrderedMap<K, V> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (key, value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, value)| (key, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {}
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, value)| (&*key, value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for IterMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, value)| (&*key, value))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {}
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, _)| key)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {}
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for ValuesMut<'a, K, V> {}
+impl<'a, K, V> Iterator for ValuesMut<'a, K, V> {
+    type Item = &'a mut V;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, value)| value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for ValuesMut<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, value)| value)
+    }
+}
+
+impl<K: ser::Serialize, V: ser::Serialize> ser::Serialize for OrderedMap<K, V> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, K, V> de::Deserialize<'de> for OrderedMap<K, V>
+where
+    K: de::Deserialize<'de> + Eq + Hash,
+    V: de::Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct Visitor<K, V>(marker::PhantomData<(K, V)>);
+        impl<'de, K, V> de::Visitor<'de> for Visitor<K, V>
+        where
+            K: de::Deserialize<'de> + Eq + Hash,
+            V: de::Deserialize<'de>,
+        {
+            type Value = OrderedMap<K, V>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut entries = Vec::with_capacity(access.size_hint().unwrap_or(0));
+                while let Some(entry) = access.next_entry()? {
+                    entries.push(entry);
+                }
+                dedup(&mut entries);
+                Ok(OrderedMap { entries })
+            }
+        }
+        deserializer.deserialize_map(Visitor(marker::PhantomData))
+    }
+}
+
+/// Removes the entries whose key appears earlier, giving the earlier entry the
+/// value of the last entry with its key, as `OrderedMap::insert` does.
+///
+/// Keys are hashed rather than compared with every earlier key, so that maps
+/// deserialized from untrusted JSON with many keys are read in linear time.
+fn dedup<K: Eq + Hash, V>(entries: &mut Vec<(K, V)>) {
+    let mut duplicates = Vec::new();
+    let mut first = HashMap::with_capacity(entries.len());
+    for (index, (key, _)) in entries.iter().enumerate() {
+        match first.entry(key) {
+            Entry::Occupied(entry) => duplicates.push((*entry.get(), index)),
+            Entry::Vacant(entry) => {
+                entry.insert(index);
+            },
+        }
+    }
+    drop(first);
+    if duplicates.is_empty() {
+        return;
+    }
+
+    let mut keep = vec![true; entries.len()];
+    for (first, duplicate) in duplicates {
+        let (head, tail) = entries.split_at_mut(duplicate);
+        std::mem::swap(&mut head[first].1, &mut tail[0].1);
+        keep[duplicate] = false;
+    }
+    let mut keep = keep.into_iter();
+    entries.retain(|_| keep.next().unwrap());
+}
+
+impl<K: ToString + Validate, V: Validate> Validate for OrderedMap<K, V> {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        for (key, value) in self.iter() {
+            key.validate(root, || path().key(&key.to_string()), report);
+            value.validate(root, || path().key(&key.to_string()), report);
+        }
+    }
+}
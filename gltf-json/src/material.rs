@@ -246,6 +246,90 @@ pub struct PbrBaseColorFactor(pub [f32; 4]);
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 pub struct StrengthFactor(pub f32);
 
+impl Material {
+    /// Returns the sorted, distinct set indices of the `TEXCOORD` attributes
+    /// sampled by the textures of the material.
+    pub fn tex_coord_sets(&self) -> Vec<u32> {
+        let pbr = &self.pbr_metallic_roughness;
+        let mut sets: Vec<u32> = pbr.base_color_texture
+            .iter()
+            .chain(pbr.metallic_roughness_texture.iter())
+            .chain(self.emissive_texture.iter())
+            .map(texture::Info::tex_coord_set)
+            .chain(self.normal_texture.iter().map(NormalTexture::tex_coord_set))
+            .chain(self.occlusion_texture.iter().map(OcclusionTexture::tex_coord_set))
+            .collect();
+        #[cfg(any(
+            feature = "KHR_materials_pbrSpecularGlossiness",
+            feature = "KHR_materials_transmission",
+            feature = "KHR_materials_volume",
+            feature = "KHR_materials_specular",
+        ))]
+        if let Some(extensions) = self.extensions.as_ref() {
+            #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+            if let Some(pbr) = extensions.pbr_specular_glossiness.as_ref() {
+                let textures = pbr.diffuse_texture.iter().chain(pbr.specular_glossiness_texture.iter());
+                sets.extend(textures.map(texture::Info::tex_coord_set));
+            }
+            #[cfg(feature = "KHR_materials_transmission")]
+            if let Some(transmission) = extensions.transmission.as_ref() {
+                let textures = transmission.transmission_texture.iter();
+                sets.extend(textures.map(texture::Info::tex_coord_set));
+            }
+            #[cfg(feature = "KHR_materials_volume")]
+            if let Some(volume) = extensions.volume.as_ref() {
+                let textures = volume.thickness_texture.iter();
+                sets.extend(textures.map(texture::Info::tex_coord_set));
+            }
+            #[cfg(feature = "KHR_materials_specular")]
+            if let Some(specular) = extensions.specular.as_ref() {
+                let textures = specular.specular_texture.iter();
+                let textures = textures.chain(specular.specular_color_texture.iter());
+                sets.extend(textures.map(texture::Info::tex_coord_set));
+            }
+        }
+        sets.sort_unstable();
+        sets.dedup();
+        sets
+    }
+}
+
+impl NormalTexture {
+    /// Returns the set index of the `TEXCOORD` attribute to sample with, taking
+    /// any `KHR_texture_transform` override into account.
+    pub fn tex_coord_set(&self) -> u32 {
+        #[cfg(feature = "KHR_texture_transform")]
+        {
+            let tex_coord = self.extensions
+                .as_ref()
+                .and_then(|extensions| extensions.texture_transform.as_ref())
+                .and_then(|transform| transform.tex_coord);
+            if let Some(tex_coord) = tex_coord {
+                return tex_coord;
+            }
+        }
+        self.tex_coord
+    }
+}
+
+impl OcclusionTexture {
+    /// Returns the set index of the `TEXCOORD` attribute to sample with, taking
+    /// any `KHR_texture_transform` override into account.
+    pub fn tex_coord_set(&self) -> u32 {
+        #[cfg(feature = "KHR_texture_transform")]
+        {
+            let tex_coord = self.extensions
+                .as_ref()
+                .and_then(|extensions| extensions.texture_transform.as_ref())
+                .and_then(|transform| transform.tex_coord);
+            if let Some(tex_coord) = tex_coord {
+                return tex_coord;
+            }
+        }
+        self.tex_coord
+    }
+}
+
 impl Default for AlphaCutoff {
     fn default() -> Self {
         AlphaCutoff(0.5)
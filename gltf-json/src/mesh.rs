@@ -2,10 +2,14 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use serde_json::from_value;
-use std::collections::HashMap;
 use std::fmt;
 use crate::validation::{Checked, Error, Validate};
-use crate::{accessor, extensions, material, Extras, Index};
+use crate::{accessor, extensions, material, Extras, Index, OrderedMap};
+
+/// The key in `mesh.extras` under which morph target names are stored by
+/// convention.
+#[cfg(feature = "extras")]
+pub const TARGET_NAMES_EXTRAS_KEY: &str = "targetNames";
 
 /// Corresponds to `GL_POINTS`.
 pub const POINTS: u32 = 0;
@@ -103,8 +107,8 @@ pub struct Mesh {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Primitive {
     /// Maps attribute semantic names to the `Accessor`s containing the
-    /// corresponding attribute data.
-    pub attributes: HashMap<Checked<Semantic>, Index<accessor::Accessor>>,
+    /// corresponding attribute data, in the order they are declared.
+    pub attributes: OrderedMap<Checked<Semantic>, Index<accessor::Accessor>>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -134,6 +138,47 @@ pub struct Primitive {
     pub targets: Option<Vec<MorphTarget>>,
 }
 
+impl Mesh {
+    /// Returns the morph target names recorded under the `"targetNames"` key of
+    /// `extras`, if any.
+    ///
+    /// This is a common convention rather than part of the specification, so the
+    /// names are not validated against the number of morph targets.
+    #[cfg(feature = "extras")]
+    pub fn target_names(&self) -> Option<Vec<String>> {
+        let extras = self.extras.as_ref()?;
+        let mut value: serde_json::Value = serde_json::from_str(extras.get()).ok()?;
+        let names = value.get_mut(TARGET_NAMES_EXTRAS_KEY)?.take();
+        from_value(names).ok()
+    }
+
+    /// Records morph target names under the `"targetNames"` key of `extras`.
+    ///
+    /// Any other data in `extras` is preserved. Fails if `extras` is present but
+    /// is not a JSON object.
+    #[cfg(feature = "extras")]
+    pub fn set_target_names<I>(&mut self, names: I) -> Result<(), crate::Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let mut map = match self.extras.as_ref() {
+            Some(extras) => match serde_json::from_str(extras.get())? {
+                serde_json::Value::Object(map) => map,
+                _ => return Err(de::Error::custom("mesh extras is not an object")),
+            },
+            None => serde_json::Map::new(),
+        };
+        let names = names
+            .into_iter()
+            .map(|name| serde_json::Value::String(name.into()))
+            .collect();
+        map.insert(TARGET_NAMES_EXTRAS_KEY.to_string(), serde_json::Value::Array(names));
+        self.extras = Some(serde_json::value::to_raw_value(&map)?);
+        Ok(())
+    }
+}
+
 fn is_primitive_mode_default(mode: &Checked<Mode>) -> bool {
     *mode == Checked::Valid(Mode::Triangles)
 }
@@ -179,6 +224,49 @@ impl Validate for Primitive {
         } else {
             report(position_path, Error::Missing);
         }
+
+        // Every texture of the material must have the `TEXCOORD` attribute it
+        // samples with, otherwise it renders incorrectly in some engines.
+        let material = self.material
+            .as_ref()
+            .and_then(|index| root.materials.get(index.value()));
+        if let Some(material) = material {
+            for set in material.tex_coord_sets() {
+                let semantic = Semantic::TexCoords(set);
+                if !self.attributes.contains_key(&Checked::Valid(semantic.clone())) {
+                    let key = semantic.to_string();
+                    report(&|| path().field("attributes").key(&key), Error::Missing);
+                }
+            }
+        }
+
+        // Sets of `JOINTS_n` and `WEIGHTS_n` are numbered densely from zero,
+        // and each set of joints has a matching set of weights.
+        let mut missing = Vec::new();
+        for semantic in self.attributes.keys() {
+            let (set, pair) = match semantic {
+                Checked::Valid(Semantic::Joints(set)) => (*set, Semantic::Weights(*set)),
+                Checked::Valid(Semantic::Weights(set)) => (*set, Semantic::Joints(*set)),
+                _ => continue,
+            };
+            let mut required = vec![pair];
+            if set > 0 {
+                required.push(match semantic {
+                    Checked::Valid(Semantic::Joints(_)) => Semantic::Joints(set - 1),
+                    _ => Semantic::Weights(set - 1),
+                });
+            }
+            for semantic in required {
+                if !self.attributes.contains_key(&Checked::Valid(semantic.clone())) {
+                    missing.push(semantic.to_string());
+                }
+            }
+        }
+        missing.sort();
+        missing.dedup();
+        for key in missing {
+            report(&|| path().field("attributes").key(&key), Error::Missing);
+        }
     }
 }
 
@@ -199,13 +287,18 @@ pub struct MorphTarget {
     #[serde(rename = "TANGENT")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tangents: Option<Index<accessor::Accessor>>,
+
+    /// Displacements of other vertex attributes, such as `TEXCOORD_0`,
+    /// `COLOR_0`, and application specific attributes.
+    #[serde(default, flatten)]
+    pub others: OrderedMap<Checked<Semantic>, Index<accessor::Accessor>>,
 }
 
 /// Vertex attribute semantic name.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Semantic {
-    /// Extra attribute name.
-    #[cfg(feature = "extras")]
+    /// Application specific attribute, named without its leading underscore,
+    /// such as `BATCHID` for `_BATCHID`.
     Extras(String),
 
     /// XYZ vertex positions.
@@ -302,7 +395,6 @@ impl Semantic {
             "NORMAL" => Valid(Normals),
             "POSITION" => Valid(Positions),
             "TANGENT" => Valid(Tangents),
-            #[cfg(feature = "extras")]
             _ if s.starts_with("_") => Valid(Extras(s[1..].to_string())),
             _ if s.starts_with("COLOR_") => {
                 match s["COLOR_".len()..].parse() {
@@ -352,7 +444,6 @@ impl ToString for Semantic {
             TexCoords(set) => format!("TEXCOORD_{}", set),
             Joints(set) => format!("JOINTS_{}", set),
             Weights(set) => format!("WEIGHTS_{}", set),
-            #[cfg(feature = "extras")]
             Extras(ref name) => format!("_{}", name),
         }
     }
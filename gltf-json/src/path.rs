@@ -74,6 +74,45 @@ impl Path {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Returns the path as a JSON pointer, as defined by [RFC 6901].
+    ///
+    /// [RFC 6901]: https://tools.ietf.org/html/rfc6901
+    ///
+    /// # Examples
+    ///
+    /// Basic usage
+    ///
+    /// ```rust
+    /// # use gltf_json::Path;
+    /// let path = Path::new().field("meshes").index(3).field("attributes").key("POSITION");
+    /// assert_eq!("/meshes/3/attributes/POSITION", path.to_json_pointer());
+    /// assert_eq!("/a~1b", Path::new().key("a/b").to_json_pointer());
+    /// ```
+    pub fn to_json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        let mut rest = self.0.as_str();
+        while !rest.is_empty() {
+            let token;
+            if rest.starts_with("[\"") {
+                let end = rest.find("\"]").unwrap_or(rest.len());
+                token = &rest[2..end];
+                rest = rest.get(end + 2..).unwrap_or("");
+            } else if rest.starts_with('[') {
+                let end = rest.find(']').unwrap_or(rest.len());
+                token = &rest[1..end];
+                rest = rest.get(end + 1..).unwrap_or("");
+            } else {
+                let start = if rest.starts_with('.') { 1 } else { 0 };
+                let end = rest[start..].find(&['.', '['][..]).map_or(rest.len(), |x| x + start);
+                token = &rest[start..end];
+                rest = &rest[end..];
+            }
+            pointer.push('/');
+            pointer.push_str(&token.replace('~', "~0").replace('/', "~1"));
+        }
+        pointer
+    }
 }
 
 impl fmt::Display for Path {
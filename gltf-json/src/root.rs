@@ -7,9 +7,14 @@ use crate::texture;
 use crate::validation;
 
 use crate::path::Path;
+use crate::span::Spans;
 use validation::Validate;
 use crate::{Accessor, Animation, Asset, Buffer, Camera, Error, Extras, Image, Material, Mesh, Node, Scene, Skin, Texture, Value};
 
+/// The key in `asset.extras` under which the asset preview image index is stored.
+#[cfg(feature = "extras")]
+pub const THUMBNAIL_EXTRAS_KEY: &str = "thumbnail";
+
 /// Helper trait for retrieving top-level objects by a universal identifier.
 pub trait Get<T> {
     /// Retrieves a single value at the given index.
@@ -25,6 +30,7 @@ pub struct Root {
     /// An array of accessors.
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[gltf(validate = "crate::accessor::validate_accessors")]
     pub accessors: Vec<Accessor>,
 
     /// An array of keyframe animations.
@@ -122,6 +128,49 @@ impl Root {
         (self as &dyn Get<T>).get(index)
     }
 
+    /// Appends an item to the corresponding top-level array, returning its index.
+    pub fn push<T>(&mut self, value: T) -> Index<T>
+        where Self: AsMut<Vec<T>>
+    {
+        let items = self.as_mut();
+        let index = Index::new(items.len() as u32);
+        items.push(value);
+        index
+    }
+
+    /// Returns the image flagged as the asset preview, if any.
+    ///
+    /// The preview is recorded as an image index under the `"thumbnail"` key of
+    /// `asset.extras`. The index is not validated.
+    #[cfg(feature = "extras")]
+    pub fn thumbnail(&self) -> Option<Index<Image>> {
+        let extras = self.asset.extras.as_ref()?;
+        let value: Value = serde_json::from_str(extras.get()).ok()?;
+        value
+            .get(THUMBNAIL_EXTRAS_KEY)
+            .and_then(Value::as_u64)
+            .map(|index| Index::new(index as u32))
+    }
+
+    /// Flags an image as the asset preview by recording its index under the
+    /// `"thumbnail"` key of `asset.extras`.
+    ///
+    /// Any other data in `asset.extras` is preserved. Fails if `asset.extras` is
+    /// present but is not a JSON object.
+    #[cfg(feature = "extras")]
+    pub fn set_thumbnail(&mut self, image: Index<Image>) -> Result<(), Error> {
+        let mut map = match self.asset.extras.as_ref() {
+            Some(extras) => match serde_json::from_str(extras.get())? {
+                Value::Object(map) => map,
+                _ => return Err(serde::de::Error::custom("asset extras is not an object")),
+            },
+            None => serde_json::Map::new(),
+        };
+        map.insert(THUMBNAIL_EXTRAS_KEY.to_string(), Value::from(image.value() as u64));
+        self.asset.extras = Some(serde_json::value::to_raw_value(&map)?);
+        Ok(())
+    }
+
     /// Deserialize from a JSON string slice.
     pub fn from_str(str_: &str) -> Result<Self, Error> {
         serde_json::from_str(str_)
@@ -132,6 +181,16 @@ impl Root {
         serde_json::from_slice(slice)
     }
 
+    /// Deserialize from a JSON byte slice, also recording the location of each
+    /// top-level object within the slice.
+    ///
+    /// The spans allow validation errors to be reported against the source
+    /// text, for example in editor tooling.
+    pub fn from_slice_with_spans(slice: &[u8]) -> Result<(Self, Spans), Error> {
+        let root = serde_json::from_slice(slice)?;
+        Ok((root, Spans::from_slice(slice)))
+    }
+
     /// Deserialize from a stream of JSON.
     pub fn from_reader<R>(reader: R) -> Result<Self, Error>
         where R: io::Read
@@ -263,6 +322,18 @@ macro_rules! impl_get {
                 self.$field.get(index.value())
             }
         }
+
+        impl AsRef<[$ty]> for Root {
+            fn as_ref(&self) -> &[$ty] {
+                &self.$field
+            }
+        }
+
+        impl AsMut<Vec<$ty>> for Root {
+            fn as_mut(&mut self) -> &mut Vec<$ty> {
+                &mut self.$field
+            }
+        }
     }
 }
 
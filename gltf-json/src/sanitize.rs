@@ -0,0 +1,317 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::{texture, Root};
+
+/// Names of the extensions holding XMP metadata.
+const XMP_EXTENSIONS: &[&str] = &["KHR_xmp_json_ld", "KHR_xmp"];
+
+/// Selects the metadata removed by `sanitize`.
+///
+/// Every kind of metadata is removed by default.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Options {
+    /// Remove the user-defined names of all objects.
+    pub names: bool,
+
+    /// Remove the application specific data of all objects.
+    pub extras: bool,
+
+    /// Remove XMP metadata packets and the references to them.
+    pub xmp: bool,
+
+    /// Remove the copyright message of the asset.
+    pub copyright: bool,
+
+    /// Remove the generator string of the asset.
+    pub generator: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            names: true,
+            extras: true,
+            xmp: true,
+            copyright: true,
+            generator: true,
+        }
+    }
+}
+
+/// Removes identifying metadata from a document, so that it may be shared
+/// without leaking details of the project it came from.
+///
+/// Names are only present with the `names` feature and extras with the `extras`
+/// feature; without them there is nothing to remove. Data kept in extras, such
+/// as morph target names, is removed along with them.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf_json::{sanitize, Root};
+/// let mut root = Root::from_str(r#"{
+///     "asset": {"version": "2.0", "generator": "Studio Exporter", "copyright": "ACME"},
+///     "nodes": [{"name": "Prototype"}]
+/// }"#).unwrap();
+/// let options = sanitize::Options { copyright: false, ..Default::default() };
+/// sanitize::sanitize(&mut root, &options);
+/// assert_eq!(root.asset.generator, None);
+/// assert_eq!(root.asset.copyright.as_deref(), Some("ACME"));
+/// # #[cfg(feature = "names")]
+/// assert_eq!(root.nodes[0].name, None);
+/// ```
+pub fn sanitize(root: &mut Root, options: &Options) {
+    if options.copyright {
+        root.asset.copyright = None;
+    }
+    if options.generator {
+        root.asset.generator = None;
+    }
+    #[cfg(feature = "names")]
+    {
+        if options.names {
+            strip_names(root);
+        }
+    }
+    if options.extras {
+        strip_extras(root);
+    }
+    if options.xmp {
+        strip_xmp(root);
+    }
+}
+
+/// Removes the user-defined names of all objects.
+#[cfg(feature = "names")]
+fn strip_names(root: &mut Root) {
+    root.accessors.iter_mut().for_each(|x| x.name = None);
+    root.animations.iter_mut().for_each(|x| x.name = None);
+    root.buffers.iter_mut().for_each(|x| x.name = None);
+    root.buffer_views.iter_mut().for_each(|x| x.name = None);
+    root.cameras.iter_mut().for_each(|x| x.name = None);
+    root.images.iter_mut().for_each(|x| x.name = None);
+    root.materials.iter_mut().for_each(|x| x.name = None);
+    root.meshes.iter_mut().for_each(|x| x.name = None);
+    root.nodes.iter_mut().for_each(|x| x.name = None);
+    root.samplers.iter_mut().for_each(|x| x.name = None);
+    root.scenes.iter_mut().for_each(|x| x.name = None);
+    root.skins.iter_mut().for_each(|x| x.name = None);
+    root.textures.iter_mut().for_each(|x| x.name = None);
+    #[cfg(feature = "KHR_lights_punctual")]
+    {
+        let lights = root.extensions.as_mut().and_then(|x| x.khr_lights_punctual.as_mut());
+        for light in lights.into_iter().flat_map(|x| x.lights.iter_mut()) {
+            light.name = None;
+        }
+    }
+    // Variant names are required and identify the variants to users, so only
+    // the names of the mappings are removed.
+    #[cfg(feature = "KHR_materials_variants")]
+    for mapping in variant_mappings(root) {
+        mapping.name = None;
+    }
+}
+
+/// Returns the `KHR_materials_variants` mappings of all primitives.
+#[cfg(feature = "KHR_materials_variants")]
+fn variant_mappings(
+    root: &mut Root,
+) -> impl Iterator<Item = &mut crate::extensions::mesh::Mapping> {
+    root.meshes
+        .iter_mut()
+        .flat_map(|mesh| mesh.primitives.iter_mut())
+        .filter_map(|primitive| primitive.extensions.as_mut())
+        .filter_map(|extensions| extensions.khr_materials_variants.as_mut())
+        .flat_map(|variants| variants.mappings.iter_mut())
+}
+
+/// Removes the application specific data of a texture reference.
+fn strip_info_extras(info: &mut texture::Info) {
+    info.extras = Default::default();
+    #[cfg(feature = "KHR_texture_transform")]
+    strip_transform_extras(info.extensions.as_mut().and_then(|x| x.texture_transform.as_mut()));
+}
+
+/// Removes the application specific data of a texture transform.
+#[cfg(feature = "KHR_texture_transform")]
+fn strip_transform_extras(transform: Option<&mut crate::extensions::texture::TextureTransform>) {
+    if let Some(transform) = transform {
+        transform.extras = Default::default();
+    }
+}
+
+/// Removes the application specific data of all objects.
+fn strip_extras(root: &mut Root) {
+    root.extras = Default::default();
+    root.asset.extras = Default::default();
+    for accessor in &mut root.accessors {
+        accessor.extras = Default::default();
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            sparse.extras = Default::default();
+            sparse.indices.extras = Default::default();
+            sparse.values.extras = Default::default();
+        }
+    }
+    for animation in &mut root.animations {
+        animation.extras = Default::default();
+        for channel in &mut animation.channels {
+            channel.extras = Default::default();
+            channel.target.extras = Default::default();
+        }
+        animation.samplers.iter_mut().for_each(|x| x.extras = Default::default());
+    }
+    root.buffers.iter_mut().for_each(|x| x.extras = Default::default());
+    root.buffer_views.iter_mut().for_each(|x| x.extras = Default::default());
+    for camera in &mut root.cameras {
+        camera.extras = Default::default();
+        if let Some(orthographic) = camera.orthographic.as_mut() {
+            orthographic.extras = Default::default();
+        }
+        if let Some(perspective) = camera.perspective.as_mut() {
+            perspective.extras = Default::default();
+        }
+    }
+    root.images.iter_mut().for_each(|x| x.extras = Default::default());
+    for material in &mut root.materials {
+        material.extras = Default::default();
+        let pbr = &mut material.pbr_metallic_roughness;
+        pbr.extras = Default::default();
+        pbr.base_color_texture.iter_mut().for_each(strip_info_extras);
+        pbr.metallic_roughness_texture.iter_mut().for_each(strip_info_extras);
+        if let Some(normal) = material.normal_texture.as_mut() {
+            normal.extras = Default::default();
+            #[cfg(feature = "KHR_texture_transform")]
+            strip_transform_extras(normal.extensions.as_mut().and_then(|x| x.texture_transform.as_mut()));
+        }
+        if let Some(occlusion) = material.occlusion_texture.as_mut() {
+            occlusion.extras = Default::default();
+            #[cfg(feature = "KHR_texture_transform")]
+            strip_transform_extras(occlusion.extensions.as_mut().and_then(|x| x.texture_transform.as_mut()));
+        }
+        material.emissive_texture.iter_mut().for_each(strip_info_extras);
+        #[cfg(any(
+            feature = "KHR_materials_pbrSpecularGlossiness",
+            feature = "KHR_materials_transmission",
+            feature = "KHR_materials_ior",
+            feature = "KHR_materials_volume",
+            feature = "KHR_materials_emissive_strength",
+            feature = "KHR_materials_specular"
+        ))]
+        {
+            if let Some(extensions) = material.extensions.as_mut() {
+                #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+                {
+                    if let Some(x) = extensions.pbr_specular_glossiness.as_mut() {
+                        x.extras = Default::default();
+                        x.diffuse_texture.iter_mut().for_each(strip_info_extras);
+                        x.specular_glossiness_texture.iter_mut().for_each(strip_info_extras);
+                    }
+                }
+                #[cfg(feature = "KHR_materials_transmission")]
+                {
+                    if let Some(x) = extensions.transmission.as_mut() {
+                        x.extras = Default::default();
+                        x.transmission_texture.iter_mut().for_each(strip_info_extras);
+                    }
+                }
+                #[cfg(feature = "KHR_materials_ior")]
+                {
+                    if let Some(x) = extensions.ior.as_mut() {
+                        x.extras = Default::default();
+                    }
+                }
+                #[cfg(feature = "KHR_materials_volume")]
+                {
+                    if let Some(x) = extensions.volume.as_mut() {
+                        x.extras = Default::default();
+                        x.thickness_texture.iter_mut().for_each(strip_info_extras);
+                    }
+                }
+                #[cfg(feature = "KHR_materials_emissive_strength")]
+                {
+                    if let Some(x) = extensions.emissive_strength.as_mut() {
+                        x.extras = Default::default();
+                    }
+                }
+                #[cfg(feature = "KHR_materials_specular")]
+                {
+                    if let Some(x) = extensions.specular.as_mut() {
+                        x.extras = Default::default();
+                        x.specular_texture.iter_mut().for_each(strip_info_extras);
+                        x.specular_color_texture.iter_mut().for_each(strip_info_extras);
+                    }
+                }
+            }
+        }
+    }
+    for mesh in &mut root.meshes {
+        mesh.extras = Default::default();
+        mesh.primitives.iter_mut().for_each(|x| x.extras = Default::default());
+    }
+    root.nodes.iter_mut().for_each(|x| x.extras = Default::default());
+    root.samplers.iter_mut().for_each(|x| x.extras = Default::default());
+    root.scenes.iter_mut().for_each(|x| x.extras = Default::default());
+    root.skins.iter_mut().for_each(|x| x.extras = Default::default());
+    root.textures.iter_mut().for_each(|x| x.extras = Default::default());
+    #[cfg(feature = "KHR_lights_punctual")]
+    {
+        let lights = root.extensions.as_mut().and_then(|x| x.khr_lights_punctual.as_mut());
+        for light in lights.into_iter().flat_map(|x| x.lights.iter_mut()) {
+            light.extras = Default::default();
+        }
+    }
+    #[cfg(feature = "KHR_materials_variants")]
+    {
+        let variants = root.extensions.as_mut().and_then(|x| x.khr_materials_variants.as_mut());
+        for variant in variants.into_iter().flat_map(|x| x.variants.iter_mut()) {
+            variant.extras = Default::default();
+        }
+        variant_mappings(root).for_each(|x| x.extras = Default::default());
+    }
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    {
+        let extensions = root.nodes.iter_mut().filter_map(|x| x.extensions.as_mut());
+        for instancing in extensions.filter_map(|x| x.ext_mesh_gpu_instancing.as_mut()) {
+            instancing.extras = Default::default();
+        }
+    }
+}
+
+/// Removes XMP extensions from an extension map.
+fn strip_xmp_extensions(others: &mut HashMap<String, Value>) {
+    for name in XMP_EXTENSIONS {
+        others.remove(*name);
+    }
+}
+
+/// Removes XMP metadata packets and the references to them.
+fn strip_xmp(root: &mut Root) {
+    root.extensions_used.retain(|name| !XMP_EXTENSIONS.contains(&name.as_str()));
+    root.extensions_required.retain(|name| !XMP_EXTENSIONS.contains(&name.as_str()));
+    if let Some(x) = root.extensions.as_mut() {
+        strip_xmp_extensions(&mut x.others);
+    }
+    if let Some(x) = root.asset.extensions.as_mut() {
+        strip_xmp_extensions(&mut x.others);
+    }
+    for x in root.animations.iter_mut().filter_map(|x| x.extensions.as_mut()) {
+        strip_xmp_extensions(&mut x.others);
+    }
+    for x in root.images.iter_mut().filter_map(|x| x.extensions.as_mut()) {
+        strip_xmp_extensions(&mut x.others);
+    }
+    for x in root.materials.iter_mut().filter_map(|x| x.extensions.as_mut()) {
+        strip_xmp_extensions(&mut x.others);
+    }
+    for x in root.meshes.iter_mut().filter_map(|x| x.extensions.as_mut()) {
+        strip_xmp_extensions(&mut x.others);
+    }
+    for x in root.nodes.iter_mut().filter_map(|x| x.extensions.as_mut()) {
+        strip_xmp_extensions(&mut x.others);
+    }
+    for x in root.scenes.iter_mut().filter_map(|x| x.extensions.as_mut()) {
+        strip_xmp_extensions(&mut x.others);
+    }
+}
@@ -1,7 +1,8 @@
 use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
-use crate::validation::Validate;
-use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
+use std::fmt;
+use crate::validation::{self, Validate};
+use crate::{camera, extensions, mesh, scene, skin, Extras, Index, Path, Root};
 
 /// A node in the node hierarchy.  When the node contains `skin`, all
 /// `mesh.primitives` must contain `JOINTS_0` and `WEIGHTS_0` attributes.
@@ -13,7 +14,7 @@ use crate::{camera, extensions, mesh, scene, skin, Extras, Index};
 /// identity. When a node is targeted for animation (referenced by an
 /// animation.channel.target), only TRS properties may be present; `matrix` will not
 /// be present.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Node {
     /// The index of the camera referenced by this node.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -77,6 +78,32 @@ pub struct Node {
     pub weights: Option<Vec<f32>>,
 }
 
+impl Validate for Node {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, validation::Error)
+    {
+        self.camera.validate(root, || path().field("camera"), report);
+        self.children.validate(root, || path().field("children"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+        self.matrix.validate(root, || path().field("matrix"), report);
+        self.mesh.validate(root, || path().field("mesh"), report);
+        self.rotation.validate(root, || path().field("rotation"), report);
+        self.scale.validate(root, || path().field("scale"), report);
+        self.translation.validate(root, || path().field("translation"), report);
+        self.skin.validate(root, || path().field("skin"), report);
+        self.weights.validate(root, || path().field("weights"), report);
+
+        // spec: A node can have either a `matrix` or any combination of TRS
+        // properties. Loaders commonly let the matrix take precedence, so this
+        // is only a warning.
+        let trs = self.translation.is_some() || self.rotation.is_some() || self.scale.is_some();
+        if self.matrix.is_some() && trs {
+            report(&|| path().field("matrix"), validation::Error::MatrixAndTrs);
+        }
+    }
+}
+
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 pub struct Scene {
@@ -95,7 +122,7 @@ pub struct Scene {
     pub name: Option<String>,
 
     /// The indices of each root node.
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub nodes: Vec<Index<Node>>,
 }
 
@@ -110,3 +137,241 @@ impl Default for UnitQuaternion {
 }
 
 impl Validate for UnitQuaternion {}
+
+/// Represents a failure to build a node with `NodeBuilder`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// More than one camera was attached to the node.
+    MultipleCameras,
+
+    /// A matrix was set together with a translation, rotation, or scale.
+    MatrixAndTrs,
+
+    /// A skin was set without a mesh for it to deform.
+    SkinWithoutMesh,
+
+    /// Morph target weights were set without a mesh for them to apply to.
+    WeightsWithoutMesh,
+
+    /// The node refers to objects that do not exist.
+    Invalid(Vec<(Path, validation::Error)>),
+}
+
+/// Helper for adding a node to a `Root`, rejecting combinations of properties
+/// that would produce an invalid node.
+///
+/// # Examples
+///
+/// ```rust
+/// # use gltf_json::scene::{BuildError, NodeBuilder};
+/// let mut root = gltf_json::Root::default();
+/// let node = NodeBuilder::new()
+///     .translation([0.0, 1.0, 0.0])
+///     .build(&mut root)
+///     .unwrap();
+/// assert_eq!(root.nodes[node.value()].translation, Some([0.0, 1.0, 0.0]));
+///
+/// let error = NodeBuilder::new()
+///     .matrix([1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0])
+///     .scale([2.0, 2.0, 2.0])
+///     .build(&mut root)
+///     .unwrap_err();
+/// assert_eq!(error, BuildError::MatrixAndTrs);
+/// ```
+#[derive(Clone, Debug)]
+pub struct NodeBuilder {
+    /// The node under construction.
+    node: Node,
+
+    /// Whether more than one camera was attached.
+    multiple_cameras: bool,
+
+    /// The node to attach the new node to as a child.
+    parent: Option<Index<Node>>,
+
+    /// The scene to attach the new node to as a root node.
+    scene: Option<Index<Scene>>,
+}
+
+impl NodeBuilder {
+    /// Creates a builder for a node with the identity transform.
+    pub fn new() -> Self {
+        Self {
+            node: Node {
+                camera: None,
+                children: None,
+                extensions: None,
+                extras: Default::default(),
+                matrix: None,
+                mesh: None,
+                #[cfg(feature = "names")]
+                name: None,
+                rotation: None,
+                scale: None,
+                translation: None,
+                skin: None,
+                weights: None,
+            },
+            multiple_cameras: false,
+            parent: None,
+            scene: None,
+        }
+    }
+
+    /// Sets the user-defined name of the node.
+    #[cfg(feature = "names")]
+    pub fn name(mut self, name: &str) -> Self {
+        self.node.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the mesh instantiated by the node.
+    pub fn mesh(mut self, mesh: Index<mesh::Mesh>) -> Self {
+        self.node.mesh = Some(mesh);
+        self
+    }
+
+    /// Sets the skin deforming the mesh of the node.
+    pub fn skin(mut self, skin: Index<skin::Skin>) -> Self {
+        self.node.skin = Some(skin);
+        self
+    }
+
+    /// Sets the morph target weights of the mesh of the node.
+    pub fn weights(mut self, weights: Vec<f32>) -> Self {
+        self.node.weights = Some(weights);
+        self
+    }
+
+    /// Attaches a camera to the node.
+    ///
+    /// A node holds at most one camera; attaching another fails the build.
+    pub fn camera(mut self, camera: Index<camera::Camera>) -> Self {
+        self.multiple_cameras |= self.node.camera.is_some();
+        self.node.camera = Some(camera);
+        self
+    }
+
+    /// Sets the transform of the node as a column-major matrix.
+    pub fn matrix(mut self, matrix: [f32; 16]) -> Self {
+        self.node.matrix = Some(matrix);
+        self
+    }
+
+    /// Sets the translation of the node.
+    pub fn translation(mut self, translation: [f32; 3]) -> Self {
+        self.node.translation = Some(translation);
+        self
+    }
+
+    /// Sets the rotation of the node as an `[x, y, z, w]` unit quaternion.
+    pub fn rotation(mut self, rotation: [f32; 4]) -> Self {
+        self.node.rotation = Some(UnitQuaternion(rotation));
+        self
+    }
+
+    /// Sets the scale of the node.
+    pub fn scale(mut self, scale: [f32; 3]) -> Self {
+        self.node.scale = Some(scale);
+        self
+    }
+
+    /// Adds an existing node as a child of the node.
+    pub fn child(mut self, child: Index<Node>) -> Self {
+        self.node.children.get_or_insert_with(Vec::new).push(child);
+        self
+    }
+
+    /// Attaches the node as a child of the given node.
+    pub fn parent(mut self, parent: Index<Node>) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Attaches the node as a root node of the given scene.
+    pub fn scene(mut self, scene: Index<Scene>) -> Self {
+        self.scene = Some(scene);
+        self
+    }
+
+    /// Checks the node and adds it to `root`, returning its index.
+    ///
+    /// `root` is left unmodified if the node is rejected.
+    pub fn build(self, root: &mut Root) -> Result<Index<Node>, BuildError> {
+        let node = &self.node;
+        if self.multiple_cameras {
+            return Err(BuildError::MultipleCameras);
+        }
+        let trs = node.translation.is_some() || node.rotation.is_some() || node.scale.is_some();
+        if node.matrix.is_some() && trs {
+            return Err(BuildError::MatrixAndTrs);
+        }
+        if node.mesh.is_none() {
+            if node.skin.is_some() {
+                return Err(BuildError::SkinWithoutMesh);
+            }
+            if node.weights.is_some() {
+                return Err(BuildError::WeightsWithoutMesh);
+            }
+        }
+
+        let mut errors = Vec::new();
+        let node_path = || Path::new().field("nodes").index(root.nodes.len());
+        node.validate(root, node_path, &mut |path, error| errors.push((path(), error)));
+        if let Some(parent) = self.parent {
+            parent.validate(root, || Path::new().field("nodes"), &mut |path, error| {
+                errors.push((path(), error))
+            });
+        }
+        if let Some(scene) = self.scene {
+            scene.validate(root, || Path::new().field("scenes"), &mut |path, error| {
+                errors.push((path(), error))
+            });
+        }
+        if !errors.is_empty() {
+            return Err(BuildError::Invalid(errors));
+        }
+
+        let node = root.push(self.node);
+        if let Some(parent) = self.parent {
+            root.nodes[parent.value()]
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(node);
+        }
+        if let Some(scene) = self.scene {
+            root.scenes[scene.value()].nodes.push(node);
+        }
+        Ok(node)
+    }
+}
+
+impl Default for NodeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::MultipleCameras => write!(f, "more than one camera attached to node"),
+            BuildError::MatrixAndTrs => {
+                write!(f, "node has both a matrix and translation, rotation, or scale")
+            },
+            BuildError::SkinWithoutMesh => write!(f, "node has a skin but no mesh"),
+            BuildError::WeightsWithoutMesh => {
+                write!(f, "node has morph target weights but no mesh")
+            },
+            BuildError::Invalid(ref errors) => {
+                write!(f, "node is invalid:")?;
+                for (path, error) in errors {
+                    write!(f, " {}: {};", path, error)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use crate::Path;
+
+/// The location of a value within JSON text.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Span {
+    /// Byte offset of the first byte of the value.
+    pub start: usize,
+
+    /// Byte offset one past the last byte of the value.
+    pub end: usize,
+
+    /// Line number of the start of the value, counting from one.
+    pub line: usize,
+
+    /// Column number of the start of the value in bytes, counting from one.
+    pub column: usize,
+}
+
+/// The locations of the top-level objects of a glTF document within its JSON
+/// text.
+///
+/// Spans are recorded for every top-level property, such as `asset`, and for
+/// every element of a top-level array, such as `nodes[3]`. Nested values are not
+/// recorded individually; `Spans::get` falls back to the innermost recorded
+/// value containing them.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf_json::{Path, Root};
+/// let text = "{\n  \"asset\": {\"version\": \"2.0\"},\n  \"nodes\": [{}, {\"mesh\": 7}]\n}";
+/// let (_root, spans) = Root::from_slice_with_spans(text.as_bytes()).unwrap();
+/// let span = spans.get(&Path::new().field("nodes").index(1).field("mesh")).unwrap();
+/// assert_eq!((span.line, span.column), (3, 17));
+/// assert_eq!(&text[span.start..span.end], "{\"mesh\": 7}");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Spans {
+    /// Recorded spans keyed by JSON path.
+    spans: HashMap<String, Span>,
+}
+
+/// An array or object being scanned.
+struct Container {
+    /// Whether the container is an array.
+    array: bool,
+
+    /// The most recent key, if an object.
+    key: String,
+
+    /// Whether the next string is a key, if an object.
+    expecting_key: bool,
+
+    /// The number of elements begun so far, if an array.
+    len: usize,
+}
+
+/// Returns the offset one past the closing quote of the string starting at
+/// `start`, or the end of the text if the string is unterminated.
+fn string_end(json: &[u8], start: usize) -> usize {
+    let mut escaped = false;
+    for (offset, &byte) in json.iter().enumerate().skip(start + 1) {
+        if escaped {
+            escaped = false;
+        } else if byte == b'\\' {
+            escaped = true;
+        } else if byte == b'"' {
+            return offset + 1;
+        }
+    }
+    json.len()
+}
+
+/// Returns the offset one past the end of the number or literal starting at
+/// `start`.
+fn scalar_end(json: &[u8], start: usize) -> usize {
+    json[start..]
+        .iter()
+        .position(|byte| b" \t\n\r,:]}[{\"".contains(byte))
+        .map_or(json.len(), |len| start + len)
+}
+
+impl Spans {
+    /// Records the spans of the top-level objects in JSON text.
+    ///
+    /// The text is not checked for syntax errors, which are left to the parser.
+    pub fn from_slice(json: &[u8]) -> Self {
+        let mut line_starts = vec![0];
+        line_starts.extend(json.iter().enumerate().filter(|&(_, &byte)| byte == b'\n').map(|(i, _)| i + 1));
+        let mut spans = HashMap::new();
+        let mut record = |path: String, start: usize, end: usize| {
+            let line = match line_starts.binary_search(&start) {
+                Ok(line) => line,
+                Err(line) => line - 1,
+            };
+            let column = start - line_starts[line] + 1;
+            spans.insert(path, Span { start, end, line: line + 1, column });
+        };
+
+        let mut stack: Vec<Container> = Vec::new();
+        // Values being scanned whose spans are recorded, with the depth at which
+        // they begin.
+        let mut open: Vec<(usize, String, usize)> = Vec::new();
+        let mut offset = 0;
+        while offset < json.len() {
+            let byte = json[offset];
+            match byte {
+                b' ' | b'\t' | b'\n' | b'\r' | b':' => {
+                    offset += 1;
+                    continue;
+                },
+                b',' => {
+                    if let Some(top) = stack.last_mut() {
+                        top.expecting_key = !top.array;
+                    }
+                    offset += 1;
+                    continue;
+                },
+                b']' | b'}' => {
+                    stack.pop();
+                    offset += 1;
+                    if open.last().map(|&(depth, _, _)| depth) == Some(stack.len()) {
+                        let (_, path, start) = open.pop().unwrap();
+                        record(path, start, offset);
+                    }
+                    continue;
+                },
+                _ => {},
+            }
+            if byte == b'"' {
+                if let Some(top) = stack.last_mut() {
+                    if !top.array && top.expecting_key {
+                        let end = string_end(json, offset);
+                        let key = &json[offset + 1..(end - 1).max(offset + 1)];
+                        top.key = String::from_utf8_lossy(key).into_owned();
+                        top.expecting_key = false;
+                        offset = end;
+                        continue;
+                    }
+                }
+            }
+
+            // `byte` begins a value.
+            let path = match stack.len() {
+                1 if !stack[0].array => Some(Path::new().field(&stack[0].key)),
+                2 if !stack[0].array && stack[1].array => {
+                    Some(Path::new().field(&stack[0].key).index(stack[1].len))
+                },
+                _ => None,
+            };
+            if let Some(top) = stack.last_mut() {
+                top.len += 1;
+            }
+            match byte {
+                b'[' | b'{' => {
+                    if let Some(path) = path {
+                        open.push((stack.len(), path.0, offset));
+                    }
+                    stack.push(Container {
+                        array: byte == b'[',
+                        key: String::new(),
+                        expecting_key: byte == b'{',
+                        len: 0,
+                    });
+                    offset += 1;
+                },
+                _ => {
+                    let end = if byte == b'"' {
+                        string_end(json, offset)
+                    } else {
+                        scalar_end(json, offset).max(offset + 1)
+                    };
+                    if let Some(path) = path {
+                        record(path.0, offset, end);
+                    }
+                    offset = end;
+                },
+            }
+        }
+        Spans { spans }
+    }
+
+    /// Returns the span of the value at `path`, or of the innermost recorded
+    /// value containing it.
+    pub fn get(&self, path: &Path) -> Option<Span> {
+        let mut path = path.as_str();
+        loop {
+            if let Some(span) = self.spans.get(path) {
+                return Some(*span);
+            }
+            path = &path[..path.rfind(['.', '['])?];
+        }
+    }
+}
+
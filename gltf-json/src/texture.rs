@@ -2,8 +2,8 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use std::fmt;
-use crate::validation::Checked;
-use crate::{extensions, image, Extras, Index};
+use crate::validation::{Checked, Error, Validate};
+use crate::{extensions, image, Extras, Index, Path, Root};
 
 /// Corresponds to `GL_NEAREST`.
 pub const NEAREST: u32 = 9728;
@@ -173,7 +173,7 @@ pub struct Sampler {
 }
 
 /// A texture and its sampler.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
@@ -185,7 +185,11 @@ pub struct Texture {
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
-    pub source: Index<image::Image>,
+    ///
+    /// May be omitted when an extension such as `KHR_texture_basisu` or
+    /// `EXT_texture_webp` provides the image instead.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Index<image::Image>>,
 
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -197,6 +201,44 @@ pub struct Texture {
     pub extras: Extras,
 }
 
+impl Texture {
+    /// Returns the index of the image to sample, preferring the images of the
+    /// enabled `KHR_texture_basisu` and `EXT_texture_webp` extensions, in that
+    /// order, over `source`.
+    pub fn image(&self) -> Option<Index<image::Image>> {
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            let extensions = self.extensions.as_ref();
+            if let Some(basisu) = extensions.and_then(|x| x.texture_basisu.as_ref()) {
+                return Some(basisu.source);
+            }
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            let extensions = self.extensions.as_ref();
+            if let Some(webp) = extensions.and_then(|x| x.texture_webp.as_ref()) {
+                return Some(webp.source);
+            }
+        }
+        self.source
+    }
+}
+
+impl Validate for Texture {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        if self.image().is_none() {
+            report(&|| path().field("source"), Error::Missing);
+        }
+
+        self.sampler.validate(root, || path().field("sampler"), report);
+        self.source.validate(root, || path().field("source"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {
@@ -217,6 +259,24 @@ pub struct Info {
     pub extras: Extras,
 }
 
+impl Info {
+    /// Returns the set index of the `TEXCOORD` attribute to sample with, taking
+    /// any `KHR_texture_transform` override into account.
+    pub fn tex_coord_set(&self) -> u32 {
+        #[cfg(feature = "KHR_texture_transform")]
+        {
+            let tex_coord = self.extensions
+                .as_ref()
+                .and_then(|extensions| extensions.texture_transform.as_ref())
+                .and_then(|transform| transform.tex_coord);
+            if let Some(tex_coord) = tex_coord {
+                return tex_coord;
+            }
+        }
+        self.tex_coord
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Checked<MagFilter> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
         where D: de::Deserializer<'de>
@@ -27,6 +27,42 @@ pub enum Error {
 
     /// Some required data has been omitted.
     Missing,
+
+    /// An object is not referenced by any other object.
+    Unused,
+
+    /// A range of bytes extends beyond the data containing it.
+    ByteRangeOutOfBounds,
+
+    /// A node is not reachable from the root nodes of any scene.
+    Unreachable,
+
+    /// The data of an image does not match its MIME type or the image format
+    /// required by a texture using it.
+    MimeTypeMismatch,
+
+    /// A node specifies both a `matrix` and TRS properties.
+    MatrixAndTrs,
+}
+
+/// How severe a problem found during validation is.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The problem prevents the document from being loaded.
+    Error,
+
+    /// The document can be loaded, but the problem is likely unintended.
+    Warning,
+}
+
+/// A problem found during validation, located in the document by its path.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// The JSON path of the offending item.
+    pub path: Path,
+
+    /// The kind of problem.
+    pub error: Error,
 }
 
 /// Specifies a type that has been pre-validated during deserialization or otherwise.
@@ -139,6 +175,132 @@ impl Validate for std::boxed::Box<serde_json::value::RawValue> {
     }
 }
 
+impl Error {
+    /// Returns a stable identifier for the kind of error, suitable for use as a
+    /// rule identifier by reporting tools.
+    pub fn code(&self) -> &'static str {
+        match *self {
+            Error::IndexOutOfBounds => "INDEX_OUT_OF_BOUNDS",
+            Error::Invalid => "INVALID_VALUE",
+            Error::Missing => "MISSING_DATA",
+            Error::Unused => "UNUSED_OBJECT",
+            Error::ByteRangeOutOfBounds => "BYTE_RANGE_OUT_OF_BOUNDS",
+            Error::Unreachable => "UNREACHABLE_NODE",
+            Error::MimeTypeMismatch => "IMAGE_MIME_TYPE_MISMATCH",
+            Error::MatrixAndTrs => "NODE_MATRIX_TRS",
+        }
+    }
+
+    /// Returns how severe the kind of error is.
+    ///
+    /// Objects that are not referenced, nodes that are not reachable from a
+    /// scene, and nodes with both a matrix and TRS properties are reported as
+    /// warnings, and every other kind of error as an error.
+    pub fn severity(&self) -> Severity {
+        match *self {
+            Error::Unused | Error::Unreachable | Error::MatrixAndTrs => Severity::Warning,
+            _ => Severity::Error,
+        }
+    }
+}
+
+impl Severity {
+    /// Returns the corresponding SARIF result level.
+    fn sarif_level(&self) -> &'static str {
+        match *self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+impl Diagnostic {
+    /// Returns the location of the offending item as a JSON pointer, such as
+    /// `/meshes/3/primitives/0/attributes/POSITION`.
+    pub fn pointer(&self) -> String {
+        self.path.to_json_pointer()
+    }
+
+    /// Returns how severe the problem is.
+    pub fn severity(&self) -> Severity {
+        self.error.severity()
+    }
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.severity().sarif_level(), self.pointer(), self.error)
+    }
+}
+
+/// Converts validation errors into a [SARIF] 2.1.0 log.
+///
+/// Each error is reported as a result located in the artifact at `uri`, with the
+/// JSON path of the offending item as its logical location. Results are reported
+/// at the `error` or `warning` level according to `Error::severity`.
+///
+/// [SARIF]: https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html
+///
+/// # Examples
+///
+/// ```rust
+/// # use gltf_json::{validation, Path};
+/// let errors = [(Path::new().field("scene"), validation::Error::IndexOutOfBounds)];
+/// let log = validation::to_sarif("scene.gltf", &errors);
+/// assert_eq!(log["runs"][0]["results"][0]["ruleId"], "INDEX_OUT_OF_BOUNDS");
+/// ```
+pub fn to_sarif(uri: &str, errors: &[(Path, Error)]) -> serde_json::Value {
+    let mut kinds: Vec<Error> = errors.iter().map(|&(_, error)| error).collect();
+    kinds.sort();
+    kinds.dedup();
+    let rules: Vec<_> = kinds
+        .iter()
+        .map(|error| {
+            serde_json::json!({
+                "id": error.code(),
+                "shortDescription": { "text": error.to_string() },
+                "defaultConfiguration": { "level": error.severity().sarif_level() },
+            })
+        })
+        .collect();
+    let results: Vec<_> = errors
+        .iter()
+        .map(|(path, error)| {
+            serde_json::json!({
+                "ruleId": error.code(),
+                "ruleIndex": kinds.binary_search(error).unwrap(),
+                "level": error.severity().sarif_level(),
+                "message": { "text": format!("{}: {}", path, error) },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": uri },
+                    },
+                    "logicalLocations": [{
+                        "fullyQualifiedName": path.as_str(),
+                        "kind": "member",
+                    }],
+                }],
+            })
+        })
+        .collect();
+    serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "gltf-json",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": "https://github.com/gltf-rs/gltf",
+                    "rules": rules,
+                },
+            },
+            "artifacts": [{ "location": { "uri": uri } }],
+            "results": results,
+        }],
+    })
+}
+
 impl std::error::Error for Error {}
 
 impl std::fmt::Display for Error {
@@ -147,6 +309,11 @@ impl std::fmt::Display for Error {
             Error::IndexOutOfBounds => "Index out of bounds",
             Error::Invalid => "Invalid value",
             Error::Missing => "Missing data",
+            Error::Unused => "Unused object",
+            Error::ByteRangeOutOfBounds => "Byte range out of bounds",
+            Error::Unreachable => "Node unreachable from any scene",
+            Error::MimeTypeMismatch => "Image data does not match its MIME type",
+            Error::MatrixAndTrs => "Node has both a matrix and TRS properties",
         })
     }
 }
@@ -0,0 +1,21 @@
+use gltf_json::limits::{Error, Limits};
+
+#[test]
+fn test_limits_check() {
+    let json = br#"{"asset": {"version": "2.0"}, "nodes": [{"name": "a \"quoted\" [name]"}, {}, {}]}"#;
+    assert_eq!(Limits::default().check(json), Ok(()));
+
+    let limits = Limits { max_depth: 2, ..Limits::default() };
+    assert_eq!(limits.check(json), Err(Error::Depth { offset: 40 }));
+
+    let limits = Limits { max_array_length: 2, ..Limits::default() };
+    assert_eq!(limits.check(json), Err(Error::ArrayLength { offset: 77 }));
+
+    let limits = Limits { max_string_length: 18, ..Limits::default() };
+    assert_eq!(limits.check(json), Err(Error::StringLength { offset: 49 }));
+    let limits = Limits { max_string_length: 19, ..Limits::default() };
+    assert_eq!(limits.check(json), Ok(()));
+
+    let limits = Limits { max_array_length: 0, ..Limits::default() };
+    assert_eq!(limits.check(br#"{"nodes": [ ]}"#), Ok(()));
+}
@@ -0,0 +1,22 @@
+use gltf_json::OrderedMap;
+
+#[test]
+fn test_ordered_map_duplicate_keys() {
+    let map: OrderedMap<String, u32> =
+        gltf_json::deserialize::from_str(r#"{"b": 0, "a": 1, "b": 2, "c": 3, "b": 4}"#).unwrap();
+    let entries: Vec<_> = map.iter().map(|(key, &value)| (key.as_str(), value)).collect();
+    assert_eq!(entries, [("b", 4), ("a", 1), ("c", 3)]);
+}
+
+#[test]
+fn test_ordered_map_many_keys() {
+    let count = 200_000;
+    let mut json = String::from("{");
+    for i in 0..count {
+        json.push_str(&format!("\"_ATTR_{}\": {},", i, i));
+    }
+    json.push_str("\"_ATTR_0\": 0}");
+    let map: OrderedMap<String, u32> = gltf_json::deserialize::from_str(&json).unwrap();
+    assert_eq!(map.len(), count);
+    assert_eq!(map.keys().next_back().map(String::as_str), Some("_ATTR_199999"));
+}
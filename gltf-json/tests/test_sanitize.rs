@@ -0,0 +1,68 @@
+use gltf_json::sanitize::{sanitize, Options};
+use gltf_json::Root;
+
+const DOCUMENT: &str = r#"{
+    "asset": {
+        "version": "2.0",
+        "generator": "Studio Exporter 4.1",
+        "copyright": "ACME Corp",
+        "extensions": {"KHR_xmp_json_ld": {"packet": 0}},
+        "extras": {"project": "secret"}
+    },
+    "extensionsUsed": ["KHR_xmp_json_ld", "KHR_materials_variants"],
+    "extensions": {
+        "KHR_xmp_json_ld": {"packets": [{"dc:creator": ["Jane Doe"]}]},
+        "KHR_materials_variants": {"variants": [{"name": "red"}]}
+    },
+    "materials": [{
+        "name": "Prototype skin",
+        "pbrMetallicRoughness": {"baseColorTexture": {"index": 0, "extras": {"path": "C:/work"}}},
+        "extensions": {"KHR_xmp_json_ld": {"packet": 0}}
+    }],
+    "images": [{"uri": "skin.png"}],
+    "textures": [{"source": 0}],
+    "nodes": [{"name": "Hero", "extras": {"owner": "jdoe"}}]
+}"#;
+
+#[test]
+fn test_sanitize_removes_metadata() {
+    let mut root = Root::from_str(DOCUMENT).unwrap();
+    sanitize(&mut root, &Options::default());
+
+    assert_eq!(root.asset.generator, None);
+    assert_eq!(root.asset.copyright, None);
+    assert_eq!(root.extensions_used, ["KHR_materials_variants"]);
+    let others = &root.extensions.as_ref().unwrap().others;
+    assert!(!others.contains_key("KHR_xmp_json_ld"));
+    // The variants are only kept untyped without the `KHR_materials_variants` feature.
+    #[cfg(not(feature = "KHR_materials_variants"))]
+    assert_eq!(others["KHR_materials_variants"]["variants"][0]["name"], "red");
+    assert!(root.asset.extensions.as_ref().unwrap().others.is_empty());
+    assert!(root.materials[0].extensions.as_ref().unwrap().others.is_empty());
+
+    let json = gltf_json::serialize::to_string(&root).unwrap();
+    for secret in &["Studio", "ACME", "Jane Doe", "secret", "C:/work", "jdoe", "Hero", "Prototype"] {
+        assert!(!json.contains(secret), "{} survived sanitization", secret);
+    }
+}
+
+#[test]
+fn test_sanitize_options() {
+    let mut root = Root::from_str(DOCUMENT).unwrap();
+    let options = Options {
+        names: false,
+        extras: false,
+        xmp: false,
+        copyright: false,
+        generator: true,
+    };
+    sanitize(&mut root, &options);
+
+    assert_eq!(root.asset.generator, None);
+    assert_eq!(root.asset.copyright.as_deref(), Some("ACME Corp"));
+    assert_eq!(root.extensions_used.len(), 2);
+    #[cfg(feature = "names")]
+    assert_eq!(root.nodes[0].name.as_deref(), Some("Hero"));
+    #[cfg(feature = "extras")]
+    assert!(root.nodes[0].extras.is_some());
+}
@@ -0,0 +1,32 @@
+use gltf_json::validation::{Error, Validate};
+use gltf_json::{Path, Root};
+
+#[test]
+fn test_spans_locate_validation_errors() {
+    let text = r#"{
+    "asset": {"version": "2.0", "generator": "a \"quoted\" {name}"},
+    "scene": 0,
+    "nodes": [
+        {"name": "[root]", "children": [1]},
+        {"mesh": 4}
+    ],
+    "scenes": [{"nodes": [0]}]
+}"#;
+    let (root, spans) = Root::from_slice_with_spans(text.as_bytes()).unwrap();
+    let mut errs = vec![];
+    root.validate(&root, Path::new, &mut |path, err| errs.push((path(), err)));
+    assert_eq!(errs, [(Path("nodes[1].mesh".into()), Error::IndexOutOfBounds)]);
+
+    let span = spans.get(&errs[0].0).unwrap();
+    assert_eq!((span.line, span.column), (6, 9));
+    assert_eq!(&text[span.start..span.end], r#"{"mesh": 4}"#);
+
+    let span = spans.get(&Path::new().field("scene")).unwrap();
+    assert_eq!(&text[span.start..span.end], "0");
+    let span = spans.get(&Path::new().field("asset").field("generator")).unwrap();
+    assert_eq!(span.line, 2);
+    assert!(text[span.start..span.end].ends_with("{name}\"}"));
+    let span = spans.get(&Path::new().field("scenes").index(0)).unwrap();
+    assert_eq!(&text[span.start..span.end], r#"{"nodes": [0]}"#);
+    assert_eq!(spans.get(&Path::new().field("meshes").index(0)), None);
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "extras")]
+
+use gltf_json as json;
+
+use json::extras::{assign_stable_ids, stable_id};
+
+fn parse(nodes: &str) -> json::Root {
+    let json = format!(r#"{{"asset": {{"version": "2.0"}}, "nodes": [{}]}}"#, nodes);
+    json::deserialize::from_str(&json).unwrap()
+}
+
+fn ids(root: &json::Root) -> Vec<String> {
+    root.nodes.iter().map(|node| stable_id(&node.extras).unwrap()).collect()
+}
+
+#[test]
+fn stable_ids_survive_reexport() {
+    let nodes = r#"{"name": "Hero"}, {"mesh": 0}, {"mesh": 0}, {"extras": {"stableId": "x"}}"#;
+    let mut first = parse(nodes);
+    assert_eq!(assign_stable_ids(&mut first).unwrap(), 3);
+    assert_eq!(assign_stable_ids(&mut first).unwrap(), 0);
+    let first = ids(&first);
+    assert_eq!(first[3], "x");
+    assert_ne!(first[1], first[2]);
+
+    // A fresh export of the same objects is given the same identifiers.
+    let mut second = parse(nodes);
+    assign_stable_ids(&mut second).unwrap();
+    assert_eq!(ids(&second), first);
+
+    // Named objects are found by name, even after they moved or changed.
+    let mut third = parse(r#"{"mesh": 1}, {"name": "Hero", "mesh": 0}"#);
+    assign_stable_ids(&mut third).unwrap();
+    let third = ids(&third);
+    #[cfg(feature = "names")]
+    assert_eq!(third[1], first[0]);
+    assert!(!first.contains(&third[0]));
+}
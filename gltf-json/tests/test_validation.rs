@@ -36,3 +36,277 @@ fn test_non_sparse_accessor_without_buffer_view_validate() {
     assert_eq!(errs,
         [(Path("accessors[0].bufferView".into()), Error::Missing)]);
 }
+
+#[test]
+fn test_validation_to_sarif() {
+    let json = import_json("tests/minimal_accessor_invalid.gltf");
+    let mut errs = vec![];
+    json.validate(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    let log = gltf_json::validation::to_sarif("minimal_accessor_invalid.gltf", &errs);
+    let run = &log["runs"][0];
+    assert_eq!(log["version"], "2.1.0");
+    assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["ruleId"], "MISSING_DATA");
+    assert_eq!(results[0]["level"], "error");
+    assert_eq!(
+        results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+        "meshes[0].primitives[0].attributes[\"POSITION\"].min");
+    assert_eq!(
+        results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "minimal_accessor_invalid.gltf");
+    let index = results[1]["ruleIndex"].as_u64().unwrap() as usize;
+    assert_eq!(run["tool"]["driver"]["rules"][index]["id"], "INVALID_VALUE");
+}
+
+#[test]
+fn test_animation_input_bounds_validate() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"componentType": 5126, "type": "SCALAR", "count": 0},
+            {"componentType": 5126, "type": "SCALAR", "count": 0, "min": [-1.0], "max": [1.0]},
+            {"componentType": 5126, "type": "VEC3", "count": 0}
+        ],
+        "nodes": [{}],
+        "animations": [{
+            "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+            "samplers": [{"input": 0, "output": 2}, {"input": 1, "output": 2}]
+        }]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    errs.retain(|(path, _)| path.as_str().starts_with("animations"));
+    assert_eq!(errs,
+        [(Path("animations[0].samplers[0].input".into()), Error::Missing),
+         (Path("animations[0].samplers[1].input".into()), Error::Invalid)]);
+}
+
+#[test]
+fn test_camera_builder_validate() {
+    use gltf_json::camera::CameraBuilder;
+
+    let mut root = gltf_json::Root::default();
+    let errs = CameraBuilder::perspective(0.8, 1.0, Some(0.5))
+        .build(&mut root)
+        .unwrap_err();
+    assert_eq!(errs,
+        [(Path("cameras[0].perspective.zfar".into()), Error::Invalid)]);
+    assert!(root.cameras.is_empty() && root.nodes.is_empty());
+
+    let scene = root.push::<gltf_json::Scene>(
+        gltf_json::deserialize::from_str(r#"{"nodes": []}"#).unwrap());
+    let node = CameraBuilder::orthographic(1.0, 1.0, 0.0, 10.0)
+        .scene(scene)
+        .build(&mut root)
+        .unwrap();
+    assert_eq!(root.scenes[0].nodes[0].value(), node.value());
+    assert_eq!(root.nodes[node.value()].camera.unwrap().value(), 0);
+}
+
+#[test]
+fn test_node_builder_validate() {
+    use gltf_json::camera::CameraBuilder;
+    use gltf_json::scene::{BuildError, NodeBuilder};
+
+    let mut root = gltf_json::Root::default();
+    let camera = CameraBuilder::perspective(0.8, 0.1, None).build(&mut root).unwrap();
+    let camera = root.nodes[camera.value()].camera.unwrap();
+    let nr_nodes = root.nodes.len();
+
+    let err = NodeBuilder::new().camera(camera).camera(camera).build(&mut root).unwrap_err();
+    assert_eq!(err, BuildError::MultipleCameras);
+    let err = NodeBuilder::new().skin(gltf_json::Index::new(0)).build(&mut root).unwrap_err();
+    assert_eq!(err, BuildError::SkinWithoutMesh);
+    let err = NodeBuilder::new().weights(vec![0.5]).build(&mut root).unwrap_err();
+    assert_eq!(err, BuildError::WeightsWithoutMesh);
+    let err = NodeBuilder::new().mesh(gltf_json::Index::new(3)).build(&mut root).unwrap_err();
+    let errs = vec![(Path("nodes[1].mesh".into()), Error::IndexOutOfBounds)];
+    assert_eq!(err, BuildError::Invalid(errs));
+    assert_eq!(root.nodes.len(), nr_nodes);
+
+    let parent = NodeBuilder::new()
+        .camera(camera)
+        .rotation([0.0, 0.0, 0.0, 1.0])
+        .build(&mut root)
+        .unwrap();
+    let child = NodeBuilder::new().parent(parent).build(&mut root).unwrap();
+    assert_eq!(root.nodes[parent.value()].children.as_ref().unwrap()[0].value(), child.value());
+}
+
+#[test]
+fn test_material_tex_coord_validate() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"componentType": 5126, "type": "VEC3", "count": 0, "min": [0, 0, 0], "max": [0, 0, 0]},
+            {"componentType": 5126, "type": "VEC2", "count": 0}
+        ],
+        "images": [{"uri": "image.png"}],
+        "textures": [{"source": 0}],
+        "materials": [{
+            "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+            "occlusionTexture": {"index": 0, "texCoord": 1}
+        }],
+        "meshes": [{"primitives": [
+            {"attributes": {"POSITION": 0, "TEXCOORD_0": 1}, "material": 0},
+            {"attributes": {"POSITION": 0, "TEXCOORD_1": 1}, "material": 0},
+            {"attributes": {"POSITION": 0}}
+        ]}]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    errs.retain(|(path, _)| path.as_str().starts_with("meshes"));
+    assert_eq!(errs,
+        [(Path("meshes[0].primitives[0].attributes[\"TEXCOORD_1\"]".into()), Error::Missing),
+         (Path("meshes[0].primitives[1].attributes[\"TEXCOORD_0\"]".into()), Error::Missing)]);
+}
+
+#[test]
+fn test_node_matrix_and_trs_validate() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "nodes": [
+            {"matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1]},
+            {"matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1], "scale": [2, 2, 2]}
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    assert_eq!(errs, [(Path("nodes[1].matrix".into()), Error::MatrixAndTrs)]);
+}
+
+#[test]
+fn test_validation_diagnostics() {
+    use gltf_json::validation::{Diagnostic, Severity};
+
+    let error = Diagnostic {
+        path: Path::new().field("meshes").index(3).field("attributes").key("POSITION"),
+        error: Error::Missing,
+    };
+    assert_eq!(error.pointer(), "/meshes/3/attributes/POSITION");
+    assert_eq!(error.severity(), Severity::Error);
+    let warning = Diagnostic { path: Path::new().field("skins").index(0), error: Error::Unused };
+    assert_eq!(warning.severity(), Severity::Warning);
+    assert_eq!(warning.to_string(), "warning: /skins/0: Unused object");
+
+    let log = gltf_json::validation::to_sarif("scene.gltf", &[(warning.path, warning.error)]);
+    assert_eq!(log["runs"][0]["results"][0]["level"], "warning");
+}
+
+#[test]
+fn test_byte_ranges_validate() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "buffers": [{"byteLength": 64}],
+        "bufferViews": [
+            {"buffer": 0, "byteLength": 48, "byteStride": 16},
+            {"buffer": 0, "byteOffset": 48, "byteLength": 24},
+            {"buffer": 0, "byteOffset": 48, "byteLength": 16}
+        ],
+        "accessors": [
+            {"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3"},
+            {"bufferView": 0, "byteOffset": 4, "componentType": 5126, "count": 3, "type": "VEC3"},
+            {"bufferView": 0, "byteOffset": 8, "componentType": 5126, "count": 3, "type": "VEC3"},
+            {
+                "componentType": 5126, "count": 8, "type": "VEC2",
+                "sparse": {
+                    "count": 2,
+                    "indices": {"bufferView": 2, "byteOffset": 14, "componentType": 5123},
+                    "values": {"bufferView": 2}
+                }
+            }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| errs.push((path(), err)));
+    assert_eq!(errs, [
+        (Path("accessors[2]".into()), Error::ByteRangeOutOfBounds),
+        (Path("accessors[3].sparse.indices".into()), Error::ByteRangeOutOfBounds),
+        (Path("bufferViews[1]".into()), Error::ByteRangeOutOfBounds),
+    ]);
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+#[test]
+fn test_meshopt_byte_ranges_validate() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["EXT_meshopt_compression"],
+        "buffers": [{"byteLength": 16}, {"byteLength": 64}],
+        "bufferViews": [
+            {
+                "buffer": 1, "byteLength": 32, "byteStride": 4,
+                "extensions": {"EXT_meshopt_compression": {
+                    "buffer": 0, "byteLength": 16, "byteStride": 4, "count": 8, "mode": "ATTRIBUTES"
+                }}
+            },
+            {
+                "buffer": 1, "byteLength": 32, "byteStride": 4,
+                "extensions": {"EXT_meshopt_compression": {
+                    "buffer": 0, "byteOffset": 8, "byteLength": 16, "byteStride": 4, "count": 9, "mode": "ATTRIBUTES"
+                }}
+            },
+            {
+                "buffer": 1, "byteLength": 32, "byteStride": 4,
+                "extensions": {"EXT_meshopt_compression": {
+                    "buffer": 0, "byteLength": 16, "byteStride": 4, "count": 4294967295, "mode": "ATTRIBUTES"
+                }}
+            }
+        ]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate(&json, gltf_json::Path::new, &mut |path, err| errs.push((path(), err)));
+    assert_eq!(errs, [
+        (Path("bufferViews[1].extensions.extMeshoptCompression".into()), Error::ByteRangeOutOfBounds),
+        (Path("bufferViews[1].extensions.extMeshoptCompression.count".into()), Error::ByteRangeOutOfBounds),
+        (Path("bufferViews[2].extensions.extMeshoptCompression.count".into()), Error::ByteRangeOutOfBounds),
+    ]);
+}
+
+#[test]
+fn test_skin_attribute_sets_validate() {
+    let json = gltf_json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"componentType": 5126, "type": "VEC3", "count": 0, "min": [0, 0, 0], "max": [0, 0, 0]},
+            {"componentType": 5123, "type": "VEC4", "count": 0},
+            {"componentType": 5126, "type": "VEC4", "count": 0}
+        ],
+        "meshes": [{"primitives": [
+            {"attributes": {
+                "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2, "JOINTS_1": 1, "WEIGHTS_1": 2
+            }},
+            {"attributes": {"POSITION": 0, "JOINTS_1": 1, "WEIGHTS_1": 2}},
+            {"attributes": {"POSITION": 0, "JOINTS_0": 1}}
+        ]}]
+    }"#).unwrap();
+    let mut errs = vec![];
+    json.validate(
+        &json,
+        gltf_json::Path::new,
+        &mut |path, err| errs.push((path(), err)),
+    );
+    errs.retain(|(path, _)| path.as_str().starts_with("meshes"));
+    assert_eq!(errs,
+        [(Path("meshes[0].primitives[1].attributes[\"JOINTS_0\"]".into()), Error::Missing),
+         (Path("meshes[0].primitives[1].attributes[\"WEIGHTS_0\"]".into()), Error::Missing),
+         (Path("meshes[0].primitives[2].attributes[\"WEIGHTS_0\"]".into()), Error::Missing)]);
+}
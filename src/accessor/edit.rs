@@ -0,0 +1,213 @@
+use std::marker::PhantomData;
+
+use json::accessor::GenericComponentType;
+use json::validation::Checked::Valid;
+
+use crate::pack::{self, Component, Element};
+
+/// Edits the elements of an accessor in place within owned buffer data.
+///
+/// This suits small fixes such as snapping vertices or zeroing bad normals,
+/// where rebuilding the buffer through a [`Packer`] would be wasteful. The
+/// accessor `min` and `max` bounds, when present, are recomputed when the
+/// `AccessorMut` is dropped.
+///
+/// Elements are written to the buffer view directly, so the edit is also seen
+/// by any other accessor that overlaps the same bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf::json;
+/// # use gltf::accessor::AccessorMut;
+/// let mut root = json::Root::default();
+/// let mut packer = gltf::pack::Packer::new(&mut root);
+/// let positions = packer.push_accessor(&mut root, &[[0.0f32, 0.0, 0.0], [1.1, 0.0, 0.0]], None);
+/// let mut buffers = vec![packer.finish(&mut root)];
+///
+/// let mut accessor = AccessorMut::<[f32; 3]>::new(&mut root, &mut buffers, positions).unwrap();
+/// accessor.set(1, [1.0, 0.0, 0.0]);
+/// drop(accessor);
+/// assert_eq!(root.accessors[positions.value()].max, Some(json::Value::from(vec![1.0, 0.0, 0.0])));
+/// ```
+///
+/// [`Packer`]: ../pack/struct.Packer.html
+pub struct AccessorMut<'a, T: Element> {
+    /// The JSON containing the accessor.
+    root: &'a mut json::Root,
+
+    /// The data of the buffer containing the accessor elements.
+    data: &'a mut [u8],
+
+    /// The index of the accessor being edited.
+    index: json::Index<json::Accessor>,
+
+    /// Byte offset of the first element into `data`.
+    start: usize,
+
+    /// Distance between consecutive elements in bytes.
+    stride: usize,
+
+    /// The number of elements.
+    count: usize,
+
+    /// Whether any element has been written.
+    dirty: bool,
+
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: Element> AccessorMut<'a, T> {
+    /// Prepares the accessor at `index` for editing.
+    ///
+    /// `buffers` holds the data of each buffer in `root`. Returns `None` if `T`
+    /// does not match the accessor type, if the accessor is sparse or has no
+    /// buffer view, or if its data lies outside of `buffers`.
+    pub fn new(
+        root: &'a mut json::Root,
+        buffers: &'a mut [Vec<u8>],
+        index: json::Index<json::Accessor>,
+    ) -> Option<Self> {
+        let accessor = root.accessors.get(index.value())?;
+        let component_type = match accessor.component_type {
+            Valid(GenericComponentType(component_type)) => component_type,
+            _ => return None,
+        };
+        if accessor.sparse.is_some()
+            || accessor.type_ != Valid(T::TYPE)
+            || component_type != T::Component::COMPONENT_TYPE
+        {
+            return None;
+        }
+        let view = root.buffer_views.get(accessor.buffer_view?.value())?;
+        let size = pack::element_size::<T>();
+        let stride = view.byte_stride.map_or(size, |stride| stride as usize);
+        if stride < size {
+            return None;
+        }
+        let view_start = view.byte_offset.unwrap_or(0) as usize;
+        let view_end = view_start.checked_add(view.byte_length as usize)?;
+        let start = view_start.checked_add(accessor.byte_offset as usize)?;
+        let count = accessor.count as usize;
+        if crate::accessor::end_of(start, stride, count, size)? > view_end {
+            return None;
+        }
+        let data = buffers.get_mut(view.buffer.value())?;
+        if view_end > data.len() {
+            return None;
+        }
+        Some(Self {
+            root,
+            data: &mut data[..],
+            index,
+            start,
+            stride,
+            count,
+            dirty: false,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.count {
+            return None;
+        }
+        let size = pack::component_size(T::Component::COMPONENT_TYPE);
+        let mut offset = self.start + index * self.stride;
+        let mut element = T::default();
+        element.visit_mut(|x| {
+            *x = T::Component::read(&self.data[offset..offset + size]);
+            offset += size;
+        });
+        Some(element)
+    }
+
+    /// Overwrites the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) {
+        assert!(index < self.count, "element index out of bounds");
+        let mut bytes = Vec::with_capacity(pack::element_size::<T>());
+        value.visit(|x| x.write(&mut bytes));
+        let offset = self.start + index * self.stride;
+        self.data[offset..offset + bytes.len()].copy_from_slice(&bytes);
+        self.dirty = true;
+    }
+}
+
+impl<'a, T: Element> Drop for AccessorMut<'a, T> {
+    fn drop(&mut self) {
+        let accessor = &self.root.accessors[self.index.value()];
+        if !self.dirty || (accessor.min.is_none() && accessor.max.is_none()) {
+            return;
+        }
+        let elements: Vec<T> = (0..self.count).filter_map(|i| self.get(i)).collect();
+        let (min, max) = pack::bounds(&elements);
+        let accessor = &mut self.root.accessors[self.index.value()];
+        accessor.min = min;
+        accessor.max = max;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AccessorMut;
+    use crate::pack::Packer;
+
+    #[test]
+    fn edit_interleaved_elements() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let mut bytes = Vec::new();
+        for &(position, normal) in &[([0.0f32, 0.0, 0.0], [0.0f32, 0.0, 1.0]), ([2.0, 1.0, 0.0], [0.0; 3])] {
+            bytes.extend(position.iter().chain(normal.iter()).flat_map(|x| x.to_le_bytes()));
+        }
+        let view = packer.push_view(&mut root, &bytes, Some(24), None);
+        let accessor = |offset, min_max| {
+            let mut accessor: json::Accessor = json::deserialize::from_str(
+                r#"{"componentType": 5126, "type": "VEC3", "count": 2}"#,
+            ).unwrap();
+            accessor.buffer_view = Some(view);
+            accessor.byte_offset = offset;
+            if min_max {
+                accessor.min = Some(json::Value::from(vec![0.0, 0.0, 0.0]));
+                accessor.max = Some(json::Value::from(vec![2.0, 1.0, 0.0]));
+            }
+            accessor
+        };
+        let positions = root.push(accessor(0, true));
+        let normals = root.push(accessor(12, false));
+        let mut buffers = vec![packer.finish(&mut root)];
+
+        assert!(AccessorMut::<[u16; 3]>::new(&mut root, &mut buffers, positions).is_none());
+        assert!(AccessorMut::<[f32; 2]>::new(&mut root, &mut buffers, positions).is_none());
+
+        let mut edit = AccessorMut::<[f32; 3]>::new(&mut root, &mut buffers, normals).unwrap();
+        assert_eq!(edit.get(1), Some([0.0; 3]));
+        edit.set(1, [0.0, 1.0, 0.0]);
+        assert_eq!(edit.get(2), None);
+        drop(edit);
+        assert!(root.accessors[normals.value()].max.is_none());
+
+        let mut edit = AccessorMut::<[f32; 3]>::new(&mut root, &mut buffers, positions).unwrap();
+        assert_eq!(edit.count(), 2);
+        edit.set(1, [-1.5, 1.0, 0.0]);
+        assert_eq!(edit.get(0), Some([0.0; 3]));
+        drop(edit);
+        let json = &root.accessors[positions.value()];
+        assert_eq!(json.min, Some(json::Value::from(vec![-1.5, 0.0, 0.0])));
+        assert_eq!(json.max, Some(json::Value::from(vec![0.0, 1.0, 0.0])));
+
+        let edit = AccessorMut::<[f32; 3]>::new(&mut root, &mut buffers, normals).unwrap();
+        assert_eq!(edit.get(0), Some([0.0, 0.0, 1.0]));
+        assert_eq!(edit.get(1), Some([0.0, 1.0, 0.0]));
+    }
+}
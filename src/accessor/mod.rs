@@ -55,7 +55,7 @@
 //! # }
 //! ```
 
-use crate::{buffer, Document};
+use crate::{buffer, Document, Error, Result};
 
 pub use json::accessor::ComponentType as DataType;
 pub use json::accessor::Type as Dimensions;
@@ -65,13 +65,70 @@ pub use json::accessor::Type as Dimensions;
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
 pub mod util;
 
+/// In-place editing of accessor data.
+pub mod edit;
+
 /// Contains data structures for sparse storage.
 pub mod sparse;
 
+/// Classification of accessors by how they are referenced.
+pub mod usage;
+
+#[doc(inline)]
+pub use self::edit::AccessorMut;
+#[doc(inline)]
+pub use self::usage::Usage;
+
 #[cfg(feature = "utils")]
 #[doc(inline)]
 pub use self::util::{Item, Iter};
 
+/// Returns the end of `count` elements of `size` bytes that are `stride` bytes
+/// apart from `start`, or `None` if the arithmetic overflows `usize`.
+pub(crate) fn end_of(start: usize, stride: usize, count: usize, size: usize) -> Option<usize> {
+    match count {
+        0 => Some(start),
+        _ => stride.checked_mul(count - 1)?.checked_add(size)?.checked_add(start),
+    }
+}
+
+/// Checks that `count` elements, `stride` bytes apart from `offset` bytes into
+/// `view`, lie within the view and that the view lies within its buffer data.
+fn check_range<'a, 's>(
+    name: &str,
+    view: buffer::View<'a>,
+    (offset, stride, count, size): (usize, usize, usize, usize),
+    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+) -> std::result::Result<(), String> {
+    let end = end_of(offset, stride, count, size)
+        .ok_or_else(|| format!("the byte range of the {} overflows", name))?;
+    if end > view.length() {
+        return Err(format!(
+            "the {} end at byte {} past the {} bytes of buffer view {}",
+            name,
+            end,
+            view.length(),
+            view.index(),
+        ));
+    }
+    let buffer = view.buffer();
+    let data = get_buffer_data(buffer.clone())
+        .ok_or_else(|| format!("the data of buffer {} is unavailable", buffer.index()))?;
+    let view_end = view.offset().checked_add(view.length());
+    let view_end = view_end
+        .ok_or_else(|| format!("the byte range of buffer view {} overflows", view.index()))?;
+    if view_end > data.len() {
+        return Err(format!(
+            "buffer view {} ends at byte {} past the {} bytes of buffer {}",
+            view.index(),
+            view_end,
+            data.len(),
+            buffer.index(),
+        ));
+    }
+    Ok(())
+}
+
 /// A typed view into a buffer view.
 #[derive(Clone, Debug)]
 pub struct Accessor<'a> {
@@ -164,6 +221,107 @@ impl<'a> Accessor<'a> {
         self.json.normalized
     }
 
+    /// Returns the format of the elements of this accessor.
+    pub(crate) fn vertex_format(&self) -> crate::mesh::VertexFormat {
+        crate::mesh::VertexFormat {
+            data_type: self.data_type(),
+            dimensions: self.dimensions(),
+            normalized: self.normalized(),
+        }
+    }
+
+    /// Returns the `wgpu` vertex format of the elements of this accessor.
+    ///
+    /// Returns an error naming the accessor if no vertex format has the same
+    /// layout, for example for three 8-bit or 16-bit components or for matrices.
+    #[cfg(feature = "wgpu-types")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wgpu-types")))]
+    pub fn wgpu_vertex_format(
+        &self,
+    ) -> std::result::Result<wgpu_types::VertexFormat, crate::mesh::UnsupportedFormat> {
+        let format = self.vertex_format();
+        format.to_wgpu().ok_or(crate::mesh::UnsupportedFormat { accessor: self.index, format })
+    }
+
+    /// Returns the `wgpu` index format of the elements of this accessor.
+    ///
+    /// Returns an error naming the accessor unless it holds scalar `u16` or
+    /// `u32` components; 8-bit indices must be widened to 16 bits first.
+    #[cfg(feature = "wgpu-types")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wgpu-types")))]
+    pub fn wgpu_index_format(
+        &self,
+    ) -> std::result::Result<wgpu_types::IndexFormat, crate::mesh::UnsupportedFormat> {
+        let format = self.vertex_format();
+        match (format.data_type, format.dimensions) {
+            (DataType::U16, Dimensions::Scalar) => Ok(wgpu_types::IndexFormat::Uint16),
+            (DataType::U32, Dimensions::Scalar) => Ok(wgpu_types::IndexFormat::Uint32),
+            _ => Err(crate::mesh::UnsupportedFormat { accessor: self.index, format }),
+        }
+    }
+
+    /// Returns the element at `index`, if it exists and its data is available.
+    ///
+    /// This is the random-access complement to [`Iter`]. Buffer view strides are
+    /// respected and sparse elements are substituted for the base values.
+    ///
+    /// [`Iter`]: struct.Iter.html
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn element<'s, T, F>(&self, index: usize, get_buffer_data: F) -> Option<T>
+    where
+        T: Item,
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        util::element(self, index, &get_buffer_data)
+    }
+
+    /// Checks that the elements of the accessor, and the indices and values of
+    /// its sparse storage, lie within their buffer views and that those lie
+    /// within the data returned by `get_buffer_data`.
+    ///
+    /// Byte ranges are computed with checked arithmetic, so ranges that
+    /// overflow `usize`, as they may on 32-bit targets, are reported rather
+    /// than wrapping. Validated documents are within the declared lengths of
+    /// their buffer views and buffers, but documents constructed without
+    /// validation, or buffer data shorter than declared, need not be; the
+    /// readers of such accessors return `None`, whereas this function returns
+    /// an `Error::AccessorBounds` describing the problem.
+    pub fn check_bounds<'s, F>(&self, get_buffer_data: F) -> Result<()>
+    where
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let error = |detail| Error::AccessorBounds { accessor: self.index, detail };
+        let size = self.size();
+        if let Some(view) = self.view() {
+            let stride = view.stride().unwrap_or(size);
+            let range = (self.offset(), stride, self.count(), size);
+            check_range("elements", view, range, &get_buffer_data).map_err(error)?;
+        }
+        if let Some(sparse) = self.sparse() {
+            let count = sparse.count() as usize;
+            let (indices, values) = (sparse.indices(), sparse.values());
+            let index_size = indices.index_type().size();
+            let view = indices.view();
+            let stride = view.stride().unwrap_or(index_size);
+            let range = (indices.offset() as usize, stride, count, index_size);
+            check_range("sparse indices", view, range, &get_buffer_data).map_err(error)?;
+            let view = values.view();
+            let stride = view.stride().unwrap_or(size);
+            let range = (values.offset() as usize, stride, count, size);
+            check_range("sparse values", view, range, &get_buffer_data).map_err(error)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the distinct ways in which the accessor is referenced by the
+    /// document, for example as vertex positions or animation keyframe times.
+    ///
+    /// See `accessor::usage::usages` for classifying every accessor at once.
+    pub fn usage(&self) -> Vec<Usage> {
+        crate::cache::usages(self.document)[self.index].clone()
+    }
+
     /// Returns sparse storage of attributes that deviate from their initialization
     /// value.
     pub fn sparse(&self) -> Option<sparse::Sparse<'a>> {
@@ -0,0 +1,142 @@
+use json::validation::Checked::Valid;
+
+use crate::mesh::Semantic;
+
+/// A way in which an accessor is referenced by a document.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Usage {
+    /// Vertex attribute data of a mesh primitive.
+    Attribute(Semantic),
+
+    /// Vertex indices of a mesh primitive.
+    Indices,
+
+    /// Vertex attribute displacements of a morph target, given by the semantic
+    /// of the displaced attribute.
+    MorphTarget(Semantic),
+
+    /// Inverse-bind matrices of a skin.
+    InverseBindMatrices,
+
+    /// Keyframe times of an animation sampler.
+    AnimationInput,
+
+    /// Keyframe values of an animation sampler.
+    AnimationOutput,
+
+    /// Per-instance data of a node, given by the attribute name, as defined by
+    /// the `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+    InstanceAttribute(String),
+}
+
+/// Returns the usages of every accessor in `root`, indexed by accessor.
+///
+/// Each list holds distinct usages in the order in which they are first found.
+/// Unreferenced accessors have no usages. This scans the document once, so it is
+/// preferable to calling `Accessor::usage` for each accessor in turn.
+pub fn usages(root: &json::Root) -> Vec<Vec<Usage>> {
+    let mut usages = vec![Vec::new(); root.accessors.len()];
+    let mut add = |index: json::Index<json::Accessor>, usage: Usage| {
+        if let Some(list) = usages.get_mut(index.value()) {
+            if !list.contains(&usage) {
+                list.push(usage);
+            }
+        }
+    };
+    for mesh in &root.meshes {
+        for primitive in &mesh.primitives {
+            for (semantic, &accessor) in &primitive.attributes {
+                if let Valid(semantic) = semantic {
+                    add(accessor, Usage::Attribute(semantic.clone()));
+                }
+            }
+            if let Some(indices) = primitive.indices {
+                add(indices, Usage::Indices);
+            }
+            for target in primitive.targets.iter().flatten() {
+                let displacements = [
+                    (target.positions, Semantic::Positions),
+                    (target.normals, Semantic::Normals),
+                    (target.tangents, Semantic::Tangents),
+                ];
+                for (accessor, semantic) in displacements.iter().cloned() {
+                    if let Some(accessor) = accessor {
+                        add(accessor, Usage::MorphTarget(semantic));
+                    }
+                }
+                for (semantic, &accessor) in &target.others {
+                    if let Valid(semantic) = semantic {
+                        add(accessor, Usage::MorphTarget(semantic.clone()));
+                    }
+                }
+            }
+        }
+    }
+    for skin in &root.skins {
+        if let Some(accessor) = skin.inverse_bind_matrices {
+            add(accessor, Usage::InverseBindMatrices);
+        }
+    }
+    for animation in &root.animations {
+        for sampler in &animation.samplers {
+            add(sampler.input, Usage::AnimationInput);
+            add(sampler.output, Usage::AnimationOutput);
+        }
+    }
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    for node in &root.nodes {
+        let extensions = node.extensions.as_ref();
+        if let Some(instancing) = extensions.and_then(|x| x.ext_mesh_gpu_instancing.as_ref()) {
+            for (name, &accessor) in &instancing.attributes {
+                add(accessor, Usage::InstanceAttribute(name.clone()));
+            }
+        }
+    }
+    usages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{usages, Usage};
+    use crate::mesh::Semantic;
+    use crate::Document;
+
+    #[test]
+    fn classify_accessor_usage() {
+        let json = json::Root::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [
+                {"componentType": 5126, "type": "VEC3", "count": 0},
+                {"componentType": 5123, "type": "SCALAR", "count": 0},
+                {"componentType": 5126, "type": "SCALAR", "count": 0},
+                {"componentType": 5126, "type": "MAT4", "count": 0},
+                {"componentType": 5126, "type": "VEC3", "count": 0}
+            ],
+            "meshes": [{"primitives": [
+                {"attributes": {"POSITION": 0}, "indices": 1, "targets": [{"POSITION": 4}]},
+                {"attributes": {"POSITION": 0, "NORMAL": 4}}
+            ]}],
+            "nodes": [{}],
+            "skins": [{"joints": [0], "inverseBindMatrices": 3}],
+            "animations": [{
+                "channels": [{"sampler": 0, "target": {"node": 0, "path": "translation"}}],
+                "samplers": [{"input": 2, "output": 4}]
+            }]
+        }"#).unwrap();
+        let usages = usages(&json);
+        assert_eq!(usages[0], vec![Usage::Attribute(Semantic::Positions)]);
+        assert_eq!(usages[1], vec![Usage::Indices]);
+        assert_eq!(usages[2], vec![Usage::AnimationInput]);
+        assert_eq!(usages[3], vec![Usage::InverseBindMatrices]);
+        assert_eq!(usages[4].len(), 3);
+        assert!(usages[4].contains(&Usage::MorphTarget(Semantic::Positions)));
+        assert!(usages[4].contains(&Usage::Attribute(Semantic::Normals)));
+        assert!(usages[4].contains(&Usage::AnimationOutput));
+
+        let document = Document::from_json_without_validation(json);
+        let accessor = document.accessors().nth(3).unwrap();
+        assert_eq!(accessor.usage(), vec![Usage::InverseBindMatrices]);
+    }
+}
@@ -2,16 +2,86 @@ use std::{iter, mem};
 use byteorder::{LE, ByteOrder};
 use std::marker::PhantomData;
 
-use crate::{accessor, buffer};
+use crate::{accessor, buffer, Normalize};
 
 fn buffer_view_slice<'a, 's>(
     view: buffer::View<'a>,
     get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
 ) -> Option<&'s [u8]> {
     let start = view.offset();
-    let end = start + view.length();
-    get_buffer_data(view.buffer())
-        .map(|slice| &slice[start..end])
+    let end = start.checked_add(view.length())?;
+    get_buffer_data(view.buffer())?.get(start..end)
+}
+
+/// Reads the `index`th item from `data`, where items are `stride` bytes apart.
+fn read_item<T: Item>(data: &[u8], stride: usize, index: usize) -> Option<T> {
+    let start = index.checked_mul(stride)?;
+    let end = start.checked_add(mem::size_of::<T>())?;
+    data.get(start..end).map(T::from_slice)
+}
+
+/// Reads the `index`th sparse index, whatever its component type.
+fn read_sparse_index(
+    data: &[u8],
+    index_type: &accessor::sparse::IndexType,
+    stride: usize,
+    index: usize,
+) -> Option<u32> {
+    match *index_type {
+        accessor::sparse::IndexType::U8 => read_item::<u8>(data, stride, index).map(u32::from),
+        accessor::sparse::IndexType::U16 => read_item::<u16>(data, stride, index).map(u32::from),
+        accessor::sparse::IndexType::U32 => read_item::<u32>(data, stride, index),
+    }
+}
+
+/// Reads a single element of an accessor, resolving sparse substitution.
+///
+/// Sparse indices are required to be strictly increasing, so the substituted
+/// value, if any, is found by binary search.
+pub(crate) fn element<'a, 's, T: Item>(
+    accessor: &accessor::Accessor<'a>,
+    index: usize,
+    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+) -> Option<T> {
+    if index >= accessor.count() {
+        return None;
+    }
+
+    if let Some(sparse) = accessor.sparse() {
+        let indices = sparse.indices();
+        let index_type = indices.index_type();
+        let index_size = index_type.size();
+        let index_view = indices.view();
+        let index_data = buffer_view_slice(index_view.clone(), get_buffer_data)?
+            .get(indices.offset() as usize..)?;
+        let index_stride = index_view.stride().unwrap_or(index_size);
+        let (mut lo, mut hi) = (0, sparse.count() as usize);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let sparse_index = read_sparse_index(index_data, &index_type, index_stride, mid)?;
+            match (sparse_index as usize).cmp(&index) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => {
+                    let values = sparse.values();
+                    let value_view = values.view();
+                    let value_data = buffer_view_slice(value_view.clone(), get_buffer_data)?
+                        .get(values.offset() as usize..)?;
+                    let value_stride = value_view.stride().unwrap_or(mem::size_of::<T>());
+                    return read_item(value_data, value_stride, mid);
+                },
+            }
+        }
+    }
+
+    match accessor.view() {
+        Some(view) => {
+            let stride = view.stride().unwrap_or(mem::size_of::<T>());
+            let data = buffer_view_slice(view, get_buffer_data)?.get(accessor.offset()..)?;
+            read_item(data, stride, index)
+        },
+        None => Some(T::zero()),
+    }
 }
 
 /// General iterator for an accessor.
@@ -103,22 +173,28 @@ pub struct SparseIter<'a, T: Item> {
 
     /// Iterator counter.
     counter: u32,
+
+    /// The number of elements in the accessor.
+    count: u32,
 }
 
 impl<'a, T: Item> SparseIter<'a, T> {
     /// Constructor.
     /// 
     /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified.
+    /// Iteration stops after `count` items, the number of elements in the accessor.
     pub fn new(
         base: Option<ItemIter<'a, T>>,
         indices: SparseIndicesIter<'a>,
         values: ItemIter<'a, T>,
+        count: usize,
     ) -> Self {
         SparseIter {
             base,
             indices: indices.peekable(),
             values: values,
             counter: 0,
+            count: count as u32,
         }
     }
 }
@@ -126,6 +202,9 @@ impl<'a, T: Item> SparseIter<'a, T> {
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
+        if self.counter >= self.count {
+            return None;
+        }
         let next_base_value = self.base.as_mut().map(|iter| iter.next()).unwrap_or(Some(T::zero()));
         if next_base_value.is_none() {
             return None;
@@ -146,7 +225,7 @@ impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.values.len() - self.counter as usize;
+        let hint = (self.count - self.counter) as usize;
         (hint, Some(hint))
     }
 }
@@ -292,12 +371,9 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                 if let Some(view) = accessor.view() {
                     let stride = view.stride().unwrap_or(mem::size_of::<T>());
                     let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                        &slice[start..end]
-                    } else {
-                        return None
-                    };
+                    let size = mem::size_of::<T>();
+                    let end = accessor::end_of(start, stride, accessor.count(), size)?;
+                    let subslice = buffer_view_slice(view, &get_buffer_data)?.get(start..end)?;
                     Some(ItemIter::new(subslice, stride))
                 } else {
                     None
@@ -308,13 +384,9 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                 let view = indices.view();
                 let index_size = indices.index_type().size();
                 let stride = view.stride().unwrap_or(index_size);
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = indices.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + index_size;
-                    &slice[start..end]
-                } else {
-                    return None
-                };
+                let start = indices.offset() as usize;
+                let end = accessor::end_of(start, stride, sparse_count, index_size)?;
+                let subslice = buffer_view_slice(view, &get_buffer_data)?.get(start..end)?;
                 match indices.index_type() {
                     accessor::sparse::IndexType::U8 => SparseIndicesIter::U8(ItemIter::new(subslice, stride)),
                     accessor::sparse::IndexType::U16 => SparseIndicesIter::U16(ItemIter::new(subslice, stride)),
@@ -324,16 +396,17 @@ impl<'a, 's, T: Item> Iter<'s, T> {
             let value_iter = {
                 let view = values.view();
                 let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = values.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
-                    &slice[start..end]
-                } else {
-                    return None
-                };
+                let start = values.offset() as usize;
+                let end = accessor::end_of(start, stride, sparse_count, mem::size_of::<T>())?;
+                let subslice = buffer_view_slice(view, &get_buffer_data)?.get(start..end)?;
                 ItemIter::new(subslice, stride)
             };
-            Some(Iter::Sparse(SparseIter::new(base_iter, index_iter, value_iter)))
+            Some(Iter::Sparse(SparseIter::new(
+                base_iter,
+                index_iter,
+                value_iter,
+                accessor.count(),
+            )))
         } else {
             debug_assert_eq!(mem::size_of::<T>(), accessor.size());
             debug_assert!(mem::size_of::<T>() > 0);
@@ -341,12 +414,9 @@ impl<'a, 's, T: Item> Iter<'s, T> {
                 let stride = view.stride().unwrap_or(mem::size_of::<T>());
                 debug_assert!(stride >= mem::size_of::<T>(), "Mismatch in stride, expected at least {} stride but found {}", mem::size_of::<T>(), stride);
                 let start = accessor.offset();
-                let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    &slice[start..end]
-                } else {
-                    return None
-                };
+                let size = mem::size_of::<T>();
+                let end = accessor::end_of(start, stride, accessor.count(), size)?;
+                let subslice = buffer_view_slice(view, &get_buffer_data)?.get(start..end)?;
                 Some(Iter::Standard(ItemIter { stride, data: subslice, _phantom: PhantomData }))
             } else {
                 None
@@ -355,6 +425,338 @@ impl<'a, 's, T: Item> Iter<'s, T> {
     }
 }
 
+/// Items of `f32` components that `ReadF32` decodes accessor items into.
+///
+/// Implemented for `f32` and arrays of two, three, and four `f32`.
+pub trait Floats: Item + Copy {
+    /// The accessor dimensions matching this item.
+    const DIMENSIONS: accessor::Dimensions;
+
+    /// The equivalent item of `i8` components.
+    type I8: Item + Copy;
+
+    /// The equivalent item of `u8` components.
+    type U8: Item + Copy;
+
+    /// The equivalent item of `i16` components.
+    type I16: Item + Copy;
+
+    /// The equivalent item of `u16` components.
+    type U16: Item + Copy;
+
+    /// Decodes normalized `i8` components.
+    fn from_i8(x: Self::I8) -> Self;
+
+    /// Decodes normalized `u8` components.
+    fn from_u8(x: Self::U8) -> Self;
+
+    /// Decodes normalized `i16` components.
+    fn from_i16(x: Self::I16) -> Self;
+
+    /// Decodes normalized `u16` components.
+    fn from_u16(x: Self::U16) -> Self;
+
+    /// Converts unnormalized `i8` components.
+    fn cast_i8(x: Self::I8) -> Self;
+
+    /// Converts unnormalized `u8` components.
+    fn cast_u8(x: Self::U8) -> Self;
+
+    /// Converts unnormalized `i16` components.
+    fn cast_i16(x: Self::I16) -> Self;
+
+    /// Converts unnormalized `u16` components.
+    fn cast_u16(x: Self::U16) -> Self;
+}
+
+macro_rules! impl_floats {
+    ($dimensions:ident, $float:ty, $i8:ty, $u8:ty, $i16:ty, $u16:ty, $cast:expr) => {
+        impl Floats for $float {
+            const DIMENSIONS: accessor::Dimensions = accessor::Dimensions::$dimensions;
+            type I8 = $i8;
+            type U8 = $u8;
+            type I16 = $i16;
+            type U16 = $u16;
+            fn from_i8(x: Self::I8) -> Self { x.normalize() }
+            fn from_u8(x: Self::U8) -> Self { x.normalize() }
+            fn from_i16(x: Self::I16) -> Self { x.normalize() }
+            fn from_u16(x: Self::U16) -> Self { x.normalize() }
+            fn cast_i8(x: Self::I8) -> Self { $cast(x) }
+            fn cast_u8(x: Self::U8) -> Self { $cast(x) }
+            fn cast_i16(x: Self::I16) -> Self { $cast(x) }
+            fn cast_u16(x: Self::U16) -> Self { $cast(x) }
+        }
+    };
+}
+
+/// Converts the components of an array to `f32`.
+fn cast_array<T: Into<f32>, const N: usize>(x: [T; N]) -> [f32; N] {
+    x.map(Into::into)
+}
+
+impl_floats!(Scalar, f32, i8, u8, i16, u16, f32::from);
+impl_floats!(Vec2, [f32; 2], [i8; 2], [u8; 2], [i16; 2], [u16; 2], cast_array);
+impl_floats!(Vec3, [f32; 3], [i8; 3], [u8; 3], [i16; 3], [u16; 3], cast_array);
+impl_floats!(Vec4, [f32; 4], [i8; 4], [u8; 4], [i16; 4], [u16; 4], cast_array);
+
+/// Reads the items of an accessor as floats, decoding normalized integer
+/// components per the glTF specification.
+///
+/// Signed components `c` of `n` bits decode to `max(c / (2^(n-1) - 1), -1)` and
+/// unsigned components to `c / (2^n - 1)`.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use gltf::accessor::util::ReadF32;
+///
+/// let (gltf, buffers, _) = gltf::import("examples/Box.gltf")?;
+/// let get_buffer_data = |buffer: gltf::Buffer| buffers.get(buffer.index()).map(|x| &*x.0);
+/// for accessor in gltf.accessors() {
+///     if let Some(iter) = ReadF32::<[f32; 3]>::new(accessor, get_buffer_data) {
+///         for item in iter {
+///             println!("{:?}", item);
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub enum ReadF32<'a, T: Floats> {
+    /// Normalized `i8` components.
+    I8(Iter<'a, T::I8>),
+
+    /// Normalized `u8` components.
+    U8(Iter<'a, T::U8>),
+
+    /// Normalized `i16` components.
+    I16(Iter<'a, T::I16>),
+
+    /// Normalized `u16` components.
+    U16(Iter<'a, T::U16>),
+
+    /// `f32` components.
+    F32(Iter<'a, T>),
+
+    /// Unnormalized `i8` components, as permitted by `KHR_mesh_quantization`.
+    CastI8(Iter<'a, T::I8>),
+
+    /// Unnormalized `u8` components, as permitted by `KHR_mesh_quantization`.
+    CastU8(Iter<'a, T::U8>),
+
+    /// Unnormalized `i16` components, as permitted by `KHR_mesh_quantization`.
+    CastI16(Iter<'a, T::I16>),
+
+    /// Unnormalized `u16` components, as permitted by `KHR_mesh_quantization`.
+    CastU16(Iter<'a, T::U16>),
+}
+
+impl<'a, 's, T: Floats> ReadF32<'s, T> {
+    /// Constructor.
+    ///
+    /// Returns `None` if the accessor dimensions do not match `T`, if it has
+    /// integer components that are not normalized, or if its data is missing.
+    pub fn new<F>(accessor: super::Accessor<'a>, get_buffer_data: F) -> Option<Self>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use accessor::DataType;
+        if accessor.dimensions() != T::DIMENSIONS {
+            return None;
+        }
+        match accessor.data_type() {
+            DataType::F32 => Iter::new(accessor, get_buffer_data).map(ReadF32::F32),
+            _ if !accessor.normalized() => None,
+            DataType::I8 => Iter::new(accessor, get_buffer_data).map(ReadF32::I8),
+            DataType::U8 => Iter::new(accessor, get_buffer_data).map(ReadF32::U8),
+            DataType::I16 => Iter::new(accessor, get_buffer_data).map(ReadF32::I16),
+            DataType::U16 => Iter::new(accessor, get_buffer_data).map(ReadF32::U16),
+            DataType::U32 => None,
+        }
+    }
+
+    /// Constructs a reader that also accepts unnormalized integer components,
+    /// as permitted by the `KHR_mesh_quantization` extension, converting each
+    /// component to `f32` unchanged.
+    ///
+    /// Returns `None` if the accessor dimensions do not match `T`, if it has
+    /// `u32` components, or if its data is missing.
+    pub fn new_quantized<F>(accessor: super::Accessor<'a>, get_buffer_data: F) -> Option<Self>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use accessor::DataType;
+        if accessor.normalized() || accessor.dimensions() != T::DIMENSIONS {
+            return Self::new(accessor, get_buffer_data);
+        }
+        match accessor.data_type() {
+            DataType::F32 => Iter::new(accessor, get_buffer_data).map(ReadF32::F32),
+            DataType::I8 => Iter::new(accessor, get_buffer_data).map(ReadF32::CastI8),
+            DataType::U8 => Iter::new(accessor, get_buffer_data).map(ReadF32::CastU8),
+            DataType::I16 => Iter::new(accessor, get_buffer_data).map(ReadF32::CastI16),
+            DataType::U16 => Iter::new(accessor, get_buffer_data).map(ReadF32::CastU16),
+            DataType::U32 => None,
+        }
+    }
+}
+
+impl<'a, T: Floats> ExactSizeIterator for ReadF32<'a, T> {}
+impl<'a, T: Floats> Iterator for ReadF32<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match *self {
+            ReadF32::I8(ref mut iter) => iter.next().map(T::from_i8),
+            ReadF32::U8(ref mut iter) => iter.next().map(T::from_u8),
+            ReadF32::I16(ref mut iter) => iter.next().map(T::from_i16),
+            ReadF32::U16(ref mut iter) => iter.next().map(T::from_u16),
+            ReadF32::F32(ref mut iter) => iter.next(),
+            ReadF32::CastI8(ref mut iter) => iter.next().map(T::cast_i8),
+            ReadF32::CastU8(ref mut iter) => iter.next().map(T::cast_u8),
+            ReadF32::CastI16(ref mut iter) => iter.next().map(T::cast_i16),
+            ReadF32::CastU16(ref mut iter) => iter.next().map(T::cast_u16),
+        }
+    }
+
+    fn nth(&mut self, nth: usize) -> Option<Self::Item> {
+        match *self {
+            ReadF32::I8(ref mut iter) => iter.nth(nth).map(T::from_i8),
+            ReadF32::U8(ref mut iter) => iter.nth(nth).map(T::from_u8),
+            ReadF32::I16(ref mut iter) => iter.nth(nth).map(T::from_i16),
+            ReadF32::U16(ref mut iter) => iter.nth(nth).map(T::from_u16),
+            ReadF32::F32(ref mut iter) => iter.nth(nth),
+            ReadF32::CastI8(ref mut iter) => iter.nth(nth).map(T::cast_i8),
+            ReadF32::CastU8(ref mut iter) => iter.nth(nth).map(T::cast_u8),
+            ReadF32::CastI16(ref mut iter) => iter.nth(nth).map(T::cast_i16),
+            ReadF32::CastU16(ref mut iter) => iter.nth(nth).map(T::cast_u16),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            ReadF32::I8(ref iter) => iter.size_hint(),
+            ReadF32::U8(ref iter) => iter.size_hint(),
+            ReadF32::I16(ref iter) => iter.size_hint(),
+            ReadF32::U16(ref iter) => iter.size_hint(),
+            ReadF32::F32(ref iter) => iter.size_hint(),
+            ReadF32::CastI8(ref iter) => iter.size_hint(),
+            ReadF32::CastU8(ref iter) => iter.size_hint(),
+            ReadF32::CastI16(ref iter) => iter.size_hint(),
+            ReadF32::CastU16(ref iter) => iter.size_hint(),
+        }
+    }
+}
+
+/// All-zero element data, as large as the largest accessor element.
+static ZEROS: [u8; 64] = [0; 64];
+
+/// Reads the elements of an accessor of any type as their raw little-endian
+/// bytes, resolving sparse substitution.
+///
+/// This suits application specific data whose type is known at run time
+/// only, such as the `_BATCHID` attribute of 3D Tiles. Each element is
+/// `Accessor::size` bytes, whose components are decoded according to the
+/// `data_type`, `dimensions`, and `normalized` properties of the accessor.
+#[derive(Clone, Debug)]
+pub struct ReadRaw<'a> {
+    /// The data from the first element on, or `None` for all-zero data.
+    data: Option<&'a [u8]>,
+
+    /// The distance between the starts of two elements in `data`.
+    stride: usize,
+
+    /// The size of an element in bytes.
+    size: usize,
+
+    /// The sparse substitutions, in increasing index order.
+    sparse: Vec<(usize, &'a [u8])>,
+
+    /// The number of sparse substitutions visited so far.
+    visited: usize,
+
+    /// The index of the next element.
+    index: usize,
+
+    /// The number of elements.
+    count: usize,
+}
+
+impl<'s> ReadRaw<'s> {
+    /// Constructor.
+    ///
+    /// Returns `None` if the data of the accessor is unavailable or too short.
+    pub fn new<'a, F>(accessor: super::Accessor<'a>, get_buffer_data: F) -> Option<Self>
+    where
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let size = accessor.size();
+        let count = accessor.count();
+        let (data, stride) = match accessor.view() {
+            Some(view) => {
+                let stride = view.stride().unwrap_or(size);
+                let data = buffer_view_slice(view, &get_buffer_data)?.get(accessor.offset()..)?;
+                (Some(data.get(..accessor::end_of(0, stride, count, size)?)?), stride)
+            },
+            None => (None, size),
+        };
+
+        let mut sparse = Vec::new();
+        if let Some(info) = accessor.sparse() {
+            let (indices, values) = (info.indices(), info.values());
+            let index_type = indices.index_type();
+            let index_view = indices.view();
+            let index_stride = index_view.stride().unwrap_or(index_type.size());
+            let index_data = buffer_view_slice(index_view, &get_buffer_data)?
+                .get(indices.offset() as usize..)?;
+            let value_view = values.view();
+            let value_stride = value_view.stride().unwrap_or(size);
+            let value_data = buffer_view_slice(value_view, &get_buffer_data)?
+                .get(values.offset() as usize..)?;
+            for i in 0..info.count() as usize {
+                let index = read_sparse_index(index_data, &index_type, index_stride, i)?;
+                let start = i.checked_mul(value_stride)?;
+                let end = start.checked_add(size)?;
+                sparse.push((index as usize, value_data.get(start..end)?));
+            }
+        }
+        Some(ReadRaw { data, stride, size, sparse, visited: 0, index: 0, count })
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadRaw<'a> {}
+impl<'a> Iterator for ReadRaw<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        // Substitutions of indices already passed are skipped, should the
+        // indices not be strictly increasing.
+        while let Some(&(sparse_index, value)) = self.sparse.get(self.visited) {
+            if sparse_index > index {
+                break;
+            }
+            self.visited += 1;
+            if sparse_index == index {
+                return Some(value);
+            }
+        }
+        match self.data {
+            Some(data) => data.get(index * self.stride..index * self.stride + self.size),
+            None => ZEROS.get(..self.size),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.count - self.index;
+        (hint, Some(hint))
+    }
+}
+
 impl<'a, T: Item> ExactSizeIterator for ItemIter<'a, T> {}
 impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     type Item = T;
@@ -411,3 +813,146 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
         (hint, Some(hint))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn random_access_elements() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let mut displacements = vec![[0.0f32; 3]; 300];
+        displacements[7] = [1.0, 2.0, 3.0];
+        displacements[299] = [-1.0, 0.0, 0.5];
+        let sparse = packer.push_sparse_accessor(&mut root, None, &displacements);
+        let base: Vec<u16> = (0..64).collect();
+        let base_accessor = packer.push_accessor(&mut root, &base, None);
+        let mut edited = base.clone();
+        edited[3] = 1000;
+        edited[40] = 2000;
+        let edited_accessor = packer.push_sparse_accessor(
+            &mut root,
+            Some((base_accessor, &base)),
+            &edited,
+        );
+        let bin = packer.finish(&mut root);
+        let document = Document::from_json(root).unwrap();
+        let get_buffer_data = |_| Some(&bin[..]);
+
+        let accessor = document.accessors().nth(sparse.value()).unwrap();
+        assert!(accessor.view().is_none());
+        for (i, expected) in displacements.iter().enumerate() {
+            assert_eq!(accessor.element::<[f32; 3], _>(i, get_buffer_data).as_ref(), Some(expected));
+        }
+        assert_eq!(accessor.element::<[f32; 3], _>(300, get_buffer_data), None);
+
+        let accessor = document.accessors().nth(edited_accessor.value()).unwrap();
+        assert!(accessor.sparse().is_some());
+        for (i, &expected) in edited.iter().enumerate() {
+            assert_eq!(accessor.element::<u16, _>(i, get_buffer_data), Some(expected));
+        }
+        assert_eq!(accessor.element::<u16, _>(10, |_| None), None);
+    }
+
+    #[test]
+    fn out_of_bounds_accessors() {
+        use super::{Iter, ReadRaw};
+        use crate::Error;
+
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let positions = packer.push_accessor(&mut root, &[[0.0f32; 3]; 2], None);
+        let bin = packer.finish(&mut root);
+        root.buffer_views[0].byte_stride = Some(252);
+        root.accessors[positions.value()].count = u32::MAX;
+        let document = Document::from_json_without_validation(root.clone());
+        let accessor = document.accessors().nth(positions.value()).unwrap();
+        assert!(Iter::<[f32; 3]>::new(accessor.clone(), |_| Some(&bin[..])).is_none());
+        assert!(ReadRaw::new(accessor.clone(), |_| Some(&bin[..])).is_none());
+        match accessor.check_bounds(|_| Some(&bin[..])) {
+            Err(Error::AccessorBounds { accessor: 0, .. }) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+
+        root.buffer_views[0].byte_stride = None;
+        root.accessors[positions.value()].count = 2;
+        let document = Document::from_json(root).unwrap();
+        let accessor = document.accessors().nth(positions.value()).unwrap();
+        assert!(accessor.check_bounds(|_| Some(&bin[..])).is_ok());
+        assert!(Iter::<[f32; 3]>::new(accessor.clone(), |_| Some(&bin[..12])).is_none());
+        let error = accessor.check_bounds(|_| Some(&bin[..12])).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "accessor 0: buffer view 0 ends at byte 24 past the 12 bytes of buffer 0",
+        );
+    }
+
+    #[test]
+    fn iterate_sparse_accessors() {
+        use super::Iter;
+
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let mut displacements = vec![[0.0f32; 3]; 5];
+        displacements[1] = [1.0, 2.0, 3.0];
+        let sparse = packer.push_sparse_accessor(&mut root, None, &displacements);
+        let base: Vec<u16> = (0..8).collect();
+        let base_accessor = packer.push_accessor(&mut root, &base, None);
+        let mut edited = base.clone();
+        edited[7] = 1000;
+        let edited_accessor = packer.push_sparse_accessor(
+            &mut root,
+            Some((base_accessor, &base)),
+            &edited,
+        );
+        let bin = packer.finish(&mut root);
+        let document = Document::from_json(root).unwrap();
+        let get_buffer_data = |_| Some(&bin[..]);
+
+        let accessor = document.accessors().nth(sparse.value()).unwrap();
+        let iter = Iter::<[f32; 3]>::new(accessor, get_buffer_data).unwrap();
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.collect::<Vec<_>>(), displacements);
+
+        let accessor = document.accessors().nth(edited_accessor.value()).unwrap();
+        let iter = Iter::<u16>::new(accessor, get_buffer_data).unwrap();
+        assert_eq!(iter.len(), 8);
+        assert_eq!(iter.collect::<Vec<_>>(), edited);
+    }
+
+    #[test]
+    fn read_normalized_floats() {
+        use super::ReadF32;
+
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let unsigned = packer.push_accessor(&mut root, &[[0u8, 255], [51, 102]], None);
+        let signed = packer.push_accessor(&mut root, &[[-32768i16, 32767], [0, -16384]], None);
+        let integers = packer.push_accessor(&mut root, &[[1u8, 2]], None);
+        root.accessors[unsigned.value()].normalized = true;
+        root.accessors[signed.value()].normalized = true;
+        let bin = packer.finish(&mut root);
+        let document = Document::from_json(root).unwrap();
+        let get_buffer_data = |_| Some(&bin[..]);
+        let accessor = |index: json::Index<json::Accessor>| {
+            document.accessors().nth(index.value()).unwrap()
+        };
+
+        let read = |index| -> Vec<f32> {
+            ReadF32::<[f32; 2]>::new(accessor(index), get_buffer_data)
+                .unwrap()
+                .flatten()
+                .collect()
+        };
+        for (&x, &expected) in read(unsigned).iter().zip(&[0.0, 1.0, 0.2, 0.4]) {
+            assert_relative_eq!(x, expected);
+        }
+        for (&x, &expected) in read(signed).iter().zip(&[-1.0, 1.0, 0.0, -0.5]) {
+            assert_relative_eq!(x, expected, epsilon = 1e-4);
+        }
+        assert!(ReadF32::<[f32; 2]>::new(accessor(integers), get_buffer_data).is_none());
+        assert!(ReadF32::<[f32; 3]>::new(accessor(unsigned), get_buffer_data).is_none());
+    }
+}
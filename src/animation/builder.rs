@@ -0,0 +1,360 @@
+use std::fmt;
+
+use json::accessor::Type;
+use json::validation::Checked::Valid;
+
+use crate::animation::{Interpolation, Property};
+use crate::pack::{Element, Packer};
+
+/// Represents a failure to add an animation channel.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// No keyframe times were given.
+    Empty,
+
+    /// The keyframe time at the given index is negative or not finite.
+    InvalidTime(usize),
+
+    /// The keyframe time at the given index is not greater than the previous
+    /// keyframe time.
+    NotIncreasing(usize),
+
+    /// The number of values does not suit the number of keyframe times and the
+    /// interpolation algorithm.
+    Length {
+        /// The number of keyframe times.
+        times: usize,
+        /// The number of values.
+        values: usize,
+    },
+
+    /// The target node, given by its index, does not exist.
+    Node(usize),
+
+    /// The value type does not suit the animated property.
+    Type(Property),
+
+    /// The animation already has a channel targeting the same property of the
+    /// node with the given index.
+    DuplicateTarget(usize, Property),
+}
+
+/// Helper for creating an animation along with its samplers and the accessors
+/// holding its keyframes.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf::json;
+/// # use gltf::animation::AnimationBuilder;
+/// let mut root = json::Root::default();
+/// let node = root.push::<json::Node>(json::deserialize::from_str("{}").unwrap());
+/// let mut packer = gltf::pack::Packer::new(&mut root);
+///
+/// let mut builder = AnimationBuilder::new();
+/// builder.channel_translation(
+///     &mut root,
+///     &mut packer,
+///     node,
+///     &[0.0, 1.0],
+///     &[[0.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+/// ).unwrap();
+/// let animation = builder.build(&mut root);
+/// let bin = packer.finish(&mut root);
+/// assert_eq!(root.animations[animation.value()].channels.len(), 1);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AnimationBuilder {
+    /// The animation under construction.
+    channels: Vec<json::animation::Channel>,
+
+    /// The samplers of the animation under construction.
+    samplers: Vec<json::animation::Sampler>,
+
+    /// Keyframe time accessors written so far, so that channels sharing the
+    /// same times share the same input accessor.
+    inputs: Vec<(Vec<f32>, json::Index<json::Accessor>)>,
+
+    /// Optional user-defined name for the animation.
+    #[cfg(feature = "names")]
+    name: Option<String>,
+}
+
+/// Returns the accessor type of the values of an animated node property, or
+/// `None` for JSON pointers, which the builder does not target.
+fn value_type(property: Property) -> Option<Type> {
+    match property {
+        Property::Translation | Property::Scale => Some(Type::Vec3),
+        Property::Rotation => Some(Type::Vec4),
+        Property::MorphTargetWeights => Some(Type::Scalar),
+        #[cfg(feature = "KHR_animation_pointer")]
+        Property::Pointer => None,
+    }
+}
+
+/// Checks that keyframe times are non-negative, finite, and strictly increasing.
+fn check_times(times: &[f32]) -> Result<(), Error> {
+    if times.is_empty() {
+        return Err(Error::Empty);
+    }
+    for (i, &time) in times.iter().enumerate() {
+        if !time.is_finite() || time < 0.0 {
+            return Err(Error::InvalidTime(i));
+        }
+        if i > 0 && time <= times[i - 1] {
+            return Err(Error::NotIncreasing(i));
+        }
+    }
+    Ok(())
+}
+
+impl AnimationBuilder {
+    /// Creates a builder for an animation with no channels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the animation.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Adds a linearly interpolated channel animating the translation of `node`.
+    pub fn channel_translation(
+        &mut self,
+        root: &mut json::Root,
+        packer: &mut Packer,
+        node: json::Index<json::Node>,
+        times: &[f32],
+        values: &[[f32; 3]],
+    ) -> Result<json::Index<json::animation::Channel>, Error> {
+        let interpolation = Interpolation::Linear;
+        self.channel(root, packer, node, Property::Translation, interpolation, times, values)
+    }
+
+    /// Adds a linearly interpolated channel animating the rotation of `node`.
+    ///
+    /// Rotations are given as `[x, y, z, w]` unit quaternions.
+    pub fn channel_rotation(
+        &mut self,
+        root: &mut json::Root,
+        packer: &mut Packer,
+        node: json::Index<json::Node>,
+        times: &[f32],
+        values: &[[f32; 4]],
+    ) -> Result<json::Index<json::animation::Channel>, Error> {
+        let interpolation = Interpolation::Linear;
+        self.channel(root, packer, node, Property::Rotation, interpolation, times, values)
+    }
+
+    /// Adds a linearly interpolated channel animating the scale of `node`.
+    pub fn channel_scale(
+        &mut self,
+        root: &mut json::Root,
+        packer: &mut Packer,
+        node: json::Index<json::Node>,
+        times: &[f32],
+        values: &[[f32; 3]],
+    ) -> Result<json::Index<json::animation::Channel>, Error> {
+        let interpolation = Interpolation::Linear;
+        self.channel(root, packer, node, Property::Scale, interpolation, times, values)
+    }
+
+    /// Adds a linearly interpolated channel animating the morph target weights
+    /// of `node`.
+    ///
+    /// `values` holds the weight of every morph target for each keyframe in turn.
+    pub fn channel_weights(
+        &mut self,
+        root: &mut json::Root,
+        packer: &mut Packer,
+        node: json::Index<json::Node>,
+        times: &[f32],
+        values: &[f32],
+    ) -> Result<json::Index<json::animation::Channel>, Error> {
+        let interpolation = Interpolation::Linear;
+        let property = Property::MorphTargetWeights;
+        self.channel(root, packer, node, property, interpolation, times, values)
+    }
+
+    /// Adds a channel animating the given property of `node`.
+    ///
+    /// For cubic spline interpolation, `values` holds an in-tangent, a value,
+    /// and an out-tangent for each keyframe. Nothing is written to `root` or
+    /// `packer` if the channel is rejected.
+    #[allow(clippy::too_many_arguments)]
+    pub fn channel<T: Element<Component = f32>>(
+        &mut self,
+        root: &mut json::Root,
+        packer: &mut Packer,
+        node: json::Index<json::Node>,
+        property: Property,
+        interpolation: Interpolation,
+        times: &[f32],
+        values: &[T],
+    ) -> Result<json::Index<json::animation::Channel>, Error> {
+        if node.value() >= root.nodes.len() {
+            return Err(Error::Node(node.value()));
+        }
+        if Some(T::TYPE) != value_type(property) {
+            return Err(Error::Type(property));
+        }
+        let duplicate = self.channels.iter().any(|channel| {
+            channel.target.node.map(|x| x.value()) == Some(node.value())
+                && channel.target.path == Valid(property)
+        });
+        if duplicate {
+            return Err(Error::DuplicateTarget(node.value(), property));
+        }
+        check_times(times)?;
+        let per_keyframe = match interpolation {
+            Interpolation::CubicSpline => 3,
+            _ => 1,
+        };
+        // Morph target weights hold one value per target for each keyframe.
+        let keyframes = times.len() * per_keyframe;
+        let targets = values.len() / keyframes;
+        let is_weights = property == Property::MorphTargetWeights;
+        if targets == 0
+            || targets * keyframes != values.len()
+            || (!is_weights && targets != 1)
+            || (interpolation == Interpolation::CubicSpline && times.len() < 2)
+        {
+            return Err(Error::Length { times: times.len(), values: values.len() });
+        }
+
+        let input = match self.inputs.iter().find(|(other, _)| other[..] == *times) {
+            Some(&(_, input)) => input,
+            None => {
+                let input = packer.push_accessor(root, times, None);
+                self.inputs.push((times.to_vec(), input));
+                input
+            },
+        };
+        let output = packer.push_accessor(root, values, None);
+        let sampler = json::Index::new(self.samplers.len() as u32);
+        self.samplers.push(json::animation::Sampler {
+            input,
+            interpolation: Valid(interpolation),
+            output,
+            extensions: None,
+            extras: Default::default(),
+        });
+        self.channels.push(json::animation::Channel {
+            sampler,
+            target: json::animation::Target {
+                node: Some(node),
+                path: Valid(property),
+                extensions: None,
+                extras: Default::default(),
+            },
+            extensions: None,
+            extras: Default::default(),
+        });
+        Ok(json::Index::new(self.channels.len() as u32 - 1))
+    }
+
+    /// Adds the animation to `root`, returning its index.
+    pub fn build(self, root: &mut json::Root) -> json::Index<json::Animation> {
+        root.push(json::Animation {
+            channels: self.channels,
+            samplers: self.samplers,
+            #[cfg(feature = "names")]
+            name: self.name,
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Empty => write!(f, "no keyframe times"),
+            Error::InvalidTime(index) => {
+                write!(f, "keyframe time {} is negative or not finite", index)
+            },
+            Error::NotIncreasing(index) => {
+                write!(f, "keyframe time {} is not greater than the previous time", index)
+            },
+            Error::Length { times, values } => write!(
+                f,
+                "{} values do not suit {} keyframe times",
+                values, times,
+            ),
+            Error::Node(node) => write!(f, "node {} does not exist", node),
+            Error::Type(property) => write!(f, "value type does not suit {:?}", property),
+            Error::DuplicateTarget(node, property) => {
+                write!(f, "node {} already has a {:?} channel", node, property)
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AnimationBuilder, Error};
+    use crate::animation::{Interpolation, Property};
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn build_trs_channels() {
+        let mut root = json::Root::default();
+        let node = root.push::<json::Node>(json::deserialize::from_str("{}").unwrap());
+        let mut packer = Packer::new(&mut root);
+        let mut builder = AnimationBuilder::new();
+        let times = [0.0, 0.5, 1.0];
+
+        let translations = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [2.0, 0.0, 0.0]];
+        builder.channel_translation(&mut root, &mut packer, node, &times, &translations).unwrap();
+        let rotations = [[0.0, 0.0, 0.0, 1.0]; 3];
+        builder.channel_rotation(&mut root, &mut packer, node, &times, &rotations).unwrap();
+        let nr_accessors = root.accessors.len();
+
+        assert_eq!(
+            builder.channel_scale(&mut root, &mut packer, node, &[0.0, 0.0], &[[1.0; 3]; 2]).unwrap_err(),
+            Error::NotIncreasing(1),
+        );
+        assert_eq!(
+            builder.channel_scale(&mut root, &mut packer, node, &[-1.0], &[[1.0; 3]]).unwrap_err(),
+            Error::InvalidTime(0),
+        );
+        assert_eq!(
+            builder.channel_scale(&mut root, &mut packer, node, &times, &[[1.0; 3]; 2]).unwrap_err(),
+            Error::Length { times: 3, values: 2 },
+        );
+        assert_eq!(
+            builder.channel_translation(&mut root, &mut packer, node, &times, &translations).unwrap_err(),
+            Error::DuplicateTarget(node.value(), Property::Translation),
+        );
+        assert_eq!(
+            builder.channel(
+                &mut root,
+                &mut packer,
+                node,
+                Property::Scale,
+                Interpolation::Step,
+                &times,
+                &[0.0f32; 3],
+            ).unwrap_err(),
+            Error::Type(Property::Scale),
+        );
+        assert_eq!(root.accessors.len(), nr_accessors);
+
+        builder.channel_weights(&mut root, &mut packer, node, &times, &[0.0; 6]).unwrap();
+        let animation = builder.build(&mut root);
+        packer.finish(&mut root);
+
+        let json = &root.animations[animation.value()];
+        assert_eq!(json.channels.len(), 3);
+        assert_eq!(json.samplers[0].input.value(), json.samplers[1].input.value());
+        assert_eq!(root.accessors[json.samplers[2].output.value()].count, 6);
+        Document::from_json(root).unwrap();
+    }
+}
@@ -5,6 +5,9 @@ use crate::Buffer;
 
 pub use json::animation::{Interpolation, Property};
 
+/// Creation of animations for export.
+pub mod builder;
+
 /// Iterators.
 pub mod iter;
 
@@ -13,6 +16,8 @@ pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
 pub mod util;
 
+#[doc(inline)]
+pub use self::builder::AnimationBuilder;
 #[cfg(feature = "utils")]
 #[doc(inline)]
 pub use self::util::Reader;
@@ -180,9 +185,18 @@ impl<'a> Target<'a> {
         &self.json.extras
     }
 
-    /// Returns the target node.
-    pub fn node(&self) -> scene::Node<'a> {
-        self.anim.document.nodes().nth(self.json.node.value()).unwrap()
+    /// Returns the target node, or `None` if the target is defined by an
+    /// extension instead.
+    pub fn node(&self) -> Option<scene::Node<'a>> {
+        self.json.node.map(|node| self.anim.document.nodes().nth(node.value()).unwrap())
+    }
+
+    /// Returns the JSON pointer to the animated property when the `property`
+    /// is `Property::Pointer`.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn pointer(&self) -> Option<&'a str> {
+        self.json.pointer()
     }
 
     /// Returns the node's property to modify or the 'weights' of the morph
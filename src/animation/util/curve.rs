@@ -0,0 +1,293 @@
+use crate::animation::{Interpolation, Property};
+use crate::math::slerp;
+
+/// The value of an animated property at some point in time.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    /// XYZ translation.
+    Translation([f32; 3]),
+
+    /// XYZW unit quaternion rotation.
+    Rotation([f32; 4]),
+
+    /// XYZ scale.
+    Scale([f32; 3]),
+
+    /// Morph target weights, one per morph target.
+    MorphTargetWeights(Vec<f32>),
+
+    /// The components of the property referenced by a JSON pointer.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    Pointer(Vec<f32>),
+}
+
+/// The keyframes of an animation channel, read into memory so that the channel
+/// can be evaluated at arbitrary times.
+///
+/// Returned by `Reader::read_curve`.
+#[derive(Clone, Debug)]
+pub struct Curve {
+    /// The animated property.
+    property: Property,
+
+    /// The interpolation algorithm between keyframes.
+    interpolation: Interpolation,
+
+    /// The keyframe times in seconds.
+    times: Vec<f32>,
+
+    /// The keyframe values, flattened. With cubic spline interpolation every
+    /// keyframe holds an in-tangent, a value, and an out-tangent, in that order.
+    values: Vec<f32>,
+
+    /// The number of components of a single value.
+    width: usize,
+}
+
+/// Scales a quaternion to unit length.
+fn normalize(q: &mut [f32]) {
+    let length = q.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if length > 0.0 {
+        q.iter_mut().for_each(|x| *x /= length);
+    }
+}
+
+impl Curve {
+    /// Creates a curve from keyframe times and flattened keyframe values.
+    ///
+    /// Returns `None` if the number of values does not suit the number of
+    /// times and the interpolation algorithm.
+    pub(crate) fn new(
+        property: Property,
+        interpolation: Interpolation,
+        times: Vec<f32>,
+        values: Vec<f32>,
+    ) -> Option<Self> {
+        let per_keyframe = match interpolation {
+            Interpolation::CubicSpline => 3,
+            _ => 1,
+        };
+        let elements = times.len() * per_keyframe;
+        if elements == 0 {
+            return None;
+        }
+        let width = values.len() / elements;
+        if width * elements != values.len() {
+            return None;
+        }
+        let expected = match property {
+            Property::Translation | Property::Scale => 3,
+            Property::Rotation => 4,
+            Property::MorphTargetWeights => width,
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => width,
+        };
+        if width != expected {
+            return None;
+        }
+        Some(Curve { property, interpolation, times, values, width })
+    }
+
+    /// Returns the animated property.
+    pub fn property(&self) -> Property {
+        self.property
+    }
+
+    /// Returns the interpolation algorithm between keyframes.
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns the keyframe times in seconds.
+    pub fn times(&self) -> &[f32] {
+        &self.times
+    }
+
+    /// Returns the time of the first and of the last keyframe.
+    pub fn range(&self) -> (f32, f32) {
+        (self.times[0], self.times[self.times.len() - 1])
+    }
+
+    /// Returns the value, or with cubic spline interpolation the tangent, of
+    /// the given element of a keyframe.
+    fn element(&self, keyframe: usize, element: usize) -> &[f32] {
+        let index = match self.interpolation {
+            Interpolation::CubicSpline => 3 * keyframe + element,
+            _ => keyframe,
+        };
+        &self.values[index * self.width..(index + 1) * self.width]
+    }
+
+    /// Returns the value of a keyframe.
+    fn keyframe(&self, keyframe: usize) -> &[f32] {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.element(keyframe, 1),
+            _ => self.element(keyframe, 0),
+        }
+    }
+
+    /// Evaluates the curve at time `t`, in seconds.
+    ///
+    /// Times before the first keyframe take the value of the first keyframe
+    /// and times after the last keyframe the value of the last keyframe.
+    /// Rotations are interpolated along the shortest path and returned as unit
+    /// quaternions.
+    pub fn evaluate(&self, t: f32) -> Value {
+        let last = self.times.len() - 1;
+        // Index of the last keyframe at or before `t`.
+        let k = self.times.iter().rposition(|&time| time <= t);
+        let mut value = match k {
+            Some(k) if k < last => {
+                let delta = self.times[k + 1] - self.times[k];
+                let s = if delta > 0.0 { (t - self.times[k]) / delta } else { 0.0 };
+                self.interpolate(k, s, delta)
+            },
+            Some(_) => self.keyframe(last).to_vec(),
+            None => self.keyframe(0).to_vec(),
+        };
+        match self.property {
+            Property::Translation => Value::Translation([value[0], value[1], value[2]]),
+            Property::Rotation => {
+                normalize(&mut value);
+                Value::Rotation([value[0], value[1], value[2], value[3]])
+            },
+            Property::Scale => Value::Scale([value[0], value[1], value[2]]),
+            Property::MorphTargetWeights => Value::MorphTargetWeights(value),
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => Value::Pointer(value),
+        }
+    }
+
+    /// Interpolates between keyframe `k` and the next keyframe, `delta`
+    /// seconds later, at the fraction `s` of the interval.
+    fn interpolate(&self, k: usize, s: f32, delta: f32) -> Vec<f32> {
+        match self.interpolation {
+            Interpolation::Step => self.keyframe(k).to_vec(),
+            Interpolation::Linear if self.property == Property::Rotation => {
+                slerp(self.keyframe(k), self.keyframe(k + 1), s).to_vec()
+            },
+            Interpolation::Linear => self
+                .keyframe(k)
+                .iter()
+                .zip(self.keyframe(k + 1))
+                .map(|(a, b)| a + (b - a) * s)
+                .collect(),
+            Interpolation::CubicSpline => {
+                let (s2, s3) = (s * s, s * s * s);
+                let v0 = self.element(k, 1);
+                let b0 = self.element(k, 2);
+                let a1 = self.element(k + 1, 0);
+                let v1 = self.element(k + 1, 1);
+                (0..self.width)
+                    .map(|i| {
+                        (2.0 * s3 - 3.0 * s2 + 1.0) * v0[i]
+                            + delta * (s3 - 2.0 * s2 + s) * b0[i]
+                            + (-2.0 * s3 + 3.0 * s2) * v1[i]
+                            + delta * (s3 - s2) * a1[i]
+                    })
+                    .collect()
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+    use crate::animation::{AnimationBuilder, Interpolation, Property};
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn evaluate_channel_curves() {
+        let mut root = json::Root::default();
+        let node = root.push::<json::Node>(json::deserialize::from_str("{}").unwrap());
+        let mut packer = Packer::new(&mut root);
+        let mut builder = AnimationBuilder::new();
+        let times = [1.0, 2.0];
+        let translations = [[0.0, 0.0, 0.0], [2.0, 4.0, 0.0]];
+        builder.channel_translation(&mut root, &mut packer, node, &times, &translations).unwrap();
+        let half_turn = [0.0, 0.0, 1.0, 0.0];
+        let rotations = [[0.0, 0.0, 0.0, 1.0], half_turn];
+        builder.channel_rotation(&mut root, &mut packer, node, &times, &rotations).unwrap();
+        // In-tangent, value, and out-tangent of each keyframe.
+        let scales = [[0.0; 3], [1.0; 3], [3.0; 3], [3.0; 3], [3.0; 3], [0.0; 3]];
+        let cubic = Interpolation::CubicSpline;
+        builder.channel(&mut root, &mut packer, node, Property::Scale, cubic, &times, &scales)
+            .unwrap();
+        let weights = [0.0, 1.0, 1.0, 0.0];
+        let step = Interpolation::Step;
+        let property = Property::MorphTargetWeights;
+        builder.channel(&mut root, &mut packer, node, property, step, &times, &weights).unwrap();
+        builder.build(&mut root);
+        let bin = packer.finish(&mut root);
+
+        let document = Document::from_json(root).unwrap();
+        let animation = document.animations().next().unwrap();
+        let curves: Vec<_> = animation
+            .channels()
+            .map(|channel| channel.reader(|_| Some(&bin)).read_curve().unwrap())
+            .collect();
+        assert_eq!(curves[0].range(), (1.0, 2.0));
+        assert_eq!(curves[0].evaluate(0.0), Value::Translation([0.0; 3]));
+        assert_eq!(curves[0].evaluate(1.5), Value::Translation([1.0, 2.0, 0.0]));
+        assert_eq!(curves[0].evaluate(3.0), Value::Translation([2.0, 4.0, 0.0]));
+
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        match curves[1].evaluate(1.5) {
+            Value::Rotation(q) => assert_relative_eq!(&q[..], &[0.0, 0.0, half, half][..]),
+            value => panic!("unexpected {:?}", value),
+        }
+
+        assert_eq!(curves[2].evaluate(1.0), Value::Scale([1.0; 3]));
+        match curves[2].evaluate(1.5) {
+            // Hermite spline from 1 to 3 with tangents 3 and 3.
+            Value::Scale(s) => assert_relative_eq!(s[0], 2.0),
+            value => panic!("unexpected {:?}", value),
+        }
+
+        assert_eq!(curves[3].evaluate(1.9), Value::MorphTargetWeights(vec![0.0, 1.0]));
+        assert_eq!(curves[3].evaluate(2.0), Value::MorphTargetWeights(vec![1.0, 0.0]));
+    }
+
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[test]
+    fn evaluate_pointer_curves() {
+        let mut root = json::Root::default();
+        root.push::<json::Material>(json::deserialize::from_str("{}").unwrap());
+        let mut packer = Packer::new(&mut root);
+        let times = packer.push_accessor(&mut root, &[0.0f32, 2.0], None);
+        let colors = [[1.0f32, 1.0, 1.0, 1.0], [0.0, 0.5, 1.0, 1.0]];
+        let colors = packer.push_accessor(&mut root, &colors, None);
+        let bin = packer.finish(&mut root);
+        let animation = format!(
+            r#"{{
+                "channels": [{{"sampler": 0, "target": {{
+                    "path": "pointer",
+                    "extensions": {{"KHR_animation_pointer": {{
+                        "pointer": "/materials/0/pbrMetallicRoughness/baseColorFactor"
+                    }}}}
+                }}}}],
+                "samplers": [{{"input": {}, "output": {}}}]
+            }}"#,
+            times.value(),
+            colors.value(),
+        );
+        root.push::<json::Animation>(json::deserialize::from_str(&animation).unwrap());
+
+        let document = Document::from_json(root.clone()).unwrap();
+        let channel = document.animations().next().unwrap().channels().next().unwrap();
+        let target = channel.target();
+        assert_eq!(target.property(), Property::Pointer);
+        assert!(target.node().is_none());
+        let pointer = "/materials/0/pbrMetallicRoughness/baseColorFactor";
+        assert_eq!(target.pointer(), Some(pointer));
+        let curve = channel.reader(|_| Some(&bin)).read_curve().unwrap();
+        assert_eq!(curve.evaluate(1.0), Value::Pointer(vec![0.5, 0.75, 1.0, 1.0]));
+
+        root.animations[0].channels[0].target.extensions = None;
+        let errors = Document::from_json(root).unwrap_err().to_string();
+        assert!(errors.contains("animations[0].channels[0].target.extensions"));
+    }
+}
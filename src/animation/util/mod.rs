@@ -4,7 +4,12 @@ pub mod rotations;
 /// Casting iterator adapters for morph target weights.
 pub mod morph_target_weights;
 
+/// Keyframe interpolation of animation channels.
+pub mod curve;
+
 use crate::accessor;
+#[cfg(feature = "KHR_animation_pointer")]
+use crate::accessor::util::ReadF32;
 
 use crate::animation::Channel;
 use crate::Buffer;
@@ -58,6 +63,22 @@ pub enum MorphTargetWeights<'a> {
     F32(accessor::Iter<'a, f32>),
 }
 
+/// Animation pointer outputs, whose type depends on the property referenced by
+/// the JSON pointer.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+#[derive(Clone, Debug)]
+pub enum Pointer<'a> {
+    /// Scalar values.
+    Scalar(ReadF32<'a, f32>),
+    /// Two-component vectors.
+    Vec2(ReadF32<'a, [f32; 2]>),
+    /// Three-component vectors.
+    Vec3(ReadF32<'a, [f32; 3]>),
+    /// Four-component vectors, such as colors and quaternions.
+    Vec4(ReadF32<'a, [f32; 4]>),
+}
+
 /// Animation output sampler values.
 pub enum ReadOutputs<'a> {
     /// XYZ translations of type `[f32; 3]`.
@@ -71,6 +92,11 @@ pub enum ReadOutputs<'a> {
 
     /// Morph target animations.
     MorphTargetWeights(MorphTargetWeights<'a>),
+
+    /// Animations of the property referenced by a JSON pointer.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    Pointer(Pointer<'a>),
 }
 
 impl<'a> Rotations<'a> {
@@ -172,6 +198,42 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
                     _ => unreachable!()
                 }
             },
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => {
+                use accessor::Dimensions;
+                let data = self.get_buffer_data.clone();
+                let pointer = match output.dimensions() {
+                    Dimensions::Scalar => ReadF32::new(output, data).map(Pointer::Scalar),
+                    Dimensions::Vec2 => ReadF32::new(output, data).map(Pointer::Vec2),
+                    Dimensions::Vec3 => ReadF32::new(output, data).map(Pointer::Vec3),
+                    Dimensions::Vec4 => ReadF32::new(output, data).map(Pointer::Vec4),
+                    _ => None,
+                };
+                pointer.map(ReadOutputs::Pointer)
+            },
         }
     }
+
+    /// Reads the keyframes of a channel into a curve that can be evaluated at
+    /// arbitrary times.
+    ///
+    /// Returns `None` if the data of either sampler accessor is unavailable or
+    /// the number of outputs does not suit the number of inputs.
+    pub fn read_curve(&self) -> Option<curve::Curve> {
+        let times = self.read_inputs()?.collect();
+        let values = match self.read_outputs()? {
+            ReadOutputs::Translations(iter) | ReadOutputs::Scales(iter) => iter.flatten().collect(),
+            ReadOutputs::Rotations(iter) => iter.into_f32().flatten().collect(),
+            ReadOutputs::MorphTargetWeights(iter) => iter.into_f32().collect(),
+            #[cfg(feature = "KHR_animation_pointer")]
+            ReadOutputs::Pointer(pointer) => match pointer {
+                Pointer::Scalar(iter) => iter.collect(),
+                Pointer::Vec2(iter) => iter.flatten().collect(),
+                Pointer::Vec3(iter) => iter.flatten().collect(),
+                Pointer::Vec4(iter) => iter.flatten().collect(),
+            },
+        };
+        let sampler = self.channel.sampler();
+        curve::Curve::new(self.channel.target().property(), sampler.interpolation(), times, values)
+    }
 }
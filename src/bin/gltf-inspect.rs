@@ -0,0 +1,36 @@
+use std::process;
+
+use gltf::json::validation::{self, Severity};
+
+fn main() {
+    let args = std::env::args().skip(1);
+    let (flags, paths): (Vec<_>, Vec<_>) = args.partition(|x| x.starts_with("--"));
+    let sarif = flags.iter().any(|x| x == "--sarif");
+    let path = match paths.as_slice() {
+        [path] if flags.iter().all(|x| x == "--sarif") => path.clone(),
+        _ => {
+            eprintln!("usage: gltf-inspect [--sarif] <FILE>");
+            process::exit(2);
+        },
+    };
+    let inspection = match gltf::inspect::inspect_file(&path) {
+        Ok(inspection) => inspection,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            process::exit(2);
+        },
+    };
+    if sarif {
+        let errors: Vec<_> = inspection
+            .diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.path.clone(), diagnostic.error))
+            .collect();
+        println!("{:#}", validation::to_sarif(&path, &errors));
+    } else {
+        println!("{}", inspection);
+    }
+    if inspection.count(Severity::Error) > 0 {
+        process::exit(1);
+    }
+}
@@ -1,6 +1,9 @@
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use std::{fmt, io, mem};
 use std::borrow::Cow;
+use std::ops::Range;
+
+use crate::Accessor;
 
 /// Represents a Glb loader error.
 #[derive(Debug)]
@@ -44,6 +47,89 @@ pub struct Glb<'a> {
     pub bin: Option<Cow<'a, [u8]>>,
 }
 
+/// Lazily reads binary glTF from a seekable stream.
+///
+/// Only the header and chunk headers are read up front; chunk data is read on
+/// demand, so large files need not be held in memory.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let file = std::fs::File::open("examples/Box.glb")?;
+/// let mut reader = gltf::binary::Reader::new(file)?;
+/// let gltf = gltf::Gltf::from_slice(&reader.read_json()?)?;
+/// let view = gltf.views().next().unwrap();
+/// let bytes = reader.read_bin_range(view.offset(), view.length())?;
+/// assert_eq!(bytes.len(), view.length());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct Reader<R> {
+    /// The underlying stream.
+    reader: R,
+
+    /// The header section of the `.glb` file.
+    header: Header,
+
+    /// The stream offset and length of the JSON chunk data.
+    json: (u64, u32),
+
+    /// The stream offset and length of the optional BIN chunk data.
+    bin: Option<(u64, u32)>,
+}
+
+/// Options for `Glb::from_document`.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[derive(Clone, Debug)]
+pub struct Options {
+    /// Whether images referenced by URI are encoded as PNG and embedded in the
+    /// `BIN` chunk. Images already stored in buffer views are always kept.
+    ///
+    /// Defaults to `true`.
+    pub embed_images: bool,
+
+    /// Whether a scene containing every root node, that is every node that is
+    /// not the child of another node, is created and made the default scene
+    /// when the document has nodes but no scenes.
+    ///
+    /// Defaults to `false`.
+    pub create_scene: bool,
+}
+
+/// The chunk table of a `.glb` file, as returned by `inspect`.
+#[derive(Clone, Debug)]
+pub struct Inspection {
+    /// The header section of the `.glb` file.
+    pub header: Header,
+
+    /// The number of bytes of the `.glb` file that are present, which is less
+    /// than `header.length` if the file is truncated.
+    pub length: u64,
+
+    /// The chunks in the order they appear in the file.
+    pub chunks: Vec<ChunkInfo>,
+}
+
+/// The location of a chunk within a `.glb` file.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ChunkInfo {
+    /// The chunk type as written in the chunk header, such as `*b"JSON"`.
+    ///
+    /// Chunks of unknown types are listed as well, since the format allows
+    /// extensions to add them.
+    pub ty: [u8; 4],
+
+    /// The offset of the chunk data in bytes from the start of the `.glb` file.
+    pub offset: u64,
+
+    /// The length of the chunk data in bytes according to the chunk header.
+    pub length: u32,
+}
+
 /// The header section of a .glb file.
 #[derive(Copy, Clone, Debug)]
 #[repr(C)]
@@ -116,6 +202,20 @@ fn align_to_multiple_of_four(n: &mut usize) {
     *n = (*n + 3) & !3;
 }
 
+/// Reads the binary glTF header and JSON chunk header, returning a reader
+/// limited to the JSON chunk.
+pub(crate) fn json_chunk_reader<R: io::Read>(mut reader: R) -> Result<io::Take<R>, Error> {
+    let header = Header::from_reader(&mut reader)?;
+    if header.version != 2 {
+        return Err(Error::Version(header.version));
+    }
+    let chunk = ChunkHeader::from_reader(&mut reader)?;
+    match chunk.ty {
+        ChunkType::Json => Ok(io::Read::take(reader, chunk.length as u64)),
+        ty => Err(Error::ChunkType(ty)),
+    }
+}
+
 fn split_binary_gltf<'a>(mut data: &'a [u8]) -> Result<(&'a [u8], Option<&'a [u8]>), Error> {
     let (json, mut data) = ChunkHeader::from_reader(&mut data)
         .and_then(|json_h| if let ChunkType::Json = json_h.ty {
@@ -162,6 +262,101 @@ fn split_binary_gltf<'a>(mut data: &'a [u8]) -> Result<(&'a [u8], Option<&'a [u8
     Ok((json, bin))
 }
 
+#[cfg(feature = "import")]
+impl Default for Options {
+    fn default() -> Self {
+        Self { embed_images: true, create_scene: false }
+    }
+}
+
+#[cfg(feature = "import")]
+impl Glb<'static> {
+    /// Constructs binary glTF from an imported document along with its buffer
+    /// and image data, as returned by `gltf::import`.
+    ///
+    /// All buffer data is merged into the `BIN` chunk with
+    /// `optimize::repack_buffers`, so buffers referenced by URI become embedded.
+    /// Images are embedded according to `options`; an image without
+    /// corresponding data in `images` keeps its URI.
+    ///
+    /// Returns `Error::Validation` if the default scene index is out of range.
+    /// Nodes that are not reachable from any scene are reported to the
+    /// installed `metrics::Metrics` as warnings.
+    pub fn from_document(
+        document: &crate::Document,
+        buffers: &[crate::buffer::Data],
+        images: &[crate::image::Data],
+        options: Options,
+    ) -> Result<Self, crate::Error> {
+        use json::validation::Error::IndexOutOfBounds;
+        let mut root = document.0.clone();
+        let in_range = root.scene.into_iter().all(|scene| scene.value() < root.scenes.len());
+        if !in_range {
+            let path = json::Path::new().field("scene");
+            return Err(crate::Error::Validation(vec![(path, IndexOutOfBounds)]));
+        }
+        if options.create_scene && root.scenes.is_empty() && !root.nodes.is_empty() {
+            let scene = root.push(json::Scene {
+                extensions: None,
+                extras: Default::default(),
+                #[cfg(feature = "names")]
+                name: None,
+                nodes: crate::unused::root_nodes(&root),
+            });
+            root.scene = Some(scene);
+        }
+        let warnings = crate::unused::unreachable(&root);
+        crate::metrics::report(|metrics| {
+            for warning in &warnings {
+                metrics.export_warning(&warning.path, warning.error);
+            }
+        });
+
+        let mut data: Vec<Vec<u8>> = buffers.iter().map(|buffer| buffer.0.clone()).collect();
+        if options.embed_images {
+            let mut packer = crate::pack::Packer::new(&mut root);
+            for (index, image) in images.iter().enumerate() {
+                match root.images.get(index) {
+                    Some(json) if json.uri.is_some() => {},
+                    _ => continue,
+                }
+                // KTX2 containers and undecoded files are embedded as they are.
+                let (encoded, mime_type) = match image.format.mime_type() {
+                    Some(mime_type) => (image.pixels.clone(), mime_type),
+                    None => (image.to_png()?, "image/png"),
+                };
+                let view = packer.push_view(&mut root, &encoded, None, None);
+                let json = &mut root.images[index];
+                json.buffer_view = Some(view);
+                json.mime_type = Some(json::image::MimeType(mime_type.to_string()));
+                json.uri = None;
+            }
+            data.push(packer.finish(&mut root));
+        }
+        let bin = crate::optimize::repack_buffers(&mut root, &data);
+        let json = json::serialize::to_vec(&root)?;
+
+        let mut length = Header::size_of() + mem::size_of::<ChunkHeader>() + json.len();
+        align_to_multiple_of_four(&mut length);
+        let bin = if root.buffers.is_empty() {
+            None
+        } else {
+            length += mem::size_of::<ChunkHeader>() + bin.len();
+            align_to_multiple_of_four(&mut length);
+            Some(Cow::Owned(bin))
+        };
+        Ok(Glb {
+            header: Header {
+                magic: *b"glTF",
+                version: 2,
+                length: length as u32,
+            },
+            json: Cow::Owned(json),
+            bin,
+        })
+    }
+}
+
 impl<'a> Glb<'a> {
     /// Writes binary glTF to a writer.
     pub fn to_writer<W>(&self, mut writer: W) -> Result<(), crate::Error>
@@ -271,18 +466,372 @@ impl<'a> Glb<'a> {
                 if let Err(e) = reader.read_exact(&mut buf).map_err(Error::Io) {
                     Err(crate::Error::Binary(e))
                 } else {
-                    split_binary_gltf(&buf)
-                        .map(|(json, bin)| Glb {
-                            header,
-                            json: json.to_vec().into(),
-                            bin: bin.map(<[u8]>::to_vec).map(Into::into),
-                        })
-                        .map_err(crate::Error::Binary)
+                    Self::from_contents(header, &buf)
                 }
             }
             x => Err(crate::Error::Binary(Error::Version(x)))
         }
     }
+
+    /// Reads binary glTF from an asynchronous stream of data.
+    ///
+    /// # Note
+    ///
+    /// As with `from_reader`, the whole of the binary glTF is read into memory,
+    /// and reading terminates early if the stream does not contain valid binary
+    /// glTF.
+    #[cfg(feature = "async")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+    pub async fn from_async_reader<R>(mut reader: R) -> Result<Self, crate::Error>
+    where
+        R: crate::import_async::AsyncRead,
+    {
+        let mut header = [0; 12];
+        reader.read_exact(&mut header).await.map_err(|e| crate::Error::Binary(Error::Io(e)))?;
+        let header = Header::from_reader(&header[..]).map_err(crate::Error::Binary)?;
+        match header.version {
+            2 => {
+                let glb_len = header.length.saturating_sub(Header::size_of() as u32);
+                let mut buf = vec![0; glb_len as usize];
+                reader.read_exact(&mut buf).await.map_err(|e| crate::Error::Binary(Error::Io(e)))?;
+                Self::from_contents(header, &buf)
+            }
+            x => Err(crate::Error::Binary(Error::Version(x)))
+        }
+    }
+
+    /// Splits the chunks following the header of binary glTF, copying them.
+    fn from_contents(header: Header, contents: &[u8]) -> Result<Self, crate::Error> {
+        split_binary_gltf(contents)
+            .map(|(json, bin)| Glb {
+                header,
+                json: json.to_vec().into(),
+                bin: bin.map(<[u8]>::to_vec).map(Into::into),
+            })
+            .map_err(crate::Error::Binary)
+    }
+}
+
+impl<R: io::Read + io::Seek> Reader<R> {
+    /// Reads the header and chunk headers of binary glTF starting at the current
+    /// position of `reader`.
+    pub fn new(reader: R) -> Result<Self, crate::Error> {
+        Self::read_headers(reader).map_err(crate::Error::Binary)
+    }
+
+    fn read_headers(mut reader: R) -> Result<Self, Error> {
+        use self::Error::Io;
+        let start = reader.stream_position().map_err(Io)?;
+        let header = Header::from_reader(&mut reader)?;
+        if header.version != 2 {
+            return Err(Error::Version(header.version));
+        }
+        let end = reader.seek(io::SeekFrom::End(0)).map_err(Io)?;
+        let contents_length = header.length.saturating_sub(Header::size_of() as u32);
+        let available = end.saturating_sub(start + Header::size_of() as u64);
+        if u64::from(contents_length) > available {
+            return Err(Error::Length { length: contents_length, length_read: available as usize });
+        }
+        let chunk_header_size = mem::size_of::<ChunkHeader>() as u64;
+        let end = start + u64::from(header.length);
+
+        // Reads the chunk header at `offset`, checking that its data lies
+        // within the file.
+        let mut chunk = |offset: u64| -> Result<(ChunkHeader, u64), Error> {
+            reader.seek(io::SeekFrom::Start(offset)).map_err(Io)?;
+            let chunk = ChunkHeader::from_reader(&mut reader)?;
+            let data = offset + chunk_header_size;
+            if data + u64::from(chunk.length) > end {
+                return Err(Error::ChunkLength {
+                    ty: chunk.ty,
+                    length: chunk.length,
+                    length_read: end.saturating_sub(data) as usize,
+                });
+            }
+            Ok((chunk, data))
+        };
+        let (json, json_offset) = chunk(start + Header::size_of() as u64)?;
+        if let ChunkType::Bin = json.ty {
+            return Err(Error::ChunkType(json.ty));
+        }
+        let bin_header = json_offset + u64::from(json.length);
+        let bin = if bin_header < end {
+            let (bin, bin_offset) = chunk(bin_header)?;
+            if let ChunkType::Json = bin.ty {
+                return Err(Error::ChunkType(bin.ty));
+            }
+            Some((bin_offset, bin.length))
+        } else {
+            None
+        };
+        let json = (json_offset, json.length);
+        Ok(Reader { reader, header, json, bin })
+    }
+
+    /// Returns the header section of the `.glb` file.
+    pub fn header(&self) -> Header {
+        self.header
+    }
+
+    /// Returns the length of the JSON chunk data in bytes.
+    pub fn json_length(&self) -> usize {
+        self.json.1 as usize
+    }
+
+    /// Returns the length of the BIN chunk data in bytes, if present.
+    pub fn bin_length(&self) -> Option<usize> {
+        self.bin.map(|(_, length)| length as usize)
+    }
+
+    /// Reads the JSON chunk data.
+    pub fn read_json(&mut self) -> Result<Vec<u8>, crate::Error> {
+        let (offset, length) = self.json;
+        self.read_at(offset, length as usize)
+    }
+
+    /// Reads `length` bytes of the BIN chunk data starting at `offset`.
+    ///
+    /// Fails if there is no BIN chunk or the range lies outside of it.
+    pub fn read_bin_range(
+        &mut self,
+        offset: usize,
+        length: usize,
+    ) -> Result<Vec<u8>, crate::Error> {
+        let (start, bin_length) = match self.bin {
+            Some(bin) => bin,
+            None => return Err(invalid_input("no BIN chunk")),
+        };
+        match offset.checked_add(length) {
+            Some(end) if end <= bin_length as usize => {},
+            _ => return Err(invalid_input("range outside of BIN chunk")),
+        }
+        self.read_at(start + offset as u64, length)
+    }
+
+    /// Returns the underlying stream.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_at(&mut self, offset: u64, length: usize) -> Result<Vec<u8>, crate::Error> {
+        let mut data = vec![0; length];
+        self.reader
+            .seek(io::SeekFrom::Start(offset))
+            .and_then(|_| self.reader.read_exact(&mut data))
+            .map_err(|error| crate::Error::Binary(Error::Io(error)))?;
+        Ok(data)
+    }
+}
+
+fn invalid_input(message: &str) -> crate::Error {
+    crate::Error::Binary(Error::Io(io::Error::new(io::ErrorKind::InvalidInput, message)))
+}
+
+impl Inspection {
+    /// Returns whether the file ends before the length given by its header.
+    pub fn is_truncated(&self) -> bool {
+        self.length < u64::from(self.header.length)
+    }
+
+    /// Returns the first `JSON` chunk, if listed.
+    pub fn json(&self) -> Option<&ChunkInfo> {
+        self.chunks.iter().find(|chunk| &chunk.ty == b"JSON")
+    }
+
+    /// Returns the first `BIN` chunk, if listed.
+    pub fn bin(&self) -> Option<&ChunkInfo> {
+        self.chunks.iter().find(|chunk| &chunk.ty == b"BIN\0")
+    }
+
+    /// Returns the byte ranges of the `.glb` file holding the data of the given
+    /// buffer views, sorted and with overlapping or adjacent ranges merged.
+    ///
+    /// Only views of the buffer stored in the `BIN` chunk are included, and
+    /// ranges are clipped to the chunk. Meshopt compressed views contribute the
+    /// range of their compressed data. Returns no ranges without a `BIN` chunk.
+    pub fn bin_ranges<'a, I>(&self, views: I) -> Vec<Range<u64>>
+    where
+        I: IntoIterator<Item = crate::buffer::View<'a>>,
+    {
+        let bin = match self.bin() {
+            Some(bin) => bin,
+            None => return Vec::new(),
+        };
+        let mut ranges: Vec<_> = views
+            .into_iter()
+            .map(|view| {
+                // Meshopt compressed views are fetched as their compressed data.
+                #[cfg(feature = "EXT_meshopt_compression")]
+                {
+                    if let Some(compression) = view.meshopt_compression() {
+                        return (compression.buffer(), compression.offset(), compression.length());
+                    }
+                }
+                (view.buffer(), view.offset(), view.length())
+            })
+            .filter(|(buffer, ..)| match buffer.source() {
+                crate::buffer::Source::Bin => true,
+                crate::buffer::Source::Uri(_) => false,
+            })
+            .map(|(_, offset, length)| {
+                let start = bin.offset + offset as u64;
+                let end = start + length as u64;
+                start.min(bin.end())..end.min(bin.end())
+            })
+            .filter(|range| range.start < range.end)
+            .collect();
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
+    /// Returns the byte ranges of the `.glb` file holding the geometry of the
+    /// given meshes, see `bin_ranges`.
+    ///
+    /// The geometry is the data of the vertex attributes, indices, and morph
+    /// targets of every primitive, including sparse accessor data and Draco
+    /// compressed data. Images used by the materials of the meshes are not
+    /// included.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), gltf::Error> {
+    /// use std::io::{Read, Seek, SeekFrom};
+    ///
+    /// // A viewer would fetch the header and JSON chunk with range requests.
+    /// let mut file = std::fs::File::open("examples/Box.glb")?;
+    /// let inspection = gltf::binary::inspect(&mut file)?;
+    /// let json = inspection.json().unwrap();
+    /// let mut data = vec![0; json.length as usize];
+    /// file.seek(SeekFrom::Start(json.offset))?;
+    /// file.read_exact(&mut data)?;
+    /// let gltf = gltf::Gltf::from_slice(&data)?;
+    /// let ranges = inspection.mesh_ranges(gltf.meshes());
+    /// assert!(ranges.iter().all(|range| range.end <= inspection.bin().unwrap().end()));
+    /// # Ok(())
+    /// # }
+    /// # run().unwrap();
+    /// ```
+    pub fn mesh_ranges<'a, I>(&self, meshes: I) -> Vec<Range<u64>>
+    where
+        I: IntoIterator<Item = crate::Mesh<'a>>,
+    {
+        let mut views = Vec::new();
+        for mesh in meshes {
+            push_mesh_views(&mut views, &mesh);
+        }
+        self.bin_ranges(views)
+    }
+
+    /// Returns the byte ranges of the `.glb` file holding the geometry of the
+    /// meshes instantiated by a scene along with the inverse bind matrices of
+    /// its skins and the instance transforms of `EXT_mesh_gpu_instancing`, see
+    /// `mesh_ranges`.
+    pub fn scene_ranges(&self, scene: &crate::Scene) -> Vec<Range<u64>> {
+        let mut views = Vec::new();
+        for (_, _, node) in scene.iter_preorder() {
+            if let Some(mesh) = node.mesh() {
+                push_mesh_views(&mut views, &mesh);
+            }
+            if let Some(accessor) = node.skin().and_then(|skin| skin.inverse_bind_matrices()) {
+                push_accessor_views(&mut views, &accessor);
+            }
+            #[cfg(feature = "EXT_mesh_gpu_instancing")]
+            {
+                if let Some(instancing) = node.instancing() {
+                    let names = ["TRANSLATION", "ROTATION", "SCALE"];
+                    for accessor in names.iter().filter_map(|name| instancing.get(name)) {
+                        push_accessor_views(&mut views, &accessor);
+                    }
+                }
+            }
+        }
+        self.bin_ranges(views)
+    }
+}
+
+/// Appends the buffer views holding the data of an accessor to `views`.
+fn push_accessor_views<'a>(views: &mut Vec<crate::buffer::View<'a>>, accessor: &Accessor<'a>) {
+    views.extend(accessor.view());
+    if let Some(sparse) = accessor.sparse() {
+        views.push(sparse.indices().view());
+        views.push(sparse.values().view());
+    }
+}
+
+/// Appends the buffer views holding the geometry of a mesh to `views`.
+fn push_mesh_views<'a>(views: &mut Vec<crate::buffer::View<'a>>, mesh: &crate::Mesh<'a>) {
+    for primitive in mesh.primitives() {
+        #[cfg(feature = "KHR_draco_mesh_compression")]
+        views.extend(primitive.draco_mesh_compression().map(|draco| draco.view()));
+        let targets = primitive.morph_targets().flat_map(|target| {
+            target.attributes().into_iter().map(|(_, accessor)| accessor)
+        });
+        let accessors = primitive
+            .attributes()
+            .map(|(_, accessor)| accessor)
+            .chain(primitive.indices())
+            .chain(targets);
+        for accessor in accessors {
+            push_accessor_views(views, &accessor);
+        }
+    }
+}
+
+impl ChunkInfo {
+    /// Returns the offset in bytes just past the end of the chunk data.
+    pub fn end(&self) -> u64 {
+        self.offset + u64::from(self.length)
+    }
+}
+
+/// Reads the header and chunk headers of binary glTF starting at the current
+/// position of `reader`, without reading any chunk data.
+///
+/// Listing the chunks is useful for diagnostics and for planning partial
+/// downloads, for example an HTTP range request for the `BIN` chunk. Unlike
+/// `Reader::new`, a truncated file is not an error: chunks are listed as long
+/// as their headers are present, and a chunk whose `end` exceeds the
+/// `Inspection::length` is incomplete.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let file = std::fs::File::open("examples/Box.glb")?;
+/// let inspection = gltf::binary::inspect(file)?;
+/// let bin = inspection.bin().unwrap();
+/// assert_eq!(bin.end(), u64::from(inspection.header.length));
+/// assert!(!inspection.is_truncated());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn inspect<R: io::Read + io::Seek>(mut reader: R) -> Result<Inspection, crate::Error> {
+    use self::Error::Io;
+    let start = reader.stream_position().map_err(Io)?;
+    let header = Header::from_reader(&mut reader)?;
+    if header.version != 2 {
+        return Err(crate::Error::Binary(Error::Version(header.version)));
+    }
+    let length = reader.seek(io::SeekFrom::End(0)).map_err(Io)? - start;
+    let end = length.min(u64::from(header.length));
+    let chunk_header_size = mem::size_of::<ChunkHeader>() as u64;
+    let mut chunks = Vec::new();
+    let mut offset = Header::size_of() as u64;
+    while offset + chunk_header_size <= end {
+        reader.seek(io::SeekFrom::Start(start + offset)).map_err(Io)?;
+        let length = reader.read_u32::<LittleEndian>().map_err(Io)?;
+        let mut ty = [0; 4];
+        reader.read_exact(&mut ty).map_err(Io)?;
+        let chunk = ChunkInfo { ty, offset: offset + chunk_header_size, length };
+        offset = chunk.end();
+        chunks.push(chunk);
+    }
+    Ok(Inspection { header, length, chunks })
 }
 
 impl fmt::Display for Error {
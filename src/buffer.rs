@@ -4,6 +4,8 @@ use std::ops;
 use crate::Document;
 
 pub use json::buffer::Target;
+#[cfg(feature = "EXT_meshopt_compression")]
+pub use json::extensions::buffer::{MeshoptFilter, MeshoptMode};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug)]
@@ -34,6 +36,19 @@ pub struct View<'a> {
     parent: Buffer<'a>,
 }
 
+/// Meshopt compressed data of a buffer view, as defined by the
+/// `EXT_meshopt_compression` extension.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+#[derive(Clone, Debug)]
+pub struct MeshoptCompression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::buffer::MeshoptCompression,
+}
+
 /// Describes a buffer data source.
 #[derive(Clone, Debug)]
 pub enum Source<'a> {
@@ -92,6 +107,18 @@ impl<'a> Buffer<'a> {
         self.json.byte_length as usize
     }
 
+    /// Returns `true` if the buffer only holds the decompressed data of meshopt
+    /// compressed buffer views and has no data of its own.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn is_meshopt_fallback(&self) -> bool {
+        let extensions = self.json.extensions.as_ref();
+        extensions
+            .and_then(|x| x.ext_meshopt_compression.as_ref())
+            .into_iter()
+            .any(|x| x.fallback)
+    }
+
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
     #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
@@ -167,8 +194,60 @@ impl<'a> View<'a> {
         self.json.target.map(|target| target.unwrap())
     }
 
+    /// Returns the meshopt compressed data of the buffer view, as defined by
+    /// the `EXT_meshopt_compression` extension.
+    ///
+    /// The buffer view itself describes the decompressed data, which
+    /// `gltf::import` decodes into the parent buffer.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn meshopt_compression(&self) -> Option<MeshoptCompression<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .ext_meshopt_compression.as_ref()
+            .map(|json| MeshoptCompression { document: self.document, json })
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
+
+#[cfg(feature = "EXT_meshopt_compression")]
+impl<'a> MeshoptCompression<'a> {
+    /// Returns the buffer holding the compressed data.
+    pub fn buffer(&self) -> Buffer<'a> {
+        self.document.buffers().nth(self.json.buffer.value()).unwrap()
+    }
+
+    /// Returns the offset of the compressed data in bytes.
+    pub fn offset(&self) -> usize {
+        self.json.byte_offset as usize
+    }
+
+    /// Returns the length of the compressed data in bytes.
+    pub fn length(&self) -> usize {
+        self.json.byte_length as usize
+    }
+
+    /// Returns the stride of the decompressed elements in bytes.
+    pub fn stride(&self) -> usize {
+        self.json.byte_stride as usize
+    }
+
+    /// Returns the number of decompressed elements.
+    pub fn count(&self) -> usize {
+        self.json.count as usize
+    }
+
+    /// Returns the compression mode.
+    pub fn mode(&self) -> MeshoptMode {
+        self.json.mode.unwrap()
+    }
+
+    /// Returns the filter applied to the decompressed elements.
+    pub fn filter(&self) -> MeshoptFilter {
+        self.json.filter.unwrap()
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::OnceLock;
+
+use crate::accessor::{usage, Usage};
+use crate::Document;
+
+/// Lookup tables of a document, each computed on first use.
+///
+/// `Document::json_mut` replaces the cache whenever the JSON may be edited,
+/// invalidating the tables, and `OnceLock` lets any thread initialize them
+/// while `Document` stays `Sync`.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Cache {
+    /// The parent of each node.
+    parents: OnceLock<Vec<Option<usize>>>,
+
+    /// The usages of each accessor.
+    usages: OnceLock<Vec<Vec<Usage>>>,
+}
+
+/// Returns the parent of each node of the hierarchy.
+pub(crate) fn node_parents(root: &json::Root) -> Vec<Option<usize>> {
+    let mut parents = vec![None; root.nodes.len()];
+    for (index, node) in root.nodes.iter().enumerate() {
+        for child in node.children.iter().flatten() {
+            if let Some(parent) = parents.get_mut(child.value()) {
+                *parent = Some(index);
+            }
+        }
+    }
+    parents
+}
+
+/// Returns the cached parent of each node of a document.
+pub(crate) fn parents(document: &Document) -> &[Option<usize>] {
+    document.1.parents.get_or_init(|| node_parents(&document.0))
+}
+
+/// Returns the cached usages of each accessor of a document.
+pub(crate) fn usages(document: &Document) -> &[Vec<Usage>] {
+    document.1.usages.get_or_init(|| usage::usages(&document.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::accessor::Usage;
+    use crate::mesh::Semantic;
+    use crate::Document;
+
+    #[test]
+    fn cached_lookups() {
+        fn assert_sync<T: Send + Sync>(_: &T) {}
+
+        let document = Document::from_json_without_validation(json::Root::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [{"componentType": 5126, "type": "VEC3", "count": 0}],
+            "meshes": [{"primitives": [{"attributes": {"POSITION": 0}}]}],
+            "nodes": [{"children": [1]}, {"children": [2], "mesh": 0}, {}]
+        }"#).unwrap());
+        assert_sync(&document);
+
+        let parents: Vec<_> = document.nodes().map(|x| x.parent().map(|x| x.index())).collect();
+        assert_eq!(parents, [None, Some(0), Some(1)]);
+        let accessor = document.accessors().next().unwrap();
+        let usage = [Usage::Attribute(Semantic::Positions)];
+        assert_eq!(accessor.usage(), usage);
+
+        let cloned = document.clone();
+        assert_eq!(cloned.nodes().nth(2).unwrap().parent().unwrap().index(), 1);
+        assert_eq!(cloned.accessors().next().unwrap().usage(), usage);
+    }
+}
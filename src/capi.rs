@@ -0,0 +1,269 @@
+//! A minimal C interface to the parser.
+//!
+//! The functions in this module are exported unmangled so that the crate can be
+//! built as a shared library and used from C, C++, or Python via `cffi`:
+//!
+//! ```sh
+//! cargo rustc --release --features capi --crate-type cdylib
+//! ```
+//!
+//! A matching declaration for use from C is as follows:
+//!
+//! ```c
+//! typedef struct gltf_t gltf_t;
+//!
+//! gltf_t *gltf_parse_glb(const uint8_t *data, size_t len, char **error);
+//! size_t gltf_count(const gltf_t *gltf, uint32_t kind);
+//! const char *gltf_name(const gltf_t *gltf, uint32_t kind, size_t index);
+//! void gltf_free(gltf_t *gltf);
+//! void gltf_free_error(char *error);
+//! ```
+//!
+//! Documents are validated when parsed. Validation failures are reported through
+//! the `error` out-parameter, one `path: message` pair per line.
+
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::{ptr, slice};
+
+use crate::Gltf;
+
+/// The kinds of top-level object that may be counted and named.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum Kind {
+    /// `accessors`.
+    Accessor = 0,
+    /// `animations`.
+    Animation = 1,
+    /// `buffers`.
+    Buffer = 2,
+    /// `bufferViews`.
+    View = 3,
+    /// `cameras`.
+    Camera = 4,
+    /// `images`.
+    Image = 5,
+    /// `materials`.
+    Material = 6,
+    /// `meshes`.
+    Mesh = 7,
+    /// `nodes`.
+    Node = 8,
+    /// `samplers`.
+    Sampler = 9,
+    /// `scenes`.
+    Scene = 10,
+    /// `skins`.
+    Skin = 11,
+    /// `textures`.
+    Texture = 12,
+}
+
+/// Number of variants of `Kind`.
+const NR_KINDS: usize = 13;
+
+/// An opaque handle to a parsed document.
+///
+/// Names are converted to C strings up front so that the pointers returned by
+/// `gltf_name` remain valid until the handle is freed.
+pub struct Handle {
+    gltf: Gltf,
+    names: Vec<Vec<Option<CString>>>,
+}
+
+impl Kind {
+    fn from_u32(kind: u32) -> Option<Self> {
+        use self::Kind::*;
+        let kinds = [
+            Accessor, Animation, Buffer, View, Camera, Image, Material, Mesh, Node, Sampler,
+            Scene, Skin, Texture,
+        ];
+        kinds.get(kind as usize).copied()
+    }
+}
+
+impl Handle {
+    fn new(gltf: Gltf) -> Self {
+        fn convert<'a, I: Iterator<Item = Option<&'a str>>>(iter: I) -> Vec<Option<CString>> {
+            iter.map(|name| name.and_then(|name| CString::new(name).ok()))
+                .collect()
+        }
+
+        let root = &gltf.document.0;
+        let mut names = Vec::with_capacity(NR_KINDS);
+        names.push(convert(root.accessors.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.animations.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.buffers.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.buffer_views.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.cameras.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.images.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.materials.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.meshes.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.nodes.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.samplers.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.scenes.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.skins.iter().map(|x| x.name.as_deref())));
+        names.push(convert(root.textures.iter().map(|x| x.name.as_deref())));
+        Handle { gltf, names }
+    }
+
+    /// Returns the parsed document.
+    pub fn gltf(&self) -> &Gltf {
+        &self.gltf
+    }
+}
+
+fn describe(error: &crate::Error) -> String {
+    match *error {
+        crate::Error::Validation(ref errors) => errors
+            .iter()
+            .map(|(path, error)| format!("{}: {}", path, error))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ref error => error.to_string(),
+    }
+}
+
+/// Parses and validates binary or standard glTF from `len` bytes at `data`.
+///
+/// Returns null on failure, in which case a description of the failure is
+/// written to `error` if it is not null. The description must be released with
+/// `gltf_free_error`.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable bytes and `error` must be null or point
+/// to writable storage for a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn gltf_parse_glb(
+    data: *const u8,
+    len: usize,
+    error: *mut *mut c_char,
+) -> *mut Handle {
+    if !error.is_null() {
+        *error = ptr::null_mut();
+    }
+    let bytes = if data.is_null() {
+        &[][..]
+    } else {
+        slice::from_raw_parts(data, len)
+    };
+    match Gltf::from_slice(bytes) {
+        Ok(gltf) => Box::into_raw(Box::new(Handle::new(gltf))),
+        Err(err) => {
+            if !error.is_null() {
+                let message = describe(&err).replace('\0', " ");
+                *error = CString::new(message).unwrap().into_raw();
+            }
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Returns the number of top-level objects of the given `Kind`.
+///
+/// Returns zero if `gltf` is null or `kind` is out of range.
+///
+/// # Safety
+///
+/// `gltf` must be null or a handle returned by `gltf_parse_glb`.
+#[no_mangle]
+pub unsafe extern "C" fn gltf_count(gltf: *const Handle, kind: u32) -> usize {
+    match (gltf.as_ref(), Kind::from_u32(kind)) {
+        (Some(handle), Some(kind)) => handle.names[kind as usize].len(),
+        _ => 0,
+    }
+}
+
+/// Returns the name of the object of the given `Kind` at `index`.
+///
+/// Returns null if the object is unnamed or does not exist. The string is owned
+/// by the handle and remains valid until it is freed.
+///
+/// # Safety
+///
+/// `gltf` must be null or a handle returned by `gltf_parse_glb`.
+#[no_mangle]
+pub unsafe extern "C" fn gltf_name(gltf: *const Handle, kind: u32, index: usize) -> *const c_char {
+    match (gltf.as_ref(), Kind::from_u32(kind)) {
+        (Some(handle), Some(kind)) => handle.names[kind as usize]
+            .get(index)
+            .and_then(Option::as_ref)
+            .map_or(ptr::null(), |name| name.as_ptr()),
+        _ => ptr::null(),
+    }
+}
+
+/// Releases a handle returned by `gltf_parse_glb`.
+///
+/// # Safety
+///
+/// `gltf` must be null or a handle returned by `gltf_parse_glb` that has not
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn gltf_free(gltf: *mut Handle) {
+    if !gltf.is_null() {
+        drop(Box::from_raw(gltf));
+    }
+}
+
+/// Releases an error description written by `gltf_parse_glb`.
+///
+/// # Safety
+///
+/// `error` must be null or a description written by `gltf_parse_glb` that has
+/// not already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn gltf_free_error(error: *mut c_char) {
+    if !error.is_null() {
+        drop(CString::from_raw(error));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    #[test]
+    fn parse_count_and_name() {
+        let data = br#"{
+            "asset": {"version": "2.0"},
+            "nodes": [{"name": "root"}, {}],
+            "scenes": [{"nodes": [0]}]
+        }"#;
+        unsafe {
+            let mut error = ptr::null_mut();
+            let gltf = gltf_parse_glb(data.as_ptr(), data.len(), &mut error);
+            assert!(!gltf.is_null());
+            assert!(error.is_null());
+            assert_eq!(gltf_count(gltf, Kind::Node as u32), 2);
+            assert_eq!(gltf_count(gltf, Kind::Scene as u32), 1);
+            assert_eq!(gltf_count(gltf, Kind::Mesh as u32), 0);
+            assert_eq!(gltf_count(gltf, NR_KINDS as u32), 0);
+            let name = gltf_name(gltf, Kind::Node as u32, 0);
+            assert_eq!(CStr::from_ptr(name).to_str(), Ok("root"));
+            assert!(gltf_name(gltf, Kind::Node as u32, 1).is_null());
+            assert!(gltf_name(gltf, Kind::Node as u32, 2).is_null());
+            gltf_free(gltf);
+        }
+    }
+
+    #[test]
+    fn parse_reports_validation_errors() {
+        let data = br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [3]}]
+        }"#;
+        unsafe {
+            let mut error = ptr::null_mut();
+            let gltf = gltf_parse_glb(data.as_ptr(), data.len(), &mut error);
+            assert!(gltf.is_null());
+            assert!(!error.is_null());
+            let message = CStr::from_ptr(error).to_str().unwrap().to_owned();
+            assert!(message.starts_with("scenes[0].nodes[0]: "), "{}", message);
+            gltf_free_error(error);
+        }
+    }
+}
@@ -0,0 +1,640 @@
+use std::fmt::Write;
+use std::{fmt, ops};
+
+use crate::mesh::Reader;
+use crate::{Buffer, Document, Semantic};
+
+/// Tolerances below which `compare` does not report numeric differences.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Tolerances {
+    /// The largest permitted deviation of a vertex position component.
+    pub position: f32,
+
+    /// The largest permitted deviation of a component of other vertex
+    /// attributes, such as normals and texture co-ordinates.
+    pub attribute: f32,
+
+    /// The largest permitted deviation of a material factor.
+    pub material: f32,
+
+    /// The largest permitted deviation of an element of a node transform
+    /// matrix.
+    pub transform: f32,
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            position: 1e-5,
+            attribute: 1e-4,
+            material: 1e-4,
+            transform: 1e-5,
+        }
+    }
+}
+
+/// The kind of a difference found by `compare`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// The number of elements differs.
+    Count {
+        /// The number of elements in the first document.
+        a: usize,
+
+        /// The number of elements in the second document.
+        b: usize,
+    },
+
+    /// The value is present in only one of the documents.
+    Presence,
+
+    /// Numeric values differ by more than the tolerance; this is the largest
+    /// difference between them.
+    Deviation(f32),
+
+    /// Non-numeric values differ.
+    Mismatch,
+}
+
+/// A difference between two documents found by `compare`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// The JSON path of the differing value.
+    pub path: json::Path,
+
+    /// How the value differs.
+    pub kind: Kind,
+}
+
+/// Collects the differences found by `compare`.
+struct Differences(Vec<Difference>);
+
+impl Differences {
+    fn push(&mut self, path: json::Path, kind: Kind) {
+        self.0.push(Difference { path, kind });
+    }
+
+    /// Records differing counts, returning whether they are equal.
+    fn count(&mut self, path: json::Path, a: usize, b: usize) -> bool {
+        if a != b {
+            self.push(path, Kind::Count { a, b });
+        }
+        a == b
+    }
+
+    /// Records values present in only one document, returning both if present.
+    fn presence<T>(&mut self, path: json::Path, a: Option<T>, b: Option<T>) -> Option<(T, T)> {
+        match (a, b) {
+            (Some(a), Some(b)) => Some((a, b)),
+            (None, None) => None,
+            _ => {
+                self.push(path, Kind::Presence);
+                None
+            },
+        }
+    }
+
+    fn mismatch<T: PartialEq>(&mut self, path: json::Path, a: T, b: T) {
+        if a != b {
+            self.push(path, Kind::Mismatch);
+        }
+    }
+
+    fn deviation(&mut self, path: json::Path, a: &[f32], b: &[f32], tolerance: f32) {
+        let deviation = a.iter().zip(b).fold(0.0f32, |max, (a, b)| max.max((a - b).abs()));
+        if deviation > tolerance || deviation.is_nan() {
+            self.push(path, Kind::Deviation(deviation));
+        }
+    }
+}
+
+/// Reads the values of a vertex attribute as floats, or `None` for custom
+/// attributes.
+fn read_attribute<'a, 's, F>(reader: &Reader<'a, 's, F>, semantic: &Semantic) -> Option<Vec<f32>>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    Some(match *semantic {
+        Semantic::Positions => reader.read_positions()?.flatten().collect(),
+        Semantic::Normals => reader.read_normals()?.flatten().collect(),
+        Semantic::Tangents => reader.read_tangents()?.flatten().collect(),
+        Semantic::Colors(set) => reader.read_colors(set)?.into_rgba_f32().flatten().collect(),
+        Semantic::TexCoords(set) => reader.read_tex_coords(set)?.into_f32().flatten().collect(),
+        Semantic::Joints(set) => {
+            reader.read_joints(set)?.into_u16().flatten().map(f32::from).collect()
+        },
+        Semantic::Weights(set) => reader.read_weights(set)?.into_f32().flatten().collect(),
+        Semantic::Extras(_) => return None,
+    })
+}
+
+fn compare_meshes<A, B>(
+    a: &Document,
+    a_buffers: &[A],
+    b: &Document,
+    b_buffers: &[B],
+    tolerances: &Tolerances,
+    differences: &mut Differences,
+) where
+    A: ops::Deref<Target = [u8]>,
+    B: ops::Deref<Target = [u8]>,
+{
+    for (mesh_a, mesh_b) in a.meshes().zip(b.meshes()) {
+        let path = json::Path::new().field("meshes").index(mesh_a.index()).field("primitives");
+        differences.count(path.clone(), mesh_a.primitives().len(), mesh_b.primitives().len());
+        for (primitive_a, primitive_b) in mesh_a.primitives().zip(mesh_b.primitives()) {
+            let path = path.index(primitive_a.index());
+            differences.mismatch(path.field("mode"), primitive_a.mode(), primitive_b.mode());
+            differences.mismatch(
+                path.field("material"),
+                primitive_a.material().index(),
+                primitive_b.material().index(),
+            );
+            let reader_a = primitive_a.reader(|x| a_buffers.get(x.index()).map(|x| &**x));
+            let reader_b = primitive_b.reader(|x| b_buffers.get(x.index()).map(|x| &**x));
+
+            let mut semantics: Vec<_> = primitive_a.attributes()
+                .chain(primitive_b.attributes())
+                .map(|(semantic, _)| semantic)
+                .collect();
+            semantics.sort_by_key(|semantic| semantic.to_string());
+            semantics.dedup();
+            for semantic in &semantics {
+                let path = path.field("attributes").key(&semantic.to_string());
+                let accessors = (primitive_a.get(semantic), primitive_b.get(semantic));
+                let (accessor_a, accessor_b) =
+                    match differences.presence(path.clone(), accessors.0, accessors.1) {
+                        Some(accessors) => accessors,
+                        None => continue,
+                    };
+                if !differences.count(path.clone(), accessor_a.count(), accessor_b.count()) {
+                    continue;
+                }
+                let values_a = read_attribute(&reader_a, semantic);
+                let values_b = read_attribute(&reader_b, semantic);
+                if let (Some(values_a), Some(values_b)) = (values_a, values_b) {
+                    let tolerance = if *semantic == Semantic::Positions {
+                        tolerances.position
+                    } else {
+                        tolerances.attribute
+                    };
+                    differences.deviation(path, &values_a, &values_b, tolerance);
+                }
+            }
+
+            let indices_a = reader_a.read_indices().map(|x| x.into_u32().collect::<Vec<_>>());
+            let indices_b = reader_b.read_indices().map(|x| x.into_u32().collect::<Vec<_>>());
+            let path = path.field("indices");
+            let indices = differences.presence(path.clone(), indices_a, indices_b);
+            if let Some((indices_a, indices_b)) = indices {
+                if differences.count(path.clone(), indices_a.len(), indices_b.len()) {
+                    differences.mismatch(path, indices_a, indices_b);
+                }
+            }
+        }
+    }
+}
+
+fn compare_materials(
+    a: &Document,
+    b: &Document,
+    tolerances: &Tolerances,
+    differences: &mut Differences,
+) {
+    let tolerance = tolerances.material;
+    for (index, (material_a, material_b)) in a.materials().zip(b.materials()).enumerate() {
+        let path = json::Path::new().field("materials").index(index);
+        let pbr_a = material_a.pbr_metallic_roughness();
+        let pbr_b = material_b.pbr_metallic_roughness();
+        let pbr = path.field("pbrMetallicRoughness");
+        differences.deviation(
+            pbr.field("baseColorFactor"),
+            &pbr_a.base_color_factor(),
+            &pbr_b.base_color_factor(),
+            tolerance,
+        );
+        differences.deviation(
+            pbr.field("metallicFactor"),
+            &[pbr_a.metallic_factor()],
+            &[pbr_b.metallic_factor()],
+            tolerance,
+        );
+        differences.deviation(
+            pbr.field("roughnessFactor"),
+            &[pbr_a.roughness_factor()],
+            &[pbr_b.roughness_factor()],
+            tolerance,
+        );
+        differences.presence(
+            pbr.field("baseColorTexture"),
+            pbr_a.base_color_texture().map(|_| ()),
+            pbr_b.base_color_texture().map(|_| ()),
+        );
+        differences.presence(
+            pbr.field("metallicRoughnessTexture"),
+            pbr_a.metallic_roughness_texture().map(|_| ()),
+            pbr_b.metallic_roughness_texture().map(|_| ()),
+        );
+        differences.presence(
+            path.field("normalTexture"),
+            material_a.normal_texture().map(|_| ()),
+            material_b.normal_texture().map(|_| ()),
+        );
+        differences.presence(
+            path.field("occlusionTexture"),
+            material_a.occlusion_texture().map(|_| ()),
+            material_b.occlusion_texture().map(|_| ()),
+        );
+        differences.presence(
+            path.field("emissiveTexture"),
+            material_a.emissive_texture().map(|_| ()),
+            material_b.emissive_texture().map(|_| ()),
+        );
+        differences.deviation(
+            path.field("emissiveFactor"),
+            &material_a.emissive_factor(),
+            &material_b.emissive_factor(),
+            tolerance,
+        );
+        differences.mismatch(
+            path.field("alphaMode"),
+            material_a.alpha_mode(),
+            material_b.alpha_mode(),
+        );
+        let cutoffs = (material_a.alpha_cutoff(), material_b.alpha_cutoff());
+        let cutoffs = differences.presence(path.field("alphaCutoff"), cutoffs.0, cutoffs.1);
+        if let Some((cutoff_a, cutoff_b)) = cutoffs {
+            differences.deviation(path.field("alphaCutoff"), &[cutoff_a], &[cutoff_b], tolerance);
+        }
+        differences.mismatch(
+            path.field("doubleSided"),
+            material_a.double_sided(),
+            material_b.double_sided(),
+        );
+    }
+}
+
+fn compare_nodes(
+    a: &Document,
+    b: &Document,
+    tolerances: &Tolerances,
+    differences: &mut Differences,
+) {
+    for (node_a, node_b) in a.nodes().zip(b.nodes()) {
+        let path = json::Path::new().field("nodes").index(node_a.index());
+        let children_a: Vec<_> = node_a.children().map(|x| x.index()).collect();
+        let children_b: Vec<_> = node_b.children().map(|x| x.index()).collect();
+        differences.mismatch(path.field("children"), children_a, children_b);
+        differences.mismatch(
+            path.field("mesh"),
+            node_a.mesh().map(|x| x.index()),
+            node_b.mesh().map(|x| x.index()),
+        );
+        differences.mismatch(
+            path.field("skin"),
+            node_a.skin().map(|x| x.index()),
+            node_b.skin().map(|x| x.index()),
+        );
+        differences.mismatch(
+            path.field("camera"),
+            node_a.camera().map(|x| x.index()),
+            node_b.camera().map(|x| x.index()),
+        );
+        let matrix_a: Vec<_> = node_a.transform().matrix().iter().flatten().copied().collect();
+        let matrix_b: Vec<_> = node_b.transform().matrix().iter().flatten().copied().collect();
+        differences.deviation(path.field("matrix"), &matrix_a, &matrix_b, tolerances.transform);
+    }
+    for (scene_a, scene_b) in a.scenes().zip(b.scenes()) {
+        let path = json::Path::new().field("scenes").index(scene_a.index()).field("nodes");
+        let nodes_a: Vec<_> = scene_a.nodes().map(|x| x.index()).collect();
+        let nodes_b: Vec<_> = scene_b.nodes().map(|x| x.index()).collect();
+        differences.mismatch(path, nodes_a, nodes_b);
+    }
+}
+
+/// Compares two documents and their buffer data, reporting the differences
+/// found between them.
+///
+/// This is intended for catching regressions in exporters, by comparing their
+/// output with a known good export. Objects are matched up by index, so the
+/// documents are expected to share the same object order.
+///
+/// The number of objects of each kind is compared, then for objects present in
+/// both documents:
+///
+/// * mesh primitives by their vertex attribute values, indices, mode, and
+///   material, reporting the largest deviation of each attribute;
+/// * materials by their factors, alpha mode, double-sidedness, and which
+///   textures are present;
+/// * nodes by their children, mesh, skin, camera, and transform matrix, the
+///   latter reported at the `matrix` property whether or not it is decomposed;
+/// * scenes by their root nodes.
+///
+/// Custom vertex attributes are compared by count only.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "import")]
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::compare::{compare, Tolerances};
+///
+/// let (a, a_buffers, _) = gltf::import("examples/Box.gltf")?;
+/// let (b, b_buffers, _) = gltf::import("examples/Box.glb")?;
+/// assert!(compare(&a, &a_buffers, &b, &b_buffers, &Tolerances::default()).is_empty());
+/// # Ok(())
+/// # }
+/// # #[cfg(feature = "import")]
+/// # run().unwrap();
+/// ```
+pub fn compare<A, B>(
+    a: &Document,
+    a_buffers: &[A],
+    b: &Document,
+    b_buffers: &[B],
+    tolerances: &Tolerances,
+) -> Vec<Difference>
+where
+    A: ops::Deref<Target = [u8]>,
+    B: ops::Deref<Target = [u8]>,
+{
+    let mut differences = Differences(Vec::new());
+    let root = json::Path::new();
+    differences.count(root.field("animations"), a.animations().len(), b.animations().len());
+    differences.count(root.field("cameras"), a.cameras().len(), b.cameras().len());
+    differences.count(root.field("images"), a.images().len(), b.images().len());
+    differences.count(root.field("materials"), a.materials().len(), b.materials().len());
+    differences.count(root.field("meshes"), a.meshes().len(), b.meshes().len());
+    differences.count(root.field("nodes"), a.nodes().len(), b.nodes().len());
+    differences.count(root.field("scenes"), a.scenes().len(), b.scenes().len());
+    differences.count(root.field("skins"), a.skins().len(), b.skins().len());
+    differences.count(root.field("textures"), a.textures().len(), b.textures().len());
+    compare_meshes(a, a_buffers, b, b_buffers, tolerances, &mut differences);
+    compare_materials(a, b, tolerances, &mut differences);
+    compare_nodes(a, b, tolerances, &mut differences);
+    differences.0
+}
+
+/// Returns a label such as `node 3 "Wheel"` for an object.
+fn label(kind: &str, index: usize, name: Option<&str>) -> String {
+    match name {
+        Some(name) => format!("{} {} {:?}", kind, index, name),
+        None => format!("{} {}", kind, index),
+    }
+}
+
+/// Returns the name of a node when names are enabled.
+fn node_name(node: &crate::Node) -> Option<String> {
+    #[cfg(feature = "names")]
+    {
+        node.name().map(String::from)
+    }
+    #[cfg(not(feature = "names"))]
+    {
+        let _ = node;
+        None
+    }
+}
+
+/// Returns the name of a material when names are enabled.
+fn material_name(material: &crate::Material) -> Option<String> {
+    #[cfg(feature = "names")]
+    {
+        material.name().map(String::from)
+    }
+    #[cfg(not(feature = "names"))]
+    {
+        let _ = material;
+        None
+    }
+}
+
+/// Returns `b - a` component-wise.
+fn delta<const N: usize>(a: [f32; N], b: [f32; N]) -> [f32; N] {
+    let mut delta = b;
+    delta.iter_mut().zip(&a).for_each(|(b, a)| *b -= a);
+    delta
+}
+
+/// Returns the largest component-wise difference between `a` and `b`.
+fn max_deviation(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).fold(0.0f32, |max, (a, b)| max.max((a - b).abs()))
+}
+
+fn report_nodes(a: &Document, b: &Document, tolerances: &Tolerances, report: &mut String) {
+    for node in b.nodes().skip(a.nodes().len()) {
+        let _ = writeln!(report, "+ {}", label("node", node.index(), node_name(&node).as_deref()));
+    }
+    for node in a.nodes().skip(b.nodes().len()) {
+        let _ = writeln!(report, "- {}", label("node", node.index(), node_name(&node).as_deref()));
+    }
+    for (node_a, node_b) in a.nodes().zip(b.nodes()) {
+        let name = node_name(&node_b);
+        let node = label("node", node_b.index(), name.as_deref());
+        let name_a = node_name(&node_a);
+        if name_a != name {
+            let _ = writeln!(report, "~ {}: renamed from {:?}", node, name_a);
+        }
+        let (translation_a, rotation_a, scale_a) = node_a.transform().decomposed();
+        let (translation_b, rotation_b, scale_b) = node_b.transform().decomposed();
+        if max_deviation(&translation_a, &translation_b) > tolerances.transform {
+            let _ = writeln!(
+                report,
+                "~ {}: translation {:?} -> {:?} (delta {:?})",
+                node,
+                translation_a,
+                translation_b,
+                delta(translation_a, translation_b),
+            );
+        }
+        if max_deviation(&rotation_a, &rotation_b) > tolerances.transform {
+            // The angle of the rotation taking one orientation to the other.
+            let dot: f32 = rotation_a.iter().zip(&rotation_b).map(|(a, b)| a * b).sum();
+            let angle = 2.0 * dot.abs().min(1.0).acos();
+            let _ = writeln!(
+                report,
+                "~ {}: rotation {:?} -> {:?} (delta {:.3} degrees)",
+                node,
+                rotation_a,
+                rotation_b,
+                angle.to_degrees(),
+            );
+        }
+        if max_deviation(&scale_a, &scale_b) > tolerances.transform {
+            let _ = writeln!(
+                report,
+                "~ {}: scale {:?} -> {:?} (delta {:?})",
+                node,
+                scale_a,
+                scale_b,
+                delta(scale_a, scale_b),
+            );
+        }
+        let mesh = (node_a.mesh().map(|x| x.index()), node_b.mesh().map(|x| x.index()));
+        if mesh.0 != mesh.1 {
+            let _ = writeln!(report, "~ {}: mesh {:?} -> {:?}", node, mesh.0, mesh.1);
+        }
+        let children_a: Vec<_> = node_a.children().map(|x| x.index()).collect();
+        let children_b: Vec<_> = node_b.children().map(|x| x.index()).collect();
+        if children_a != children_b {
+            let _ = writeln!(report, "~ {}: children {:?} -> {:?}", node, children_a, children_b);
+        }
+    }
+}
+
+fn report_materials(a: &Document, b: &Document, tolerances: &Tolerances, report: &mut String) {
+    for material in b.materials().skip(a.materials().len()) {
+        let name = material_name(&material);
+        let index = material.index().unwrap();
+        let _ = writeln!(report, "+ {}", label("material", index, name.as_deref()));
+    }
+    for material in a.materials().skip(b.materials().len()) {
+        let name = material_name(&material);
+        let index = material.index().unwrap();
+        let _ = writeln!(report, "- {}", label("material", index, name.as_deref()));
+    }
+    for (material_a, material_b) in a.materials().zip(b.materials()) {
+        let name = material_name(&material_b);
+        let material = label("material", material_b.index().unwrap(), name.as_deref());
+        let mut factor = |property: &str, x: &[f32], y: &[f32]| {
+            if max_deviation(x, y) > tolerances.material {
+                let _ = writeln!(report, "~ {}: {} {:?} -> {:?}", material, property, x, y);
+            }
+        };
+        let pbr_a = material_a.pbr_metallic_roughness();
+        let pbr_b = material_b.pbr_metallic_roughness();
+        factor("baseColorFactor", &pbr_a.base_color_factor(), &pbr_b.base_color_factor());
+        factor("metallicFactor", &[pbr_a.metallic_factor()], &[pbr_b.metallic_factor()]);
+        factor("roughnessFactor", &[pbr_a.roughness_factor()], &[pbr_b.roughness_factor()]);
+        factor("emissiveFactor", &material_a.emissive_factor(), &material_b.emissive_factor());
+        let cutoff_a = material_a.alpha_cutoff();
+        let cutoff_b = material_b.alpha_cutoff();
+        if let (Some(x), Some(y)) = (cutoff_a, cutoff_b) {
+            factor("alphaCutoff", &[x], &[y]);
+        } else if cutoff_a.is_some() != cutoff_b.is_some() {
+            let (x, y) = (cutoff_a, cutoff_b);
+            let _ = writeln!(report, "~ {}: alphaCutoff {:?} -> {:?}", material, x, y);
+        }
+        if material_a.alpha_mode() != material_b.alpha_mode() {
+            let _ = writeln!(
+                report,
+                "~ {}: alphaMode {:?} -> {:?}",
+                material,
+                material_a.alpha_mode(),
+                material_b.alpha_mode(),
+            );
+        }
+        if material_a.double_sided() != material_b.double_sided() {
+            let _ = writeln!(
+                report,
+                "~ {}: doubleSided {} -> {}",
+                material,
+                material_a.double_sided(),
+                material_b.double_sided(),
+            );
+        }
+        let textures = [
+            (
+                "baseColorTexture",
+                pbr_a.base_color_texture().map(|x| x.texture().index()),
+                pbr_b.base_color_texture().map(|x| x.texture().index()),
+            ),
+            (
+                "metallicRoughnessTexture",
+                pbr_a.metallic_roughness_texture().map(|x| x.texture().index()),
+                pbr_b.metallic_roughness_texture().map(|x| x.texture().index()),
+            ),
+            (
+                "normalTexture",
+                material_a.normal_texture().map(|x| x.texture().index()),
+                material_b.normal_texture().map(|x| x.texture().index()),
+            ),
+            (
+                "occlusionTexture",
+                material_a.occlusion_texture().map(|x| x.texture().index()),
+                material_b.occlusion_texture().map(|x| x.texture().index()),
+            ),
+            (
+                "emissiveTexture",
+                material_a.emissive_texture().map(|x| x.texture().index()),
+                material_b.emissive_texture().map(|x| x.texture().index()),
+            ),
+        ];
+        for (property, x, y) in textures.iter() {
+            if x != y {
+                let _ = writeln!(report, "~ {}: {} {:?} -> {:?}", material, property, x, y);
+            }
+        }
+    }
+}
+
+/// Returns a human-readable summary of the differences between two documents,
+/// for reviewing changes to an asset.
+///
+/// Like `compare`, objects are matched up by index. The report lists changes
+/// in the number of each kind of object, added and removed nodes and
+/// materials, changes to node names, transforms, meshes, and children, and
+/// changes to material factors, alpha modes, double-sidedness, and textures.
+/// Transforms are reported decomposed, with the change of translation and
+/// scale and the angle between the rotations; differences within the default
+/// `Tolerances` are ignored. Each line starts with `+` for an added object,
+/// `-` for a removed object, and `~` for a changed object.
+///
+/// Buffer data is not read, so changes to geometry are not reported; use
+/// `compare` for those.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let a = gltf::Gltf::open("examples/Box.gltf")?;
+/// let mut json = a.document.clone().into_json();
+/// json.nodes[1].translation = Some([1.0, 0.0, 0.0]);
+/// let b = gltf::Document::from_json(json)?;
+/// let report = gltf::diff_report(&a, &b);
+/// assert!(report.contains("translation [0.0, 0.0, 0.0] -> [1.0, 0.0, 0.0]"));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn diff_report(a: &Document, b: &Document) -> String {
+    let tolerances = Tolerances::default();
+    let mut report = String::new();
+    let counts = [
+        ("animations", a.animations().len(), b.animations().len()),
+        ("cameras", a.cameras().len(), b.cameras().len()),
+        ("images", a.images().len(), b.images().len()),
+        ("materials", a.materials().len(), b.materials().len()),
+        ("meshes", a.meshes().len(), b.meshes().len()),
+        ("nodes", a.nodes().len(), b.nodes().len()),
+        ("scenes", a.scenes().len(), b.scenes().len()),
+        ("skins", a.skins().len(), b.skins().len()),
+        ("textures", a.textures().len(), b.textures().len()),
+    ];
+    for (field, count_a, count_b) in counts.iter().filter(|(_, a, b)| a != b) {
+        let _ = writeln!(report, "{}: {} -> {}", field, count_a, count_b);
+    }
+    report_nodes(a, b, &tolerances, &mut report);
+    report_materials(a, b, &tolerances, &mut report);
+    if report.is_empty() {
+        report.push_str("no differences\n");
+    }
+    report
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Kind::Count { a, b } => write!(f, "count differs: {} vs {}", a, b),
+            Kind::Presence => write!(f, "present in only one document"),
+            Kind::Deviation(deviation) => write!(f, "values deviate by up to {}", deviation),
+            Kind::Mismatch => write!(f, "values differ"),
+        }
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.kind)
+    }
+}
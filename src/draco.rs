@@ -0,0 +1,274 @@
+use json::buffer::Target;
+
+use crate::accessor::{DataType, Dimensions};
+use crate::mesh::Semantic;
+use crate::pack::Packer;
+use crate::{buffer, Document, Error, Result};
+
+/// The name of the extension.
+const EXTENSION: &str = "KHR_draco_mesh_compression";
+
+/// Decodes Draco compressed geometry for `decompress`.
+///
+/// This library does not include a Draco decoder. Implement this trait with
+/// one, such as bindings to the reference implementation, which decodes the
+/// data of a primitive and converts its attributes to the requested types.
+pub trait Decoder {
+    /// Decodes the compressed data of a primitive, converting the attributes
+    /// with the given unique ids to the types of their accessors.
+    ///
+    /// Returns `None` if the data cannot be decoded.
+    fn decode(&self, data: &[u8], attributes: &[Attribute]) -> Option<Decoded>;
+}
+
+/// An attribute to be decoded, described by its accessor in the primitive.
+#[derive(Clone, Debug)]
+pub struct Attribute {
+    /// The semantic of the attribute in the primitive.
+    pub semantic: Semantic,
+
+    /// The unique id of the attribute in the compressed data.
+    pub id: u32,
+
+    /// The component data type of the accessor.
+    pub data_type: DataType,
+
+    /// The dimensions of the accessor.
+    pub dimensions: Dimensions,
+
+    /// Whether the integer components of the accessor are normalized.
+    pub normalized: bool,
+
+    /// The number of vertices.
+    pub count: usize,
+}
+
+/// The geometry decoded from the compressed data of a primitive.
+#[derive(Clone, Debug, Default)]
+pub struct Decoded {
+    /// The vertex indices, or empty if the primitive has no indices.
+    pub indices: Vec<u32>,
+
+    /// The data of each requested attribute, in the order requested, as
+    /// tightly packed little-endian components of the requested type.
+    pub attributes: Vec<Vec<u8>>,
+}
+
+/// Data decoded for an accessor, to be written to a new buffer view.
+struct Decompressed {
+    /// The index of the accessor.
+    accessor: usize,
+
+    /// The decoded data, tightly packed.
+    data: Vec<u8>,
+
+    /// The target of the new buffer view.
+    target: Target,
+}
+
+/// Decodes the data of a primitive, checking it against its accessors.
+fn decode_primitive<D>(
+    primitive: &crate::Primitive,
+    buffers: &[buffer::Data],
+    decoder: &D,
+) -> Option<Vec<Decompressed>>
+where
+    D: Decoder + ?Sized,
+{
+    let draco = primitive.draco_mesh_compression()?;
+    let view = draco.view();
+    let data = buffers.get(view.buffer().index())?.get(view.offset()..view.offset() + view.length())?;
+    let mut accessors = Vec::new();
+    let mut requests = Vec::new();
+    for (semantic, id) in draco.attributes() {
+        let accessor = primitive.get(&semantic)?;
+        requests.push(Attribute {
+            semantic,
+            id,
+            data_type: accessor.data_type(),
+            dimensions: accessor.dimensions(),
+            normalized: accessor.normalized(),
+            count: accessor.count(),
+        });
+        accessors.push(accessor);
+    }
+
+    let decoded = decoder.decode(data, &requests)?;
+    if decoded.attributes.len() != requests.len() {
+        return None;
+    }
+    let mut decompressed = Vec::new();
+    for (accessor, data) in accessors.into_iter().zip(decoded.attributes) {
+        if data.len() != accessor.count().checked_mul(accessor.size())? {
+            return None;
+        }
+        decompressed.push(Decompressed { accessor: accessor.index(), data, target: Target::ArrayBuffer });
+    }
+    match primitive.indices() {
+        Some(accessor) => {
+            if decoded.indices.len() != accessor.count() {
+                return None;
+            }
+            let mut data = Vec::with_capacity(accessor.count() * accessor.size());
+            for &index in &decoded.indices {
+                match accessor.data_type() {
+                    DataType::U8 if index <= u8::MAX as u32 => data.push(index as u8),
+                    DataType::U16 if index <= u16::MAX as u32 => {
+                        data.extend_from_slice(&(index as u16).to_le_bytes())
+                    },
+                    DataType::U32 => data.extend_from_slice(&index.to_le_bytes()),
+                    _ => return None,
+                }
+            }
+            decompressed.push(Decompressed {
+                accessor: accessor.index(),
+                data,
+                target: Target::ElementArrayBuffer,
+            });
+        },
+        None if !decoded.indices.is_empty() => return None,
+        None => {},
+    }
+    Some(decompressed)
+}
+
+/// Decompresses the primitives of an imported document that use
+/// `KHR_draco_mesh_compression`, returning the number of primitives
+/// decompressed.
+///
+/// The decoded data is written to a new buffer appended to `buffers` and to
+/// the document, and the accessors of each primitive are given buffer views
+/// into it, so that the decoded geometry is read by `mesh::Reader` like any
+/// other. The extension is then removed from the primitives and from the
+/// extensions used and required by the document.
+///
+/// Returns `Error::Draco` if the data of a primitive cannot be decoded or does
+/// not match its accessors, leaving the document unmodified.
+///
+/// ```
+/// # fn run(decoder: &dyn gltf::draco::Decoder) -> gltf::Result<()> {
+/// let (mut document, mut buffers, _) = gltf::import("examples/Box.gltf")?;
+/// gltf::draco::decompress(&mut document, &mut buffers, decoder)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn decompress<D>(document: &mut Document, buffers: &mut Vec<buffer::Data>, decoder: &D) -> Result<usize>
+where
+    D: Decoder + ?Sized,
+{
+    let mut primitives = Vec::new();
+    for mesh in document.meshes() {
+        for primitive in mesh.primitives() {
+            if primitive.draco_mesh_compression().is_none() {
+                continue;
+            }
+            let error = || Error::Draco { mesh: mesh.index(), primitive: primitive.index() };
+            let decompressed = decode_primitive(&primitive, buffers, decoder).ok_or_else(error)?;
+            primitives.push((mesh.index(), primitive.index(), decompressed));
+        }
+    }
+    if primitives.is_empty() {
+        return Ok(0);
+    }
+
+    let root = document.json_mut();
+    let mut packer = Packer::new(root);
+    for (mesh, primitive, decompressed) in &primitives {
+        for Decompressed { accessor, data, target } in decompressed {
+            let view = packer.push_view(root, data, None, Some(*target));
+            let accessor = &mut root.accessors[*accessor];
+            accessor.buffer_view = Some(view);
+            accessor.byte_offset = 0;
+        }
+        if let Some(extensions) = root.meshes[*mesh].primitives[*primitive].extensions.as_mut() {
+            extensions.khr_draco_mesh_compression = None;
+        }
+    }
+    root.extensions_used.retain(|x| x != EXTENSION);
+    root.extensions_required.retain(|x| x != EXTENSION);
+    buffers.push(buffer::Data(packer.finish(root)));
+    Ok(primitives.len())
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use super::{Attribute, Decoded, Decoder};
+    use crate::accessor::{DataType, Dimensions};
+    use crate::mesh::Semantic;
+    use crate::Error;
+
+    /// Decodes the compressed data `b"tri"` into a single triangle.
+    struct Triangle;
+
+    impl Decoder for Triangle {
+        fn decode(&self, data: &[u8], attributes: &[Attribute]) -> Option<Decoded> {
+            if data != b"tri" {
+                return None;
+            }
+            let mut decoded = Decoded { indices: vec![0, 2, 1], attributes: Vec::new() };
+            for attribute in attributes {
+                assert_eq!(attribute.semantic, Semantic::Positions);
+                assert_eq!(attribute.id, 7);
+                assert_eq!((attribute.data_type, attribute.dimensions), (DataType::F32, Dimensions::Vec3));
+                let positions = [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+                decoded.attributes.push(positions.iter().flat_map(|x| x.to_le_bytes()).collect());
+            }
+            Some(decoded)
+        }
+    }
+
+    const JSON: &str = r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_draco_mesh_compression"],
+        "extensionsRequired": ["KHR_draco_mesh_compression"],
+        "buffers": [{"byteLength": 3, "uri": "data:application/octet-stream;base64,dHJp"}],
+        "bufferViews": [{"buffer": 0, "byteLength": 3}],
+        "accessors": [
+            {"componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"componentType": 5123, "count": 3, "type": "SCALAR"}
+        ],
+        "meshes": [{
+            "primitives": [{
+                "attributes": {"POSITION": 0},
+                "indices": 1,
+                "extensions": {
+                    "KHR_draco_mesh_compression": {"bufferView": 0, "attributes": {"POSITION": 7}}
+                }
+            }]
+        }]
+    }"#;
+
+    #[test]
+    fn decompress_draco_primitives() {
+        let (mut document, mut buffers, _) = crate::import_slice(JSON.as_bytes()).unwrap();
+        assert_eq!(super::decompress(&mut document, &mut buffers, &Triangle).unwrap(), 1);
+        assert_eq!(buffers.len(), 2);
+        assert_eq!(document.extensions_required().count(), 0);
+        document.validate().unwrap();
+
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        assert!(primitive.draco_mesh_compression().is_none());
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        let positions: Vec<_> = reader.read_positions().unwrap().collect();
+        assert_eq!(positions, [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+        let indices: Vec<_> = reader.read_indices().unwrap().into_u32().collect();
+        assert_eq!(indices, [0, 2, 1]);
+        assert_eq!(super::decompress(&mut document, &mut buffers, &Triangle).unwrap(), 0);
+    }
+
+    #[test]
+    fn reject_mismatched_draco_data() {
+        let corrupt = JSON.replace("dHJp", "dHJ5");
+        let (mut document, mut buffers, _) = crate::import_slice(corrupt.as_bytes()).unwrap();
+        match super::decompress(&mut document, &mut buffers, &Triangle) {
+            Err(Error::Draco { mesh: 0, primitive: 0 }) => {},
+            result => panic!("unexpected result: {:?}", result),
+        }
+        assert_eq!(buffers.len(), 1);
+
+        let mismatched = JSON.replace("\"count\": 3, \"type\": \"VEC3\"", "\"count\": 4, \"type\": \"VEC3\"");
+        let (mut document, mut buffers, _) = crate::import_slice(mismatched.as_bytes()).unwrap();
+        assert!(super::decompress(&mut document, &mut buffers, &Triangle).is_err());
+        assert!(document.meshes().next().unwrap().primitives().next().unwrap().draco_mesh_compression().is_some());
+    }
+}
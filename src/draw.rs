@@ -0,0 +1,225 @@
+use std::cmp::Ordering;
+
+use crate::material::AlphaMode;
+use crate::math::{Matrix4, Vector3};
+use crate::scene::iter::Walk;
+use crate::{Document, Node, Primitive, Scene, Semantic};
+
+/// A primitive instantiated in a scene, as returned by `Scene::draw_order`.
+#[derive(Clone, Debug)]
+pub struct Draw<'a> {
+    /// The node instantiating the primitive.
+    pub node: Node<'a>,
+
+    /// The primitive to be drawn.
+    pub primitive: Primitive<'a>,
+
+    /// The world transform of `node`.
+    pub transform: [[f32; 4]; 4],
+
+    /// The alpha rendering mode of the primitive material.
+    pub alpha_mode: AlphaMode,
+
+    /// The distance from the camera to the centre of the primitive bounds along
+    /// the view direction.
+    pub depth: f32,
+}
+
+/// A reason why a blended primitive may be composited in the wrong order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Warning {
+    /// The `POSITION` accessor has no `min` and `max` bounds to sort by.
+    MissingBounds,
+
+    /// The material is double-sided, so back faces may be drawn over front faces
+    /// unless the triangles are sorted too.
+    DoubleSided,
+
+    /// The bounds overlap those of the blended primitive with the given index in
+    /// the same mesh, so the two cannot be ordered by depth alone.
+    Overlapping(usize),
+}
+
+/// The primitives of a document grouped by alpha rendering mode.
+///
+/// Primitives are identified by `(mesh, primitive)` index pairs.
+#[derive(Clone, Debug, Default)]
+pub struct Report {
+    /// Primitives with an `Opaque` material.
+    pub opaque: Vec<(usize, usize)>,
+
+    /// Primitives with a `Mask` material.
+    pub mask: Vec<(usize, usize)>,
+
+    /// Primitives with a `Blend` material.
+    pub blend: Vec<(usize, usize)>,
+
+    /// Problems found with the ordering of blended primitives.
+    pub warnings: Vec<((usize, usize), Warning)>,
+}
+
+/// Returns the `POSITION` bounds of a primitive, if declared.
+fn bounds(primitive: &Primitive) -> Option<([f32; 3], [f32; 3])> {
+    let accessor = primitive.get(&Semantic::Positions)?;
+    let min = json::deserialize::from_value(accessor.min()?).ok()?;
+    let max = json::deserialize::from_value(accessor.max()?).ok()?;
+    Some((min, max))
+}
+
+fn overlaps(a: &([f32; 3], [f32; 3]), b: &([f32; 3], [f32; 3])) -> bool {
+    (0..3).all(|i| a.0[i] < b.1[i] && b.0[i] < a.1[i])
+}
+
+/// Classifies every primitive of `document` by alpha rendering mode and flags
+/// blended primitives that a simple back-to-front sort may not order correctly.
+pub fn report(document: &Document) -> Report {
+    let mut report = Report::default();
+    for mesh in document.meshes() {
+        let mut blended = Vec::new();
+        for primitive in mesh.primitives() {
+            let id = (mesh.index(), primitive.index());
+            let material = primitive.material();
+            match material.alpha_mode() {
+                AlphaMode::Opaque => report.opaque.push(id),
+                AlphaMode::Mask => report.mask.push(id),
+                AlphaMode::Blend => {
+                    report.blend.push(id);
+                    if material.double_sided() {
+                        report.warnings.push((id, Warning::DoubleSided));
+                    }
+                    match bounds(&primitive) {
+                        Some(bounds) => {
+                            for &(other, ref other_bounds) in &blended {
+                                if overlaps(&bounds, other_bounds) {
+                                    report.warnings.push((id, Warning::Overlapping(other)));
+                                }
+                            }
+                            blended.push((primitive.index(), bounds));
+                        },
+                        None => report.warnings.push((id, Warning::MissingBounds)),
+                    }
+                },
+            }
+        }
+    }
+    report
+}
+
+/// Returns the primitives instantiated in `scene` in a suggested drawing order.
+///
+/// See `Scene::draw_order`.
+pub(crate) fn order<'a>(scene: &Scene<'a>, camera: [[f32; 4]; 4]) -> Vec<Draw<'a>> {
+    let identity = Matrix4::from_nonuniform_scale(1.0, 1.0, 1.0);
+    let view = Matrix4::from_array(camera).invert().unwrap_or(identity);
+    let mut draws = Vec::new();
+    for (node, world) in Walk::new(scene.nodes()) {
+        let mesh = match node.mesh() {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        for primitive in mesh.primitives() {
+            let centre = match bounds(&primitive) {
+                Some((min, max)) => Vector3::new(
+                    0.5 * (min[0] + max[0]),
+                    0.5 * (min[1] + max[1]),
+                    0.5 * (min[2] + max[2]),
+                ),
+                None => Vector3::new(0.0, 0.0, 0.0),
+            };
+            // The camera looks down its local -Z axis.
+            let depth = -(view * world).transform_point(centre).z;
+            draws.push(Draw {
+                node: node.clone(),
+                alpha_mode: primitive.material().alpha_mode(),
+                primitive,
+                transform: world.as_array(),
+                depth,
+            });
+        }
+    }
+    let rank = |alpha_mode| match alpha_mode {
+        AlphaMode::Opaque => 0,
+        AlphaMode::Mask => 1,
+        AlphaMode::Blend => 2,
+    };
+    draws.sort_by(|a, b| {
+        let depth = if a.alpha_mode == AlphaMode::Blend {
+            b.depth.partial_cmp(&a.depth)
+        } else {
+            a.depth.partial_cmp(&b.depth)
+        };
+        rank(a.alpha_mode)
+            .cmp(&rank(b.alpha_mode))
+            .then(depth.unwrap_or(Ordering::Equal))
+    });
+    draws
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{report, Warning};
+    use crate::material::AlphaMode;
+    use crate::Document;
+
+    #[test]
+    fn classify_and_order_primitives() {
+        let json = json::Root::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [
+                {"componentType": 5126, "type": "VEC3", "count": 1, "min": [-1, -1, -1], "max": [1, 1, 1]},
+                {"componentType": 5126, "type": "VEC3", "count": 1, "min": [0, 0, 0], "max": [2, 2, 2]},
+                {"componentType": 5126, "type": "VEC3", "count": 1}
+            ],
+            "materials": [{"alphaMode": "BLEND"}, {"alphaMode": "MASK", "doubleSided": true}],
+            "meshes": [
+                {"primitives": [
+                    {"attributes": {"POSITION": 0}, "material": 0},
+                    {"attributes": {"POSITION": 1}, "material": 0},
+                    {"attributes": {"POSITION": 0}}
+                ]},
+                {"primitives": [
+                    {"attributes": {"POSITION": 2}, "material": 0},
+                    {"attributes": {"POSITION": 0}, "material": 1}
+                ]}
+            ],
+            "nodes": [
+                {"mesh": 0, "translation": [0, 0, -10]},
+                {"mesh": 1, "translation": [0, 0, -5]}
+            ],
+            "scenes": [{"nodes": [0, 1]}]
+        }"#).unwrap();
+        let document = Document::from_json_without_validation(json);
+
+        let report = report(&document);
+        assert_eq!(report.opaque, vec![(0, 2)]);
+        assert_eq!(report.mask, vec![(1, 1)]);
+        assert_eq!(report.blend, vec![(0, 0), (0, 1), (1, 0)]);
+        assert_eq!(
+            report.warnings,
+            vec![((0, 1), Warning::Overlapping(0)), ((1, 0), Warning::MissingBounds)],
+        );
+
+        let camera = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 2.0, 1.0],
+        ];
+        let scene = document.scenes().next().unwrap();
+        let order: Vec<_> = scene
+            .draw_order(camera)
+            .iter()
+            .map(|draw| (draw.node.index(), draw.primitive.index(), draw.alpha_mode, draw.depth))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                (0, 2, AlphaMode::Opaque, 12.0),
+                (1, 1, AlphaMode::Mask, 7.0),
+                (0, 0, AlphaMode::Blend, 12.0),
+                (0, 1, AlphaMode::Blend, 11.0),
+                (1, 0, AlphaMode::Blend, 7.0),
+            ],
+        );
+    }
+}
@@ -0,0 +1,665 @@
+use std::fmt;
+
+use json::buffer::Target;
+use json::material::{AlphaCutoff, AlphaMode, EmissiveFactor, PbrBaseColorFactor, StrengthFactor};
+use json::mesh::{Mode, Semantic};
+use json::scene::NodeBuilder;
+use json::validation::Checked::Valid;
+
+use crate::pack::Packer;
+use crate::{binary, buffer, image, Document, Result};
+
+/// Options for `to_gltf`.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// The largest buffer or encoded image, in bytes, that is inlined as a
+    /// base64 data URI; larger resources are written to separate files.
+    ///
+    /// Defaults to `None`, writing every resource to a separate file.
+    pub inline_small_resources: Option<usize>,
+}
+
+impl Options {
+    /// Inlines the buffers and images of at most `max_bytes` bytes as data
+    /// URIs, see `inline_small_resources`.
+    pub fn inline_small_resources(mut self, max_bytes: usize) -> Self {
+        self.inline_small_resources = Some(max_bytes);
+        self
+    }
+
+    /// Returns `true` if a resource of the given size is to be inlined.
+    fn inlines(&self, size: usize) -> bool {
+        matches!(self.inline_small_resources, Some(max) if size <= max)
+    }
+}
+
+/// A standard glTF asset, as returned by `to_gltf`.
+#[derive(Clone, Debug, Default)]
+pub struct Export {
+    /// The glTF JSON, to be written to a `.gltf` file.
+    pub json: Vec<u8>,
+
+    /// The external resources, paired with their URIs relative to the `.gltf`
+    /// file, to be written next to it.
+    pub files: Vec<(String, Vec<u8>)>,
+}
+
+/// Returns the data URI of a resource.
+fn data_uri(mime_type: &str, data: &[u8]) -> String {
+    format!("data:{};base64,{}", mime_type, base64::encode(data))
+}
+
+/// Constructs standard glTF from an imported document along with its buffer
+/// and image data, as returned by `gltf::import`.
+///
+/// Each buffer, including the `BIN` chunk of binary glTF, and each image
+/// referenced by URI is either inlined as a base64 data URI or written to an
+/// external file named after `name`, such as `model0.bin` or `model1.png`,
+/// according to `options`. Data URIs are padded and declare the
+/// `application/octet-stream` type for buffers and `image/png` or `image/ktx2`
+/// for images, which are encoded as they are for `Glb::from_document`. Images
+/// stored in buffer views are kept there, and images without data in `images`
+/// keep their URI.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let (document, buffers, images) = gltf::import("examples/Box.gltf")?;
+/// let options = gltf::export::Options::default().inline_small_resources(1024);
+/// let export = gltf::export::to_gltf(&document, &buffers, &images, "Box", &options)?;
+/// assert!(export.files.is_empty());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn to_gltf(
+    document: &Document,
+    buffers: &[buffer::Data],
+    images: &[image::Data],
+    name: &str,
+    options: &Options,
+) -> Result<Export> {
+    let mut root = document.0.clone();
+    let mut files = Vec::new();
+    for (index, (json, data)) in root.buffers.iter_mut().zip(buffers).enumerate() {
+        let data = &data.0[..data.0.len().min(json.byte_length as usize)];
+        json.uri = Some(if options.inlines(data.len()) {
+            data_uri("application/octet-stream", data)
+        } else {
+            let uri = format!("{}{}.bin", name, index);
+            files.push((uri.clone(), data.to_vec()));
+            uri
+        });
+    }
+    for (index, (json, data)) in root.images.iter_mut().zip(images).enumerate() {
+        if json.uri.is_none() {
+            continue;
+        }
+        // KTX2 containers and undecoded files are written as they are.
+        let (encoded, mime_type) = match data.format.mime_type() {
+            Some(mime_type) => (data.pixels.clone(), mime_type),
+            None => (data.to_png()?, "image/png"),
+        };
+        let extension = match data.format {
+            image::Format::Jpeg => "jpg",
+            _ => &mime_type["image/".len()..],
+        };
+        json.mime_type = None;
+        json.uri = Some(if options.inlines(encoded.len()) {
+            data_uri(mime_type, &encoded)
+        } else {
+            let uri = format!("{}{}.{}", name, index, extension);
+            files.push((uri.clone(), encoded));
+            uri
+        });
+    }
+    let json = json::serialize::to_vec_pretty(&root)?;
+    Ok(Export { json, files })
+}
+
+/// Represents a failure to add a mesh with `DocumentBuilder::mesh`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum BuildError {
+    /// An attribute of the primitive with the given index has a different
+    /// number of elements to its positions.
+    Length {
+        /// The index of the primitive in the mesh.
+        primitive: usize,
+        /// The attribute with the wrong number of elements.
+        semantic: Semantic,
+        /// The number of positions.
+        expected: usize,
+        /// The number of elements of the attribute.
+        actual: usize,
+    },
+
+    /// An index of the primitive with the given index refers to a vertex that
+    /// does not exist.
+    Index {
+        /// The index of the primitive in the mesh.
+        primitive: usize,
+        /// The out of bounds vertex index.
+        index: u32,
+    },
+}
+
+/// Helper for constructing a glTF asset programmatically, packing the vertex
+/// data of its meshes into a single buffer.
+///
+/// The asset has one scene, which is the default scene. The finished asset is
+/// written as standard glTF with `to_gltf` or as binary glTF with `to_glb`, or
+/// returned as a `Document` with its buffer data by `build`.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::export::{DocumentBuilder, MaterialBuilder, MeshBuilder, PrimitiveBuilder};
+/// use gltf::json::scene::NodeBuilder;
+///
+/// let mut builder = DocumentBuilder::new();
+/// let red = builder.material(MaterialBuilder::new().base_color_factor([1.0, 0.0, 0.0, 1.0]));
+/// let triangle = PrimitiveBuilder::new(vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]])
+///     .indices(vec![0, 1, 2])
+///     .material(red);
+/// let mesh = builder.mesh(MeshBuilder::new().primitive(triangle)).unwrap();
+/// let scene = builder.scene();
+/// builder.node(NodeBuilder::new().mesh(mesh).scene(scene)).unwrap();
+///
+/// let glb = builder.to_glb()?;
+/// let (document, buffers, _) = gltf::import_slice(&glb)?;
+/// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+/// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+/// assert_eq!(reader.read_positions().unwrap().len(), 3);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DocumentBuilder {
+    /// The asset under construction.
+    root: json::Root,
+
+    /// Packs vertex data into the buffer of the asset.
+    packer: Packer,
+
+    /// The default scene.
+    scene: json::Index<json::Scene>,
+}
+
+/// Helper for adding a mesh to a `DocumentBuilder`.
+#[derive(Clone, Debug, Default)]
+pub struct MeshBuilder {
+    /// The user-defined name of the mesh.
+    #[cfg(feature = "names")]
+    name: Option<String>,
+
+    /// The primitives of the mesh.
+    primitives: Vec<PrimitiveBuilder>,
+}
+
+/// Helper for adding a triangle list primitive to a `MeshBuilder`.
+#[derive(Clone, Debug)]
+pub struct PrimitiveBuilder {
+    /// XYZ vertex positions.
+    positions: Vec<[f32; 3]>,
+
+    /// XYZ vertex normals.
+    normals: Option<Vec<[f32; 3]>>,
+
+    /// XYZW vertex tangents.
+    tangents: Option<Vec<[f32; 4]>>,
+
+    /// UV texture co-ordinates of the first set.
+    tex_coords: Option<Vec<[f32; 2]>>,
+
+    /// RGBA vertex colors of the first set.
+    colors: Option<Vec<[f32; 4]>>,
+
+    /// Vertex indices.
+    indices: Option<Vec<u32>>,
+
+    /// The material to render the primitive with.
+    material: Option<json::Index<json::Material>>,
+}
+
+/// Helper for adding a metallic-roughness material to a `DocumentBuilder`.
+#[derive(Clone, Debug, Default)]
+pub struct MaterialBuilder {
+    /// The material under construction.
+    material: json::Material,
+}
+
+impl DocumentBuilder {
+    /// Creates a builder for an asset with an empty default scene.
+    pub fn new() -> Self {
+        let mut root = json::Root::default();
+        let packer = Packer::new(&mut root);
+        let scene = root.push(json::Scene {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            nodes: Vec::new(),
+        });
+        root.scene = Some(scene);
+        Self { root, packer, scene }
+    }
+
+    /// Returns the index of the default scene, to attach root nodes to.
+    pub fn scene(&self) -> json::Index<json::Scene> {
+        self.scene
+    }
+
+    /// Returns the glTF JSON of the asset, for adding objects that the builder
+    /// does not cover.
+    pub fn root_mut(&mut self) -> &mut json::Root {
+        &mut self.root
+    }
+
+    /// Adds a material, returning its index.
+    pub fn material(&mut self, material: MaterialBuilder) -> json::Index<json::Material> {
+        self.root.push(material.material)
+    }
+
+    /// Adds a mesh, packing the vertex data of its primitives, and returns its
+    /// index.
+    ///
+    /// Indices are stored as unsigned shorts when every index is below 65535,
+    /// which is reserved as the primitive restart value.
+    ///
+    /// Returns an error, leaving the asset unmodified, if an attribute of a
+    /// primitive has a different number of elements to its positions, or if
+    /// an index is out of bounds.
+    pub fn mesh(
+        &mut self,
+        mesh: MeshBuilder,
+    ) -> std::result::Result<json::Index<json::Mesh>, BuildError> {
+        for (index, primitive) in mesh.primitives.iter().enumerate() {
+            primitive.check(index)?;
+        }
+        let mut primitives = Vec::new();
+        for primitive in mesh.primitives {
+            primitives.push(self.primitive(primitive));
+        }
+        Ok(self.root.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: mesh.name,
+            primitives,
+            weights: None,
+        }))
+    }
+
+    /// Packs the vertex data of a primitive.
+    fn primitive(&mut self, primitive: PrimitiveBuilder) -> json::mesh::Primitive {
+        let root = &mut self.root;
+        let packer = &mut self.packer;
+        let target = Some(Target::ArrayBuffer);
+        let mut attributes = json::OrderedMap::new();
+        let positions = packer.push_accessor(root, &primitive.positions, target);
+        attributes.insert(Valid(Semantic::Positions), positions);
+        if let Some(normals) = primitive.normals {
+            let normals = packer.push_accessor(root, &normals, target);
+            attributes.insert(Valid(Semantic::Normals), normals);
+        }
+        if let Some(tangents) = primitive.tangents {
+            let tangents = packer.push_accessor(root, &tangents, target);
+            attributes.insert(Valid(Semantic::Tangents), tangents);
+        }
+        if let Some(tex_coords) = primitive.tex_coords {
+            let tex_coords = packer.push_accessor(root, &tex_coords, target);
+            attributes.insert(Valid(Semantic::TexCoords(0)), tex_coords);
+        }
+        if let Some(colors) = primitive.colors {
+            let colors = packer.push_accessor(root, &colors, target);
+            attributes.insert(Valid(Semantic::Colors(0)), colors);
+        }
+        let indices = primitive.indices.map(|indices| {
+            let max = indices.iter().cloned().max().unwrap_or(0);
+            let target = Some(Target::ElementArrayBuffer);
+            // 65535 is reserved as the primitive restart value.
+            if max < u16::MAX as u32 {
+                let indices: Vec<u16> = indices.iter().map(|&i| i as u16).collect();
+                packer.push_accessor(root, &indices, target)
+            } else {
+                packer.push_accessor(root, &indices, target)
+            }
+        });
+        json::mesh::Primitive {
+            attributes,
+            extensions: None,
+            extras: Default::default(),
+            indices,
+            material: primitive.material,
+            mode: Valid(Mode::Triangles),
+            targets: None,
+        }
+    }
+
+    /// Checks a node and adds it to the asset, returning its index; see
+    /// `NodeBuilder::build`.
+    ///
+    /// Nodes are not attached to the default scene unless the builder says so,
+    /// for example with `NodeBuilder::scene(builder.scene())`.
+    pub fn node(
+        &mut self,
+        node: NodeBuilder,
+    ) -> std::result::Result<json::Index<json::Node>, json::scene::BuildError> {
+        node.build(&mut self.root)
+    }
+
+    /// Finishes the asset, returning it with its buffer data.
+    ///
+    /// The buffer is omitted if no mesh was added. Returns `Error::Validation`
+    /// if the asset is invalid, for example if an object added through
+    /// `root_mut` refers to another that does not exist.
+    pub fn build(self) -> Result<(Document, Vec<buffer::Data>)> {
+        let DocumentBuilder { mut root, packer, .. } = self;
+        let buffers = if packer.is_empty() {
+            root.buffers.remove(packer.buffer().value());
+            Vec::new()
+        } else {
+            vec![buffer::Data(packer.finish(&mut root))]
+        };
+        Ok((Document::from_json(root)?, buffers))
+    }
+
+    /// Finishes the asset and constructs standard glTF, with the buffer
+    /// written to `{name}0.bin` or inlined according to `options`; see
+    /// `to_gltf`.
+    pub fn to_gltf(self, name: &str, options: &Options) -> Result<Export> {
+        let (document, buffers) = self.build()?;
+        to_gltf(&document, &buffers, &[], name, options)
+    }
+
+    /// Finishes the asset and constructs binary glTF, with the buffer stored
+    /// in the `BIN` chunk.
+    pub fn to_glb(self) -> Result<Vec<u8>> {
+        let (document, buffers) = self.build()?;
+        let options = binary::Options::default();
+        binary::Glb::from_document(&document, &buffers, &[], options)?.to_vec()
+    }
+}
+
+impl Default for DocumentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MeshBuilder {
+    /// Creates a builder for a mesh without primitives.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the mesh.
+    #[cfg(feature = "names")]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Adds a primitive to the mesh.
+    pub fn primitive(mut self, primitive: PrimitiveBuilder) -> Self {
+        self.primitives.push(primitive);
+        self
+    }
+}
+
+impl PrimitiveBuilder {
+    /// Creates a builder for a triangle list primitive with the given vertex
+    /// positions.
+    pub fn new(positions: Vec<[f32; 3]>) -> Self {
+        Self {
+            positions,
+            normals: None,
+            tangents: None,
+            tex_coords: None,
+            colors: None,
+            indices: None,
+            material: None,
+        }
+    }
+
+    /// Sets the vertex normals, one for each position.
+    pub fn normals(mut self, normals: Vec<[f32; 3]>) -> Self {
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Sets the vertex tangents, one for each position.
+    pub fn tangents(mut self, tangents: Vec<[f32; 4]>) -> Self {
+        self.tangents = Some(tangents);
+        self
+    }
+
+    /// Sets the texture co-ordinates of the first set, one for each position.
+    pub fn tex_coords(mut self, tex_coords: Vec<[f32; 2]>) -> Self {
+        self.tex_coords = Some(tex_coords);
+        self
+    }
+
+    /// Sets the vertex colors of the first set, one for each position.
+    pub fn colors(mut self, colors: Vec<[f32; 4]>) -> Self {
+        self.colors = Some(colors);
+        self
+    }
+
+    /// Sets the vertex indices, three for each triangle.
+    pub fn indices(mut self, indices: Vec<u32>) -> Self {
+        self.indices = Some(indices);
+        self
+    }
+
+    /// Sets the material to render the primitive with.
+    pub fn material(mut self, material: json::Index<json::Material>) -> Self {
+        self.material = Some(material);
+        self
+    }
+
+    /// Checks that every attribute has an element for each position and that
+    /// every index refers to a vertex, reporting errors for the primitive at
+    /// `index` of its mesh.
+    fn check(&self, index: usize) -> std::result::Result<(), BuildError> {
+        let expected = self.positions.len();
+        let lengths = [
+            (Semantic::Normals, self.normals.as_ref().map(Vec::len)),
+            (Semantic::Tangents, self.tangents.as_ref().map(Vec::len)),
+            (Semantic::TexCoords(0), self.tex_coords.as_ref().map(Vec::len)),
+            (Semantic::Colors(0), self.colors.as_ref().map(Vec::len)),
+        ];
+        for (semantic, actual) in lengths.iter().cloned() {
+            match actual {
+                Some(actual) if actual != expected => {
+                    return Err(BuildError::Length { primitive: index, semantic, expected, actual })
+                },
+                _ => {},
+            }
+        }
+        let indices = self.indices.iter().flatten();
+        if let Some(&vertex) = indices.into_iter().find(|&&i| i as usize >= expected) {
+            return Err(BuildError::Index { primitive: index, index: vertex });
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BuildError::Length { primitive, ref semantic, expected, actual } => write!(
+                f,
+                "primitive {} has {} {} elements for {} positions",
+                primitive,
+                actual,
+                semantic.to_string(),
+                expected,
+            ),
+            BuildError::Index { primitive, index } => {
+                write!(f, "primitive {} index {} is out of bounds", primitive, index)
+            },
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+impl MaterialBuilder {
+    /// Creates a builder for the default material, which is opaque, white,
+    /// and fully metallic and rough.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the material.
+    #[cfg(feature = "names")]
+    pub fn name(mut self, name: &str) -> Self {
+        self.material.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the linear RGBA base color factor.
+    pub fn base_color_factor(mut self, factor: [f32; 4]) -> Self {
+        self.material.pbr_metallic_roughness.base_color_factor = PbrBaseColorFactor(factor);
+        self
+    }
+
+    /// Sets the metalness factor.
+    pub fn metallic_factor(mut self, factor: f32) -> Self {
+        self.material.pbr_metallic_roughness.metallic_factor = StrengthFactor(factor);
+        self
+    }
+
+    /// Sets the roughness factor.
+    pub fn roughness_factor(mut self, factor: f32) -> Self {
+        self.material.pbr_metallic_roughness.roughness_factor = StrengthFactor(factor);
+        self
+    }
+
+    /// Sets the linear RGB emissive factor.
+    pub fn emissive_factor(mut self, factor: [f32; 3]) -> Self {
+        self.material.emissive_factor = EmissiveFactor(factor);
+        self
+    }
+
+    /// Sets the alpha rendering mode, with the alpha cutoff for `Mask`.
+    pub fn alpha_mode(mut self, mode: AlphaMode, cutoff: Option<f32>) -> Self {
+        self.material.alpha_mode = Valid(mode);
+        self.material.alpha_cutoff = cutoff.map(AlphaCutoff);
+        self
+    }
+
+    /// Sets whether back faces are rendered.
+    pub fn double_sided(mut self, double_sided: bool) -> Self {
+        self.material.double_sided = double_sided;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_gltf, BuildError, DocumentBuilder, MeshBuilder, Options, PrimitiveBuilder};
+    use json::mesh::Semantic;
+    use crate::image::{Data, Format};
+
+    #[test]
+    fn inline_small_resources() {
+        let (document, buffers, _) = crate::import("examples/Box.gltf").unwrap();
+        let length = document.buffers().next().unwrap().length();
+
+        let export = to_gltf(&document, &buffers, &[], "Box", &Options::default()).unwrap();
+        assert_eq!(export.files.len(), 1);
+        assert_eq!(export.files[0].0, "Box0.bin");
+        assert_eq!(export.files[0].1.len(), length);
+        let root = json::Root::from_slice(&export.json).unwrap();
+        assert_eq!(root.buffers[0].uri.as_deref(), Some("Box0.bin"));
+
+        let options = Options::default().inline_small_resources(length - 1);
+        assert_eq!(to_gltf(&document, &buffers, &[], "Box", &options).unwrap().files.len(), 1);
+        let options = Options::default().inline_small_resources(length);
+        let export = to_gltf(&document, &buffers, &[], "Box", &options).unwrap();
+        assert!(export.files.is_empty());
+        let root = json::Root::from_slice(&export.json).unwrap();
+        let uri = root.buffers[0].uri.as_ref().unwrap();
+        let base64 = uri.strip_prefix("data:application/octet-stream;base64,").unwrap();
+        assert_eq!(base64.len() % 4, 0);
+        let (_, imported, _) = crate::import_slice(&export.json).unwrap();
+        assert_eq!(imported[0].0, buffers[0].0);
+
+        let mut root = document.into_json();
+        root.images.push(json::deserialize::from_str(r#"{"uri": "red.png"}"#).unwrap());
+        let document = crate::Document::from_json(root).unwrap();
+        let image = Data {
+            pixels: vec![255, 0, 0, 255],
+            format: Format::R8G8B8A8,
+            width: 1,
+            height: 1,
+            premultiplied: false,
+        };
+        let images = [image];
+        let export = to_gltf(&document, &buffers, &images, "Box", &options).unwrap();
+        let root = json::Root::from_slice(&export.json).unwrap();
+        assert!(root.images[0].uri.as_ref().unwrap().starts_with("data:image/png;base64,"));
+        let (_, _, imported) = crate::import_slice(&export.json).unwrap();
+        assert_eq!(imported[0].pixels, images[0].pixels);
+        let export = to_gltf(&document, &buffers, &images, "Box", &Options::default()).unwrap();
+        assert_eq!(export.files[1].0, "Box0.png");
+    }
+
+    #[test]
+    fn document_builder() {
+        let mut builder = DocumentBuilder::new();
+        let positions: Vec<_> = (0..70_000).map(|i| [i as f32, 0.0, 0.0]).collect();
+        let quad = PrimitiveBuilder::new(positions[..4].to_vec())
+            .tex_coords(vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]])
+            .indices(vec![0, 1, 2, 0, 2, 3]);
+        let restart =
+            PrimitiveBuilder::new(positions[..65_536].to_vec()).indices(vec![0, 1, 65_535]);
+        let large = PrimitiveBuilder::new(positions).indices(vec![0, 1, 69_999]);
+        let mesh = MeshBuilder::new().primitive(quad).primitive(restart).primitive(large);
+        builder.mesh(mesh).unwrap();
+        let export = builder.to_gltf("model", &Options::default()).unwrap();
+        assert_eq!(export.files.len(), 1);
+        assert_eq!(export.files[0].0, "model0.bin");
+
+        let root = json::Root::from_slice(&export.json).unwrap();
+        let component_type = |primitive: usize| {
+            let indices = root.meshes[0].primitives[primitive].indices.unwrap();
+            root.accessors[indices.value()].component_type.unwrap().0
+        };
+        assert_eq!(component_type(0), json::accessor::ComponentType::U16);
+        assert_eq!(component_type(1), json::accessor::ComponentType::U32);
+        assert_eq!(component_type(2), json::accessor::ComponentType::U32);
+        assert!(root.accessors.iter().all(|accessor| accessor.buffer_view.is_some()));
+
+        let (document, buffers) = DocumentBuilder::new().build().unwrap();
+        assert_eq!(document.scenes().len(), 1);
+        assert!(document.buffers().next().is_none() && buffers.is_empty());
+    }
+
+    #[test]
+    fn document_builder_rejects_invalid_primitives() {
+        let mut builder = DocumentBuilder::new();
+        let triangle = PrimitiveBuilder::new(vec![[0.0; 3]; 3]);
+        let normals = triangle.clone().normals(vec![[0.0, 0.0, 1.0]]);
+        let mesh = MeshBuilder::new().primitive(triangle.clone()).primitive(normals);
+        let error = BuildError::Length {
+            primitive: 1,
+            semantic: Semantic::Normals,
+            expected: 3,
+            actual: 1,
+        };
+        assert_eq!(builder.mesh(mesh).unwrap_err(), error);
+        let indices = triangle.indices(vec![0, 1, 3]);
+        let error = BuildError::Index { primitive: 0, index: 3 };
+        assert_eq!(builder.mesh(MeshBuilder::new().primitive(indices)).unwrap_err(), error);
+
+        let (document, buffers) = builder.build().unwrap();
+        assert_eq!(document.meshes().len(), 0);
+        assert!(document.accessors().next().is_none() && buffers.is_empty());
+    }
+}
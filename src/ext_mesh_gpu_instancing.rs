@@ -0,0 +1,229 @@
+use gltf_json::Extras;
+use crate::{Accessor, Document};
+
+#[cfg(feature = "utils")]
+use crate::accessor::util::ReadF32;
+#[cfg(feature = "utils")]
+use crate::Buffer;
+
+/// The instance attributes of a node whose mesh is drawn once per instance.
+#[derive(Clone, Debug)]
+pub struct Instancing<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+}
+
+impl<'a> Instancing<'a> {
+    /// Constructs an `Instancing`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the accessor of the instance attribute with the given name,
+    /// such as `"TRANSLATION"` or an application specific `"_ID"`.
+    pub fn get(&self, name: &str) -> Option<Accessor<'a>> {
+        let index = self.json.attributes.get(name)?;
+        self.document.accessors().nth(index.value())
+    }
+
+    /// Returns the accessor of the instance translations.
+    pub fn translation(&self) -> Option<Accessor<'a>> {
+        self.get("TRANSLATION")
+    }
+
+    /// Returns the accessor of the instance rotations.
+    pub fn rotation(&self) -> Option<Accessor<'a>> {
+        self.get("ROTATION")
+    }
+
+    /// Returns the accessor of the instance scales.
+    pub fn scale(&self) -> Option<Accessor<'a>> {
+        self.get("SCALE")
+    }
+
+    /// Returns the number of instances, which is the number of elements of
+    /// every instance attribute.
+    pub fn count(&self) -> usize {
+        self.json
+            .attributes
+            .values()
+            .next()
+            .and_then(|index| self.document.accessors().nth(index.value()))
+            .map_or(0, |accessor| accessor.count())
+    }
+
+    /// Constructs a reader of the instance transforms.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader { instancing: self.clone(), get_buffer_data }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a Extras {
+        &self.json.extras
+    }
+}
+
+/// Instance transform reader.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// The instance attributes to read.
+    instancing: Instancing<'a>,
+
+    /// Returns the data of a buffer.
+    get_buffer_data: F,
+}
+
+#[cfg(feature = "utils")]
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Visits the instance translations, dequantizing integer translations
+    /// permitted by `KHR_mesh_quantization`.
+    pub fn read_translations(&self) -> Option<ReadF32<'s, [f32; 3]>> {
+        let accessor = self.instancing.translation()?;
+        ReadF32::new_quantized(accessor, self.get_buffer_data.clone())
+    }
+
+    /// Visits the instance rotations as unit quaternions, dequantizing
+    /// normalized integer components.
+    pub fn read_rotations(&self) -> Option<ReadF32<'s, [f32; 4]>> {
+        let accessor = self.instancing.rotation()?;
+        ReadF32::new(accessor, self.get_buffer_data.clone())
+    }
+
+    /// Visits the instance scales, dequantizing integer scales permitted by
+    /// `KHR_mesh_quantization`.
+    pub fn read_scales(&self) -> Option<ReadF32<'s, [f32; 3]>> {
+        let accessor = self.instancing.scale()?;
+        ReadF32::new_quantized(accessor, self.get_buffer_data.clone())
+    }
+
+    /// Visits the column-major transform of each instance, relative to the
+    /// node, composed as `T * R * S` with absent attributes taken as the
+    /// identity.
+    ///
+    /// Returns `None` if the node has none of the `TRANSLATION`, `ROTATION`,
+    /// and `SCALE` attributes, or if one of them cannot be read.
+    pub fn read_matrices(&self) -> Option<ReadMatrices<'s>> {
+        let instancing = &self.instancing;
+        let attributes = [instancing.translation(), instancing.rotation(), instancing.scale()];
+        let remaining = attributes.iter().flatten().map(|x| x.count()).min()?;
+        let translations = match attributes[0] {
+            Some(_) => Some(self.read_translations()?),
+            None => None,
+        };
+        let rotations = match attributes[1] {
+            Some(_) => Some(self.read_rotations()?),
+            None => None,
+        };
+        let scales = match attributes[2] {
+            Some(_) => Some(self.read_scales()?),
+            None => None,
+        };
+        Some(ReadMatrices { translations, rotations, scales, remaining })
+    }
+}
+
+/// Iterator returned by `Reader::read_matrices`.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct ReadMatrices<'a> {
+    /// The instance translations, if any.
+    translations: Option<ReadF32<'a, [f32; 3]>>,
+
+    /// The instance rotations, if any.
+    rotations: Option<ReadF32<'a, [f32; 4]>>,
+
+    /// The instance scales, if any.
+    scales: Option<ReadF32<'a, [f32; 3]>>,
+
+    /// The number of instances left to visit.
+    remaining: usize,
+}
+
+#[cfg(feature = "utils")]
+impl<'a> Iterator for ReadMatrices<'a> {
+    type Item = [[f32; 4]; 4];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let transform = crate::scene::Transform::Decomposed {
+            translation: self.translations.as_mut().map_or(Some([0.0; 3]), Iterator::next)?,
+            rotation: self.rotations.as_mut().map_or(Some([0.0, 0.0, 0.0, 1.0]), Iterator::next)?,
+            scale: self.scales.as_mut().map_or(Some([1.0; 3]), Iterator::next)?,
+        };
+        Some(transform.matrix())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[cfg(feature = "utils")]
+impl<'a> ExactSizeIterator for ReadMatrices<'a> {}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn read_instance_matrices() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let translations = [[1.0f32, 2.0, 3.0], [0.0, 0.0, -1.0]];
+        let translation = packer.push_accessor(&mut root, &translations, None);
+        let scales = [[2.0f32; 3], [1.0, 1.0, 0.5]];
+        let scale = packer.push_accessor(&mut root, &scales, None);
+        let bin = packer.finish(&mut root);
+        let node = format!(
+            r#"{{"extensions": {{"EXT_mesh_gpu_instancing": {{
+                "attributes": {{"TRANSLATION": {}, "SCALE": {}}}
+            }}}}}}"#,
+            translation.value(),
+            scale.value(),
+        );
+        root.push::<json::Node>(json::deserialize::from_str(&node).unwrap());
+
+        let document = Document::from_json(root).unwrap();
+        let node = document.nodes().next().unwrap();
+        let instancing = node.instancing().unwrap();
+        assert_eq!(instancing.count(), 2);
+        assert!(instancing.rotation().is_none());
+        assert_eq!(instancing.translation().unwrap().index(), translation.value());
+        let reader = instancing.reader(|_| Some(&bin));
+        let matrices: Vec<_> = reader.read_matrices().unwrap().collect();
+        assert_eq!(matrices.len(), 2);
+        assert_eq!(matrices[0][0], [2.0, 0.0, 0.0, 0.0]);
+        assert_eq!(matrices[0][3], [1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(matrices[1][2], [0.0, 0.0, 0.5, 0.0]);
+        assert_eq!(matrices[1][3], [0.0, 0.0, -1.0, 1.0]);
+
+        let usages = crate::accessor::usage::usages(&document.0);
+        let usage = crate::accessor::usage::Usage::InstanceAttribute("SCALE".to_string());
+        assert_eq!(usages[scale.value()], [usage]);
+        assert!(document.validate_full().iter().all(|x| !x.path.as_str().starts_with("access")));
+    }
+}
@@ -1,3 +1,6 @@
+use json::validation::{Diagnostic, Error};
+use json::Path;
+
 use crate::{buffer, Document};
 
 #[cfg(feature = "import")]
@@ -38,6 +41,68 @@ pub enum Format {
 
     /// Red, green, blue, alpha (16 bits).
     R16G16B16A16,
+
+    /// A KTX2 container, as referenced by the `KHR_texture_basisu` extension.
+    ///
+    /// The container is not transcoded: `Data::pixels` holds the whole file
+    /// and the dimensions are those of its base mip level.
+    Ktx2,
+
+    /// An undecoded PNG file, as imported with `import::ImagePolicy::RawBytes`.
+    ///
+    /// `Data::pixels` holds the whole file and the dimensions are read from its
+    /// header.
+    Png,
+
+    /// An undecoded JPEG file, as imported with `import::ImagePolicy::RawBytes`.
+    Jpeg,
+
+    /// An undecoded WebP file, as imported with `import::ImagePolicy::RawBytes`.
+    WebP,
+}
+
+#[cfg(feature = "import")]
+impl Format {
+    /// Returns the MIME type of the file held by images of an encoded format,
+    /// or `None` for decoded pixel formats.
+    pub fn mime_type(self) -> Option<&'static str> {
+        match self {
+            Format::Ktx2 => Some("image/ktx2"),
+            Format::Png => Some("image/png"),
+            Format::Jpeg => Some("image/jpeg"),
+            Format::WebP => Some("image/webp"),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if images of the format hold an encoded file rather than
+    /// pixels.
+    pub fn is_encoded(self) -> bool {
+        self.mime_type().is_some()
+    }
+}
+
+/// The identifier at the start of every KTX2 file.
+pub(crate) const KTX2_IDENTIFIER: [u8; 12] =
+    [0xAB, b'K', b'T', b'X', b' ', b'2', b'0', 0xBB, b'\r', b'\n', 0x1A, b'\n'];
+
+/// The MIME types recognized by `sniff_mime_type`.
+const SNIFFED_MIME_TYPES: [&str; 4] = ["image/png", "image/jpeg", "image/ktx2", "image/webp"];
+
+/// Returns the MIME type of an encoded image, recognized by the magic bytes it
+/// starts with: `image/png`, `image/jpeg`, `image/ktx2`, or `image/webp`.
+pub fn sniff_mime_type(encoded: &[u8]) -> Option<&'static str> {
+    if encoded.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if encoded.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if encoded.starts_with(&KTX2_IDENTIFIER) {
+        Some("image/ktx2")
+    } else if encoded.starts_with(b"RIFF") && encoded.get(8..12) == Some(&b"WEBP"[..]) {
+        Some("image/webp")
+    } else {
+        None
+    }
 }
 
 /// Describes an image data source.
@@ -91,6 +156,13 @@ pub struct Data {
 
     /// The image width in pixels.
     pub height: u32,
+
+    /// Whether the color channels are multiplied by the alpha channel.
+    ///
+    /// Decoded images are never premultiplied, since glTF and the PNG and JPEG
+    /// formats store straight alpha; see `premultiply` for blending with
+    /// premultiplied alpha.
+    pub premultiplied: bool,
 }
 
 impl<'a> Image<'a> {
@@ -142,6 +214,79 @@ impl<'a> Image<'a> {
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
+
+    /// Returns the MIME type declared for the image and the path of the
+    /// property declaring it: the `mimeType`, or else the media type of a data
+    /// URI or the type implied by the file extension of the URI.
+    fn declared_mime_type(&self) -> Option<(&'a str, Path)> {
+        let path = Path::new().field("images").index(self.index);
+        if let Some(mime_type) = self.json.mime_type.as_ref() {
+            return Some((mime_type.0.as_str(), path.field("mimeType")));
+        }
+        let uri = self.json.uri.as_deref()?;
+        let mime_type = match uri.strip_prefix("data:") {
+            Some(data) => data.split([';', ',']).next()?,
+            None => match uri.rsplit('.').next()?.to_ascii_lowercase().as_str() {
+                "png" => "image/png",
+                "jpg" | "jpeg" => "image/jpeg",
+                "ktx2" => "image/ktx2",
+                "webp" => "image/webp",
+                _ => return None,
+            },
+        };
+        Some((mime_type, path.field("uri")))
+    }
+
+    /// Checks the encoded data of the image, such as the contents of its buffer
+    /// view or of the file its URI refers to, against its MIME type.
+    ///
+    /// The type of the data is recognized by its magic bytes, see
+    /// `sniff_mime_type`. A `MimeTypeMismatch` error is reported at the
+    /// property declaring the MIME type of the image if the data is of another
+    /// type, and at each texture source referring to the image if the data is
+    /// not of a format that source allows: PNG or JPEG for `source`, KTX2 for
+    /// `KHR_texture_basisu`, and WebP for `EXT_texture_webp`. Engines commonly
+    /// fail to create textures from such images at runtime. MIME types other
+    /// than those recognized are not checked.
+    pub fn validate_data(&self, encoded: &[u8]) -> Vec<Diagnostic> {
+        let sniffed = sniff_mime_type(encoded);
+        let mut diagnostics = Vec::new();
+        let mut check = |path: Path, allowed: &[&str]| {
+            if !matches!(sniffed, Some(x) if allowed.contains(&x)) {
+                diagnostics.push(Diagnostic { path, error: Error::MimeTypeMismatch });
+            }
+        };
+        if let Some((declared, path)) = self.declared_mime_type() {
+            if SNIFFED_MIME_TYPES.contains(&declared) {
+                check(path, &[declared]);
+            }
+        }
+        for (index, texture) in self.document.0.textures.iter().enumerate() {
+            let path = || Path::new().field("textures").index(index);
+            if texture.source.map(|x| x.value()) == Some(self.index) {
+                check(path().field("source"), &["image/png", "image/jpeg"]);
+            }
+            #[cfg(feature = "KHR_texture_basisu")]
+            {
+                let extensions = texture.extensions.as_ref();
+                let basisu = extensions.and_then(|x| x.texture_basisu.as_ref());
+                if basisu.map(|x| x.source.value()) == Some(self.index) {
+                    let path = path().field("extensions").field("KHR_texture_basisu");
+                    check(path.field("source"), &["image/ktx2"]);
+                }
+            }
+            #[cfg(feature = "EXT_texture_webp")]
+            {
+                let extensions = texture.extensions.as_ref();
+                let webp = extensions.and_then(|x| x.texture_webp.as_ref());
+                if webp.map(|x| x.source.value()) == Some(self.index) {
+                    let path = path().field("extensions").field("EXT_texture_webp");
+                    check(path.field("source"), &["image/webp"]);
+                }
+            }
+        }
+        diagnostics
+    }
 }
 
 #[cfg(feature = "import")]
@@ -150,20 +295,351 @@ impl Data {
     /// to expose such functionality to the user.
     pub(crate) fn new(image: DynamicImage) -> Self {
         use image_crate::GenericImageView;
+        // Float images, which glTF images do not decode to, are narrowed to
+        // 16 bits per channel.
+        let image = match image {
+            DynamicImage::ImageRgb32F(_) => DynamicImage::ImageRgb16(image.to_rgb16()),
+            DynamicImage::ImageRgba32F(_) => DynamicImage::ImageRgba16(image.to_rgba16()),
+            image => image,
+        };
         let format = match image {
             DynamicImage::ImageLuma8(_) => Format::R8,
             DynamicImage::ImageLumaA8(_) => Format::R8G8,
             DynamicImage::ImageRgb8(_) => Format::R8G8B8,
             DynamicImage::ImageRgba8(_) => Format::R8G8B8A8,
-            DynamicImage::ImageBgr8(_) => Format::B8G8R8,
-            DynamicImage::ImageBgra8(_) => Format::B8G8R8A8,
             DynamicImage::ImageLuma16(_) => Format::R16,
             DynamicImage::ImageLumaA16(_) => Format::R16G16,
             DynamicImage::ImageRgb16(_) => Format::R16G16B16,
             DynamicImage::ImageRgba16(_) => Format::R16G16B16A16,
+            image => return Data::new(DynamicImage::ImageRgba8(image.to_rgba8())),
         };
         let (width, height) = image.dimensions();
-        let pixels = image.to_bytes();
-        Data { format, width, height, pixels }
+        let pixels = image.into_bytes();
+        Data { format, width, height, pixels, premultiplied: false }
+    }
+
+    /// Wraps a KTX2 file, reading its dimensions from the header.
+    ///
+    /// Returns `None` if the data does not start with a KTX2 header.
+    pub(crate) fn ktx2(encoded: &[u8]) -> Option<Self> {
+        if encoded.len() < 28 || encoded[..12] != KTX2_IDENTIFIER {
+            return None;
+        }
+        let read = |offset: usize| {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&encoded[offset..offset + 4]);
+            u32::from_le_bytes(bytes)
+        };
+        // One-dimensional textures have a height of zero.
+        let (width, height) = (read(20), read(24).max(1));
+        let pixels = encoded.to_vec();
+        Some(Data { format: Format::Ktx2, width, height, pixels, premultiplied: false })
+    }
+
+    /// Wraps an encoded PNG, JPEG, or WebP file without decoding it, reading
+    /// its dimensions from the header.
+    pub(crate) fn encoded(
+        encoded: &[u8],
+        format: Format,
+    ) -> Result<Self, image_crate::ImageError> {
+        let encoding = match format {
+            Format::Png => image_crate::ImageFormat::Png,
+            Format::Jpeg => image_crate::ImageFormat::Jpeg,
+            _ => image_crate::ImageFormat::WebP,
+        };
+        let cursor = std::io::Cursor::new(encoded);
+        let (width, height) = image_crate::io::Reader::with_format(cursor, encoding)
+            .into_dimensions()?;
+        let pixels = encoded.to_vec();
+        Ok(Data { format, width, height, pixels, premultiplied: false })
+    }
+
+    /// Decodes an image imported with `import::ImagePolicy::RawBytes`.
+    ///
+    /// Images of other formats, including KTX2 containers, which are not
+    /// transcoded, are returned unchanged.
+    pub fn decode(&self) -> Result<Self, image_crate::ImageError> {
+        let encoding = match self.format {
+            Format::Png => image_crate::ImageFormat::Png,
+            Format::Jpeg => image_crate::ImageFormat::Jpeg,
+            Format::WebP => image_crate::ImageFormat::WebP,
+            _ => return Ok(self.clone()),
+        };
+        let decoded = image_crate::load_from_memory_with_format(&self.pixels, encoding)?;
+        Ok(Data::new(decoded))
+    }
+
+    /// Returns the image at half resolution.
+    ///
+    /// Each pixel is the average of a 2x2 block of source pixels. Odd dimensions
+    /// are rounded up, repeating the last row or column of the source. KTX2
+    /// and other encoded images are returned unchanged.
+    pub fn downsample(&self) -> Self {
+        let (channels, depth) = self.layout();
+        if self.width == 0 || self.height == 0 || self.format.is_encoded() {
+            return self.clone();
+        }
+        let width = self.width / 2 + self.width % 2;
+        let height = self.height / 2 + self.height % 2;
+        let mut pixels = Vec::with_capacity(width as usize * height as usize * channels * depth);
+        for y in 0..height {
+            let rows = [2 * y, (2 * y + 1).min(self.height - 1)];
+            for x in 0..width {
+                let columns = [2 * x, (2 * x + 1).min(self.width - 1)];
+                for channel in 0..channels {
+                    let mut sum = 0;
+                    for &row in &rows {
+                        for &column in &columns {
+                            sum += self.read(column, row, channel);
+                        }
+                    }
+                    let average = (sum + 2) / 4;
+                    if depth == 1 {
+                        pixels.push(average as u8);
+                    } else {
+                        pixels.extend_from_slice(&(average as u16).to_ne_bytes());
+                    }
+                }
+            }
+        }
+        let premultiplied = self.premultiplied;
+        Data { format: self.format, width, height, pixels, premultiplied }
+    }
+
+    /// Returns the number of channels and the number of bytes per channel.
+    ///
+    /// Encoded files are treated as a single channel of bytes.
+    fn layout(&self) -> (usize, usize) {
+        match self.format {
+            Format::R8 | Format::Ktx2 | Format::Png | Format::Jpeg | Format::WebP => (1, 1),
+            Format::R8G8 => (2, 1),
+            Format::R8G8B8 | Format::B8G8R8 => (3, 1),
+            Format::R8G8B8A8 | Format::B8G8R8A8 => (4, 1),
+            Format::R16 => (1, 2),
+            Format::R16G16 => (2, 2),
+            Format::R16G16B16 => (3, 2),
+            Format::R16G16B16A16 => (4, 2),
+        }
+    }
+
+    /// Returns the value of a channel of the pixel at column `x` and row `y`.
+    pub(crate) fn read(&self, x: u32, y: u32, channel: usize) -> u32 {
+        let (channels, depth) = self.layout();
+        let offset = ((y * self.width + x) as usize * channels + channel) * depth;
+        if depth == 1 {
+            u32::from(self.pixels[offset])
+        } else {
+            u32::from(u16::from_ne_bytes([self.pixels[offset], self.pixels[offset + 1]]))
+        }
+    }
+
+    /// Inverts the green channel, which converts a normal map between the
+    /// OpenGL (+Y up) and DirectX (+Y down) conventions.
+    ///
+    /// Single channel and encoded images are left unchanged.
+    pub fn flip_green(&mut self) {
+        let (channels, depth) = self.layout();
+        if channels < 2 {
+            return;
+        }
+        for pixel in self.pixels.chunks_exact_mut(channels * depth) {
+            let green = &mut pixel[depth..2 * depth];
+            if depth == 1 {
+                green[0] = u8::MAX - green[0];
+            } else {
+                let value = u16::MAX - u16::from_ne_bytes([green[0], green[1]]);
+                green.copy_from_slice(&value.to_ne_bytes());
+            }
+        }
+    }
+
+    /// Returns whether the last channel of the format is alpha.
+    ///
+    /// Two channel formats are decoded from grayscale images with alpha.
+    fn has_alpha(&self) -> bool {
+        matches!(
+            self.format,
+            Format::R8G8
+                | Format::R8G8B8A8
+                | Format::B8G8R8A8
+                | Format::R16G16
+                | Format::R16G16B16A16
+        )
+    }
+
+    /// Applies `f` to each color channel value with the alpha value of its
+    /// pixel and the maximum channel value.
+    fn map_color<F>(&mut self, f: F)
+    where
+        F: Fn(u32, u32, u32) -> u32,
+    {
+        let (channels, depth) = self.layout();
+        let max = if depth == 1 { u32::from(u8::MAX) } else { u32::from(u16::MAX) };
+        for pixel in self.pixels.chunks_exact_mut(channels * depth) {
+            let (color, alpha) = pixel.split_at_mut((channels - 1) * depth);
+            if depth == 1 {
+                let alpha = u32::from(alpha[0]);
+                color.iter_mut().for_each(|x| *x = f(u32::from(*x), alpha, max) as u8);
+            } else {
+                let alpha = u32::from(u16::from_ne_bytes([alpha[0], alpha[1]]));
+                for x in color.chunks_exact_mut(2) {
+                    let value = f(u32::from(u16::from_ne_bytes([x[0], x[1]])), alpha, max);
+                    x.copy_from_slice(&(value as u16).to_ne_bytes());
+                }
+            }
+        }
+    }
+
+    /// Multiplies the color channels by the alpha channel, as expected by
+    /// renderers blending with premultiplied alpha.
+    ///
+    /// Does nothing if the image has no alpha channel or is already
+    /// premultiplied. The last channel of two channel formats is treated as
+    /// alpha.
+    pub fn premultiply(&mut self) {
+        if self.premultiplied || !self.has_alpha() {
+            return;
+        }
+        self.map_color(|x, alpha, max| (x * alpha + max / 2) / max);
+        self.premultiplied = true;
+    }
+
+    /// Divides the color channels by the alpha channel, undoing `premultiply`.
+    ///
+    /// Does nothing if the image is not premultiplied. Fully transparent
+    /// pixels become black, and the precision of the color of nearly
+    /// transparent pixels lost by premultiplication is not recovered.
+    pub fn unpremultiply(&mut self) {
+        if !self.premultiplied {
+            return;
+        }
+        self.map_color(|x, alpha, max| match alpha {
+            0 => 0,
+            _ => ((x * max + alpha / 2) / alpha).min(max),
+        });
+        self.premultiplied = false;
+    }
+
+    /// Encodes the pixel data as PNG, with straight alpha.
+    ///
+    /// PNG files are returned as they are, and JPEG and WebP files are decoded
+    /// first.
+    pub(crate) fn to_png(&self) -> Result<Vec<u8>, image_crate::ImageError> {
+        use image_crate::{ColorType, ImageEncoder};
+        match self.format {
+            Format::Png => return Ok(self.pixels.clone()),
+            Format::Jpeg | Format::WebP => return self.decode()?.to_png(),
+            _ => {},
+        }
+        let straight;
+        let data = if self.premultiplied {
+            let mut copy = self.clone();
+            copy.unpremultiply();
+            straight = copy;
+            &straight
+        } else {
+            self
+        };
+        let mut pixels = std::borrow::Cow::Borrowed(&data.pixels[..]);
+        let color = match self.format {
+            Format::R8 => ColorType::L8,
+            Format::R8G8 => ColorType::La8,
+            Format::R8G8B8 => ColorType::Rgb8,
+            Format::R8G8B8A8 => ColorType::Rgba8,
+            Format::B8G8R8 | Format::B8G8R8A8 => {
+                // PNG has no blue-first channel orders.
+                let channels = if self.format == Format::B8G8R8 { 3 } else { 4 };
+                for pixel in pixels.to_mut().chunks_exact_mut(channels) {
+                    pixel.swap(0, 2);
+                }
+                if channels == 3 { ColorType::Rgb8 } else { ColorType::Rgba8 }
+            },
+            Format::R16 => ColorType::L16,
+            Format::R16G16 => ColorType::La16,
+            Format::R16G16B16 => ColorType::Rgb16,
+            Format::R16G16B16A16 => ColorType::Rgba16,
+            Format::Png | Format::Jpeg | Format::WebP => unreachable!(),
+            Format::Ktx2 => {
+                use image_crate::error::{ImageFormatHint, UnsupportedError};
+                let hint = ImageFormatHint::Name("KTX2".to_string());
+                return Err(image_crate::ImageError::Unsupported(UnsupportedError::from(hint)));
+            },
+        };
+        let mut png = Vec::new();
+        image_crate::codecs::png::PngEncoder::new(&mut png)
+            .write_image(&pixels, self.width, self.height, color)?;
+        Ok(png)
+    }
+}
+
+#[cfg(all(test, feature = "import"))]
+mod tests {
+    use super::{sniff_mime_type, Data, Format, KTX2_IDENTIFIER};
+
+    #[test]
+    fn premultiply_alpha() {
+        let pixels = vec![255, 128, 0, 128, 10, 20, 30, 0, 40, 50, 60, 255];
+        let format = Format::R8G8B8A8;
+        let mut image = Data { pixels, format, width: 3, height: 1, premultiplied: false };
+        image.unpremultiply();
+        assert_eq!(image.pixels[0], 255);
+        image.premultiply();
+        assert!(image.premultiplied);
+        assert_eq!(image.pixels, [128, 64, 0, 128, 0, 0, 0, 0, 40, 50, 60, 255]);
+        image.premultiply();
+        assert_eq!(image.pixels[0], 128);
+
+        let png = image.to_png().unwrap();
+        let decoded = Data::new(image_crate::load_from_memory(&png).unwrap());
+        assert!(!decoded.premultiplied);
+        assert_eq!(decoded.pixels, [255, 128, 0, 128, 0, 0, 0, 0, 40, 50, 60, 255]);
+        image.unpremultiply();
+        assert_eq!(image.pixels, decoded.pixels);
+
+        let pixels = [1000u16, 32768].iter().flat_map(|x| x.to_ne_bytes()).collect();
+        let format = Format::R16G16;
+        let mut luma = Data { pixels, format, width: 1, height: 1, premultiplied: false };
+        luma.premultiply();
+        assert_eq!(u16::from_ne_bytes([luma.pixels[0], luma.pixels[1]]), 500);
+        let (pixels, format) = (vec![9; 3], Format::R8G8B8);
+        let mut opaque = Data { pixels, format, width: 1, height: 1, premultiplied: false };
+        opaque.premultiply();
+        assert!(!opaque.premultiplied);
+    }
+
+    #[test]
+    fn validate_image_data() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "buffers": [{"byteLength": 12}],
+            "bufferViews": [{"buffer": 0, "byteLength": 12}],
+            "images": [
+                {"uri": "a.PNG"},
+                {"uri": "data:image/jpeg;base64,"},
+                {"bufferView": 0, "mimeType": "image/ktx2"},
+                {"uri": "a.bin"}
+            ],
+            "textures": [{"source": 0}, {"source": 2}, {"source": 3}]
+        }"#;
+        let root = json::Root::from_slice(json.as_bytes()).unwrap();
+        let document = crate::Document::from_json_without_validation(root);
+        let images: Vec<_> = document.images().collect();
+        let png = b"\x89PNG\r\n\x1a\n";
+        let jpeg = [0xFF, 0xD8, 0xFF, 0xE0];
+        let paths = |index: usize, encoded: &[u8]| -> Vec<String> {
+            let diagnostics = images[index].validate_data(encoded);
+            diagnostics.iter().map(|x| x.path.as_str().to_string()).collect()
+        };
+
+        assert_eq!(sniff_mime_type(b"RIFF\0\0\0\0WEBPVP8 "), Some("image/webp"));
+        assert_eq!(sniff_mime_type(b"RIFF\0\0\0\0WAVE"), None);
+        assert!(paths(0, png).is_empty());
+        assert_eq!(paths(0, &jpeg), ["images[0].uri"]);
+        assert!(paths(1, &jpeg).is_empty());
+        assert_eq!(paths(1, b"GIF89a"), ["images[1].uri"]);
+        assert_eq!(paths(2, &KTX2_IDENTIFIER), ["textures[1].source"]);
+        assert_eq!(paths(2, png), ["images[2].mimeType"]);
+        assert!(paths(3, png).is_empty());
+        assert_eq!(paths(3, b"GIF89a"), ["textures[2].source"]);
     }
 }
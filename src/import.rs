@@ -1,18 +1,59 @@
 use crate::buffer;
 use crate::image;
 use base64;
+use std::borrow::Cow;
 use std::{fs, io};
 
-use crate::{Document, Error, Gltf, Result};
-use image_crate::ImageFormat::{Jpeg, Png};
+use crate::{metrics, Document, Error, Gltf, Result};
+use image_crate::ImageFormat::{self, Jpeg, Png};
+#[cfg(feature = "EXT_texture_webp")]
+use image_crate::ImageFormat::WebP;
 use std::path::Path;
 
 /// Return type of `import`.
 type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
 
+/// How images are loaded by `import_with_options`.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ImagePolicy {
+    /// Images are read and decoded into pixels, as by `import`.
+    #[default]
+    Decode,
+
+    /// Images are read but not decoded, for uploading PNG or JPEG files to the
+    /// GPU directly or decoding them later with `image::Data::decode`.
+    ///
+    /// The format of each image is `Png`, `Jpeg`, `WebP`, or `Ktx2`, and
+    /// `image::Data::pixels` holds the whole file.
+    RawBytes,
+
+    /// Images are neither read nor decoded, and no image data is returned.
+    Skip,
+}
+
+/// Options for `import_with_options` and `import_slice_with_options`.
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    /// How images are loaded.
+    ///
+    /// Defaults to `ImagePolicy::Decode`.
+    pub images: ImagePolicy,
+}
+
+impl Options {
+    /// Sets how images are loaded, see `images`.
+    pub fn images(mut self, policy: ImagePolicy) -> Self {
+        self.images = policy;
+        self
+    }
+}
+
+/// Return type of `import_slice_borrowed`.
+type BorrowedImport<'a> = (Document, Vec<Cow<'a, [u8]>>, Vec<image::Data>);
+
 /// Represents the set of URI schemes the importer supports.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-enum Scheme<'a> {
+pub(crate) enum Scheme<'a> {
     /// `data:[<media type>];base64,<data>`.
     Data(Option<&'a str>, &'a str),
 
@@ -29,7 +70,7 @@ enum Scheme<'a> {
 }
 
 impl<'a> Scheme<'a> {
-    fn parse<'s>(uri: &'s str) -> Scheme<'s> {
+    pub(crate) fn parse<'s>(uri: &'s str) -> Scheme<'s> {
         if uri.contains(":") {
             if uri.starts_with("data:") {
                 let match0 = &uri["data:".len()..].split(";base64,").nth(0);
@@ -53,20 +94,90 @@ impl<'a> Scheme<'a> {
         }
     }
 
-    fn read(base: Option<&Path>, uri: &str) -> Result<Vec<u8>> {
-        match Scheme::parse(uri) {
+    /// Reads the data referenced by a URI, decoding data URIs and fetching
+    /// every other URI from `resolver`.
+    pub(crate) fn read<R>(resolver: &mut R, uri: &str) -> Result<Vec<u8>>
+    where
+        R: ResolveUri + ?Sized,
+    {
+        let data = match Scheme::parse(uri) {
             // The path may be unused in the Scheme::Data case
             // Example: "uri" : "data:application/octet-stream;base64,wsVHPgA...."
             Scheme::Data(_, base64) => base64::decode(&base64).map_err(Error::Base64),
-            Scheme::File(path) if base.is_some() => read_to_end(path),
-            Scheme::Relative if base.is_some() => read_to_end(base.unwrap().join(uri)),
-            Scheme::Unsupported => Err(Error::UnsupportedScheme),
+            _ => resolver.resolve(uri),
+        }?;
+        metrics::report(|metrics| metrics.bytes_read(data.len()));
+        Ok(data)
+    }
+}
+
+/// Fetches the data referenced by the URIs of buffers and images.
+///
+/// Implement this to import glTF whose resources are served over HTTP or
+/// stored in an archive, and pass it to `import_with_resolver`. Data URIs are
+/// decoded by the importer and never passed to the resolver.
+///
+/// Closures taking a URI and returning its data implement `ResolveUri`:
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use std::collections::HashMap;
+///
+/// let mut files = HashMap::new();
+/// files.insert("Box.gltf", std::fs::read("examples/Box.gltf")?);
+/// files.insert("Box0.bin", std::fs::read("examples/Box0.bin")?);
+/// let mut resolver = |uri: &str| files.get(uri).cloned().ok_or(gltf::Error::UnsupportedScheme);
+/// let (document, buffers, _) = gltf::import_with_resolver(&mut resolver, "Box.gltf")?;
+/// assert_eq!(buffers.len(), document.buffers().len());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub trait ResolveUri {
+    /// Reads the whole resource referenced by `uri`, which is a URI from the
+    /// glTF JSON or the URI passed to `import_with_resolver`.
+    fn resolve(&mut self, uri: &str) -> Result<Vec<u8>>;
+}
+
+impl<F> ResolveUri for F
+where
+    F: FnMut(&str) -> Result<Vec<u8>>,
+{
+    fn resolve(&mut self, uri: &str) -> Result<Vec<u8>> {
+        self(uri)
+    }
+}
+
+/// The `ResolveUri` used by `import` and `import_slice`, which reads `file:`
+/// URIs and URIs relative to a base directory from the file system.
+///
+/// Without a base directory, as for `import_slice`, every URI is rejected with
+/// `Error::ExternalReferenceInSliceImport`.
+#[derive(Clone, Copy, Debug)]
+pub struct FileResolver<'a> {
+    /// The directory that relative URIs are resolved against.
+    base: Option<&'a Path>,
+}
+
+impl<'a> FileResolver<'a> {
+    /// Creates a resolver for URIs relative to `base`.
+    pub fn new(base: Option<&'a Path>) -> Self {
+        FileResolver { base }
+    }
+}
+
+impl<'a> ResolveUri for FileResolver<'a> {
+    fn resolve(&mut self, uri: &str) -> Result<Vec<u8>> {
+        match (Scheme::parse(uri), self.base) {
+            (Scheme::File(path), Some(_)) => read_to_end(path),
+            (Scheme::Relative, Some(base)) => read_to_end(base.join(uri)),
+            (Scheme::Unsupported, _) => Err(Error::UnsupportedScheme),
             _ => Err(Error::ExternalReferenceInSliceImport),
         }
     }
 }
 
-fn read_to_end<P>(path: P) -> Result<Vec<u8>>
+pub(crate) fn read_to_end<P>(path: P) -> Result<Vec<u8>>
 where
     P: AsRef<Path>,
 {
@@ -82,129 +193,357 @@ where
     Ok(data)
 }
 
+/// Checks that the data of a buffer is long enough and pads it to a multiple
+/// of four bytes.
+pub(crate) fn buffer_data(buffer: &buffer::Buffer, mut data: Vec<u8>) -> Result<buffer::Data> {
+    if data.len() < buffer.length() {
+        return Err(Error::BufferLength {
+            buffer: buffer.index(),
+            expected: buffer.length(),
+            actual: data.len(),
+        });
+    }
+    while data.len() & 3 != 0 {
+        data.push(0);
+    }
+    Ok(buffer::Data(data))
+}
+
 /// Import the buffer data referenced by a glTF document.
+///
+/// With the `parallel` feature, the buffers are read concurrently.
 pub fn import_buffer_data(
     document: &Document,
     base: Option<&Path>,
+    blob: Option<Vec<u8>>,
+) -> Result<Vec<buffer::Data>> {
+    #[cfg(feature = "parallel")]
+    {
+        par_buffer_data(document, FileResolver::new(base), blob)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        resolve_buffer_data(document, &mut FileResolver::new(base), blob)
+    }
+}
+
+/// Reads the buffers of a document on the rayon thread pool.
+///
+/// As by `resolve_buffer_data`, the blob is the data of the first buffer that
+/// refers to it, and the first error in buffer order is returned.
+#[cfg(feature = "parallel")]
+fn par_buffer_data(
+    document: &Document,
+    resolver: FileResolver,
     mut blob: Option<Vec<u8>>,
 ) -> Result<Vec<buffer::Data>> {
+    use rayon::prelude::*;
+
+    let mut sources = Vec::new();
+    for buffer in document.buffers() {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if buffer.is_meshopt_fallback() {
+                sources.push((buffer, None));
+                continue;
+            }
+        }
+        let bin = match buffer.source() {
+            buffer::Source::Uri(_) => None,
+            buffer::Source::Bin => blob.take(),
+        };
+        sources.push((buffer, bin));
+    }
+    let buffers: Vec<_> = sources
+        .into_par_iter()
+        .map(|(buffer, bin)| {
+            #[cfg(feature = "EXT_meshopt_compression")]
+            {
+                if buffer.is_meshopt_fallback() {
+                    return buffer_data(&buffer, vec![0; buffer.length()]);
+                }
+            }
+            let data = match buffer.source() {
+                buffer::Source::Uri(uri) => Scheme::read(&mut resolver.clone(), uri),
+                buffer::Source::Bin => bin.ok_or(Error::MissingBlob),
+            }?;
+            buffer_data(&buffer, data)
+        })
+        .collect();
+    #[allow(unused_mut)]
+    let mut buffers = buffers.into_iter().collect::<Result<Vec<_>>>()?;
+    #[cfg(feature = "EXT_meshopt_compression")]
+    decode_meshopt_views(document, &mut buffers)?;
+    Ok(buffers)
+}
+
+fn resolve_buffer_data<R>(
+    document: &Document,
+    resolver: &mut R,
+    mut blob: Option<Vec<u8>>,
+) -> Result<Vec<buffer::Data>>
+where
+    R: ResolveUri + ?Sized,
+{
     let mut buffers = Vec::new();
     for buffer in document.buffers() {
-        let mut data = match buffer.source() {
-            buffer::Source::Uri(uri) => Scheme::read(base, uri),
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if buffer.is_meshopt_fallback() {
+                buffers.push(buffer_data(&buffer, vec![0; buffer.length()])?);
+                continue;
+            }
+        }
+        let data = match buffer.source() {
+            buffer::Source::Uri(uri) => Scheme::read(resolver, uri),
             buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
         }?;
-        if data.len() < buffer.length() {
-            return Err(Error::BufferLength {
-                buffer: buffer.index(),
-                expected: buffer.length(),
-                actual: data.len(),
-            });
-        }
-        while data.len() % 4 != 0 {
-            data.push(0);
-        }
-        buffers.push(buffer::Data(data));
+        buffers.push(buffer_data(&buffer, data)?);
     }
+    #[cfg(feature = "EXT_meshopt_compression")]
+    decode_meshopt_views(document, &mut buffers)?;
     Ok(buffers)
 }
 
+/// Decodes the meshopt compressed buffer views of a document into the buffers
+/// the views refer to, which are usually fallback buffers without data.
+#[cfg(feature = "EXT_meshopt_compression")]
+pub(crate) fn decode_meshopt_views(
+    document: &Document,
+    buffers: &mut [buffer::Data],
+) -> Result<()> {
+    for view in document.views() {
+        let decoded = match decode_meshopt_view(&view, |index| &buffers[index][..])? {
+            Some(decoded) => decoded,
+            None => continue,
+        };
+        let begin = view.offset();
+        buffers[view.buffer().index()].0[begin..begin + decoded.len()].copy_from_slice(&decoded);
+    }
+    Ok(())
+}
+
+/// Decodes a buffer view if it is meshopt compressed, reading the compressed
+/// data from the buffer with the given index returned by `buffer`.
+///
+/// Returns `None` if the view is not compressed.
+#[cfg(feature = "EXT_meshopt_compression")]
+pub(crate) fn decode_meshopt_view<'b, F>(view: &buffer::View, buffer: F) -> Result<Option<Vec<u8>>>
+where
+    F: Fn(usize) -> &'b [u8],
+{
+    let compression = match view.meshopt_compression() {
+        Some(compression) => compression,
+        None => return Ok(None),
+    };
+    let error = || Error::Meshopt { view: view.index() };
+    // The count comes from the JSON, so it is checked against the length of
+    // the view before any memory is allocated for the decoded data.
+    compression
+        .count()
+        .checked_mul(compression.stride())
+        .filter(|&length| length <= view.length())
+        .ok_or_else(error)?;
+    let begin = compression.offset();
+    let data = buffer(compression.buffer().index())
+        .get(begin..begin + compression.length())
+        .ok_or_else(error)?;
+    let decoded = crate::meshopt::decode(
+        compression.mode(),
+        compression.filter(),
+        compression.count(),
+        compression.stride(),
+        data,
+    )
+    .ok_or_else(error)?;
+    Ok(Some(decoded))
+}
+
+#[cfg(feature = "guess_mime_type")]
+fn guess_format(encoded_image: &[u8]) -> Option<ImageFormat> {
+    match image_crate::guess_format(encoded_image) {
+        Ok(ImageFormat::Png) => Some(Png),
+        Ok(ImageFormat::Jpeg) => Some(Jpeg),
+        _ => None,
+    }
+}
+
+#[cfg(not(feature = "guess_mime_type"))]
+fn guess_format(_encoded_image: &[u8]) -> Option<ImageFormat> {
+    None
+}
+
+/// Decodes the encoded data of an image.
+///
+/// The encoding is determined by the media type of a data URI, the MIME type
+/// of the image, or, without a MIME type, the extension of the URI, falling
+/// back to guessing from the data with the `guess_mime_type` feature.
+pub(crate) fn decode_image(image: &image::Image, encoded_image: &[u8]) -> Result<image::Data> {
+    load_image(image, encoded_image, ImagePolicy::Decode)
+}
+
+/// Decodes the encoded data of an image as by `decode_image`, or wraps it if
+/// `policy` is `ImagePolicy::RawBytes`.
+fn load_image(
+    image: &image::Image,
+    encoded_image: &[u8],
+    policy: ImagePolicy,
+) -> Result<image::Data> {
+    let (uri, media_type) = match image.source() {
+        image::Source::Uri { uri, mime_type } => match Scheme::parse(uri) {
+            Scheme::Data(Some(media_type), _) => (None, Some(media_type)),
+            _ => (Some(uri), mime_type),
+        },
+        image::Source::View { mime_type, .. } => (None, Some(mime_type)),
+    };
+    let extension = uri.and_then(|uri| uri.rsplit('.').next());
+    let ktx2 = match (media_type, extension) {
+        (Some("image/ktx2"), _) | (None, Some("ktx2")) => true,
+        _ => encoded_image.starts_with(&image::KTX2_IDENTIFIER),
+    };
+    if ktx2 {
+        metrics::report(|metrics| metrics.image_decoded(encoded_image.len()));
+        return image::Data::ktx2(encoded_image).ok_or(Error::UnsupportedImageEncoding);
+    }
+    let encoded_format = match (media_type, extension) {
+        (Some("image/png"), _) | (None, Some("png")) => Png,
+        (Some("image/jpeg"), _) | (None, Some("jpg")) | (None, Some("jpeg")) => Jpeg,
+        #[cfg(feature = "EXT_texture_webp")]
+        (Some("image/webp"), _) | (None, Some("webp")) => WebP,
+        _ => match guess_format(encoded_image) {
+            Some(format) => format,
+            None => return Err(Error::UnsupportedImageEncoding),
+        },
+    };
+    if policy == ImagePolicy::RawBytes {
+        let format = match encoded_format {
+            Png => image::Format::Png,
+            Jpeg => image::Format::Jpeg,
+            _ => image::Format::WebP,
+        };
+        return Ok(image::Data::encoded(encoded_image, format)?);
+    }
+    let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
+    metrics::report(|metrics| metrics.image_decoded(encoded_image.len()));
+    Ok(image::Data::new(decoded_image))
+}
+
 /// Import the image data referenced by a glTF document.
+///
+/// With the `parallel` feature, the images are read and decoded concurrently.
 pub fn import_image_data(
     document: &Document,
     base: Option<&Path>,
     buffer_data: &[buffer::Data],
 ) -> Result<Vec<image::Data>> {
-    let mut images = Vec::new();
-    #[cfg(feature = "guess_mime_type")]
-    let guess_format = |encoded_image: &[u8]| match image_crate::guess_format(encoded_image) {
-        Ok(image_crate::ImageFormat::Png) => Some(Png),
-        Ok(image_crate::ImageFormat::Jpeg) => Some(Jpeg),
-        _ => None,
-    };
-    #[cfg(not(feature = "guess_mime_type"))]
-    let guess_format = |_encoded_image: &[u8]| None;
-    for image in document.images() {
-        match image.source() {
-            image::Source::Uri { uri, mime_type } if base.is_some() => {
-                match Scheme::parse(uri) {
-                    Scheme::Data(Some(annoying_case), base64) => {
-                        let encoded_image = base64::decode(&base64).map_err(Error::Base64)?;
-                        let encoded_format = match annoying_case.as_ref() {
-                            "image/png" => Png,
-                            "image/jpeg" => Jpeg,
-                            _ => match guess_format(&encoded_image) {
-                                Some(format) => format,
-                                None => return Err(Error::UnsupportedImageEncoding),
-                            },
-                        };
-                        let decoded_image = image_crate::load_from_memory_with_format(
-                            &encoded_image,
-                            encoded_format,
-                        )?;
-                        images.push(image::Data::new(decoded_image));
-                        continue;
-                    }
-                    Scheme::Unsupported => return Err(Error::UnsupportedScheme),
-                    _ => {}
-                }
-                let encoded_image = Scheme::read(base, uri)?;
-                let encoded_format = match mime_type {
-                    Some("image/png") => Png,
-                    Some("image/jpeg") => Jpeg,
-                    Some(_) => match guess_format(&encoded_image) {
-                        Some(format) => format,
-                        None => return Err(Error::UnsupportedImageEncoding),
-                    },
-                    None => match uri.rsplit(".").next() {
-                        Some("png") => Png,
-                        Some("jpg") | Some("jpeg") => Jpeg,
-                        _ => match guess_format(&encoded_image) {
-                            Some(format) => format,
-                            None => return Err(Error::UnsupportedImageEncoding),
-                        },
-                    },
-                };
-                let decoded_image =
-                    image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            }
-            image::Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffer_data[view.buffer().index()].0;
-                let begin = view.offset();
-                let end = begin + view.length();
-                let encoded_image = &parent_buffer_data[begin..end];
-                let encoded_format = match mime_type {
-                    "image/png" => Png,
-                    "image/jpeg" => Jpeg,
-                    _ => match guess_format(encoded_image) {
-                        Some(format) => format,
-                        None => return Err(Error::UnsupportedImageEncoding),
-                    },
-                };
-                let decoded_image =
-                    image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            }
-            _ => return Err(Error::ExternalReferenceInSliceImport),
-        }
+    file_image_data(document, base, buffer_data, ImagePolicy::Decode)
+}
+
+/// Loads the images of a document from the file system according to `policy`.
+fn file_image_data(
+    document: &Document,
+    base: Option<&Path>,
+    buffer_data: &[buffer::Data],
+    policy: ImagePolicy,
+) -> Result<Vec<image::Data>> {
+    if policy == ImagePolicy::Skip {
+        return Ok(Vec::new());
+    }
+    #[cfg(feature = "parallel")]
+    {
+        par_image_data(document, FileResolver::new(base), buffer_data, policy)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        resolve_image_data(document, &mut FileResolver::new(base), buffer_data, policy)
     }
+}
 
-    Ok(images)
+/// Reads and decodes the images of a document on the rayon thread pool,
+/// returning the first error in image order.
+#[cfg(feature = "parallel")]
+fn par_image_data(
+    document: &Document,
+    resolver: FileResolver,
+    buffer_data: &[buffer::Data],
+    policy: ImagePolicy,
+) -> Result<Vec<image::Data>> {
+    use rayon::prelude::*;
+
+    let images: Vec<_> = document.images().collect();
+    let images: Vec<_> = images
+        .into_par_iter()
+        .map(|image| image_data(&image, &mut resolver.clone(), buffer_data, policy))
+        .collect();
+    images.into_iter().collect()
 }
 
-fn import_impl(Gltf { document, blob }: Gltf, base: Option<&Path>) -> Result<Import> {
-    let buffer_data = import_buffer_data(&document, base, blob)?;
-    let image_data = import_image_data(&document, base, &buffer_data)?;
+/// Reads and, unless `policy` is `ImagePolicy::RawBytes`, decodes the data of
+/// an image.
+fn image_data<R>(
+    image: &image::Image,
+    resolver: &mut R,
+    buffer_data: &[buffer::Data],
+    policy: ImagePolicy,
+) -> Result<image::Data>
+where
+    R: ResolveUri + ?Sized,
+{
+    let read;
+    let encoded_image = match image.source() {
+        image::Source::Uri { uri, .. } => {
+            read = Scheme::read(resolver, uri)?;
+            &read[..]
+        }
+        image::Source::View { view, .. } => {
+            let parent_buffer_data = &buffer_data[view.buffer().index()].0;
+            let begin = view.offset();
+            let end = begin + view.length();
+            &parent_buffer_data[begin..end]
+        }
+    };
+    load_image(image, encoded_image, policy)
+}
+
+fn resolve_image_data<R>(
+    document: &Document,
+    resolver: &mut R,
+    buffer_data: &[buffer::Data],
+    policy: ImagePolicy,
+) -> Result<Vec<image::Data>>
+where
+    R: ResolveUri + ?Sized,
+{
+    let images = document.images();
+    images.map(|image| image_data(&image, resolver, buffer_data, policy)).collect()
+}
+
+fn import_impl<R>(Gltf { document, blob }: Gltf, resolver: &mut R) -> Result<Import>
+where
+    R: ResolveUri + ?Sized,
+{
+    let buffer_data = resolve_buffer_data(&document, resolver, blob)?;
+    let image_data = resolve_image_data(&document, resolver, &buffer_data, ImagePolicy::Decode)?;
     let import = (document, buffer_data, image_data);
     Ok(import)
 }
 
-fn import_path(path: &Path) -> Result<Import> {
+/// Imports the buffers and images of a document from the file system.
+pub(crate) fn import_files(
+    Gltf { document, blob }: Gltf,
+    base: Option<&Path>,
+    options: &Options,
+) -> Result<Import> {
+    let buffer_data = import_buffer_data(&document, base, blob)?;
+    let image_data = file_image_data(&document, base, &buffer_data, options.images)?;
+    Ok((document, buffer_data, image_data))
+}
+
+fn import_path(path: &Path, options: &Options) -> Result<Import> {
     let base = path.parent().unwrap_or(Path::new("./"));
     let file = fs::File::open(path).map_err(Error::Io)?;
     let reader = io::BufReader::new(file);
-    import_impl(Gltf::from_reader(reader)?, Some(base))
+    import_files(Gltf::from_reader(reader)?, Some(base), options)
 }
 
 /// Import some glTF 2.0 from the file system.
@@ -221,6 +560,9 @@ fn import_path(path: &Path) -> Result<Import> {
 /// # }
 /// ```
 ///
+/// With the `parallel` feature, buffers and images are read, and images
+/// decoded, concurrently on the rayon thread pool.
+///
 /// ### Note
 ///
 /// This function is provided as a convenience for loading glTF and associated
@@ -238,15 +580,46 @@ pub fn import<P>(path: P) -> Result<Import>
 where
     P: AsRef<Path>,
 {
-    import_path(path.as_ref())
+    import_path(path.as_ref(), &Options::default())
+}
+
+/// Import some glTF 2.0 from the file system, loading images according to
+/// `options`.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::import::{ImagePolicy, Options};
+///
+/// let options = Options::default().images(ImagePolicy::Skip);
+/// let (document, buffers, images) = gltf::import_with_options("examples/Box.gltf", &options)?;
+/// assert_eq!(buffers.len(), document.buffers().len());
+/// assert!(images.is_empty());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_with_options<P>(path: P, options: &Options) -> Result<Import>
+where
+    P: AsRef<Path>,
+{
+    import_path(path.as_ref(), options)
 }
 
-pub(crate) fn import_slice_impl(slice: &[u8]) -> Result<Import> {
-    import_impl(Gltf::from_slice(slice)?, None)
+pub(crate) fn import_slice_impl(slice: &[u8], options: &Options) -> Result<Import> {
+    import_files(Gltf::from_slice(slice)?, None, options)
 }
 
 /// Import some glTF 2.0 from a slice
 ///
+/// The slice may hold either binary glTF, recognized by its `glTF` magic, or
+/// standalone glTF JSON. Buffers and images must be embedded, either in the
+/// `BIN` chunk or as data URIs, since there is no base path to resolve other
+/// URIs against; this makes `import_slice` usable on targets without a file
+/// system, such as WebAssembly. Use `import_with_resolver` to fetch external
+/// resources instead.
+///
 /// ```
 /// # extern crate gltf;
 /// # use std::fs;
@@ -268,5 +641,275 @@ pub fn import_slice<S>(slice: S) -> Result<Import>
 where
     S: AsRef<[u8]>,
 {
-    import_slice_impl(slice.as_ref())
+    import_slice_impl(slice.as_ref(), &Options::default())
+}
+
+/// Import some glTF 2.0 from a slice as by `import_slice`, loading images
+/// according to `options`.
+pub fn import_slice_with_options<S>(slice: S, options: &Options) -> Result<Import>
+where
+    S: AsRef<[u8]>,
+{
+    import_slice_impl(slice.as_ref(), options)
+}
+
+/// Import some glTF 2.0 from a slice without copying the `BIN` chunk.
+///
+/// As for `import_slice`, the slice may hold binary glTF or glTF JSON whose
+/// buffers and images are embedded. The buffer of the `BIN` chunk borrows
+/// from `slice` rather than holding a copy of what is often most of the file,
+/// and is not padded to a multiple of four bytes as by `import_slice`.
+/// Buffers from data URIs are decoded into memory. With
+/// `EXT_meshopt_compression`, documents with compressed buffer views have
+/// every buffer copied, as the views are decoded into their fallback buffers.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let bytes = std::fs::read("examples/Box.glb").map_err(gltf::Error::Io)?;
+/// let (document, buffers, _) = gltf::import_slice_borrowed(&bytes)?;
+/// assert!(matches!(buffers[0], std::borrow::Cow::Borrowed(_)));
+/// let mesh = document.meshes().next().unwrap();
+/// let primitive = mesh.primitives().next().unwrap();
+/// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+/// assert_eq!(reader.read_positions().unwrap().len(), 24);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_slice_borrowed(slice: &[u8]) -> Result<BorrowedImport<'_>> {
+    metrics::report(|metrics| metrics.bytes_read(slice.len()));
+    let (json, mut blob): (json::Root, Option<Cow<[u8]>>);
+    if slice.starts_with(b"glTF") {
+        let glb = crate::binary::Glb::from_slice(slice)?;
+        json = json::deserialize::from_slice(&glb.json)?;
+        blob = glb.bin;
+    } else {
+        json = json::deserialize::from_slice(slice)?;
+        blob = None;
+    }
+    let document = Document::from_json(json)?;
+    let resolver = &mut FileResolver::new(None);
+
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if buffer.is_meshopt_fallback() {
+                buffers.push(Cow::Owned(vec![0; buffer.length()]));
+                continue;
+            }
+        }
+        let data = match buffer.source() {
+            buffer::Source::Uri(uri) => Cow::Owned(Scheme::read(resolver, uri)?),
+            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob)?,
+        };
+        if data.len() < buffer.length() {
+            return Err(Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            });
+        }
+        buffers.push(data);
+    }
+    #[cfg(feature = "EXT_meshopt_compression")]
+    {
+        if document.views().any(|view| view.meshopt_compression().is_some()) {
+            let owned = buffers.into_iter().map(|x| buffer::Data(x.into_owned()));
+            let mut owned: Vec<_> = owned.collect();
+            decode_meshopt_views(&document, &mut owned)?;
+            buffers = owned.into_iter().map(|x| Cow::Owned(x.0)).collect();
+        }
+    }
+
+    let mut images = Vec::new();
+    for image in document.images() {
+        let read;
+        let encoded_image = match image.source() {
+            image::Source::Uri { uri, .. } => {
+                read = Scheme::read(resolver, uri)?;
+                &read[..]
+            },
+            image::Source::View { view, .. } => {
+                let begin = view.offset();
+                &buffers[view.buffer().index()][begin..begin + view.length()]
+            },
+        };
+        images.push(decode_image(&image, encoded_image)?);
+    }
+    Ok((document, buffers, images))
+}
+
+/// Import some glTF 2.0 through a `ResolveUri`, reading the `.gltf` or `.glb`
+/// file at `uri` and the buffers and images it references.
+///
+/// The returned data is the same as that returned by `import`. See
+/// `ResolveUri` for an example.
+pub fn import_with_resolver<R>(resolver: &mut R, uri: &str) -> Result<Import>
+where
+    R: ResolveUri + ?Sized,
+{
+    let gltf = Gltf::from_slice(&resolver.resolve(uri)?)?;
+    import_impl(gltf, resolver)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::image::{Data, Format};
+    use crate::Error;
+
+    #[test]
+    fn import_embedded_slices() {
+        let (_, expected, _) = crate::import("examples/Box.gltf").unwrap();
+        let glb = std::fs::read("examples/Box.glb").unwrap();
+        let (_, buffers, _) = super::import_slice(&glb).unwrap();
+        assert_eq!(buffers[0].0, expected[0].0);
+
+        let bin = base64::encode(std::fs::read("examples/Box0.bin").unwrap());
+        let pixels = vec![255, 0, 0, 255];
+        let image = Data {
+            pixels: pixels.clone(),
+            format: Format::R8G8B8A8,
+            width: 1,
+            height: 1,
+            premultiplied: false,
+        };
+        let png = base64::encode(image.to_png().unwrap());
+        let json = std::fs::read_to_string("examples/Box.gltf")
+            .unwrap()
+            .replace("Box0.bin", &format!("data:application/octet-stream;base64,{}", bin))
+            .replacen("\"asset\"", &format!(
+                "\"images\": [{{ \"uri\": \"data:image/png;base64,{}\" }}], \"asset\"",
+                png,
+            ), 1);
+        let (_, buffers, images) = super::import_slice(json.as_bytes()).unwrap();
+        assert_eq!(buffers[0].0, expected[0].0);
+        assert_eq!(images[0].pixels, pixels);
+
+        let external = std::fs::read("examples/Box.gltf").unwrap();
+        match super::import_slice(&external) {
+            Err(Error::ExternalReferenceInSliceImport) => {}
+            result => panic!("unexpected result: {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[test]
+    fn import_image_policies() {
+        use super::{ImagePolicy, Options};
+
+        let image = Data {
+            pixels: vec![255, 0, 0, 255, 0, 255, 0, 255],
+            format: Format::R8G8B8A8,
+            width: 2,
+            height: 1,
+            premultiplied: false,
+        };
+        let png = image.to_png().unwrap();
+        let json = std::fs::read_to_string("examples/Box.gltf").unwrap().replacen(
+            "\"asset\"",
+            &format!(
+                "\"images\": [{{ \"uri\": \"data:image/png;base64,{}\" }}], \"asset\"",
+                base64::encode(&png),
+            ),
+            1,
+        );
+        let base = std::path::Path::new("examples");
+        let gltf = || crate::Gltf::from_slice(json.as_bytes()).unwrap();
+
+        let options = Options::default().images(ImagePolicy::RawBytes);
+        let (_, _, images) = super::import_files(gltf(), Some(base), &options).unwrap();
+        assert_eq!(images[0].format, Format::Png);
+        assert_eq!(images[0].format.mime_type(), Some("image/png"));
+        assert_eq!((images[0].width, images[0].height), (2, 1));
+        assert_eq!(images[0].pixels, png);
+        assert_eq!(images[0].decode().unwrap().pixels, image.pixels);
+
+        let options = Options::default().images(ImagePolicy::Skip);
+        let (_, buffers, images) = super::import_files(gltf(), Some(base), &options).unwrap();
+        assert_eq!(buffers.len(), 1);
+        assert!(images.is_empty());
+
+        let (_, _, images) = super::import_files(gltf(), Some(base), &Options::default()).unwrap();
+        assert_eq!(images[0].pixels, image.pixels);
+    }
+
+    #[test]
+    fn import_borrowed_slices() {
+        let glb = std::fs::read("examples/Box.glb").unwrap();
+        let (_, expected, _) = super::import_slice(&glb).unwrap();
+        let (_, buffers, _) = super::import_slice_borrowed(&glb).unwrap();
+        let range = glb.as_ptr_range();
+        assert!(range.contains(&buffers[0].as_ptr()));
+        assert_eq!(buffers[0][..], expected[0].0[..buffers[0].len()]);
+
+        let external = std::fs::read("examples/Box.gltf").unwrap();
+        match super::import_slice_borrowed(&external) {
+            Err(Error::ExternalReferenceInSliceImport) => {}
+            result => panic!("unexpected result: {:?}", result.map(|_| ())),
+        }
+    }
+
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[test]
+    fn import_meshopt_compressed_views() {
+        // The elements `[1, 2, 3, 4]` and `[3, 2, 1, 4]` with raw byte groups.
+        let mut compressed = vec![0xa0];
+        for deltas in [[0, 4], [0, 0], [0, 3], [0, 0]].iter() {
+            compressed.push(0x03);
+            compressed.extend_from_slice(deltas);
+            compressed.extend_from_slice(&[0; 14]);
+        }
+        compressed.extend_from_slice(&[0; 28]);
+        compressed.extend_from_slice(&[1, 2, 3, 4]);
+        let json = format!(
+            r#"{{
+                "asset": {{"version": "2.0"}},
+                "extensionsUsed": ["EXT_meshopt_compression"],
+                "buffers": [
+                    {{"byteLength": {}, "uri": "data:application/octet-stream;base64,{}"}},
+                    {{
+                        "byteLength": 8,
+                        "extensions": {{"EXT_meshopt_compression": {{"fallback": true}}}}
+                    }}
+                ],
+                "bufferViews": [{{
+                    "buffer": 1,
+                    "byteLength": 8,
+                    "byteStride": 4,
+                    "extensions": {{
+                        "EXT_meshopt_compression": {{
+                            "buffer": 0,
+                            "byteLength": {},
+                            "byteStride": 4,
+                            "count": 2,
+                            "mode": "ATTRIBUTES"
+                        }}
+                    }}
+                }}]
+            }}"#,
+            compressed.len(),
+            base64::encode(&compressed),
+            compressed.len(),
+        );
+        let (_, buffers, _) = super::import_slice(json.as_bytes()).unwrap();
+        assert_eq!(buffers[1].0, [1, 2, 3, 4, 3, 2, 1, 4]);
+
+        for count in ["3", "4294967295"].iter() {
+            let corrupt = json.replace("\"count\": 2", &format!("\"count\": {}", count));
+            match super::import_slice(corrupt.as_bytes()) {
+                Err(Error::Validation(_)) => {}
+                result => panic!("unexpected result: {:?}", result.map(|_| ())),
+            }
+            // Without validation the decoder must still refuse the count.
+            let document = crate::Document::from_json_without_validation(
+                json::Root::from_slice(corrupt.as_bytes()).unwrap(),
+            );
+            match super::import_buffer_data(&document, None, None) {
+                Err(Error::Meshopt { view: 0 }) => {}
+                result => panic!("unexpected result: {:?}", result.map(|_| ())),
+            }
+        }
+    }
 }
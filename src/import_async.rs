@@ -0,0 +1,277 @@
+use std::future::Future;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+
+use crate::import::{self, Scheme};
+use crate::{buffer, image, metrics, Document, Error, Gltf, Result};
+
+/// Return type of `import_source`.
+type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
+
+/// Future returned by `Source::read`.
+pub type Read<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>>;
+
+/// Future returned by `AsyncRead::read_exact`.
+pub type ReadExact<'a> = Pin<Box<dyn Future<Output = io::Result<()>> + Send + 'a>>;
+
+/// A stream of bytes read asynchronously, for `Glb::from_async_reader`.
+///
+/// This crate does not depend on an asynchronous runtime, so the readers of
+/// any executor are adapted by implementing this trait, for example with
+/// `tokio::io::AsyncReadExt::read_exact`.
+pub trait AsyncRead {
+    /// Reads exactly enough bytes to fill `buf`, failing with
+    /// `io::ErrorKind::UnexpectedEof` if the stream ends first.
+    fn read_exact<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExact<'a>;
+}
+
+/// Fetches the files that make up a glTF asset.
+///
+/// Implementations decide how URIs are resolved, for example relative to a
+/// directory on disk, to an HTTP base URL, or to the entries of an archive,
+/// and may perform their reads with the file or network API of any executor.
+/// Data URIs are decoded by the importer and never passed to the source.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// struct Files(HashMap<String, Vec<u8>>);
+///
+/// impl gltf::import_async::Source for Files {
+///     fn read<'a>(&'a self, uri: &'a str) -> gltf::import_async::Read<'a> {
+///         Box::pin(async move { self.0.get(uri).cloned().ok_or(gltf::Error::UnsupportedScheme) })
+///     }
+/// }
+/// ```
+pub trait Source {
+    /// Reads the whole file referenced by `uri`, which is a URI from the glTF
+    /// JSON or the URI passed to `import_source`.
+    fn read<'a>(&'a self, uri: &'a str) -> Read<'a>;
+}
+
+/// A `Source` that reads files relative to a directory with `std::fs`.
+///
+/// The reads block the thread polling the future. Services running on an
+/// asynchronous executor should provide a `Source` built on the executor's file
+/// API instead, such as `tokio::fs::read`.
+#[derive(Clone, Debug)]
+pub struct FileSource {
+    /// The directory that relative URIs are resolved against.
+    base: PathBuf,
+}
+
+impl FileSource {
+    /// Creates a source that resolves relative URIs against `base`.
+    pub fn new<P>(base: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        FileSource { base: base.into() }
+    }
+}
+
+impl Source for FileSource {
+    fn read<'a>(&'a self, uri: &'a str) -> Read<'a> {
+        Box::pin(async move {
+            match Scheme::parse(uri) {
+                Scheme::File(path) => import::read_to_end(path),
+                Scheme::Relative => import::read_to_end(self.base.join(uri)),
+                _ => Err(Error::UnsupportedScheme),
+            }
+        })
+    }
+}
+
+/// Reads the data referenced by a URI of a buffer or image, decoding data URIs
+/// and fetching every other URI from `source`.
+async fn read<S>(source: &S, uri: &str) -> Result<Vec<u8>>
+where
+    S: Source + ?Sized,
+{
+    let data = match Scheme::parse(uri) {
+        Scheme::Data(_, base64) => base64::decode(base64).map_err(Error::Base64)?,
+        _ => source.read(uri).await?,
+    };
+    metrics::report(|metrics| metrics.bytes_read(data.len()));
+    Ok(data)
+}
+
+/// Import some glTF 2.0 from a `Source`, reading the `.gltf` or `.glb` file at
+/// `uri` and the buffers and images it references.
+///
+/// The returned data is the same as that returned by `gltf::import`.
+///
+/// ### Note
+///
+/// Only the reads are asynchronous. Buffers are validated and images decoded
+/// synchronously on the thread polling the future, so importing assets with
+/// large images may stall an executor thread; such imports can be run on a
+/// blocking thread pool instead, such as with `tokio::task::spawn_blocking`.
+pub async fn import_source<S>(source: &S, uri: &str) -> Result<Import>
+where
+    S: Source + ?Sized,
+{
+    let Gltf { document, mut blob } = Gltf::from_slice(&source.read(uri).await?)?;
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if buffer.is_meshopt_fallback() {
+                buffers.push(import::buffer_data(&buffer, vec![0; buffer.length()])?);
+                continue;
+            }
+        }
+        let data = match buffer.source() {
+            buffer::Source::Uri(uri) => read(source, uri).await?,
+            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob)?,
+        };
+        buffers.push(import::buffer_data(&buffer, data)?);
+    }
+    #[cfg(feature = "EXT_meshopt_compression")]
+    import::decode_meshopt_views(&document, &mut buffers)?;
+    let mut images = Vec::new();
+    for image in document.images() {
+        let data = match image.source() {
+            image::Source::Uri { uri, .. } => read(source, uri).await?,
+            image::Source::View { view, .. } => {
+                let begin = view.offset();
+                buffers[view.buffer().index()][begin..begin + view.length()].to_vec()
+            },
+        };
+        images.push(import::decode_image(&image, &data)?);
+    }
+    Ok((document, buffers, images))
+}
+
+/// Import some glTF 2.0 from the file system asynchronously.
+///
+/// This is `import_source` with a `FileSource` for the directory containing
+/// `path`, so the reads block the thread polling the future; see the note on
+/// `FileSource`. The returned data is the same as that returned by
+/// `gltf::import`.
+///
+/// Returns `Error::Io` if the file name of `path` is not valid UTF-8.
+pub async fn import_async<P>(path: P) -> Result<Import>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or_else(|| Path::new("./"));
+    let uri = path.file_name().and_then(|name| name.to_str()).ok_or_else(|| {
+        Error::Io(io::Error::new(io::ErrorKind::InvalidInput, "file name is not valid UTF-8"))
+    })?;
+    import_source(&FileSource::new(base), uri).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    use super::{import_async, import_source, AsyncRead, FileSource, Read, ReadExact, Source};
+    use crate::{Error, Glb};
+
+    /// Wakes a thread blocked in `block_on`.
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    /// Polls a future to completion on the current thread.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// Serves the binary glTF example from memory, yielding once per read.
+    struct Memory;
+
+    impl Source for Memory {
+        fn read<'a>(&'a self, uri: &'a str) -> Read<'a> {
+            Box::pin(async move {
+                let mut yielded = false;
+                std::future::poll_fn(|context| {
+                    if yielded {
+                        return Poll::Ready(());
+                    }
+                    yielded = true;
+                    context.waker().wake_by_ref();
+                    Poll::Pending
+                })
+                .await;
+                match uri {
+                    "model.glb" => Ok(std::fs::read("examples/Box.glb")?),
+                    _ => Err(Error::UnsupportedScheme),
+                }
+            })
+        }
+    }
+
+    #[test]
+    fn import_from_async_sources() {
+        let (document, buffers, images) = block_on(import_source(&FileSource::new("examples"), "Box.gltf")).unwrap();
+        let (_, expected, _) = crate::import("examples/Box.gltf").unwrap();
+        assert_eq!(document.meshes().count(), 1);
+        assert_eq!(buffers[0].0, expected[0].0);
+        assert!(images.is_empty());
+
+        let (document, buffers, _) = block_on(import_source(&Memory, "model.glb")).unwrap();
+        assert_eq!(document.buffers().count(), buffers.len());
+        assert_eq!(buffers[0].0, expected[0].0);
+        assert!(block_on(import_source(&Memory, "missing.glb")).is_err());
+
+        let (document, buffers, _) = block_on(import_async("examples/Box.gltf")).unwrap();
+        assert_eq!(document.meshes().count(), 1);
+        assert_eq!(buffers[0].0, expected[0].0);
+    }
+
+    /// Reads a byte slice a few bytes at a time, yielding before each read.
+    struct Chunked<'s>(&'s [u8]);
+
+    impl<'s> AsyncRead for Chunked<'s> {
+        fn read_exact<'a>(&'a mut self, buf: &'a mut [u8]) -> ReadExact<'a> {
+            Box::pin(async move {
+                for chunk in buf.chunks_mut(5) {
+                    let mut yielded = false;
+                    std::future::poll_fn(|context| {
+                        if yielded {
+                            return Poll::Ready(());
+                        }
+                        yielded = true;
+                        context.waker().wake_by_ref();
+                        Poll::Pending
+                    })
+                    .await;
+                    std::io::Read::read_exact(&mut self.0, chunk)?;
+                }
+                Ok(())
+            })
+        }
+    }
+
+    #[test]
+    fn read_glb_from_async_reader() {
+        let data = std::fs::read("examples/Box.glb").unwrap();
+        let expected = Glb::from_slice(&data).unwrap();
+        let glb = block_on(Glb::from_async_reader(Chunked(&data))).unwrap();
+        assert_eq!(glb.json, expected.json);
+        assert_eq!(glb.bin, expected.bin);
+
+        let truncated = block_on(Glb::from_async_reader(Chunked(&data[..data.len() - 1])));
+        assert!(truncated.is_err());
+        assert!(block_on(Glb::from_async_reader(Chunked(b"glTF"))).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+use std::fmt;
+
+use json::validation::Checked::Valid;
+use json::validation::{Diagnostic, Severity};
+
+use crate::mesh::{Mode, Semantic};
+use crate::optimize::{self, Suggestion};
+use crate::Document;
+
+/// The number of objects of each kind in a document, as counted by `inspect`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Counts {
+    /// The number of scenes.
+    pub scenes: usize,
+
+    /// The number of nodes.
+    pub nodes: usize,
+
+    /// The number of meshes.
+    pub meshes: usize,
+
+    /// The number of primitives of every mesh.
+    pub primitives: usize,
+
+    /// The number of vertices of every primitive, counted once per primitive
+    /// even where primitives share their attributes.
+    pub vertices: usize,
+
+    /// The number of triangles drawn by every primitive, including unrolled
+    /// triangle strips and fans.
+    pub triangles: usize,
+
+    /// The number of materials.
+    pub materials: usize,
+
+    /// The number of textures.
+    pub textures: usize,
+
+    /// The number of images.
+    pub images: usize,
+
+    /// The number of animations.
+    pub animations: usize,
+
+    /// The number of skins.
+    pub skins: usize,
+
+    /// The total declared length of the buffers in bytes.
+    pub buffer_bytes: usize,
+}
+
+/// A conformance and content report of a document, as returned by `inspect`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inspection {
+    /// The generator recorded in the asset metadata.
+    pub generator: Option<String>,
+
+    /// The extensions used by the document.
+    pub extensions_used: Vec<String>,
+
+    /// The number of objects of each kind.
+    pub counts: Counts,
+
+    /// Every problem found by `Document::validate_full`, followed by the images
+    /// whose data does not match their MIME type, see `Image::validate_data`.
+    pub diagnostics: Vec<Diagnostic>,
+
+    /// The wasteful encodings found by `optimize::lint`.
+    pub suggestions: Vec<Suggestion>,
+}
+
+impl Inspection {
+    /// Returns the number of diagnostics of the given severity.
+    pub fn count(&self, severity: Severity) -> usize {
+        self.diagnostics.iter().filter(|x| x.severity() == severity).count()
+    }
+
+    /// Returns `true` if no diagnostic prevents the document from loading.
+    pub fn is_valid(&self) -> bool {
+        self.count(Severity::Error) == 0
+    }
+}
+
+/// Returns the number of triangles drawn by a primitive of `count` vertices.
+fn triangles(mode: Mode, count: usize) -> usize {
+    match mode {
+        Mode::Triangles => count / 3,
+        Mode::TriangleStrip | Mode::TriangleFan => count.saturating_sub(2),
+        _ => 0,
+    }
+}
+
+/// Counts the objects of a document.
+fn counts(document: &Document) -> Counts {
+    let mut counts = Counts {
+        scenes: document.scenes().len(),
+        nodes: document.nodes().len(),
+        meshes: document.meshes().len(),
+        materials: document.materials().len(),
+        textures: document.textures().len(),
+        images: document.images().len(),
+        animations: document.animations().len(),
+        skins: document.skins().len(),
+        buffer_bytes: document.buffers().map(|x| x.length()).sum(),
+        ..Counts::default()
+    };
+    let root = &document.0;
+    let count = |index: json::Index<json::Accessor>| {
+        root.accessors.get(index.value()).map_or(0, |x| x.count as usize)
+    };
+    for primitive in root.meshes.iter().flat_map(|mesh| mesh.primitives.iter()) {
+        let mode = match primitive.mode {
+            Valid(mode) => mode,
+            _ => continue,
+        };
+        let positions = primitive.attributes.get(&Valid(Semantic::Positions));
+        let positions = positions.map_or(0, |x| count(*x));
+        let drawn = primitive.indices.map_or(positions, count);
+        counts.primitives += 1;
+        counts.vertices += positions;
+        counts.triangles += triangles(mode, drawn);
+    }
+    counts
+}
+
+/// Returns the problems with the data of the images stored in buffer views,
+/// skipping views and buffers that do not exist.
+fn image_diagnostics(document: &Document, buffers: &[Vec<u8>]) -> Vec<Diagnostic> {
+    let root = &document.0;
+    let encoded = |json: &json::Image| {
+        let view = root.buffer_views.get(json.buffer_view?.value())?;
+        let begin = view.byte_offset.unwrap_or(0) as usize;
+        let end = begin.checked_add(view.byte_length as usize)?;
+        buffers.get(view.buffer.value())?.get(begin..end)
+    };
+    let images = document.images().zip(&root.images);
+    let diagnostics = images.filter_map(|(image, json)| Some(image.validate_data(encoded(json)?)));
+    diagnostics.flatten().collect()
+}
+
+/// Inspects a document, which need not be valid, collecting its problems,
+/// object counts, and wasteful encodings.
+///
+/// `buffers` holds the contents of each buffer of the document; encodings of
+/// data outside of `buffers`, including images referenced by URI, are not
+/// checked.
+pub fn inspect(document: &Document, buffers: &[Vec<u8>]) -> Inspection {
+    let root = &document.0;
+    let mut diagnostics = document.validate_full();
+    diagnostics.extend(image_diagnostics(document, buffers));
+    Inspection {
+        generator: root.asset.generator.clone(),
+        extensions_used: root.extensions_used.clone(),
+        counts: counts(document),
+        diagnostics,
+        suggestions: optimize::lint(root, buffers),
+    }
+}
+
+/// Inspects the `.gltf` or `.glb` file at `path`, loading the buffers it
+/// references but not validating it first.
+///
+/// The data of images referenced by URI is read as well and checked against
+/// their MIME types; images that cannot be read are not checked. Returns an
+/// error if the file cannot be parsed or its buffers cannot be loaded. The
+/// `gltf-inspect` binary prints the result of this function.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn inspect_file<P>(path: P) -> crate::Result<Inspection>
+where
+    P: AsRef<std::path::Path>,
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or_else(|| std::path::Path::new("./"));
+    let bytes = std::fs::read(path).map_err(crate::Error::Io)?;
+    let crate::Gltf { document, blob } = crate::Gltf::from_slice_without_validation(&bytes)?;
+    let buffers = crate::import::import_buffer_data(&document, Some(base), blob)?;
+    let buffers: Vec<_> = buffers.into_iter().map(|data| data.0).collect();
+    let mut inspection = inspect(&document, &buffers);
+    let mut resolver = crate::import::FileResolver::new(Some(base));
+    for (image, json) in document.images().zip(&document.0.images) {
+        let uri = match json.uri.as_deref() {
+            Some(uri) if json.buffer_view.is_none() => uri,
+            _ => continue,
+        };
+        if let Ok(encoded) = crate::import::Scheme::read(&mut resolver, uri) {
+            inspection.diagnostics.extend(image.validate_data(&encoded));
+        }
+    }
+    Ok(inspection)
+}
+
+impl fmt::Display for Inspection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "generator: {}", self.generator.as_deref().unwrap_or("<unknown>"))?;
+        if !self.extensions_used.is_empty() {
+            writeln!(f, "extensions used: {}", self.extensions_used.join(", "))?;
+        }
+        let counts = &self.counts;
+        let rows = [
+            ("scenes", counts.scenes),
+            ("nodes", counts.nodes),
+            ("meshes", counts.meshes),
+            ("primitives", counts.primitives),
+            ("vertices", counts.vertices),
+            ("triangles", counts.triangles),
+            ("materials", counts.materials),
+            ("textures", counts.textures),
+            ("images", counts.images),
+            ("animations", counts.animations),
+            ("skins", counts.skins),
+            ("buffer bytes", counts.buffer_bytes),
+        ];
+        for (name, count) in rows.iter() {
+            writeln!(f, "{}: {}", name, count)?;
+        }
+        for diagnostic in &self.diagnostics {
+            writeln!(f, "{}", diagnostic)?;
+        }
+        for suggestion in &self.suggestions {
+            writeln!(f, "suggestion: {}", suggestion)?;
+        }
+        write!(
+            f,
+            "{} errors, {} warnings, {} suggestions",
+            self.count(Severity::Error),
+            self.count(Severity::Warning),
+            self.suggestions.len(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use json::validation::Severity;
+
+    use super::inspect;
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn inspect_document() {
+        let mut root = json::Root::default();
+        root.asset.generator = Some("Example Exporter".to_string());
+        let mut packer = Packer::new(&mut root);
+        let positions = packer.push_accessor(&mut root, &[[0.0f32; 3]; 4], None);
+        let indices = packer.push_accessor(&mut root, &[0u32, 1, 2, 2, 1, 3], None);
+        let unused = packer.push_accessor(&mut root, &[0.0f32], None);
+        let buffers = vec![packer.finish(&mut root)];
+        let mesh = format!(
+            r#"{{"primitives": [
+                {{"attributes": {{"POSITION": {0}}}, "indices": {1}}},
+                {{"attributes": {{"POSITION": {0}}}, "mode": 5}}
+            ]}}"#,
+            positions.value(),
+            indices.value(),
+        );
+        root.meshes.push(json::deserialize::from_str(&mesh).unwrap());
+
+        let inspection = inspect(&Document::from_json_without_validation(root), &buffers);
+        assert_eq!(inspection.generator.as_deref(), Some("Example Exporter"));
+        assert_eq!(inspection.counts.primitives, 2);
+        assert_eq!(inspection.counts.vertices, 8);
+        assert_eq!(inspection.counts.triangles, 4);
+        assert!(inspection.is_valid());
+        assert_eq!(inspection.count(Severity::Warning), 2);
+        let path = format!("accessors[{}]", unused.value());
+        assert!(inspection.diagnostics.iter().any(|x| x.path.as_str() == path));
+        assert_eq!(inspection.suggestions.len(), 1);
+        let report = inspection.to_string();
+        assert!(report.contains("triangles: 4\n"));
+        assert!(report.ends_with("0 errors, 2 warnings, 1 suggestions"));
+    }
+
+    #[test]
+    fn inspect_image_data() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "buffers": [{"byteLength": 4}],
+            "bufferViews": [{"buffer": 0, "byteLength": 4}, {"buffer": 1, "byteLength": 4}],
+            "images": [
+                {"bufferView": 0, "mimeType": "image/png"},
+                {"bufferView": 1, "mimeType": "image/png"}
+            ],
+            "textures": [{"source": 0}, {"source": 1}]
+        }"#;
+        let root = json::Root::from_slice(json.as_bytes()).unwrap();
+        let buffers = vec![vec![0xFF, 0xD8, 0xFF, 0xE0]];
+        let inspection = inspect(&Document::from_json_without_validation(root), &buffers);
+        let mismatches: Vec<_> = inspection
+            .diagnostics
+            .iter()
+            .filter(|x| x.error == json::validation::Error::MimeTypeMismatch)
+            .map(|x| x.path.as_str())
+            .collect();
+        assert_eq!(mismatches, ["images[0].mimeType"]);
+        assert!(!inspection.is_valid());
+    }
+}
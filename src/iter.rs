@@ -7,6 +7,7 @@ use crate::camera::Camera;
 use crate::image::Image;
 use crate::material::Material;
 use crate::mesh::Mesh;
+use crate::scene::iter::Walk;
 use crate::scene::{Node, Scene};
 use crate::skin::Skin;
 use crate::texture::{Sampler, Texture};
@@ -92,6 +93,17 @@ pub struct Lights<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits every material variant in a glTF asset.
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug)]
+pub struct Variants<'a> {
+    /// Internal variant iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::Variant>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
 /// An `Iterator` that visits every material in a glTF asset.
 #[derive(Clone, Debug)]
 pub struct Materials<'a> {
@@ -122,6 +134,17 @@ pub struct Nodes<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits the nodes of a glTF asset with a given component,
+/// such as a mesh, along with their world transforms.
+#[derive(Clone, Debug)]
+pub struct ComponentNodes<'a> {
+    /// The traversal of the node hierarchy.
+    pub(crate) walk: Walk<'a>,
+
+    /// Whether the node with the corresponding index is to be visited.
+    pub(crate) mask: Vec<bool>,
+}
+
 /// An `Iterator` that visits every sampler in a glTF asset.
 #[derive(Clone, Debug)]
 pub struct Samplers<'a> {
@@ -354,6 +377,38 @@ impl<'a> Iterator for Lights<'a> {
     }
 }
 
+#[cfg(feature = "KHR_materials_variants")]
+impl<'a> ExactSizeIterator for Variants<'a> {}
+
+#[cfg(feature = "KHR_materials_variants")]
+impl<'a> Iterator for Variants<'a> {
+    type Item = crate::khr_materials_variants::Variant<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_materials_variants::Variant::new(document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next_back()
+            .map(|(index, json)| crate::khr_materials_variants::Variant::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .nth(n)
+            .map(|(index, json)| crate::khr_materials_variants::Variant::new(document, index, json))
+    }
+}
+
 impl<'a> ExactSizeIterator for Materials<'a> {}
 impl<'a> Iterator for Materials<'a> {
     type Item = Material<'a>;
@@ -500,3 +555,14 @@ impl<'a> Iterator for Textures<'a> {
         self.iter.nth(n).map(|(index, json)| Texture::new(self.document, index, json))
     }
 }
+
+impl<'a> Iterator for ComponentNodes<'a> {
+    type Item = (Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        let mask = &self.mask;
+        self.walk
+            .by_ref()
+            .find(|(node, _)| mask[node.index()])
+            .map(|(node, world)| (node, world.as_array()))
+    }
+}
@@ -0,0 +1,43 @@
+use gltf_json::Extras;
+use crate::Document;
+
+/// A named set of material assignments, such as a colorway of a product.
+#[derive(Clone, Debug)]
+pub struct Variant<'a> {
+    /// The parent `Document` struct.
+    #[allow(dead_code)]
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::Variant,
+}
+
+impl<'a> Variant<'a> {
+    /// Constructs a `Variant`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::root::Variant,
+    ) -> Self {
+        Self { document, index, json }
+    }
+
+    /// Returns the internal JSON index, as passed to
+    /// `Primitive::material_for_variant`.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the variant.
+    pub fn name(&self) -> &'a str {
+        &self.json.name
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a Extras {
+        &self.json.extras
+    }
+}
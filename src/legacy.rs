@@ -0,0 +1,666 @@
+use std::collections::HashMap;
+
+use json::Value;
+
+use crate::{binary, Document, Error, Gltf, Result};
+
+/// The collections of glTF 1.0 that are converted, whose objects are keyed by
+/// ID rather than stored in arrays.
+const COLLECTIONS: [&str; 13] = [
+    "accessors",
+    "animations",
+    "buffers",
+    "bufferViews",
+    "cameras",
+    "images",
+    "materials",
+    "meshes",
+    "nodes",
+    "samplers",
+    "scenes",
+    "skins",
+    "textures",
+];
+
+/// The ID of the `KHR_binary_glTF` buffer holding the body of binary glTF.
+const BINARY_BUFFER: &str = "binary_glTF";
+
+/// The extensions of glTF 1.0 that are folded into the converted document.
+const CONVERTED_EXTENSIONS: [&str; 2] = ["KHR_binary_glTF", "KHR_materials_common"];
+
+/// The `GL_CULL_FACE` and `GL_BLEND` states of glTF 1.0 techniques.
+const CULL_FACE: u64 = 2884;
+const BLEND: u64 = 3042;
+
+/// Returns a JSON object of the given entries, leaving out `null` values.
+fn object<'a, I>(entries: I) -> Value
+where
+    I: IntoIterator<Item = (&'a str, Value)>,
+{
+    let mut object = Value::Object(Default::default());
+    for (key, value) in entries.into_iter().filter(|(_, value)| !value.is_null()) {
+        object[key] = value;
+    }
+    object
+}
+
+/// Returns the entries of `source` with the given keys, which are the same in
+/// glTF 1.0 and 2.0.
+fn copy<'a>(source: &Value, keys: &[&'a str]) -> Vec<(&'a str, Value)> {
+    keys.iter().map(|&key| (key, source[key].clone())).collect()
+}
+
+/// Returns the objects of a glTF 1.0 collection, the `KHR_binary_glTF` buffer
+/// first, as binary glTF 2.0 requires of the buffer of its `BIN` chunk.
+fn entries<'a>(legacy: &'a Value, collection: &str) -> Vec<(&'a str, &'a Value)> {
+    let objects = legacy[collection].as_object().into_iter().flatten();
+    let mut entries: Vec<_> = objects.map(|(id, x)| (id.as_str(), x)).collect();
+    if collection == "buffers" {
+        entries.sort_by_key(|(id, _)| *id != BINARY_BUFFER);
+    }
+    entries
+}
+
+/// Returns the glTF 2.0 name of a glTF 1.0 attribute semantic, such as
+/// `JOINTS_0` for `JOINT`. Application specific semantics are prefixed with an
+/// underscore.
+fn semantic(name: &str) -> String {
+    let (base, set) = match name.rfind('_') {
+        Some(i) if name[i + 1..].parse::<u32>().is_ok() => (&name[..i], &name[i + 1..]),
+        _ => (name, "0"),
+    };
+    match base {
+        "POSITION" | "NORMAL" | "TANGENT" => base.to_string(),
+        "TEXCOORD" | "COLOR" => format!("{}_{}", base, set),
+        "JOINT" => format!("JOINTS_{}", set),
+        "WEIGHT" => format!("WEIGHTS_{}", set),
+        _ if name.starts_with('_') => name.to_string(),
+        _ => format!("_{}", name),
+    }
+}
+
+/// Converts the objects of a glTF 1.0 document to glTF 2.0.
+struct Converter<'a> {
+    /// The glTF 1.0 JSON.
+    legacy: &'a Value,
+
+    /// The glTF 2.0 index of each object, by collection and ID.
+    indices: HashMap<&'a str, HashMap<&'a str, usize>>,
+}
+
+impl<'a> Converter<'a> {
+    fn new(legacy: &'a Value) -> Self {
+        let indices = COLLECTIONS
+            .iter()
+            .map(|&collection| {
+                let ids = entries(legacy, collection).into_iter().map(|(id, _)| id);
+                (collection, ids.enumerate().map(|(index, id)| (id, index)).collect())
+            })
+            .collect();
+        Self { legacy, indices }
+    }
+
+    /// Returns the glTF 2.0 index of the object with the given ID, or `null`
+    /// if there is none.
+    fn index(&self, collection: &str, id: &Value) -> Value {
+        let index = id.as_str().and_then(|id| self.indices.get(collection)?.get(id));
+        index.map_or(Value::Null, |&index| Value::from(index))
+    }
+
+    /// Returns the glTF 2.0 indices of the objects with the given IDs, leaving
+    /// out unknown IDs.
+    fn indices(&self, collection: &str, ids: &Value) -> Vec<Value> {
+        let ids = ids.as_array().into_iter().flatten();
+        ids.map(|id| self.index(collection, id)).filter(|x| !x.is_null()).collect()
+    }
+
+    fn buffers(&self) -> Vec<Value> {
+        let buffers = entries(self.legacy, "buffers").into_iter();
+        let buffer = |(id, buffer): (&str, &Value)| {
+            let uri = if id == BINARY_BUFFER { Value::Null } else { buffer["uri"].clone() };
+            object(copy(buffer, &["byteLength", "name"]).into_iter().chain(Some(("uri", uri))))
+        };
+        buffers.map(buffer).collect()
+    }
+
+    /// Returns the buffer views, followed by copies of the views that are read
+    /// with several strides, and the accessors reading them.
+    fn views_and_accessors(&self) -> (Vec<Value>, Vec<Value>) {
+        let mut views: Vec<_> = entries(self.legacy, "bufferViews")
+            .into_iter()
+            .map(|(_, view)| {
+                let mut length = view["byteLength"].as_u64().unwrap_or(0);
+                if length == 0 {
+                    let buffer = view["buffer"].as_str().unwrap_or_default();
+                    let buffer_length = self.legacy["buffers"][buffer]["byteLength"].as_u64();
+                    let offset = view["byteOffset"].as_u64().unwrap_or(0);
+                    length = buffer_length.unwrap_or(0).saturating_sub(offset);
+                }
+                let buffer = self.index("buffers", &view["buffer"]);
+                let entries = copy(view, &["byteOffset", "target", "name"]).into_iter();
+                object(entries.chain(vec![("buffer", buffer), ("byteLength", length.into())]))
+            })
+            .collect();
+
+        // glTF 1.0 declares strides per accessor and glTF 2.0 per buffer view.
+        let original = views.clone();
+        let mut strided = HashMap::new();
+        let mut claimed = vec![false; views.len()];
+        let mut accessors = Vec::new();
+        for (_, accessor) in entries(self.legacy, "accessors") {
+            let stride = accessor["byteStride"].as_u64().unwrap_or(0);
+            let view = self.index("bufferViews", &accessor["bufferView"]).as_u64();
+            let view = view.map(|view| {
+                *strided.entry((view, stride)).or_insert_with(|| {
+                    let index = if claimed[view as usize] {
+                        views.push(original[view as usize].clone());
+                        views.len() - 1
+                    } else {
+                        claimed[view as usize] = true;
+                        view as usize
+                    };
+                    if stride != 0 {
+                        views[index]["byteStride"] = stride.into();
+                    }
+                    index
+                })
+            });
+            let keys = ["byteOffset", "componentType", "count", "type", "min", "max", "name"];
+            let entries = copy(accessor, &keys).into_iter();
+            accessors.push(object(entries.chain(Some(("bufferView", view.into())))));
+        }
+        (views, accessors)
+    }
+
+    fn images(&self) -> Vec<Value> {
+        let images = entries(self.legacy, "images").into_iter();
+        let image = |(_, image): (&str, &Value)| {
+            let binary = &image["extensions"]["KHR_binary_glTF"];
+            if binary.is_object() {
+                let view = ("bufferView", self.index("bufferViews", &binary["bufferView"]));
+                let entries = copy(binary, &["mimeType"]).into_iter().chain(Some(view));
+                object(entries.chain(copy(image, &["name"])))
+            } else {
+                object(copy(image, &["uri", "name"]))
+            }
+        };
+        images.map(image).collect()
+    }
+
+    fn textures(&self) -> Vec<Value> {
+        let textures = entries(self.legacy, "textures").into_iter();
+        let texture = |(_, texture): (&str, &Value)| {
+            object(vec![
+                ("sampler", self.index("samplers", &texture["sampler"])),
+                ("source", self.index("images", &texture["source"])),
+                ("name", texture["name"].clone()),
+            ])
+        };
+        textures.map(texture).collect()
+    }
+
+    /// Approximates a glTF 1.0 material with a metallic-roughness material.
+    fn material(&self, material: &Value) -> Value {
+        let common = &material["extensions"]["KHR_materials_common"];
+        let technique = material["technique"].as_str().unwrap_or_default();
+        let technique = &self.legacy["techniques"][technique];
+        let value = |name: &str| {
+            let values = if common.is_object() { &common["values"] } else { &material["values"] };
+            match &values[name] {
+                Value::Null => &technique["parameters"][name]["value"],
+                value => value,
+            }
+        };
+        let color = |name| match value(name) {
+            Value::Array(x) => Some(x.iter().map(|x| x.as_f64().unwrap_or(0.0)).collect()),
+            _ => None,
+        };
+        let texture = |name| match value(name) {
+            Value::String(_) => object(Some(("index", self.index("textures", value(name))))),
+            _ => Value::Null,
+        };
+
+        let unlit = common["technique"].as_str() == Some("CONSTANT");
+        let diffuse = if unlit { "emission" } else { "diffuse" };
+        let mut base_color: Vec<f64> = color(diffuse).unwrap_or_else(|| vec![1.0; 4]);
+        base_color.resize(4, 1.0);
+        base_color[3] *= value("transparency").as_f64().unwrap_or(1.0);
+        let emissive = match color("emission") {
+            Some(mut emission) if !unlit => {
+                emission.resize(3, 0.0);
+                emission.into()
+            },
+            _ => Value::Null,
+        };
+        let shininess = value("shininess").as_f64().filter(|_| !unlit);
+        let roughness = shininess.map_or(1.0, |x| (2.0 / (x.max(0.0) + 2.0)).sqrt());
+
+        let states = technique["states"]["enable"].as_array().into_iter().flatten();
+        let enabled = |state| states.clone().any(|x| x.as_u64() == Some(state));
+        let double_sided = match common["doubleSided"].as_bool() {
+            Some(double_sided) => double_sided,
+            None => technique.is_object() && !enabled(CULL_FACE),
+        };
+        let blend = common["transparent"].as_bool().unwrap_or(false) || enabled(BLEND);
+        let alpha_mode = if blend || base_color[3] < 1.0 { "BLEND".into() } else { Value::Null };
+
+        let pbr = object(vec![
+            ("baseColorFactor", base_color.into()),
+            ("baseColorTexture", texture(diffuse)),
+            ("metallicFactor", 0.0.into()),
+            ("roughnessFactor", roughness.into()),
+        ]);
+        object(vec![
+            ("pbrMetallicRoughness", pbr),
+            ("emissiveFactor", emissive),
+            ("emissiveTexture", if unlit { Value::Null } else { texture("emission") }),
+            ("alphaMode", alpha_mode),
+            ("doubleSided", if double_sided { true.into() } else { Value::Null }),
+            ("name", material["name"].clone()),
+        ])
+    }
+
+    fn meshes(&self) -> Vec<Value> {
+        let meshes = entries(self.legacy, "meshes").into_iter();
+        let primitive = |primitive: &Value| {
+            let attributes = primitive["attributes"].as_object().into_iter().flatten();
+            let attributes =
+                attributes.map(|(name, id)| (semantic(name), self.index("accessors", id)));
+            let mut map = Value::Object(Default::default());
+            for (name, index) in attributes.filter(|(_, index)| !index.is_null()) {
+                map[name] = index;
+            }
+            object(vec![
+                ("attributes", map),
+                ("indices", self.index("accessors", &primitive["indices"])),
+                ("material", self.index("materials", &primitive["material"])),
+                ("mode", primitive["mode"].clone()),
+            ])
+        };
+        let mesh = |(_, mesh): (&str, &Value)| {
+            let primitives = mesh["primitives"].as_array().into_iter().flatten();
+            let primitives = primitives.map(primitive).collect::<Vec<_>>();
+            object(vec![("primitives", primitives.into()), ("name", mesh["name"].clone())])
+        };
+        meshes.map(mesh).collect()
+    }
+
+    /// Returns the nodes, followed by a child node for each mesh of a node but
+    /// the first, as glTF 2.0 nodes have a single mesh.
+    fn nodes(&self) -> Vec<Value> {
+        let legacy = entries(self.legacy, "nodes");
+        let mut nodes = Vec::with_capacity(legacy.len());
+        let mut extra = Vec::new();
+        for (_, node) in &legacy {
+            let skin = self.index("skins", &node["skin"]);
+            let meshes = self.indices("meshes", &node["meshes"]);
+            let mut children = self.indices("nodes", &node["children"]);
+            for mesh in meshes.iter().skip(1) {
+                children.push((legacy.len() + extra.len()).into());
+                extra.push(object(vec![("mesh", mesh.clone()), ("skin", skin.clone())]));
+            }
+            let keys = ["matrix", "translation", "rotation", "scale", "name"];
+            let children = if children.is_empty() { Value::Null } else { children.into() };
+            nodes.push(object(copy(node, &keys).into_iter().chain(vec![
+                ("camera", self.index("cameras", &node["camera"])),
+                ("children", children),
+                ("mesh", meshes.first().cloned().unwrap_or(Value::Null)),
+                ("skin", skin),
+            ])));
+        }
+        nodes.extend(extra);
+        nodes
+    }
+
+    /// Converts the skins, resolving their joint names to the nodes declaring
+    /// them. Bind shape matrices are not applied.
+    fn skins(&self) -> Vec<Value> {
+        let nodes = entries(self.legacy, "nodes");
+        let joint = |name: &Value| nodes.iter().position(|(_, node)| &node["jointName"] == name);
+        let skins = entries(self.legacy, "skins").into_iter();
+        let skin = |(id, skin): (&str, &Value)| {
+            let names = skin["jointNames"].as_array().into_iter().flatten();
+            let joints: Vec<Value> = names.filter_map(joint).map(Value::from).collect();
+            let skinned = nodes.iter().find(|(_, node)| node["skin"].as_str() == Some(id));
+            let skeleton = skinned.map_or(Value::Null, |(_, node)| {
+                self.index("nodes", &node["skeletons"][0])
+            });
+            object(vec![
+                ("inverseBindMatrices", self.index("accessors", &skin["inverseBindMatrices"])),
+                ("joints", joints.into()),
+                ("skeleton", skeleton),
+                ("name", skin["name"].clone()),
+            ])
+        };
+        skins.map(skin).collect()
+    }
+
+    fn animations(&self) -> Vec<Value> {
+        let animations = entries(self.legacy, "animations").into_iter();
+        let animation = |(_, animation): (&str, &Value)| {
+            let parameters = &animation["parameters"];
+            let legacy = entries(animation, "samplers");
+            let accessor = |name: &Value| {
+                self.index("accessors", &parameters[name.as_str().unwrap_or("")])
+            };
+            let samplers: Vec<_> = legacy
+                .iter()
+                .map(|(_, sampler)| {
+                    object(vec![
+                        ("input", accessor(&sampler["input"])),
+                        ("output", accessor(&sampler["output"])),
+                        ("interpolation", sampler["interpolation"].clone()),
+                    ])
+                })
+                .collect();
+            let channels = animation["channels"].as_array().into_iter().flatten();
+            let channels: Vec<_> = channels
+                .map(|channel| {
+                    let sampler = channel["sampler"].as_str();
+                    let sampler = legacy.iter().position(|(id, _)| Some(*id) == sampler);
+                    let target = &channel["target"];
+                    let node = self.index("nodes", &target["id"]);
+                    let target = object(vec![("node", node), ("path", target["path"].clone())]);
+                    object(vec![("sampler", sampler.into()), ("target", target)])
+                })
+                .collect();
+            object(vec![
+                ("channels", channels.into()),
+                ("samplers", samplers.into()),
+                ("name", animation["name"].clone()),
+            ])
+        };
+        animations.map(animation).collect()
+    }
+
+    fn cameras(&self) -> Vec<Value> {
+        let keys = ["type", "perspective", "orthographic", "name"];
+        let cameras = entries(self.legacy, "cameras").into_iter();
+        cameras.map(|(_, camera)| object(copy(camera, &keys))).collect()
+    }
+
+    fn samplers(&self) -> Vec<Value> {
+        let keys = ["magFilter", "minFilter", "wrapS", "wrapT", "name"];
+        let samplers = entries(self.legacy, "samplers").into_iter();
+        samplers.map(|(_, sampler)| object(copy(sampler, &keys))).collect()
+    }
+
+    fn scenes(&self) -> Vec<Value> {
+        let scenes = entries(self.legacy, "scenes").into_iter();
+        let scene = |(_, scene): (&str, &Value)| {
+            let nodes = self.indices("nodes", &scene["nodes"]);
+            object(vec![("nodes", nodes.into()), ("name", scene["name"].clone())])
+        };
+        scenes.map(scene).collect()
+    }
+
+    fn convert(&self) -> Value {
+        let legacy = self.legacy;
+        let (views, accessors) = self.views_and_accessors();
+        let materials = entries(legacy, "materials").into_iter();
+        let materials = materials.map(|(_, material)| self.material(material)).collect();
+        let mut asset = object(copy(&legacy["asset"], &["generator", "copyright"]));
+        asset["version"] = "2.0".into();
+        let extensions = legacy["extensionsUsed"].as_array().into_iter().flatten();
+        let extensions = extensions.filter(|x| {
+            !CONVERTED_EXTENSIONS.iter().any(|&converted| x.as_str() == Some(converted))
+        });
+        let extensions: Vec<_> = extensions.cloned().collect();
+        let arrays = vec![
+            ("accessors", accessors),
+            ("animations", self.animations()),
+            ("buffers", self.buffers()),
+            ("bufferViews", views),
+            ("cameras", self.cameras()),
+            ("images", self.images()),
+            ("materials", materials),
+            ("meshes", self.meshes()),
+            ("nodes", self.nodes()),
+            ("samplers", self.samplers()),
+            ("scenes", self.scenes()),
+            ("skins", self.skins()),
+            ("textures", self.textures()),
+            ("extensionsUsed", extensions),
+        ];
+        let arrays = arrays.into_iter().filter(|(_, x)| !x.is_empty());
+        let arrays = arrays.map(|(key, x)| (key, Value::from(x)));
+        let scene = ("scene", self.index("scenes", &legacy["scene"]));
+        object(arrays.chain(vec![("asset", asset), scene]))
+    }
+}
+
+/// Returns `true` if glTF JSON declares version 1.0, or declares no version
+/// but stores its nodes or meshes keyed by ID, as glTF 1.0 does.
+fn is_legacy_json(json: &Value) -> bool {
+    match json["asset"]["version"].as_str() {
+        Some(version) => version.starts_with("1."),
+        None => json["nodes"].is_object() || json["meshes"].is_object(),
+    }
+}
+
+/// Splits binary glTF 1.0 into its JSON content and binary body.
+fn split_binary_gltf(slice: &[u8]) -> Result<(&[u8], &[u8])> {
+    use binary::Error::{Length, UnknownChunkType};
+
+    let word = |i: usize| {
+        let bytes = slice.get(i..i + 4).ok_or(Length { length: 20, length_read: slice.len() })?;
+        Ok::<_, binary::Error>(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    };
+    let (length, content_length, content_format) = (word(8)?, word(12)?, word(16)?);
+    if content_format != 0 {
+        return Err(Error::Binary(UnknownChunkType(content_format.to_le_bytes())));
+    }
+    let end = 20 + content_length as usize;
+    if (length as usize) < end || length as usize > slice.len() {
+        return Err(Error::Binary(Length { length, length_read: slice.len() }));
+    }
+    Ok((&slice[20..end], &slice[end..length as usize]))
+}
+
+/// Returns `true` if a slice holds glTF 1.0, either as JSON or as binary glTF
+/// of container version 1.
+pub fn is_legacy(slice: &[u8]) -> bool {
+    if slice.starts_with(b"glTF") {
+        return slice.get(4..8) == Some(&1u32.to_le_bytes()[..]);
+    }
+    matches!(json::deserialize::from_slice(slice), Ok(json) if is_legacy_json(&json))
+}
+
+/// Converts glTF 1.0 JSON to glTF 2.0.
+///
+/// Objects keyed by ID become arrays ordered by ID, with the `KHR_binary_glTF`
+/// buffer first, and references become indices. Accessor strides move to their
+/// buffer views, which are duplicated where accessors read them with different
+/// strides. Nodes with several meshes are given a child node for each mesh but
+/// the first, and skins refer to their joints by index instead of by name.
+/// Materials are approximated by metallic-roughness materials, as described
+/// below; techniques, programs, and shaders are discarded.
+///
+/// The `diffuse`, `emission`, `shininess`, and `transparency` values of a
+/// material, read from `KHR_materials_common` or the technique parameters,
+/// set the base color, emissive factor, roughness, and alpha, and the culling
+/// and blending states of the technique set `doubleSided` and `alphaMode`.
+/// Attribute semantics are renamed, such as `JOINT` to `JOINTS_0`, and
+/// application specific semantics are prefixed with an underscore. Bind shape
+/// matrices of skins and accessor bounds that were not declared are not
+/// computed, since the buffers are not read.
+pub fn convert(legacy: &Value) -> Result<json::Root> {
+    Ok(json::deserialize::from_value(Converter::new(legacy).convert())?)
+}
+
+/// Loads glTF from a slice of bytes, converting glTF 1.0 to 2.0 as by
+/// `convert`.
+///
+/// The slice may hold glTF 2.0, binary glTF 1.0 with its `KHR_binary_glTF`
+/// body, or glTF 1.0 JSON; glTF 2.0 is loaded as by `Gltf::from_slice`. The
+/// converted document is validated, and the body of binary glTF 1.0 becomes the
+/// blob of the returned `Gltf`.
+pub fn from_slice(slice: &[u8]) -> Result<Gltf> {
+    let (content, body) = if slice.starts_with(b"glTF") {
+        if !is_legacy(slice) {
+            return Gltf::from_slice(slice);
+        }
+        let (content, body) = split_binary_gltf(slice)?;
+        (content, Some(body))
+    } else {
+        (slice, None)
+    };
+    let legacy: Value = json::deserialize::from_slice(content)?;
+    if body.is_none() && !is_legacy_json(&legacy) {
+        return Gltf::from_slice(slice);
+    }
+    let document = Document::from_json(convert(&legacy)?)?;
+    Ok(Gltf { document, blob: body.map(<[u8]>::to_vec) })
+}
+
+/// Imports glTF 1.0 or 2.0 from the file system, converting glTF 1.0 to 2.0 as
+/// by `from_slice`.
+///
+/// Returns the same data as `gltf::import`.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn import<P>(
+    path: P,
+) -> Result<(Document, Vec<crate::buffer::Data>, Vec<crate::image::Data>)>
+where
+    P: AsRef<std::path::Path>,
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or_else(|| std::path::Path::new("./"));
+    let gltf = from_slice(&std::fs::read(path).map_err(Error::Io)?)?;
+    crate::import::import_files(gltf, Some(base), &Default::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_slice, is_legacy};
+    use crate::material::AlphaMode;
+
+    /// Returns binary glTF 1.0 of the given JSON content and body.
+    fn binary_gltf(content: &str, body: &[u8]) -> Vec<u8> {
+        let length = 20 + content.len() + body.len();
+        let mut slice = b"glTF".to_vec();
+        for word in [1, length as u32, content.len() as u32, 0] {
+            slice.extend(word.to_le_bytes());
+        }
+        slice.extend(content.as_bytes());
+        slice.extend(body);
+        slice
+    }
+
+    #[test]
+    fn convert_binary_gltf() {
+        let content = r#"{
+            "asset": {"version": "1.0", "generator": "exporter"},
+            "extensionsUsed": ["KHR_binary_glTF", "KHR_materials_common"],
+            "buffers": {
+                "binary_glTF": {"byteLength": 44, "type": "arraybuffer", "uri": "data:,"}
+            },
+            "bufferViews": {
+                "attributes": {"buffer": "binary_glTF", "byteLength": 36, "target": 34962},
+                "indices": {"buffer": "binary_glTF", "byteOffset": 36, "byteLength": 6}
+            },
+            "accessors": {
+                "positions": {
+                    "bufferView": "attributes", "byteOffset": 0, "byteStride": 12,
+                    "componentType": 5126, "count": 3, "type": "VEC3",
+                    "min": [0, 0, 0], "max": [1, 1, 0]
+                },
+                "last": {
+                    "bufferView": "attributes", "byteOffset": 12, "byteStride": 24,
+                    "componentType": 5126, "count": 1, "type": "VEC3",
+                    "min": [1, 0, 0], "max": [1, 0, 0]
+                },
+                "indices": {
+                    "bufferView": "indices", "byteOffset": 0,
+                    "componentType": 5123, "count": 3, "type": "SCALAR"
+                }
+            },
+            "materials": {
+                "common": {"extensions": {"KHR_materials_common": {
+                    "technique": "BLINN",
+                    "values": {"diffuse": [1, 0, 0, 1], "shininess": 14, "transparency": 0.5}
+                }}},
+                "shaded": {"technique": "technique", "values": {"emission": [0.5, 0.5, 0.5, 1]}}
+            },
+            "techniques": {"technique": {
+                "parameters": {"diffuse": {"type": 35666, "value": [0, 1, 0, 1]}},
+                "states": {"enable": [2929]}
+            }},
+            "meshes": {
+                "a": {"primitives": [{
+                    "attributes": {"POSITION": "positions", "BATCHID": "last"},
+                    "indices": "indices", "material": "common"
+                }]},
+                "b": {"primitives": [{
+                    "attributes": {"POSITION": "positions"}, "material": "shaded"
+                }]}
+            },
+            "nodes": {
+                "root": {"children": ["child"], "meshes": ["a", "b"]},
+                "child": {"translation": [0, 1, 0]}
+            },
+            "scenes": {"default": {"nodes": ["root"]}},
+            "scene": "default"
+        }"#;
+        let mut body = Vec::new();
+        for x in [0.0f32, 0.0, 0.0, 1.0, 0.0, 0.0, 1.0, 1.0, 0.0] {
+            body.extend(x.to_le_bytes());
+        }
+        for x in [0u16, 1, 2, 0] {
+            body.extend(x.to_le_bytes());
+        }
+        let slice = binary_gltf(content, &body);
+        assert!(is_legacy(&slice));
+        assert!(is_legacy(content.as_bytes()));
+
+        let gltf = from_slice(&slice).unwrap();
+        let blob = gltf.blob.as_deref().unwrap();
+        assert_eq!(blob, &body[..]);
+        let json = &gltf.document.0;
+        assert_eq!(json.asset.version, "2.0");
+        assert!(json.extensions_used.is_empty());
+        assert_eq!(json.buffers[0].uri, None);
+        let strides: Vec<_> = json.buffer_views.iter().map(|view| view.byte_stride).collect();
+        assert_eq!(strides, [Some(24), None, Some(12)]);
+        assert_eq!(json.accessors[2].buffer_view.unwrap().value(), 2);
+
+        let materials: Vec<_> = gltf.materials().collect();
+        let pbr = materials[0].pbr_metallic_roughness();
+        assert_eq!(pbr.base_color_factor(), [1.0, 0.0, 0.0, 0.5]);
+        assert_eq!(pbr.metallic_factor(), 0.0);
+        assert!((pbr.roughness_factor() - 0.125f32.sqrt()).abs() < 1e-6);
+        assert_eq!(materials[0].alpha_mode(), AlphaMode::Blend);
+        assert!(!materials[0].double_sided());
+        let pbr = materials[1].pbr_metallic_roughness();
+        assert_eq!(pbr.base_color_factor(), [0.0, 1.0, 0.0, 1.0]);
+        assert_eq!(materials[1].emissive_factor(), [0.5, 0.5, 0.5]);
+        assert_eq!(materials[1].alpha_mode(), AlphaMode::Opaque);
+        assert!(materials[1].double_sided());
+
+        let nodes: Vec<_> = gltf.nodes().collect();
+        assert_eq!(nodes.len(), 3);
+        let children: Vec<_> = nodes[1].children().map(|child| child.index()).collect();
+        assert_eq!(children, [0, 2]);
+        assert_eq!(nodes[1].mesh().unwrap().index(), 0);
+        assert_eq!(nodes[2].mesh().unwrap().index(), 1);
+        assert_eq!(gltf.default_scene().unwrap().nodes().next().unwrap().index(), 1);
+
+        let primitive = nodes[1].mesh().unwrap().primitives().next().unwrap();
+        let custom = crate::Semantic::Extras("BATCHID".to_string());
+        assert_eq!(primitive.get(&custom).unwrap().index(), 1);
+        let reader = primitive.reader(|_| Some(blob));
+        let positions: Vec<_> = reader.read_positions().unwrap().collect();
+        assert_eq!(positions, [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]]);
+        let indices: Vec<_> = reader.read_indices().unwrap().into_u32().collect();
+        assert_eq!(indices, [0, 1, 2]);
+    }
+
+    #[test]
+    fn load_gltf_2() {
+        let slice = std::fs::read("examples/Box.gltf").unwrap();
+        assert!(!is_legacy(&slice));
+        let gltf = from_slice(&slice).unwrap();
+        assert_eq!(gltf.meshes().count(), 1);
+    }
+}
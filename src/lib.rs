@@ -103,6 +103,31 @@ pub mod buffer;
 /// Cameras and their projections.
 pub mod camera;
 
+/// Comparison of documents for regression testing of exporters.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+pub mod compare;
+
+/// A minimal C interface to the parser.
+#[cfg(feature = "capi")]
+#[cfg_attr(docsrs, doc(cfg(feature = "capi")))]
+pub mod capi;
+
+/// Decompression of `KHR_draco_mesh_compression` primitives with a Draco
+/// decoder.
+#[cfg(all(feature = "import", feature = "KHR_draco_mesh_compression"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "import", feature = "KHR_draco_mesh_compression"))))]
+pub mod draco;
+
+/// Alpha mode analysis and draw ordering for simple renderers.
+pub mod draw;
+
+/// Building assets programmatically and exporting standard glTF with external
+/// or inlined resources.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub mod export;
+
 /// Images that may be used by textures.
 pub mod image;
 
@@ -111,7 +136,24 @@ pub mod image;
 #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
 pub mod import;
 
+/// Asynchronous import of glTF assets.
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub mod import_async;
+
+/// Support for the `EXT_mesh_gpu_instancing` extension.
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+pub mod ext_mesh_gpu_instancing;
+
+/// Conformance and content reports of glTF documents.
+pub mod inspect;
+
 /// Iterators for walking the glTF node hierarchy.
+///
+/// Every iterator visits objects in the order they are declared in the JSON,
+/// including the attributes of each primitive, which are stored in a
+/// `json::OrderedMap` and serialized in the same order.
 pub mod iter;
 
 /// Support for the `KHR_lights_punctual` extension.
@@ -119,15 +161,58 @@ pub mod iter;
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
 pub mod khr_lights_punctual;
 
+/// Support for the `KHR_materials_variants` extension.
+#[cfg(feature = "KHR_materials_variants")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+pub mod khr_materials_variants;
+
+/// Conversion of glTF 1.0 assets to glTF 2.0.
+#[cfg(feature = "legacy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "legacy")))]
+pub mod legacy;
+
 /// Material properties of primitives.
 pub mod material;
 
+/// Lazily computed lookup tables of documents.
+mod cache;
+
 /// For internal use.
 mod math;
 
+/// Decoders for `EXT_meshopt_compression`.
+#[cfg(all(feature = "import", feature = "EXT_meshopt_compression"))]
+mod meshopt;
+
+/// Detection of unreferenced objects.
+mod unused;
+
 /// Meshes and their primitives.
 pub mod mesh;
 
+/// Callbacks for collecting import metrics.
+pub mod metrics;
+
+/// Memory-mapped import of glTF assets.
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+pub mod mmap;
+
+/// Optimization passes over glTF JSON and buffer data.
+pub mod optimize;
+
+/// Packing of binary data into buffers for export.
+pub mod pack;
+
+/// Checks of assets against the hardware limits of target platforms.
+pub mod profile;
+
+/// Workarounds for known bugs of glTF exporters.
+pub mod quirks;
+
+/// Export of node subsets with their unused resources trimmed.
+pub mod select;
+
 /// The glTF node heirarchy.
 pub mod scene;
 
@@ -147,6 +232,9 @@ pub use self::binary::Glb;
 pub use self::buffer::Buffer;
 #[doc(inline)]
 pub use self::camera::Camera;
+#[cfg(feature = "utils")]
+#[doc(inline)]
+pub use self::compare::diff_report;
 #[doc(inline)]
 pub use self::image::Image;
 #[cfg(feature = "import")]
@@ -155,6 +243,24 @@ pub use self::import::import;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_slice_borrowed;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_slice_with_options;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_with_options;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::import_with_resolver;
+#[cfg(feature = "async")]
+#[doc(inline)]
+pub use self::import_async::import_async;
+#[cfg(feature = "mmap")]
+#[doc(inline)]
+pub use self::mmap::import_mmap;
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
@@ -179,6 +285,15 @@ pub type Result<T> = result::Result<T, Error>;
 /// Represents a runtime error.
 #[derive(Debug)]
 pub enum Error {
+    /// The data of an accessor lies outside of its buffer view or buffer.
+    AccessorBounds {
+        /// The index of the offending accessor.
+        accessor: usize,
+
+        /// A description of the byte range that is out of bounds.
+        detail: String,
+    },
+
     /// Base 64 decoding error.
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
@@ -211,12 +326,34 @@ pub enum Error {
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     Image(image_crate::ImageError),
-    
+
+    /// JSON parse limit exceeded.
+    Limit(json::limits::Error),
+
     /// The `BIN` chunk of binary glTF is referenced but does not exist.
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     MissingBlob,
 
+    /// Meshopt compressed data of a buffer view could not be decoded.
+    #[cfg(all(feature = "import", feature = "EXT_meshopt_compression"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "import", feature = "EXT_meshopt_compression"))))]
+    Meshopt {
+        /// The index of the offending buffer view.
+        view: usize,
+    },
+
+    /// Draco compressed data of a primitive could not be decoded, or does not
+    /// match the accessors of the primitive.
+    #[cfg(all(feature = "import", feature = "KHR_draco_mesh_compression"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "import", feature = "KHR_draco_mesh_compression"))))]
+    Draco {
+        /// The index of the mesh.
+        mesh: usize,
+        /// The index of the offending primitive in the mesh.
+        primitive: usize,
+    },
+
     /// An external file is referenced in a slice only import without path
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
@@ -248,7 +385,39 @@ pub struct Gltf {
 
 /// glTF JSON wrapper.
 #[derive(Clone, Debug)]
-pub struct Document(json::Root);
+pub struct Document(json::Root, cache::Cache);
+
+/// Reads only the `asset` metadata of standard or binary glTF from a reader.
+///
+/// This is much faster than loading the document when only the version,
+/// generator, or copyright notice is needed, since reading stops once the
+/// `asset` object has been parsed. Nothing else is validated.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let file = std::fs::File::open("examples/Box.glb")?;
+/// let asset = gltf::read_asset(std::io::BufReader::new(file))?;
+/// assert_eq!(asset.version, "2.0");
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub fn read_asset<R>(mut reader: R) -> Result<json::Asset>
+where
+    R: io::Read,
+{
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    let reader = io::Read::chain(&magic[..], reader);
+    let asset = if &magic == b"glTF" {
+        json::Asset::from_root_reader(binary::json_chunk_reader(reader)?)?
+    } else {
+        json::Asset::from_root_reader(reader)?
+    };
+    Ok(asset)
+}
 
 impl Gltf {
     /// Convenience function that loads glTF from the file system.
@@ -272,14 +441,16 @@ impl Gltf {
         reader.seek(io::SeekFrom::Start(0))?;
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if magic.starts_with(b"glTF") {
-            let mut glb = binary::Glb::from_reader(reader)?;
+            let mut glb = binary::Glb::from_reader(&mut reader)?;
             // TODO: use `json::from_reader` instead of `json::from_slice`
             json = json::deserialize::from_slice(&glb.json)?;
             blob = glb.bin.take().map(|x| x.into_owned());
         } else {
-            json = json::deserialize::from_reader(reader)?;
+            json = json::deserialize::from_reader(&mut reader)?;
             blob = None;
         };
+        let length = reader.stream_position()? as usize;
+        metrics::report(|metrics| metrics.bytes_read(length));
         let document = Document::from_json_without_validation(json);
         Ok(Gltf { document, blob })
     }
@@ -297,6 +468,7 @@ impl Gltf {
     /// Loads glTF from a slice of bytes without performing validation
     /// checks.
     pub fn from_slice_without_validation(slice: &[u8]) -> Result<Self> {
+        metrics::report(|metrics| metrics.bytes_read(slice.len()));
         let (json, blob): (json::Root, Option<Vec<u8>>);
         if slice.starts_with(b"glTF") {
             let mut glb = binary::Glb::from_slice(slice)?;
@@ -316,6 +488,20 @@ impl Gltf {
         let _ = gltf.document.validate()?;
         Ok(gltf)
     }
+
+    /// Loads glTF from a slice of bytes, first checking that the JSON stays
+    /// within the given limits.
+    ///
+    /// This is suited to untrusted uploads, since oversized or deeply nested
+    /// JSON is rejected before any of it is deserialized.
+    pub fn from_slice_with_limits(slice: &[u8], limits: &json::limits::Limits) -> Result<Self> {
+        if slice.starts_with(b"glTF") {
+            limits.check(&binary::Glb::from_slice(slice)?.json)?;
+        } else {
+            limits.check(slice)?;
+        }
+        Self::from_slice(slice)
+    }
 }
 
 impl ops::Deref for Gltf {
@@ -342,7 +528,7 @@ impl Document {
     /// Loads glTF from pre-deserialized JSON without performing
     /// validation checks.
     pub fn from_json_without_validation(json: json::Root) -> Self {
-        Document(json)
+        Document(json, cache::Cache::default())
     }
 
     /// Unwraps the glTF document.
@@ -350,15 +536,44 @@ impl Document {
         self.0
     }
 
+    /// Returns the glTF JSON for editing.
+    ///
+    /// Edits are not validated, and the lookup tables cached by the document
+    /// are discarded, to be recomputed from the edited JSON on demand.
+    pub fn json_mut(&mut self) -> &mut json::Root {
+        self.1 = cache::Cache::default();
+        &mut self.0
+    }
+
+    /// Returns the material at `index` for editing.
+    pub fn material_mut(&mut self, index: usize) -> Option<material::MaterialMut<'_>> {
+        let json = self.json_mut().materials.get_mut(index)?;
+        Some(material::MaterialMut::new(index, json))
+    }
+
+    /// Returns the node at `index` for editing.
+    pub fn node_mut(&mut self, index: usize) -> Option<scene::NodeMut<'_>> {
+        let json = self.json_mut().nodes.get_mut(index)?;
+        Some(scene::NodeMut::new(index, json))
+    }
+
     /// Perform validation checks on loaded glTF.
+    ///
+    /// Problems reported with warning severity do not fail validation; use
+    /// `validate_full` to collect them.
     pub fn validate(&self) -> Result<()> {
-        use json::validation::Validate;
+        use json::validation::{Severity, Validate};
         let mut errors = Vec::new();
-        self.0.validate(
-            &self.0,
-            json::Path::new,
-            &mut |path, error| errors.push((path(), error)),
-        );
+        self.0.validate(&self.0, json::Path::new, &mut |path, error| {
+            if error.severity() == Severity::Error {
+                errors.push((path(), error))
+            }
+        });
+        metrics::report(|metrics| {
+            for &(ref path, error) in &errors {
+                metrics.validation_error(path, error);
+            }
+        });
         if errors.is_empty() {
             Ok(())
         } else {
@@ -366,6 +581,25 @@ impl Document {
         }
     }
 
+    /// Collects every problem with the loaded glTF, including those that do not
+    /// prevent loading, for reporting by authoring tools.
+    ///
+    /// The problems checked by `validate`, including the warnings it ignores,
+    /// are followed by warnings for objects that are not referenced by any
+    /// other object and for nodes that are not reachable from any scene. Each
+    /// problem is located by a JSON pointer, see `Diagnostic::pointer`.
+    pub fn validate_full(&self) -> Vec<json::validation::Diagnostic> {
+        use json::validation::{Diagnostic, Validate};
+        let mut diagnostics = Vec::new();
+        self.0.validate(
+            &self.0,
+            json::Path::new,
+            &mut |path, error| diagnostics.push(Diagnostic { path: path(), error }),
+        );
+        diagnostics.extend(unused::report(&self.0));
+        diagnostics
+    }
+
     /// Returns an `Iterator` that visits the accessors of the glTF asset.
     pub fn accessors(&self) -> iter::Accessors {
         iter::Accessors {
@@ -424,6 +658,18 @@ impl Document {
         }
     }
 
+    /// Returns the image flagged as the asset preview via `asset.extras`, if any.
+    ///
+    /// This allows asset browsers to display a thumbnail without rendering the
+    /// scene. See `json::Root::set_thumbnail` for attaching a preview on export.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn thumbnail(&self) -> Option<Image<'_>> {
+        self.0
+            .thumbnail()
+            .and_then(|index| self.images().nth(index.value()))
+    }
+
     /// Returns an `Iterator` that visits the lights of the glTF asset as defined by the
     /// `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
@@ -443,6 +689,18 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits the material variants of the glTF asset
+    /// as defined by the `KHR_materials_variants` extension.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn variants(&self) -> Option<iter::Variants<'_>> {
+        let variants = self.0.extensions.as_ref()?.khr_materials_variants.as_ref()?;
+        Some(iter::Variants {
+            iter: variants.variants.iter().enumerate(),
+            document: self,
+        })
+    }
+
     /// Returns an `Iterator` that visits the materials of the glTF asset.
     pub fn materials(&self) -> iter::Materials {
         iter::Materials {
@@ -451,6 +709,15 @@ impl Document {
         }
     }
 
+    /// Returns the bindings of all materials, in index order, flattened into
+    /// plain data for engines with their own asset formats.
+    ///
+    /// Primitives without a material use the default material, whose binding
+    /// is returned by `Material::binding` on `Primitive::material`.
+    pub fn material_binding_table(&self) -> Vec<material::MaterialBinding> {
+        self.materials().map(|material| material.binding()).collect()
+    }
+
     /// Returns an `Iterator` that visits the meshes of the glTF asset.
     pub fn meshes(&self) -> iter::Meshes {
         iter::Meshes {
@@ -467,6 +734,63 @@ impl Document {
         }
     }
 
+    /// Returns the nodes that carry the given tag, in index order.
+    ///
+    /// See `json::extras::Taggable` for how tags are recorded.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn tagged_nodes(&self, tag: &str) -> Vec<Node<'_>> {
+        self.nodes().filter(|node| node.has_tag(tag)).collect()
+    }
+
+    /// Returns the meshes that carry the given tag, in index order.
+    ///
+    /// See `json::extras::Taggable` for how tags are recorded.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn tagged_meshes(&self, tag: &str) -> Vec<Mesh<'_>> {
+        self.meshes().filter(|mesh| mesh.has_tag(tag)).collect()
+    }
+
+    /// Returns an `Iterator` that visits every node with a mesh along with its
+    /// world transform.
+    ///
+    /// Nodes are visited in pre-order from each node without a parent, whether
+    /// or not it is part of a scene. See `Scene::flatten` for the nodes of a
+    /// single scene.
+    pub fn mesh_nodes(&self) -> iter::ComponentNodes<'_> {
+        self.component_nodes(self.0.nodes.iter().map(|node| node.mesh.is_some()).collect())
+    }
+
+    /// Returns an `Iterator` that visits every node with a camera along with its
+    /// world transform, in the same order as `mesh_nodes`.
+    pub fn camera_nodes(&self) -> iter::ComponentNodes<'_> {
+        self.component_nodes(self.0.nodes.iter().map(|node| node.camera.is_some()).collect())
+    }
+
+    /// Returns an `Iterator` that visits every node used as a joint by a skin
+    /// along with its world transform, in the same order as `mesh_nodes`.
+    pub fn joint_nodes(&self) -> iter::ComponentNodes<'_> {
+        let mut joints = vec![false; self.0.nodes.len()];
+        for joint in self.0.skins.iter().flat_map(|skin| skin.joints.iter()) {
+            if let Some(joint) = joints.get_mut(joint.value()) {
+                *joint = true;
+            }
+        }
+        self.component_nodes(joints)
+    }
+
+    /// Returns an `Iterator` that visits the nodes whose entry in `mask`, indexed
+    /// by node index, is `true`.
+    fn component_nodes(&self, mask: Vec<bool>) -> iter::ComponentNodes<'_> {
+        let parents = cache::parents(self);
+        let roots = self.nodes().filter(move |node| parents[node.index()].is_none());
+        iter::ComponentNodes {
+            walk: scene::iter::Walk::new(roots),
+            mask,
+        }
+    }
+
     /// Returns an `Iterator` that visits the samplers of the glTF asset.
     pub fn samplers(&self) -> iter::Samplers {
         iter::Samplers {
@@ -514,6 +838,9 @@ impl std::fmt::Display for Error {
         match *self {
             #[cfg(feature = "import")]
             Error::Base64(ref e) => e.fmt(f),
+            Error::AccessorBounds { accessor, ref detail } => {
+                write!(f, "accessor {}: {}", accessor, detail)
+            },
             Error::Binary(ref e) => e.fmt(f),
             #[cfg(feature = "import")]
             Error::BufferLength { buffer, expected, actual } => {
@@ -529,8 +856,17 @@ impl std::fmt::Display for Error {
             Error::Io(ref e) => e.fmt(f),
             #[cfg(feature = "import")]
             Error::Image(ref e) => e.fmt(f),
+            Error::Limit(ref e) => e.fmt(f),
             #[cfg(feature = "import")]
             Error::MissingBlob => write!(f, "missing binary portion of binary glTF"),
+            #[cfg(all(feature = "import", feature = "EXT_meshopt_compression"))]
+            Error::Meshopt { view } => {
+                write!(f, "buffer view {}: invalid meshopt compressed data", view)
+            },
+            #[cfg(all(feature = "import", feature = "KHR_draco_mesh_compression"))]
+            Error::Draco { mesh, primitive } => {
+                write!(f, "mesh {}, primitive {}: invalid Draco compressed data", mesh, primitive)
+            },
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => write!(f, "external reference in slice only import"),
             #[cfg(feature = "import")]
@@ -569,6 +905,12 @@ impl From<image_crate::ImageError> for Error {
     }
 }
 
+impl From<json::limits::Error> for Error {
+    fn from(err: json::limits::Error) -> Self {
+        Error::Limit(err)
+    }
+}
+
 impl From<json::Error> for Error {
     fn from(err: json::Error) -> Self {
         Error::Deserialize(err)
@@ -614,11 +956,11 @@ impl Normalize<i16> for u8 {
 }
 
 impl Normalize<u16> for u8 {
-    fn normalize(self) -> u16 { self.max(0) as u16 * 2 }
+    fn normalize(self) -> u16 { self as u16 * 0x101 }
 }
 
 impl Normalize<f32> for u8 {
-    fn normalize(self) -> f32 { (self as f32 * 32767.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { self as f32 * 255.0_f32.recip() }
 }
 
 impl Normalize<i8> for i16 {
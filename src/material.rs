@@ -125,6 +125,38 @@ impl<'a> Material<'a> {
             .map(|x| x.ior.0)
     }
 
+    /// Parameter values that define the volume beneath the surface of the
+    /// material
+    #[cfg(feature = "KHR_materials_volume")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_volume")))]
+    pub fn volume(&self) -> Option<Volume<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .volume.as_ref()
+            .map(|x| Volume::new(self.document, x))
+    }
+
+    /// The strength by which the emissive color of the material is multiplied
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_emissive_strength")))]
+    pub fn emissive_strength(&self) -> Option<f32> {
+        self.json.extensions
+            .as_ref()?
+            .emissive_strength.as_ref()
+            .map(|x| x.emissive_strength.0)
+    }
+
+    /// Parameter values that define the strength and color of the specular
+    /// reflection of the material
+    #[cfg(feature = "KHR_materials_specular")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
+    pub fn specular(&self) -> Option<Specular<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .specular.as_ref()
+            .map(|x| Specular::new(self.document, x))
+    }
+
     /// A tangent space normal map.
     ///
     /// The texture contains RGB components in linear space. Each texel represents
@@ -193,12 +225,119 @@ impl<'a> Material<'a> {
             .map_or(false, |extensions| extensions.unlit.is_some())
     }
 
+    /// Flattens the material into plain data for engines with their own asset
+    /// formats.
+    pub fn binding(&self) -> MaterialBinding {
+        let pbr = self.pbr_metallic_roughness();
+        let info = |info: Option<texture::Info>| {
+            info.map(|x| TextureBinding::new(x.texture(), x.resolved_uv_pipeline(), 1.0))
+        };
+        #[allow(unused_mut)]
+        let mut emissive_factor = self.emissive_factor();
+        #[cfg(feature = "KHR_materials_emissive_strength")]
+        {
+            let strength = self.emissive_strength().unwrap_or(1.0);
+            emissive_factor.iter_mut().for_each(|x| *x *= strength);
+        }
+        #[cfg(feature = "KHR_materials_unlit")]
+        let unlit = self.unlit();
+        #[cfg(not(feature = "KHR_materials_unlit"))]
+        let unlit = false;
+        MaterialBinding {
+            material: self.index,
+            #[cfg(feature = "names")]
+            name: self.name().map(String::from),
+            base_color_factor: pbr.base_color_factor(),
+            metallic_factor: pbr.metallic_factor(),
+            roughness_factor: pbr.roughness_factor(),
+            emissive_factor,
+            render_state: crate::mesh::RenderState::new(self),
+            unlit,
+            base_color_texture: info(pbr.base_color_texture()),
+            metallic_roughness_texture: info(pbr.metallic_roughness_texture()),
+            normal_texture: self.normal_texture().map(|x| {
+                TextureBinding::new(x.texture(), x.resolved_uv_pipeline(), x.scale())
+            }),
+            occlusion_texture: self.occlusion_texture().map(|x| {
+                TextureBinding::new(x.texture(), x.resolved_uv_pipeline(), x.strength())
+            }),
+            emissive_texture: info(self.emissive_texture()),
+        }
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
 }
 
+/// A material being edited, as returned by `Document::material_mut`.
+#[derive(Debug)]
+pub struct MaterialMut<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a mut json::material::Material,
+}
+
+impl<'a> MaterialMut<'a> {
+    /// Constructs a `MaterialMut`.
+    pub(crate) fn new(index: usize, json: &'a mut json::material::Material) -> Self {
+        Self { index, json }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Sets the alpha cutoff of `Mask` mode, or the default cutoff if `None`.
+    pub fn set_alpha_cutoff(&mut self, alpha_cutoff: Option<f32>) {
+        self.json.alpha_cutoff = alpha_cutoff.map(json::material::AlphaCutoff);
+    }
+
+    /// Sets the alpha rendering mode.
+    pub fn set_alpha_mode(&mut self, alpha_mode: AlphaMode) {
+        self.json.alpha_mode = json::validation::Checked::Valid(alpha_mode);
+    }
+
+    /// Sets whether the material is double-sided.
+    pub fn set_double_sided(&mut self, double_sided: bool) {
+        self.json.double_sided = double_sided;
+    }
+
+    /// Sets the linear RGB emissive factor.
+    pub fn set_emissive_factor(&mut self, emissive_factor: [f32; 3]) {
+        self.json.emissive_factor = json::material::EmissiveFactor(emissive_factor);
+    }
+
+    /// Sets the linear RGBA base color factor of the metallic-roughness model.
+    pub fn set_base_color_factor(&mut self, base_color_factor: [f32; 4]) {
+        let factor = json::material::PbrBaseColorFactor(base_color_factor);
+        self.json.pbr_metallic_roughness.base_color_factor = factor;
+    }
+
+    /// Sets the metalness factor of the metallic-roughness model.
+    pub fn set_metallic_factor(&mut self, metallic_factor: f32) {
+        let factor = json::material::StrengthFactor(metallic_factor);
+        self.json.pbr_metallic_roughness.metallic_factor = factor;
+    }
+
+    /// Sets the roughness factor of the metallic-roughness model.
+    pub fn set_roughness_factor(&mut self, roughness_factor: f32) {
+        let factor = json::material::StrengthFactor(roughness_factor);
+        self.json.pbr_metallic_roughness.roughness_factor = factor;
+    }
+
+    /// Sets the user-defined name of the material.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn set_name(&mut self, name: Option<&str>) {
+        self.json.name = name.map(str::to_string);
+    }
+}
+
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 pub struct PbrMetallicRoughness<'a> {
@@ -320,6 +459,129 @@ impl<'a> Transmission<'a> {
     }
 }
 
+/// A set of parameter values that are used to define the volume beneath the
+/// surface of the material
+#[cfg(feature = "KHR_materials_volume")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_volume")))]
+pub struct Volume<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Volume,
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_volume")))]
+impl<'a> Volume<'a> {
+    /// Constructs `Volume`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Volume,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the thickness of the volume beneath the surface, in the
+    /// coordinate space of the mesh.
+    ///
+    /// The default value is `0.0`, meaning that the material is thin-walled.
+    pub fn thickness_factor(&self) -> f32 {
+        self.json.thickness_factor.0
+    }
+
+    /// Returns the thickness texture, whose G channel is multiplied by the
+    /// thickness factor.
+    pub fn thickness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.thickness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the average distance that light travels in the medium before
+    /// interacting with a particle, in world space.
+    ///
+    /// The default value is infinity.
+    pub fn attenuation_distance(&self) -> f32 {
+        self.json.attenuation_distance.unwrap_or(f32::INFINITY)
+    }
+
+    /// Returns the color that white light turns into due to absorption when
+    /// reaching the attenuation distance.
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn attenuation_color(&self) -> [f32; 3] {
+        self.json.attenuation_color.0
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// A set of parameter values that are used to define the strength and color of
+/// the specular reflection of the material
+#[cfg(feature = "KHR_materials_specular")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
+pub struct Specular<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Specular,
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
+impl<'a> Specular<'a> {
+    /// Constructs `Specular`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Specular,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the strength of the specular reflection.
+    ///
+    /// The default value is `1.0`.
+    pub fn specular_factor(&self) -> f32 {
+        self.json.specular_factor.0
+    }
+
+    /// Returns the specular texture, whose A channel is multiplied by the
+    /// specular factor.
+    pub fn specular_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.specular_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Returns the F0 color of the specular reflection, in linear RGB.
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn specular_color_factor(&self) -> [f32; 3] {
+        self.json.specular_color_factor.0
+    }
+
+    /// Returns the specular color texture, whose sRGB-encoded RGB channels are
+    /// multiplied by the specular color factor.
+    pub fn specular_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.specular_color_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
 /// A set of parameter values that are used to define the specular-glossiness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -433,6 +695,28 @@ impl<'a> NormalTexture<'a> {
         self.texture.clone()
     }
 
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
+    /// Returns the texture co-ordinate set and transform to sample the texture
+    /// with, as `texture::Info::resolved_uv_pipeline` does.
+    pub fn resolved_uv_pipeline(&self) -> texture::UvPipeline {
+        #[cfg(feature = "KHR_texture_transform")]
+        let transform = self.texture_transform().map(|x| (x.tex_coord(), x.matrix()));
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        let transform = None;
+        texture::UvPipeline::new(self.tex_coord(), transform)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -474,6 +758,28 @@ impl<'a> OcclusionTexture<'a> {
     pub fn texture(&self) -> texture::Texture<'a> {
         self.texture.clone()
     }
+
+    /// Returns texture transform information
+    #[cfg(feature = "KHR_texture_transform")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_transform")))]
+    pub fn texture_transform(&self) -> Option<texture::TextureTransform<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .texture_transform
+            .as_ref()
+            .map(texture::TextureTransform::new)
+    }
+
+    /// Returns the texture co-ordinate set and transform to sample the texture
+    /// with, as `texture::Info::resolved_uv_pipeline` does.
+    pub fn resolved_uv_pipeline(&self) -> texture::UvPipeline {
+        #[cfg(feature = "KHR_texture_transform")]
+        let transform = self.texture_transform().map(|x| (x.tex_coord(), x.matrix()));
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        let transform = None;
+        texture::UvPipeline::new(self.tex_coord(), transform)
+    }
     
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
@@ -492,3 +798,287 @@ impl<'a> AsRef<texture::Texture<'a>> for OcclusionTexture<'a> {
         &self.texture
     }
 }
+
+/// Changes to the properties of a material, where every property left as
+/// `None` keeps the value of the material being overridden.
+///
+/// Overrides are collected in a `Look` and resolved into concrete materials of
+/// a copy of the document, leaving the base document untouched.
+#[derive(Clone, Debug, Default)]
+pub struct MaterialOverride {
+    /// Replaces the base color factor.
+    pub base_color_factor: Option<[f32; 4]>,
+
+    /// Replaces the base color texture.
+    pub base_color_texture: Option<json::texture::Info>,
+
+    /// Replaces the metalness factor.
+    pub metallic_factor: Option<f32>,
+
+    /// Replaces the roughness factor.
+    pub roughness_factor: Option<f32>,
+
+    /// Replaces the emissive factor.
+    pub emissive_factor: Option<[f32; 3]>,
+
+    /// Replaces the emissive texture.
+    pub emissive_texture: Option<json::texture::Info>,
+
+    /// Replaces the alpha rendering mode.
+    pub alpha_mode: Option<AlphaMode>,
+
+    /// Replaces the alpha cutoff.
+    pub alpha_cutoff: Option<f32>,
+
+    /// Replaces whether the material is double-sided.
+    pub double_sided: Option<bool>,
+}
+
+impl MaterialOverride {
+    /// Applies the changes to a material.
+    pub fn apply(&self, material: &mut json::Material) {
+        let pbr = &mut material.pbr_metallic_roughness;
+        if let Some(x) = self.base_color_factor {
+            pbr.base_color_factor = json::material::PbrBaseColorFactor(x);
+        }
+        if let Some(x) = self.base_color_texture.as_ref() {
+            pbr.base_color_texture = Some(x.clone());
+        }
+        if let Some(x) = self.metallic_factor {
+            pbr.metallic_factor = json::material::StrengthFactor(x);
+        }
+        if let Some(x) = self.roughness_factor {
+            pbr.roughness_factor = json::material::StrengthFactor(x);
+        }
+        if let Some(x) = self.emissive_factor {
+            material.emissive_factor = json::material::EmissiveFactor(x);
+        }
+        if let Some(x) = self.emissive_texture.as_ref() {
+            material.emissive_texture = Some(x.clone());
+        }
+        if let Some(x) = self.alpha_mode {
+            material.alpha_mode = json::validation::Checked::Valid(x);
+        }
+        if let Some(x) = self.alpha_cutoff {
+            material.alpha_cutoff = Some(json::material::AlphaCutoff(x));
+        }
+        if let Some(x) = self.double_sided {
+            material.double_sided = x;
+        }
+    }
+}
+
+/// One look of a document, made of material overrides applied to materials
+/// throughout the document or to the primitives of individual nodes.
+///
+/// A single base asset can be exported with several looks by resolving each
+/// look in turn.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::material::{Look, MaterialOverride};
+///
+/// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+/// let base = document.into_json();
+/// let red = MaterialOverride {
+///     base_color_factor: Some([1.0, 0.0, 0.0, 1.0]),
+///     ..Default::default()
+/// };
+/// let root = Look::new().material(0, red).resolve(&base);
+/// assert_eq!(root.materials[0].pbr_metallic_roughness.base_color_factor.0[0], 1.0);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Look {
+    /// Overrides of materials throughout the document, by material index.
+    materials: Vec<(usize, MaterialOverride)>,
+
+    /// Overrides of the materials of nodes, by node index.
+    nodes: Vec<(usize, MaterialOverride)>,
+}
+
+impl Look {
+    /// Creates a look without overrides.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Overrides a material wherever it is used.
+    pub fn material(&mut self, material: usize, layer: MaterialOverride) -> &mut Self {
+        self.materials.push((material, layer));
+        self
+    }
+
+    /// Overrides the materials of the primitives of a node's mesh, on top of
+    /// any overrides of the materials themselves.
+    pub fn node(&mut self, node: usize, layer: MaterialOverride) -> &mut Self {
+        self.nodes.push((node, layer));
+        self
+    }
+
+    /// Returns a copy of `root` with the overrides resolved into concrete
+    /// materials.
+    ///
+    /// Material overrides change the materials in place. Every node with
+    /// overrides receives a copy of its mesh whose primitives refer to new
+    /// materials, leaving the original mesh to the other nodes using it.
+    /// Primitives without a material are given an overridden copy of the
+    /// default material. Overrides are applied in the order they were added
+    /// and indices out of range are ignored.
+    pub fn resolve(&self, root: &json::Root) -> json::Root {
+        let mut root = root.clone();
+        for (material, layer) in &self.materials {
+            if let Some(material) = root.materials.get_mut(*material) {
+                layer.apply(material);
+            }
+        }
+        for (node, layer) in &self.nodes {
+            let mesh = match root.nodes.get(*node).and_then(|node| node.mesh) {
+                Some(mesh) => mesh,
+                None => continue,
+            };
+            let mut mesh = match root.meshes.get(mesh.value()) {
+                Some(mesh) => mesh.clone(),
+                None => continue,
+            };
+            for primitive in &mut mesh.primitives {
+                let mut material = primitive
+                    .material
+                    .and_then(|material| root.materials.get(material.value()))
+                    .cloned()
+                    .unwrap_or_default();
+                layer.apply(&mut material);
+                primitive.material = Some(root.push(material));
+            }
+            root.nodes[*node].mesh = Some(root.push(mesh));
+        }
+        root
+    }
+}
+
+/// A texture bound to a slot of a `MaterialBinding`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextureBinding {
+    /// The index of the texture.
+    pub texture: usize,
+
+    /// The index of the image sampled, as returned by `Texture::source`.
+    pub image: usize,
+
+    /// The filtering and wrapping modes of the texture's sampler.
+    pub sampler: texture::SamplerState,
+
+    /// The texture co-ordinate set and transform to sample with.
+    pub uv: texture::UvPipeline,
+
+    /// The normal scale of normal textures and the strength of occlusion
+    /// textures, and `1.0` for all other slots.
+    pub scale: f32,
+}
+
+impl TextureBinding {
+    /// Resolves the binding of a texture sampled with `uv`.
+    fn new(texture: texture::Texture, uv: texture::UvPipeline, scale: f32) -> Self {
+        TextureBinding {
+            texture: texture.index(),
+            image: texture.source().index(),
+            sampler: texture.sampler().state(),
+            uv,
+            scale,
+        }
+    }
+}
+
+/// A material of the metallic-roughness model flattened into plain data, as
+/// returned by `Material::binding` and `Document::material_binding_table`.
+///
+/// Every value is resolved, with defaults applied, so that engines can copy
+/// the binding into their own asset format without consulting the document.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialBinding {
+    /// The index of the material, or `None` for the default material.
+    pub material: Option<usize>,
+
+    /// The user-defined name of the material.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub name: Option<String>,
+
+    /// The base color factor.
+    pub base_color_factor: [f32; 4],
+
+    /// The metalness factor.
+    pub metallic_factor: f32,
+
+    /// The roughness factor.
+    pub roughness_factor: f32,
+
+    /// The emissive factor, multiplied by the emissive strength of
+    /// `KHR_materials_emissive_strength` when the feature is enabled.
+    pub emissive_factor: [f32; 3],
+
+    /// The alpha mode, alpha cutoff, and face culling of the material.
+    pub render_state: crate::mesh::RenderState,
+
+    /// Whether the material is unlit, as defined by `KHR_materials_unlit`.
+    pub unlit: bool,
+
+    /// The base color texture.
+    pub base_color_texture: Option<TextureBinding>,
+
+    /// The metallic-roughness texture.
+    pub metallic_roughness_texture: Option<TextureBinding>,
+
+    /// The normal texture.
+    pub normal_texture: Option<TextureBinding>,
+
+    /// The occlusion texture.
+    pub occlusion_texture: Option<TextureBinding>,
+
+    /// The emissive texture.
+    pub emissive_texture: Option<TextureBinding>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AlphaMode, Look, MaterialOverride};
+    use json::validation::Checked;
+
+    #[test]
+    fn resolve_material_overrides() {
+        let base: json::Root = json::deserialize::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "materials": [{"pbrMetallicRoughness": {"roughnessFactor": 0.5}}],
+            "meshes": [{"primitives": [{"attributes": {}, "material": 0}, {"attributes": {}}]}],
+            "nodes": [{"mesh": 0}, {"mesh": 0}]
+        }"#).unwrap();
+        let mut look = Look::new();
+        look.material(0, MaterialOverride { metallic_factor: Some(0.0), ..Default::default() })
+            .node(1, MaterialOverride {
+                alpha_mode: Some(AlphaMode::Blend),
+                double_sided: Some(true),
+                ..Default::default()
+            })
+            .node(5, MaterialOverride::default());
+        let root = look.resolve(&base);
+
+        assert_eq!(base.materials.len(), 1);
+        assert_eq!(base.materials[0].pbr_metallic_roughness.metallic_factor.0, 1.0);
+        assert_eq!(root.materials.len(), 3);
+        assert_eq!(root.materials[0].pbr_metallic_roughness.metallic_factor.0, 0.0);
+        assert_eq!(root.nodes[0].mesh.unwrap().value(), 0);
+        let mesh = &root.meshes[root.nodes[1].mesh.unwrap().value()];
+        let materials: Vec<_> = mesh
+            .primitives
+            .iter()
+            .map(|primitive| &root.materials[primitive.material.unwrap().value()])
+            .collect();
+        assert_eq!(materials[0].pbr_metallic_roughness.roughness_factor.0, 0.5);
+        assert_eq!(materials[0].pbr_metallic_roughness.metallic_factor.0, 0.0);
+        assert_eq!(materials[1].pbr_metallic_roughness.roughness_factor.0, 1.0);
+        assert!(materials.iter().all(|material| material.double_sided));
+        assert!(materials.iter().all(|x| x.alpha_mode == Checked::Valid(AlphaMode::Blend)));
+    }
+}
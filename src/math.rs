@@ -14,7 +14,7 @@
 //
 // Modified for the gltf crate by the gltf library developers.
 
-use std::ops;
+use std::{iter, ops};
 
 #[cfg(test)]
 mod test {
@@ -260,7 +260,6 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
     pub fn from_array(m: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4::new(
             m[0][0], m[0][1], m[0][2], m[0][3],
@@ -324,6 +323,63 @@ impl Matrix4 {
             self.w.as_array(),
         ]
     }
+
+    /// Transforms a point by this homogeneous transformation matrix.
+    pub fn transform_point(&self, p: Vector3) -> Vector3 {
+        let v = self.x * p.x + self.y * p.y + self.z * p.z + self.w;
+        Vector3::new(v.x, v.y, v.z)
+    }
+
+    /// Returns the inverse of this matrix, or `None` if it is singular.
+    pub fn invert(&self) -> Option<Matrix4> {
+        let a = self.as_array();
+        let m = |i: usize| a[i / 4][i % 4];
+        let mut inv = [0.0; 16];
+        inv[0] = m(5) * m(10) * m(15) - m(5) * m(11) * m(14) - m(9) * m(6) * m(15)
+            + m(9) * m(7) * m(14) + m(13) * m(6) * m(11) - m(13) * m(7) * m(10);
+        inv[4] = -m(4) * m(10) * m(15) + m(4) * m(11) * m(14) + m(8) * m(6) * m(15)
+            - m(8) * m(7) * m(14) - m(12) * m(6) * m(11) + m(12) * m(7) * m(10);
+        inv[8] = m(4) * m(9) * m(15) - m(4) * m(11) * m(13) - m(8) * m(5) * m(15)
+            + m(8) * m(7) * m(13) + m(12) * m(5) * m(11) - m(12) * m(7) * m(9);
+        inv[12] = -m(4) * m(9) * m(14) + m(4) * m(10) * m(13) + m(8) * m(5) * m(14)
+            - m(8) * m(6) * m(13) - m(12) * m(5) * m(10) + m(12) * m(6) * m(9);
+        inv[1] = -m(1) * m(10) * m(15) + m(1) * m(11) * m(14) + m(9) * m(2) * m(15)
+            - m(9) * m(3) * m(14) - m(13) * m(2) * m(11) + m(13) * m(3) * m(10);
+        inv[5] = m(0) * m(10) * m(15) - m(0) * m(11) * m(14) - m(8) * m(2) * m(15)
+            + m(8) * m(3) * m(14) + m(12) * m(2) * m(11) - m(12) * m(3) * m(10);
+        inv[9] = -m(0) * m(9) * m(15) + m(0) * m(11) * m(13) + m(8) * m(1) * m(15)
+            - m(8) * m(3) * m(13) - m(12) * m(1) * m(11) + m(12) * m(3) * m(9);
+        inv[13] = m(0) * m(9) * m(14) - m(0) * m(10) * m(13) - m(8) * m(1) * m(14)
+            + m(8) * m(2) * m(13) + m(12) * m(1) * m(10) - m(12) * m(2) * m(9);
+        inv[2] = m(1) * m(6) * m(15) - m(1) * m(7) * m(14) - m(5) * m(2) * m(15)
+            + m(5) * m(3) * m(14) + m(13) * m(2) * m(7) - m(13) * m(3) * m(6);
+        inv[6] = -m(0) * m(6) * m(15) + m(0) * m(7) * m(14) + m(4) * m(2) * m(15)
+            - m(4) * m(3) * m(14) - m(12) * m(2) * m(7) + m(12) * m(3) * m(6);
+        inv[10] = m(0) * m(5) * m(15) - m(0) * m(7) * m(13) - m(4) * m(1) * m(15)
+            + m(4) * m(3) * m(13) + m(12) * m(1) * m(7) - m(12) * m(3) * m(5);
+        inv[14] = -m(0) * m(5) * m(14) + m(0) * m(6) * m(13) + m(4) * m(1) * m(14)
+            - m(4) * m(2) * m(13) - m(12) * m(1) * m(6) + m(12) * m(2) * m(5);
+        inv[3] = -m(1) * m(6) * m(11) + m(1) * m(7) * m(10) + m(5) * m(2) * m(11)
+            - m(5) * m(3) * m(10) - m(9) * m(2) * m(7) + m(9) * m(3) * m(6);
+        inv[7] = m(0) * m(6) * m(11) - m(0) * m(7) * m(10) - m(4) * m(2) * m(11)
+            + m(4) * m(3) * m(10) + m(8) * m(2) * m(7) - m(8) * m(3) * m(6);
+        inv[11] = -m(0) * m(5) * m(11) + m(0) * m(7) * m(9) + m(4) * m(1) * m(11)
+            - m(4) * m(3) * m(9) - m(8) * m(1) * m(7) + m(8) * m(3) * m(5);
+        inv[15] = m(0) * m(5) * m(10) - m(0) * m(6) * m(9) - m(4) * m(1) * m(10)
+            + m(4) * m(2) * m(9) + m(8) * m(1) * m(6) - m(8) * m(2) * m(5);
+
+        let det = m(0) * inv[0] + m(1) * inv[4] + m(2) * inv[8] + m(3) * inv[12];
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let r = |i: usize| inv[i] / det;
+        Some(Matrix4::new(
+            r(0), r(1), r(2), r(3),
+            r(4), r(5), r(6), r(7),
+            r(8), r(9), r(10), r(11),
+            r(12), r(13), r(14), r(15),
+        ))
+    }
 }
 
 impl ops::Mul<Matrix4> for Matrix4 {
@@ -403,3 +459,72 @@ impl Quaternion {
         }
     }
 }
+
+/// A floating point scalar, for helpers shared by `f32` and `f64` data.
+pub trait Float:
+    Copy
+    + PartialOrd
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+    + iter::Sum
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn from_f32(x: f32) -> Self;
+    fn acos(self) -> Self;
+    fn sin(self) -> Self;
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($ty:ty) => {
+        impl Float for $ty {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn from_f32(x: f32) -> Self {
+                x as $ty
+            }
+            fn acos(self) -> Self {
+                <$ty>::acos(self)
+            }
+            fn sin(self) -> Self {
+                <$ty>::sin(self)
+            }
+            fn sqrt(self) -> Self {
+                <$ty>::sqrt(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
+
+/// Spherical linear interpolation between two unit quaternions, taking the
+/// shortest path, normalized to unit length.
+pub fn slerp<T: Float>(a: &[T], b: &[T], t: T) -> [T; 4] {
+    let mut dot: T = a.iter().zip(b).map(|(&a, &b)| a * b).sum();
+    let sign = if dot < T::ZERO { T::ZERO - T::ONE } else { T::ONE };
+    dot = dot * sign;
+    let (wa, wb) = if dot > T::from_f32(0.9995) {
+        // Nearly parallel; fall back to linear interpolation.
+        (T::ONE - t, t)
+    } else {
+        let angle = dot.acos();
+        let sin = angle.sin();
+        (((T::ONE - t) * angle).sin() / sin, (t * angle).sin() / sin)
+    };
+    let mut q = [T::ZERO; 4];
+    for (q, (&a, &b)) in q.iter_mut().zip(a.iter().zip(b)) {
+        *q = wa * a + wb * sign * b;
+    }
+    let length = q.iter().map(|&x| x * x).sum::<T>().sqrt();
+    if length > T::ZERO {
+        q.iter_mut().for_each(|x| *x = *x / length);
+    }
+    q
+}
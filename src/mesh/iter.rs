@@ -1,4 +1,4 @@
-use std::{collections, iter, slice};
+use std::{iter, slice};
 
 use super::{Attribute, Mesh, MorphTarget, Primitive};
 use crate::Document;
@@ -9,11 +9,16 @@ pub struct MorphTargets<'a> {
     /// The parent `Document` struct.
     pub(crate) document: &'a Document,
 
+    /// The JSON struct of the parent mesh.
+    #[cfg(feature = "extras")]
+    pub(crate) mesh: &'a json::mesh::Mesh,
+
     /// The internal JSON iterator.
-    pub(crate) iter: slice::Iter<'a, json::mesh::MorphTarget>,
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::mesh::MorphTarget>>,
 }
 
-/// An `Iterator` that visits the attributes of a `Primitive`.
+/// An `Iterator` that visits the attributes of a `Primitive` in the order they
+/// are declared.
 #[derive(Clone, Debug)]
 pub struct Attributes<'a> {
     /// The parent `Document` struct.
@@ -23,11 +28,25 @@ pub struct Attributes<'a> {
     pub(crate) prim: Primitive<'a>,
 
     /// The internal attribute iterator.
-    pub(crate) iter: collections::hash_map::Iter<
-            'a,
+    pub(crate) iter: json::map::Iter<
+        'a,
         json::validation::Checked<json::mesh::Semantic>,
         json::Index<json::accessor::Accessor>,
-        >,
+    >,
+}
+
+/// An `Iterator` that visits the attributes of a Draco compressed `Primitive`
+/// in the order they are declared.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+#[derive(Clone, Debug)]
+pub struct DracoAttributes<'a> {
+    /// The internal attribute iterator.
+    pub(crate) iter: json::map::Iter<
+        'a,
+        json::validation::Checked<json::mesh::Semantic>,
+        u32,
+    >,
 }
 
 /// An `Iterator` that visits the primitives of a `Mesh`.
@@ -58,6 +77,20 @@ impl<'a> Iterator for Attributes<'a> {
     }
 }
 
+#[cfg(feature = "KHR_draco_mesh_compression")]
+impl<'a> ExactSizeIterator for DracoAttributes<'a> {}
+#[cfg(feature = "KHR_draco_mesh_compression")]
+impl<'a> Iterator for DracoAttributes<'a> {
+    type Item = (super::Semantic, u32);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(key, id)| (key.as_ref().unwrap().clone(), *id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 impl<'a> ExactSizeIterator for Primitives<'a> {}
 impl<'a> Iterator for Primitives<'a> {
     type Item = Primitive<'a>;
@@ -79,7 +112,12 @@ impl<'a> Iterator for Primitives<'a> {
     }
 }
 
-fn map_morph_target<'a>(document: &'a crate::Document, json: &json::mesh::MorphTarget) -> MorphTarget<'a> {
+fn map_morph_target<'a>(
+    targets: &MorphTargets<'a>,
+    index: usize,
+    json: &'a json::mesh::MorphTarget,
+) -> MorphTarget<'a> {
+    let document = targets.document;
     let positions = json.positions
         .as_ref()
         .map(|index| document.accessors().nth(index.value()).unwrap());
@@ -90,6 +128,11 @@ fn map_morph_target<'a>(document: &'a crate::Document, json: &json::mesh::MorphT
         .as_ref()
         .map(|index| document.accessors().nth(index.value()).unwrap());
     MorphTarget {
+        index,
+        document,
+        json,
+        #[cfg(feature = "extras")]
+        mesh: targets.mesh,
         positions,
         normals,
         tangents,
@@ -100,9 +143,8 @@ impl<'a> ExactSizeIterator for MorphTargets<'a> {}
 impl<'a> Iterator for MorphTargets<'a> {
     type Item = MorphTarget<'a>;
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter
-            .next()
-            .map(|json| map_morph_target(self.document, json))
+        let (index, json) = self.iter.next()?;
+        Some(map_morph_target(self, index, json))
     }
     fn size_hint(&self) -> (usize, Option<usize>) {
         self.iter.size_hint()
@@ -110,11 +152,12 @@ impl<'a> Iterator for MorphTargets<'a> {
     fn count(self) -> usize {
         self.iter.count()
     }
-    fn last(self) -> Option<Self::Item> {
-        let document = self.document;
-        self.iter.last().map(|json| map_morph_target(document, json))
+    fn last(mut self) -> Option<Self::Item> {
+        let (index, json) = self.iter.next_back()?;
+        Some(map_morph_target(&self, index, json))
     }
     fn nth(&mut self, n: usize) -> Option<Self::Item> {
-        self.iter.nth(n).map(|json| map_morph_target(self.document, json))
+        let (index, json) = self.iter.nth(n)?;
+        Some(map_morph_target(self, index, json))
     }
 }
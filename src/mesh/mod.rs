@@ -57,6 +57,8 @@ pub mod iter;
 pub mod util;
 
 use crate::{Accessor, Buffer, Document, Material};
+use crate::accessor::{DataType, Dimensions};
+use crate::material::AlphaMode;
 
 #[cfg(feature = "utils")]
 use crate::accessor;
@@ -80,6 +82,195 @@ pub struct Bounds<T> {
     pub max: T,
 }
 
+/// The render state of a primitive, resolved from its material.
+///
+/// The default value is the render state of the default material.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderState {
+    /// The alpha rendering mode.
+    pub alpha_mode: AlphaMode,
+
+    /// The alpha cutoff value, or `None` unless `alpha_mode` is `Mask`.
+    ///
+    /// Defaults to `0.5` in `Mask` mode.
+    pub alpha_cutoff: Option<f32>,
+
+    /// Whether back faces are rendered, with their normals reversed.
+    pub double_sided: bool,
+}
+
+/// The format of the elements of a vertex attribute.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VertexFormat {
+    /// The component data type.
+    pub data_type: DataType,
+
+    /// The number of components per element.
+    pub dimensions: Dimensions,
+
+    /// Whether integer components are normalized to `[0, 1]` or `[-1, 1]`.
+    pub normalized: bool,
+}
+
+impl VertexFormat {
+    /// Returns the name of the equivalent WebGPU vertex format, such as
+    /// `"unorm8x4"`, which `wgpu::VertexFormat` mirrors variant for variant.
+    ///
+    /// Returns `None` if no WebGPU vertex format has the same layout, for example
+    /// for three 8-bit or 16-bit components or for matrices.
+    pub fn webgpu_name(&self) -> Option<&'static str> {
+        use self::DataType::*;
+        use self::Dimensions::*;
+        let name = match (self.data_type, self.normalized, self.dimensions) {
+            (F32, false, Scalar) => "float32",
+            (F32, false, Vec2) => "float32x2",
+            (F32, false, Vec3) => "float32x3",
+            (F32, false, Vec4) => "float32x4",
+            (U32, false, Scalar) => "uint32",
+            (U32, false, Vec2) => "uint32x2",
+            (U32, false, Vec3) => "uint32x3",
+            (U32, false, Vec4) => "uint32x4",
+            (U8, false, Vec2) => "uint8x2",
+            (U8, false, Vec4) => "uint8x4",
+            (U8, true, Vec2) => "unorm8x2",
+            (U8, true, Vec4) => "unorm8x4",
+            (I8, false, Vec2) => "sint8x2",
+            (I8, false, Vec4) => "sint8x4",
+            (I8, true, Vec2) => "snorm8x2",
+            (I8, true, Vec4) => "snorm8x4",
+            (U16, false, Vec2) => "uint16x2",
+            (U16, false, Vec4) => "uint16x4",
+            (U16, true, Vec2) => "unorm16x2",
+            (U16, true, Vec4) => "unorm16x4",
+            (I16, false, Vec2) => "sint16x2",
+            (I16, false, Vec4) => "sint16x4",
+            (I16, true, Vec2) => "snorm16x2",
+            (I16, true, Vec4) => "snorm16x4",
+            _ => return None,
+        };
+        Some(name)
+    }
+}
+
+#[cfg(feature = "wgpu-types")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu-types")))]
+impl VertexFormat {
+    /// Returns the equivalent `wgpu_types::VertexFormat`.
+    ///
+    /// Returns `None` for the formats without a WebGPU name, see `webgpu_name`.
+    pub fn to_wgpu(&self) -> Option<wgpu_types::VertexFormat> {
+        use self::DataType::*;
+        use self::Dimensions::*;
+        use wgpu_types::VertexFormat as Wgpu;
+        let format = match (self.data_type, self.normalized, self.dimensions) {
+            (F32, false, Scalar) => Wgpu::Float32,
+            (F32, false, Vec2) => Wgpu::Float32x2,
+            (F32, false, Vec3) => Wgpu::Float32x3,
+            (F32, false, Vec4) => Wgpu::Float32x4,
+            (U32, false, Scalar) => Wgpu::Uint32,
+            (U32, false, Vec2) => Wgpu::Uint32x2,
+            (U32, false, Vec3) => Wgpu::Uint32x3,
+            (U32, false, Vec4) => Wgpu::Uint32x4,
+            (U8, false, Vec2) => Wgpu::Uint8x2,
+            (U8, false, Vec4) => Wgpu::Uint8x4,
+            (U8, true, Vec2) => Wgpu::Unorm8x2,
+            (U8, true, Vec4) => Wgpu::Unorm8x4,
+            (I8, false, Vec2) => Wgpu::Sint8x2,
+            (I8, false, Vec4) => Wgpu::Sint8x4,
+            (I8, true, Vec2) => Wgpu::Snorm8x2,
+            (I8, true, Vec4) => Wgpu::Snorm8x4,
+            (U16, false, Vec2) => Wgpu::Uint16x2,
+            (U16, false, Vec4) => Wgpu::Uint16x4,
+            (U16, true, Vec2) => Wgpu::Unorm16x2,
+            (U16, true, Vec4) => Wgpu::Unorm16x4,
+            (I16, false, Vec2) => Wgpu::Sint16x2,
+            (I16, false, Vec4) => Wgpu::Sint16x4,
+            (I16, true, Vec2) => Wgpu::Snorm16x2,
+            (I16, true, Vec4) => Wgpu::Snorm16x4,
+            _ => return None,
+        };
+        Some(format)
+    }
+}
+
+/// An accessor whose elements have no equivalent `wgpu` vertex or index
+/// format, returned by `Accessor::wgpu_vertex_format` and
+/// `Accessor::wgpu_index_format`.
+#[cfg(feature = "wgpu-types")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wgpu-types")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnsupportedFormat {
+    /// The index of the accessor.
+    pub accessor: usize,
+
+    /// The format of the elements of the accessor.
+    pub format: VertexFormat,
+}
+
+#[cfg(feature = "wgpu-types")]
+impl std::fmt::Display for UnsupportedFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "accessor {}: no wgpu format for {}{:?} {:?} elements",
+            self.accessor,
+            if self.format.normalized { "normalized " } else { "" },
+            self.format.data_type,
+            self.format.dimensions,
+        )
+    }
+}
+
+#[cfg(feature = "wgpu-types")]
+impl std::error::Error for UnsupportedFormat {}
+
+/// Returns the name of the WebGPU index format, `"uint16"` or `"uint32"`, for
+/// indices of the given data type, which `wgpu::IndexFormat` mirrors.
+///
+/// Returns `None` for 8-bit indices, which must be widened to 16 bits first.
+pub fn webgpu_index_format(data_type: DataType) -> Option<&'static str> {
+    match data_type {
+        DataType::U16 => Some("uint16"),
+        DataType::U32 => Some("uint32"),
+        _ => None,
+    }
+}
+
+/// The range of a buffer holding the data of a vertex attribute, in a layout
+/// that can be bound directly as a GPU vertex buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VertexBinding {
+    /// The index of the buffer holding the data.
+    pub buffer: usize,
+
+    /// The offset in bytes of the first element within the buffer.
+    pub offset: usize,
+
+    /// The distance in bytes between the starts of consecutive elements.
+    pub stride: usize,
+
+    /// The format of the elements.
+    pub format: VertexFormat,
+}
+
+/// The reason the data of a vertex attribute must be repacked, for example
+/// through the primitive reader, before it can be uploaded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Repack {
+    /// The accessor is sparse, so its values are not held in a single range.
+    Sparse,
+
+    /// The accessor has no buffer view and holds only zeros.
+    Missing,
+
+    /// The element type has no equivalent WebGPU vertex format, as for three
+    /// 8-bit or 16-bit components or for matrices.
+    Format,
+
+    /// The offset or the stride is not a multiple of four bytes.
+    Unaligned,
+}
+
 /// A set of primitives to be rendered.
 #[derive(Clone, Debug)]
 pub struct Mesh<'a>  {
@@ -96,6 +287,19 @@ pub struct Mesh<'a>  {
 /// A single morph target for a mesh primitive.
 #[derive(Clone, Debug)]
 pub struct MorphTarget<'a> {
+    /// The index of the morph target within its primitive.
+    index: usize,
+
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::mesh::MorphTarget,
+
+    /// The JSON struct of the parent mesh.
+    #[cfg(feature = "extras")]
+    mesh: &'a json::mesh::Mesh,
+
     /// XYZ vertex position displacements.
     positions: Option<Accessor<'a>>,
 
@@ -106,6 +310,25 @@ pub struct MorphTarget<'a> {
     tangents: Option<Accessor<'a>>,
 }
 
+/// Draco compressed geometry of a `Primitive`.
+///
+/// The data must be decompressed with a Draco decoder before it can be read;
+/// the accessors of the primitive describe the decompressed data. This library
+/// does not include a decoder, so `Reader` returns `None` for attributes whose
+/// accessors have no buffer view until the document is passed to
+/// `draco::decompress` with one, and `KHR_draco_mesh_compression` is not listed
+/// in `json::extensions::ENABLED_EXTENSIONS`.
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+#[derive(Clone, Debug)]
+pub struct DracoMeshCompression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::DracoMeshCompression,
+}
+
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug)]
 pub struct Primitive<'a>  {
@@ -119,6 +342,82 @@ pub struct Primitive<'a>  {
     json: &'a json::mesh::Primitive,
 }
 
+#[cfg(feature = "utils")]
+impl Bounds<[f32; 3]> {
+    /// Returns the smallest box containing both boxes.
+    pub(crate) fn union(&self, other: &Self) -> Self {
+        let mut bounds = self.clone();
+        for i in 0..3 {
+            bounds.min[i] = bounds.min[i].min(other.min[i]);
+            bounds.max[i] = bounds.max[i].max(other.max[i]);
+        }
+        bounds
+    }
+
+    /// Returns the smallest box containing the corners of this box transformed
+    /// by a column-major matrix.
+    pub(crate) fn transformed(&self, matrix: [[f32; 4]; 4]) -> Self {
+        let m = &matrix;
+        let mut bounds = Bounds { min: [f32::INFINITY; 3], max: [f32::NEG_INFINITY; 3] };
+        for corner in 0..8 {
+            let x = if corner & 1 == 0 { self.min[0] } else { self.max[0] };
+            let y = if corner & 2 == 0 { self.min[1] } else { self.max[1] };
+            let z = if corner & 4 == 0 { self.min[2] } else { self.max[2] };
+            let p = [
+                m[0][0] * x + m[1][0] * y + m[2][0] * z + m[3][0],
+                m[0][1] * x + m[1][1] * y + m[2][1] * z + m[3][1],
+                m[0][2] * x + m[1][2] * y + m[2][2] * z + m[3][2],
+            ];
+            bounds = bounds.union(&Bounds { min: p, max: p });
+        }
+        bounds
+    }
+}
+
+impl RenderState {
+    /// Resolves the render state of a material.
+    pub(crate) fn new(material: &Material) -> Self {
+        let alpha_mode = material.alpha_mode();
+        let alpha_cutoff = match alpha_mode {
+            AlphaMode::Mask => Some(material.alpha_cutoff().unwrap_or(0.5)),
+            _ => None,
+        };
+        RenderState {
+            alpha_mode,
+            alpha_cutoff,
+            double_sided: material.double_sided(),
+        }
+    }
+
+    /// Returns `true` if back faces should be culled.
+    pub fn cull_back_faces(&self) -> bool {
+        !self.double_sided
+    }
+
+    /// Returns `true` if the primitive should be composited with the background.
+    pub fn blend(&self) -> bool {
+        self.alpha_mode == AlphaMode::Blend
+    }
+
+    /// Returns `true` if the primitive should write to the depth buffer.
+    ///
+    /// Blended primitives are conventionally drawn without depth writes after
+    /// all other primitives.
+    pub fn depth_write(&self) -> bool {
+        !self.blend()
+    }
+}
+
+impl Default for RenderState {
+    fn default() -> Self {
+        Self {
+            alpha_mode: AlphaMode::Opaque,
+            alpha_cutoff: None,
+            double_sided: false,
+        }
+    }
+}
+
 /// Mesh primitive reader.
 #[derive(Clone, Debug)]
 pub struct Reader<'a, 's, F>
@@ -168,10 +467,76 @@ impl<'a> Mesh<'a>  {
         }
     }
 
+    /// Returns the bounds of the `POSITION` attributes of all primitives of the
+    /// mesh, or `None` if no primitive has positions.
+    ///
+    /// The accessor `min` and `max` are used where present and valid, and the
+    /// positions scanned otherwise. Morph target displacements are ignored.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn bounding_box<'s, F>(&self, get_buffer_data: F) -> Option<BoundingBox>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        self.primitives()
+            .filter_map(|primitive| primitive.position_bounds(get_buffer_data.clone()))
+            .fold(None, |bounds: Option<BoundingBox>, x| {
+                Some(bounds.map_or(x.clone(), |bounds| bounds.union(&x)))
+            })
+    }
+
     /// Defines the weights to be applied to the morph targets.
     pub fn weights(&self) -> Option<&'a [f32]> {
         self.json.weights.as_ref().map(Vec::as_slice)
     }
+
+    /// Returns the morph target names recorded under the `"targetNames"` key of
+    /// `extras`, if any.
+    ///
+    /// See `json::Mesh::set_target_names` for recording names on export.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn target_names(&self) -> Option<Vec<String>> {
+        self.json.target_names()
+    }
+
+    /// Returns the tags recorded under the `"tags"` key of `extras`.
+    ///
+    /// See `json::extras::Taggable` for tagging meshes on export.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn tags(&self) -> Vec<String> {
+        json::extras::Taggable::tags(self.json)
+    }
+
+    /// Returns whether the mesh carries the given tag.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        json::extras::Taggable::has_tag(self.json, tag)
+    }
+}
+
+/// Returns whether a byte count is a multiple of four.
+fn is_aligned(bytes: usize) -> bool {
+    bytes & 3 == 0
+}
+
+/// Describes the buffer range of a vertex attribute accessor, if it can be
+/// bound directly.
+fn vertex_binding(accessor: &Accessor) -> Result<VertexBinding, Repack> {
+    if accessor.sparse().is_some() {
+        return Err(Repack::Sparse);
+    }
+    let view = accessor.view().ok_or(Repack::Missing)?;
+    let format = accessor.vertex_format();
+    format.webgpu_name().ok_or(Repack::Format)?;
+    let offset = view.offset() + accessor.offset();
+    let stride = view.stride().unwrap_or_else(|| accessor.size());
+    if !is_aligned(offset) || !is_aligned(stride) {
+        return Err(Repack::Unaligned);
+    }
+    Ok(VertexBinding { buffer: view.buffer().index(), offset, stride, format })
 }
 
 impl<'a> Primitive<'a> {
@@ -198,6 +563,37 @@ impl<'a> Primitive<'a> {
         Bounds { min, max }
     }
 
+    /// Returns the bounds of the `POSITION` attribute from the accessor `min`
+    /// and `max`, or by scanning the positions if those are absent or invalid.
+    #[cfg(feature = "utils")]
+    fn position_bounds<'s, F>(&self, get_buffer_data: F) -> Option<BoundingBox>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let accessor = self.get(&Semantic::Positions)?;
+        let declared = accessor.min().zip(accessor.max()).and_then(|(min, max)| {
+            let min: [f32; 3] = json::deserialize::from_value(min).ok()?;
+            let max: [f32; 3] = json::deserialize::from_value(max).ok()?;
+            let valid = (0..3).all(|i| {
+                min[i].is_finite() && max[i].is_finite() && min[i] <= max[i]
+            });
+            if valid {
+                Some(Bounds { min, max })
+            } else {
+                None
+            }
+        });
+        if declared.is_some() || accessor.data_type() != accessor::DataType::F32 {
+            return declared;
+        }
+        accessor::Iter::<[f32; 3]>::new(accessor, get_buffer_data)?
+            .filter(|p| p.iter().all(|x| x.is_finite()))
+            .fold(None, |bounds: Option<BoundingBox>, p| {
+                let point = Bounds { min: p, max: p };
+                Some(bounds.map_or(point.clone(), |bounds| bounds.union(&point)))
+            })
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -210,6 +606,17 @@ impl<'a> Primitive<'a> {
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
+    /// Returns the number of sets of joint influences, which are the sets
+    /// `0..n` of both `JOINTS_n` and `WEIGHTS_n` attributes.
+    pub fn influence_sets(&self) -> u32 {
+        (0..)
+            .take_while(|&set| {
+                self.get(&Semantic::Joints(set)).is_some()
+                    && self.get(&Semantic::Weights(set)).is_some()
+            })
+            .count() as u32
+    }
+
     /// Returns the internal JSON index.
     pub fn index(&self) -> usize {
         self.index
@@ -222,7 +629,8 @@ impl<'a> Primitive<'a> {
             .map(|index| self.mesh.document.accessors().nth(index.value()).unwrap())
     }
 
-    /// Returns an `Iterator` that visits the vertex attributes.
+    /// Returns an `Iterator` that visits the vertex attributes in the order they
+    /// are declared.
     pub fn attributes(&self) -> iter::Attributes<'a> {
         iter::Attributes {
             document: self.mesh.document,
@@ -231,6 +639,16 @@ impl<'a> Primitive<'a> {
         }
     }
 
+    /// Returns the application specific attributes, such as `_BATCHID` or
+    /// `_FEATURE_ID_0`, paired with their names including the leading
+    /// underscore, in the order they are declared.
+    pub fn custom_attributes(&self) -> Vec<(String, Accessor<'a>)> {
+        self.attributes()
+            .filter(|(semantic, _)| matches!(semantic, Semantic::Extras(_)))
+            .map(|(semantic, accessor)| (semantic.to_string(), accessor))
+            .collect()
+    }
+
     /// Returns the material to apply to this primitive when rendering
     pub fn material(&self) -> Material<'a> {
         self.json.material
@@ -239,23 +657,69 @@ impl<'a> Primitive<'a> {
             .unwrap_or_else(|| Material::default(self.mesh.document))
     }
 
+    /// Returns the material to apply to this primitive when rendering the given
+    /// variant of `Document::variants`.
+    ///
+    /// Primitives without a `KHR_materials_variants` mapping for the variant use
+    /// their default material, as returned by `material`.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn material_for_variant(&self, variant: usize) -> Material<'a> {
+        let mapping = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+            .and_then(|variants| {
+                variants.mappings.iter().find(|mapping| {
+                    mapping.variants.iter().any(|index| index.value() == variant)
+                })
+            });
+        match mapping {
+            Some(mapping) => self.mesh.document.materials().nth(mapping.material.value()).unwrap(),
+            None => self.material(),
+        }
+    }
+
+    /// Returns the render state implied by the material of this primitive.
+    pub fn resolved_render_state(&self) -> RenderState {
+        RenderState::new(&self.material())
+    }
+
     /// The type of primitives to render.
     pub fn mode(&self) -> Mode {
         self.json.mode.unwrap()
     }
 
+    /// Describes where the data of each vertex attribute lies within its
+    /// buffer, so that the original buffer ranges can be bound directly rather
+    /// than copied through the reader.
+    ///
+    /// Attributes are visited in the same order as by `attributes`. The
+    /// layout of an attribute is `Err` when its data must be repacked first.
+    pub fn vertex_buffer_layout(&self) -> Vec<(Semantic, Result<VertexBinding, Repack>)> {
+        self.attributes()
+            .map(|(semantic, accessor)| (semantic, vertex_binding(&accessor)))
+            .collect()
+    }
+
+    /// Returns the Draco compressed geometry of the primitive, from the
+    /// `KHR_draco_mesh_compression` extension.
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_draco_mesh_compression")))]
+    pub fn draco_mesh_compression(&self) -> Option<DracoMeshCompression<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .khr_draco_mesh_compression.as_ref()
+            .map(|json| DracoMeshCompression { document: self.mesh.document, json })
+    }
+
     /// Returns an `Iterator` that visits the morph targets of the primitive.
     pub fn morph_targets(&self) -> iter::MorphTargets<'a> {
-        if let Some(slice) = self.json.targets.as_ref() {
-            iter::MorphTargets {
-                document: self.mesh.document,
-                iter: slice.iter(),
-            }
-        } else {
-            iter::MorphTargets {
-                document: self.mesh.document,
-                iter: (&[]).iter(),
-            }
+        let slice = self.json.targets.as_deref().unwrap_or(&[]);
+        iter::MorphTargets {
+            document: self.mesh.document,
+            #[cfg(feature = "extras")]
+            mesh: self.mesh.json,
+            iter: slice.iter().enumerate(),
         }
     }
 
@@ -277,25 +741,45 @@ impl<'a> Primitive<'a> {
 impl<'a, 's, F> Reader<'a, 's, F>
     where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
+    /// Returns `true` if the document declares `KHR_mesh_quantization`.
+    fn is_quantized(&self) -> bool {
+        self.primitive.mesh.document.extensions_used().any(|x| x == "KHR_mesh_quantization")
+    }
+
+    /// Reads an attribute of `f32` components, also accepting the integer
+    /// components permitted by `KHR_mesh_quantization` if it is declared.
+    fn read_floats<T>(&self, semantic: &Semantic) -> Option<accessor::util::ReadF32<'s, T>>
+    where
+        T: accessor::util::Floats,
+    {
+        let accessor = self.primitive.get(semantic)?;
+        if self.is_quantized() {
+            accessor::util::ReadF32::new_quantized(accessor, self.get_buffer_data.clone())
+        } else {
+            accessor::util::ReadF32::new(accessor, self.get_buffer_data.clone())
+        }
+    }
+
     /// Visits the vertex positions of a primitive.
+    ///
+    /// With `KHR_mesh_quantization`, integer positions are dequantized to
+    /// `f32` by the normalization rules of the accessor, and unnormalized
+    /// components are converted unchanged. Quantized positions are scaled
+    /// into place by the transform of the node instantiating the mesh.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
-        self.primitive
-            .get(&Semantic::Positions)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        self.read_floats(&Semantic::Positions)
     }
 
-    /// Visits the vertex normals of a primitive.
+    /// Visits the vertex normals of a primitive, dequantizing normalized
+    /// integer normals permitted by `KHR_mesh_quantization`.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
-        self.primitive
-            .get(&Semantic::Normals)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        self.read_floats(&Semantic::Normals)
     }
 
-    /// Visits the vertex tangents of a primitive.
+    /// Visits the vertex tangents of a primitive, dequantizing normalized
+    /// integer tangents permitted by `KHR_mesh_quantization`.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
-        self.primitive
-            .get(&Semantic::Tangents)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+        self.read_floats(&Semantic::Tangents)
     }
 
     /// Visits the vertex colors of a primitive.
@@ -350,17 +834,27 @@ impl<'a, 's, F> Reader<'a, 's, F>
     }
 
     /// Visits the vertex texture co-ordinates of a primitive.
+    ///
+    /// With `KHR_mesh_quantization`, signed and unnormalized integer
+    /// co-ordinates are read as `ReadTexCoords::Quantized`.
     pub fn read_tex_coords(&self, set: u32) -> Option<util::ReadTexCoords<'s>> {
         use accessor::DataType;
         use self::util::ReadTexCoords;
         self.primitive
             .get(&Semantic::TexCoords(set))
             .and_then(|accessor| {
+                let get_buffer_data = self.get_buffer_data.clone();
+                let is_quantized = self.is_quantized();
+                let is_normalized = accessor.normalized() || !is_quantized;
                 match accessor.data_type() {
-                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U8),
-                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U16),
-                    DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::F32),
-                    _ => unreachable!(),
+                    DataType::U8 if is_normalized => accessor::Iter::new(accessor, get_buffer_data).map(ReadTexCoords::U8),
+                    DataType::U16 if is_normalized => accessor::Iter::new(accessor, get_buffer_data).map(ReadTexCoords::U16),
+                    DataType::F32 => accessor::Iter::new(accessor, get_buffer_data).map(ReadTexCoords::F32),
+                    _ if is_quantized => {
+                        accessor::util::ReadF32::new_quantized(accessor, get_buffer_data)
+                            .map(ReadTexCoords::Quantized)
+                    },
+                    _ => None,
                 }
             })
     }
@@ -381,6 +875,89 @@ impl<'a, 's, F> Reader<'a, 's, F>
             })
     }
 
+    /// Visits the joint influences of every set of `JOINTS_n` and `WEIGHTS_n`
+    /// attributes together, for skinning with more than four joints per
+    /// vertex.
+    ///
+    /// Returns `None` if the primitive has no joints and weights or the data
+    /// of a set is unavailable.
+    pub fn read_influences(&self) -> Option<util::ReadInfluences<'s>> {
+        let sets = (0..self.primitive.influence_sets())
+            .map(|set| {
+                let joints = self.read_joints(set)?.into_u16();
+                let weights = self.read_weights(set)?.into_f32();
+                Some((joints, weights))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if sets.is_empty() {
+            return None;
+        }
+        Some(util::ReadInfluences { sets })
+    }
+
+    /// Visits the vertex index triples of the triangles of a primitive drawn as
+    /// triangles, a triangle strip, or a triangle fan.
+    ///
+    /// Non-indexed primitives draw their vertices in order, as many as there
+    /// are positions. Returns `None` for other modes or if the indices or
+    /// positions are unavailable.
+    pub fn read_triangles(&self) -> Option<util::ReadTriangles> {
+        let mode = self.primitive.mode();
+        match mode {
+            Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan => {},
+            _ => return None,
+        }
+        let indices = match self.primitive.indices() {
+            Some(_) => self.read_indices()?.into_u32().collect(),
+            None => (0..self.primitive.get(&Semantic::Positions)?.count() as u32).collect(),
+        };
+        Some(util::ReadTriangles { indices, mode, triangle: 0 })
+    }
+
+    /// Visits the vertex positions of the triangles of a primitive, as visited
+    /// by `read_triangles`, for collision detection and ray casting.
+    pub fn read_triangle_positions(&self) -> Option<util::ReadTrianglePositions> {
+        let triangles = self.read_triangles()?;
+        let positions = self.read_positions()?.collect();
+        Some(util::ReadTrianglePositions { triangles, positions })
+    }
+
+    /// Computes a tangent of each vertex of a primitive without a `TANGENT`
+    /// attribute, as glTF asks of clients rendering its normal texture.
+    ///
+    /// Tangents are computed from the positions, normals, and the texture
+    /// co-ordinates used by the normal texture of the material, using the
+    /// angle weighting of MikkTSpace. The `w` component is the handedness, as
+    /// for `read_tangents`.
+    ///
+    /// The results are not MikkTSpace compatible at UV seams and the mirror
+    /// lines of mirrored UVs: vertices are not split where the tangent space
+    /// is discontinuous, so a vertex shared across such a seam gets a single
+    /// averaged tangent, or a fallback tangent where the tangents cancel out.
+    /// Normal maps baked against MikkTSpace tangents may show artifacts there.
+    ///
+    /// Returns `None` if the primitive does not draw triangles, or if its
+    /// positions, normals, texture co-ordinates, or indices are unavailable.
+    pub fn generate_tangents(&self) -> Option<Vec<[f32; 4]>> {
+        let set = self.primitive.material().normal_texture().map_or(0, |x| x.tex_coord());
+        let positions: Vec<_> = self.read_positions()?.collect();
+        let normals: Vec<_> = self.read_normals()?.collect();
+        let tex_coords: Vec<_> = self.read_tex_coords(set)?.into_f32().collect();
+        let triangles = self.read_triangles()?;
+        Some(util::generate_tangents(&positions, &normals, &tex_coords, triangles))
+    }
+
+    /// Visits the elements of the application specific attribute with the
+    /// given name, including its leading underscore, as raw bytes.
+    ///
+    /// Returns `None` if the name does not start with an underscore, the
+    /// primitive has no such attribute, or its data is unavailable.
+    pub fn read_custom(&self, name: &str) -> Option<accessor::util::ReadRaw<'s>> {
+        let semantic = Semantic::Extras(name.strip_prefix('_')?.to_string());
+        let accessor = self.primitive.get(&semantic)?;
+        accessor::util::ReadRaw::new(accessor, self.get_buffer_data.clone())
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
@@ -388,9 +965,68 @@ impl<'a, 's, F> Reader<'a, 's, F>
             reader: self.clone(),
         }
     }
+
+    /// Visits the displacements of the attribute with the given semantic in
+    /// the morph target with the given index, such as the `TEXCOORD_0`
+    /// displacements that `read_morph_targets` does not visit.
+    ///
+    /// Normalized integer displacements are decoded to `f32`.
+    pub fn read_displacements(
+        &self,
+        target: usize,
+        semantic: &Semantic,
+    ) -> Option<util::ReadDisplacements<'s>> {
+        use accessor::util::ReadF32;
+        use accessor::Dimensions;
+        use self::util::ReadDisplacements;
+        let accessor = self.primitive.morph_targets().nth(target)?.get(semantic)?;
+        let data = self.get_buffer_data.clone();
+        match accessor.dimensions() {
+            Dimensions::Scalar => ReadF32::new(accessor, data).map(ReadDisplacements::Scalar),
+            Dimensions::Vec2 => ReadF32::new(accessor, data).map(ReadDisplacements::Vec2),
+            Dimensions::Vec3 => ReadF32::new(accessor, data).map(ReadDisplacements::Vec3),
+            Dimensions::Vec4 => ReadF32::new(accessor, data).map(ReadDisplacements::Vec4),
+            _ => None,
+        }
+    }
+
+    /// Views interleaved vertex data as a slice of `V`.
+    ///
+    /// Each entry of `layout` pairs an attribute semantic with the byte offset of
+    /// the corresponding field within `V`. The attributes must share a single
+    /// buffer view whose stride equals the size of `V`, and their offsets must
+    /// match the layout, so that each vertex can be copied as-is, for example
+    /// straight to GPU memory. The data is borrowed from the buffer when it is
+    /// suitably aligned and copied otherwise.
+    ///
+    /// Setting the field types of `V` to match the accessor component types is
+    /// the responsibility of the caller.
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn read_pod<V: bytemuck::AnyBitPattern>(
+        &self,
+        layout: &[(Semantic, usize)],
+    ) -> Result<std::borrow::Cow<'s, [V]>, util::pod::Error> {
+        util::pod::read(self.primitive, self.get_buffer_data.clone(), layout)
+    }
 }
 
 impl<'a> MorphTarget<'a> {
+    /// Returns the index of the morph target within its primitive.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of the morph target, if recorded under the
+    /// `"targetNames"` key of the parent mesh `extras`.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn name(&self) -> Option<String> {
+        self.mesh
+            .target_names()
+            .and_then(|names| names.into_iter().nth(self.index))
+    }
+
     /// Returns the XYZ vertex position displacements.
     pub fn positions(&self) -> Option<Accessor<'a>> {
         self.positions.clone()
@@ -405,4 +1041,304 @@ impl<'a> MorphTarget<'a> {
     pub fn tangents(&self) -> Option<Accessor<'a>> {
         self.tangents.clone()
     }
+
+    /// Returns the displacements of the attribute with the given semantic.
+    pub fn get(&self, semantic: &Semantic) -> Option<Accessor<'a>> {
+        match semantic {
+            Semantic::Positions => self.positions(),
+            Semantic::Normals => self.normals(),
+            Semantic::Tangents => self.tangents(),
+            _ => {
+                let index = self.json.others.get(&Checked::Valid(semantic.clone()))?;
+                self.document.accessors().nth(index.value())
+            },
+        }
+    }
+
+    /// Returns the displacements of every attribute of the morph target,
+    /// `POSITION`, `NORMAL`, and `TANGENT` first and the others in the order
+    /// they are declared.
+    pub fn attributes(&self) -> Vec<Attribute<'a>> {
+        let others: Vec<_> = self.json.others
+            .keys()
+            .filter_map(|semantic| match semantic {
+                Checked::Valid(semantic) => Some(semantic.clone()),
+                Checked::Invalid => None,
+            })
+            .collect();
+        [Semantic::Positions, Semantic::Normals, Semantic::Tangents]
+            .iter()
+            .cloned()
+            .chain(others)
+            .filter_map(|semantic| Some((semantic.clone(), self.get(&semantic)?)))
+            .collect()
+    }
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+impl<'a> DracoMeshCompression<'a> {
+    /// Returns the buffer view holding the compressed data.
+    pub fn view(&self) -> crate::buffer::View<'a> {
+        self.document.views().nth(self.json.buffer_view.value()).unwrap()
+    }
+
+    /// Returns the unique id in the compressed data of the attribute with the
+    /// given semantic.
+    pub fn attribute_id(&self, semantic: &Semantic) -> Option<u32> {
+        self.json.attributes.get(&Checked::Valid(semantic.clone())).copied()
+    }
+
+    /// Returns an `Iterator` that visits the attribute semantics of the
+    /// primitive along with their unique ids in the compressed data.
+    pub fn attributes(&self) -> iter::DracoAttributes<'a> {
+        iter::DracoAttributes { iter: self.json.attributes.iter() }
+    }
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn read_quantized_attributes() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let positions = packer.push_accessor(&mut root, &[[-2i16, 0, 300], [4, 5, 6]], None);
+        let normals = packer.push_accessor(&mut root, &[[0i8, 127, 0], [-127, 0, 0]], None);
+        let tex_coords = packer.push_accessor(&mut root, &[[0u16, 2], [1000, 3]], None);
+        root.accessors[normals.value()].normalized = true;
+        let bin = packer.finish(&mut root);
+        let mesh = format!(
+            r#"{{"primitives": [{{"attributes": {{
+                "POSITION": {}, "NORMAL": {}, "TEXCOORD_0": {}
+            }}}}]}}"#,
+            positions.value(),
+            normals.value(),
+            tex_coords.value(),
+        );
+        root.meshes.push(json::deserialize::from_str(&mesh).unwrap());
+
+        let document = Document::from_json_without_validation(root.clone());
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|_| Some(&bin[..]));
+        assert!(reader.read_positions().is_none());
+
+        root.extensions_used.push("KHR_mesh_quantization".to_string());
+        let document = Document::from_json_without_validation(root);
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|_| Some(&bin[..]));
+        let positions: Vec<_> = reader.read_positions().unwrap().collect();
+        assert_eq!(positions, [[-2.0, 0.0, 300.0], [4.0, 5.0, 6.0]]);
+        let normals: Vec<_> = reader.read_normals().unwrap().collect();
+        assert_eq!(normals, [[0.0, 1.0, 0.0], [-1.0, 0.0, 0.0]]);
+        let tex_coords: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+        assert_eq!(tex_coords, [[0.0, 2.0], [1000.0, 3.0]]);
+    }
+
+    #[test]
+    fn read_morph_target_displacements() {
+        use crate::mesh::util::ReadDisplacements;
+        use crate::Semantic;
+
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let positions = packer.push_accessor(&mut root, &[[0.0f32; 3], [1.0, 0.0, 0.0]], None);
+        let tangents = packer.push_accessor(&mut root, &[[0.0f32, 1.0, 0.0], [0.0; 3]], None);
+        let uvs = packer.push_accessor(&mut root, &[[-127i8, 0], [127, 127]], None);
+        root.accessors[uvs.value()].normalized = true;
+        let bin = packer.finish(&mut root);
+        let mesh = format!(
+            r#"{{"primitives": [{{
+                "attributes": {{"POSITION": {0}, "TEXCOORD_0": {2}}},
+                "targets": [{{"POSITION": {0}, "TANGENT": {1}, "TEXCOORD_0": {2}}}]
+            }}]}}"#,
+            positions.value(),
+            tangents.value(),
+            uvs.value(),
+        );
+        root.meshes.push(json::deserialize::from_str(&mesh).unwrap());
+        let json = json::serialize::to_string(&root.meshes[0]).unwrap();
+        assert!(json.contains(r#""TEXCOORD_0":2}]"#));
+
+        let document = Document::from_json_without_validation(root);
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let target = primitive.morph_targets().next().unwrap();
+        let semantics: Vec<_> = target.attributes().into_iter().map(|(x, _)| x).collect();
+        assert_eq!(semantics, [Semantic::Positions, Semantic::Tangents, Semantic::TexCoords(0)]);
+        let usages = crate::accessor::usage::usages(&document.0);
+        assert!(usages[uvs.value()].contains(&crate::accessor::Usage::MorphTarget(
+            Semantic::TexCoords(0)
+        )));
+
+        let reader = primitive.reader(|_| Some(&bin[..]));
+        let (_, _, tangents) = reader.read_morph_targets().next().unwrap();
+        assert_eq!(tangents.unwrap().next(), Some([0.0, 1.0, 0.0]));
+        match reader.read_displacements(0, &Semantic::TexCoords(0)).unwrap() {
+            ReadDisplacements::Vec2(iter) => {
+                assert_eq!(iter.collect::<Vec<_>>(), [[-1.0, 0.0], [1.0, 1.0]])
+            },
+            _ => panic!("expected two-component displacements"),
+        }
+        assert!(reader.read_displacements(0, &Semantic::Normals).is_none());
+        assert!(reader.read_displacements(1, &Semantic::Positions).is_none());
+    }
+
+    #[test]
+    fn read_custom_attributes() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let positions = packer.push_accessor(&mut root, &[[0.0f32; 3]; 3], None);
+        let batch_ids = packer.push_accessor(&mut root, &[7u16, 7, 9], None);
+        let features = packer.push_accessor(&mut root, &[0.0f32, 1.0, 2.0], None);
+        let patch = [(1, 5.0f32)];
+        let features = packer.push_accessor_patch(&mut root, (features, &[0.0, 1.0, 2.0]), &patch);
+        let bin = packer.finish(&mut root);
+        let mesh = format!(
+            r#"{{"primitives": [{{"attributes": {{
+                "POSITION": {}, "_BATCHID": {}, "_FEATURE_ID_0": {}
+            }}}}]}}"#,
+            positions.value(),
+            batch_ids.value(),
+            features.value(),
+        );
+        root.meshes.push(json::deserialize::from_str(&mesh).unwrap());
+
+        let document = Document::from_json(root).unwrap();
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let names: Vec<_> = primitive.custom_attributes().into_iter().map(|(x, _)| x).collect();
+        assert_eq!(names, ["_BATCHID", "_FEATURE_ID_0"]);
+
+        let reader = primitive.reader(|_| Some(&bin[..]));
+        let batch_ids: Vec<_> = reader.read_custom("_BATCHID").unwrap().collect();
+        assert_eq!(batch_ids, [&[7, 0][..], &[7, 0], &[9, 0]]);
+        let features: Vec<_> = reader
+            .read_custom("_FEATURE_ID_0")
+            .unwrap()
+            .map(|x| f32::from_le_bytes([x[0], x[1], x[2], x[3]]))
+            .collect();
+        assert_eq!(features, [0.0, 5.0, 2.0]);
+        assert!(reader.read_custom("BATCHID").is_none());
+        assert!(reader.read_custom("_MISSING").is_none());
+    }
+
+    #[test]
+    fn attributes_in_declaration_order() {
+        let json = r#"{
+            "asset": {"version": "2.0"},
+            "accessors": [{"componentType": 5126, "type": "VEC3", "count": 0}],
+            "meshes": [{"primitives": [{"attributes": {
+                "TEXCOORD_0": 0, "_Z": 0, "POSITION": 0, "_A": 0, "NORMAL": 0
+            }}]}]
+        }"#;
+        let root = json::Root::from_str(json).unwrap();
+        let names = |root: &json::Root| -> Vec<_> {
+            let primitive = &root.meshes[0].primitives[0];
+            primitive.attributes.keys().map(|x| x.as_ref().unwrap().to_string()).collect()
+        };
+        let order = ["TEXCOORD_0", "_Z", "POSITION", "_A", "NORMAL"];
+        assert_eq!(names(&root), order);
+        let written = json::serialize::to_string(&root).unwrap();
+        assert_eq!(names(&json::Root::from_str(&written).unwrap()), order);
+
+        let document = Document::from_json_without_validation(root);
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let semantics: Vec<_> = primitive.attributes().map(|(x, _)| x.to_string()).collect();
+        assert_eq!(semantics, order);
+        let custom: Vec<_> = primitive.custom_attributes().into_iter().map(|(x, _)| x).collect();
+        assert_eq!(custom, ["_Z", "_A"]);
+    }
+
+    #[test]
+    fn read_triangles_of_every_mode() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let square = [[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0]];
+        let positions = packer.push_accessor(&mut root, &square, None);
+        let indices = packer.push_accessor(&mut root, &[0u16, 1, 3, 2], None);
+        let bin = packer.finish(&mut root);
+        let primitive = |mode: u32, indices: Option<json::Index<json::Accessor>>| {
+            let json = format!(r#"{{"attributes": {{"POSITION": {}}}"#, positions.value());
+            let json = format!(r#"{}, "mode": {}"#, json, mode);
+            match indices {
+                Some(indices) => format!(r#"{}, "indices": {}}}"#, json, indices.value()),
+                None => json + "}",
+            }
+        };
+        let mesh = format!(
+            r#"{{"primitives": [{}, {}, {}, {}, {}]}}"#,
+            primitive(4, Some(indices)),
+            primitive(5, None),
+            primitive(6, Some(indices)),
+            primitive(4, None),
+            primitive(1, None),
+        );
+        root.meshes.push(json::deserialize::from_str(&mesh).unwrap());
+
+        let document = Document::from_json(root).unwrap();
+        let triangles: Vec<Option<Vec<_>>> = document
+            .meshes()
+            .next()
+            .unwrap()
+            .primitives()
+            .map(|primitive| {
+                let reader = primitive.reader(|_| Some(&bin[..]));
+                reader.read_triangles().map(Iterator::collect)
+            })
+            .collect();
+        assert_eq!(triangles, [
+            Some(vec![[0, 1, 3]]),
+            Some(vec![[0, 1, 2], [2, 1, 3]]),
+            Some(vec![[0, 1, 3], [0, 3, 2]]),
+            Some(vec![[0, 1, 2]]),
+            None,
+        ]);
+
+        let primitive = document.meshes().next().unwrap().primitives().nth(1).unwrap();
+        let reader = primitive.reader(|_| Some(&bin[..]));
+        let triangles = reader.read_triangles().unwrap();
+        assert_eq!(triangles.len(), 2);
+        let positions: Vec<_> = reader.read_triangle_positions().unwrap().collect();
+        let expected = [[square[0], square[1], square[2]], [square[2], square[1], square[3]]];
+        assert_eq!(positions, expected);
+    }
+
+    #[test]
+    fn generate_tangents_of_quads() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let square = [[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 0.0]];
+        let positions = packer.push_accessor(&mut root, &square, None);
+        let normals = packer.push_accessor(&mut root, &[[0.0f32, 0.0, 1.0]; 4], None);
+        let uvs = [[0.0f32, 1.0], [1.0, 1.0], [0.0, 0.0], [1.0, 0.0]];
+        let uvs = packer.push_accessor(&mut root, &uvs, None);
+        let mirrored = [[1.0f32, 1.0], [0.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+        let mirrored = packer.push_accessor(&mut root, &mirrored, None);
+        let indices = packer.push_accessor(&mut root, &[0u16, 1, 2, 2, 1, 3], None);
+        let bin = packer.finish(&mut root);
+        let primitive = |uvs: json::Index<json::Accessor>| {
+            format!(
+                r#"{{"attributes": {{"POSITION": {}, "NORMAL": {}, "TEXCOORD_0": {}}},
+                    "indices": {}}}"#,
+                positions.value(),
+                normals.value(),
+                uvs.value(),
+                indices.value(),
+            )
+        };
+        let mesh = format!(r#"{{"primitives": [{}, {}]}}"#, primitive(uvs), primitive(mirrored));
+        root.meshes.push(json::deserialize::from_str(&mesh).unwrap());
+
+        let document = Document::from_json(root).unwrap();
+        let tangents: Vec<_> = document
+            .meshes()
+            .next()
+            .unwrap()
+            .primitives()
+            .map(|primitive| primitive.reader(|_| Some(&bin[..])).generate_tangents().unwrap())
+            .collect();
+        // The bitangent, `cross(normal, tangent) * w`, points up the normal map.
+        assert_eq!(tangents[0], [[1.0, 0.0, 0.0, 1.0]; 4]);
+        assert_eq!(tangents[1], [[-1.0, 0.0, 0.0, -1.0]; 4]);
+    }
 }
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::accessor::util::Floats;
 use crate::Normalize;
 
 use super::ReadColors;
@@ -214,11 +215,11 @@ impl Cast for RgbF32 {
     type Output = [f32; 3];
 
     fn cast_rgb_u8(x: [u8; 3]) -> Self::Output {
-        x.into_rgb().normalize()
+        Floats::from_u8(x)
     }
 
     fn cast_rgb_u16(x: [u16; 3]) -> Self::Output {
-        x.into_rgb().normalize()
+        Floats::from_u16(x)
     }
 
     fn cast_rgb_f32(x: [f32; 3]) -> Self::Output {
@@ -226,11 +227,11 @@ impl Cast for RgbF32 {
     }
 
     fn cast_rgba_u8(x: [u8; 4]) -> Self::Output {
-        x.into_rgb().normalize()
+        Floats::from_u8(x.into_rgb())
     }
 
     fn cast_rgba_u16(x: [u16; 4]) -> Self::Output {
-        x.into_rgb().normalize()
+        Floats::from_u16(x.into_rgb())
     }
 
     fn cast_rgba_f32(x: [f32; 4]) -> Self::Output {
@@ -298,11 +299,11 @@ impl Cast for RgbaF32 {
     type Output = [f32; 4];
 
     fn cast_rgb_u8(x: [u8; 3]) -> Self::Output {
-        x.normalize().into_rgba()
+        <[f32; 3]>::from_u8(x).into_rgba()
     }
 
     fn cast_rgb_u16(x: [u16; 3]) -> Self::Output {
-        x.normalize().into_rgba()
+        <[f32; 3]>::from_u16(x).into_rgba()
     }
 
     fn cast_rgb_f32(x: [f32; 3]) -> Self::Output {
@@ -310,11 +311,11 @@ impl Cast for RgbaF32 {
     }
 
     fn cast_rgba_u8(x: [u8; 4]) -> Self::Output {
-        x.normalize().into_rgba()
+        Floats::from_u8(x)
     }
 
     fn cast_rgba_u16(x: [u16; 4]) -> Self::Output {
-        x.normalize().into_rgba()
+        Floats::from_u16(x)
     }
 
     fn cast_rgba_f32(x: [f32; 4]) -> Self::Output {
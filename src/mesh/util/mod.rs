@@ -13,20 +13,34 @@ pub mod tex_coords;
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
+/// Transforming iterator adapters for XYZ vectors.
+pub mod vectors;
+
+/// Tangent generation for primitives without tangents.
+mod tangents;
+
+pub(crate) use self::tangents::generate as generate_tangents;
+
+/// Reading interleaved vertex data as plain-old-data structs.
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub mod pod;
+
 use crate::mesh;
 
+use crate::accessor::util::ReadF32;
 use crate::accessor::Iter;
 use crate::Buffer;
 
 /// XYZ vertex positions of type `[f32; 3]`.
-pub type ReadPositions<'a> = Iter<'a, [f32; 3]>;
+pub type ReadPositions<'a> = ReadF32<'a, [f32; 3]>;
 
 /// XYZ vertex normals of type `[f32; 3]`.
-pub type ReadNormals<'a> = Iter<'a, [f32; 3]>;
+pub type ReadNormals<'a> = ReadF32<'a, [f32; 3]>;
 
 /// XYZW vertex tangents of type `[f32; 4]` where the `w` component is a
 /// sign value (-1 or +1) indicating the handedness of the tangent basis.
-pub type ReadTangents<'a> = Iter<'a, [f32; 4]>;
+pub type ReadTangents<'a> = ReadF32<'a, [f32; 4]>;
 
 /// XYZ vertex position displacements of type `[f32; 3]`.
 pub type ReadPositionDisplacements<'a> = Iter<'a, [f32; 3]>;
@@ -87,6 +101,9 @@ pub enum ReadTexCoords<'a> {
     U16(Iter<'a, [u16; 2]>),
     /// UV texture co-ordinates of type `[f32; 2]`.
     F32(Iter<'a, [f32; 2]>),
+    /// Signed or unnormalized UV texture co-ordinates permitted by
+    /// `KHR_mesh_quantization`, decoded to `[f32; 2]`.
+    Quantized(ReadF32<'a, [f32; 2]>),
 }
 
 /// Weights.
@@ -100,6 +117,139 @@ pub enum ReadWeights<'a> {
     F32(Iter<'a, [f32; 4]>),
 }
 
+/// Joint influences of every set of `JOINTS_n` and `WEIGHTS_n` attributes,
+/// visiting the `(joint, weight)` pairs of non-zero weight of each vertex.
+#[derive(Clone, Debug)]
+pub struct ReadInfluences<'a> {
+    /// The joints and weights of each set, in set order.
+    pub(crate) sets: Vec<(
+        joints::CastingIter<'a, joints::U16>,
+        weights::CastingIter<'a, weights::F32>,
+    )>,
+}
+
+impl<'a> ExactSizeIterator for ReadInfluences<'a> {}
+impl<'a> Iterator for ReadInfluences<'a> {
+    type Item = Vec<(u16, f32)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut influences = Vec::with_capacity(4 * self.sets.len());
+        for (joints, weights) in &mut self.sets {
+            let (joints, weights) = (joints.next()?, weights.next()?);
+            for (&joint, &weight) in joints.iter().zip(&weights) {
+                if weight != 0.0 {
+                    influences.push((joint, weight));
+                }
+            }
+        }
+        Some(influences)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.sets
+            .iter()
+            .map(|(joints, weights)| joints.len().min(weights.len()))
+            .min()
+            .unwrap_or(0);
+        (len, Some(len))
+    }
+}
+
+/// Vertex index triples of the triangles of a primitive, unrolling triangle
+/// strips and fans.
+///
+/// Every triangle is visited with the winding of the first triangle of a
+/// strip or fan, so all of them face the same way. Degenerate triangles, such
+/// as those joining the parts of a strip, are visited too.
+#[derive(Clone, Debug)]
+pub struct ReadTriangles {
+    /// The vertex draw sequence of the primitive.
+    pub(crate) indices: Vec<u32>,
+
+    /// The topology of the primitive, one of the triangle modes.
+    pub(crate) mode: mesh::Mode,
+
+    /// The index of the next triangle to visit.
+    pub(crate) triangle: usize,
+}
+
+impl ReadTriangles {
+    /// Returns the number of triangles of the draw sequence.
+    fn total(&self) -> usize {
+        let n = self.indices.len();
+        match self.mode {
+            mesh::Mode::Triangles => n / 3,
+            _ => n.saturating_sub(2),
+        }
+    }
+}
+
+impl ExactSizeIterator for ReadTriangles {}
+impl Iterator for ReadTriangles {
+    type Item = [u32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let i = self.triangle;
+        if i >= self.total() {
+            return None;
+        }
+        self.triangle += 1;
+        let v = &self.indices;
+        Some(match self.mode {
+            mesh::Mode::Triangles => [v[3 * i], v[3 * i + 1], v[3 * i + 2]],
+            // Every other triangle of a strip is wound the other way round.
+            mesh::Mode::TriangleStrip if i % 2 == 1 => [v[i + 1], v[i], v[i + 2]],
+            mesh::Mode::TriangleStrip => [v[i], v[i + 1], v[i + 2]],
+            _ => [v[0], v[i + 1], v[i + 2]],
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.total() - self.triangle.min(self.total());
+        (len, Some(len))
+    }
+}
+
+/// Vertex positions of the triangles of a primitive, in the order and winding
+/// of `ReadTriangles`.
+#[derive(Clone, Debug)]
+pub struct ReadTrianglePositions {
+    /// The vertex index triples of the triangles.
+    pub(crate) triangles: ReadTriangles,
+
+    /// The vertex positions of the primitive.
+    pub(crate) positions: Vec<[f32; 3]>,
+}
+
+impl Iterator for ReadTrianglePositions {
+    type Item = [[f32; 3]; 3];
+
+    /// Stops at the first triangle referring to a vertex out of bounds.
+    fn next(&mut self) -> Option<Self::Item> {
+        let [a, b, c] = self.triangles.next()?;
+        let position = |index: u32| self.positions.get(index as usize).copied();
+        Some([position(a)?, position(b)?, position(c)?])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.triangles.size_hint().1)
+    }
+}
+
+/// Vertex attribute displacements of a morph target, whose type depends on
+/// the displaced attribute.
+#[derive(Clone, Debug)]
+pub enum ReadDisplacements<'a> {
+    /// Scalar displacements.
+    Scalar(ReadF32<'a, f32>),
+    /// Two-component displacements, such as of texture co-ordinates.
+    Vec2(ReadF32<'a, [f32; 2]>),
+    /// Three-component displacements, such as of positions and RGB colors.
+    Vec3(ReadF32<'a, [f32; 3]>),
+    /// Four-component displacements, such as of RGBA colors.
+    Vec4(ReadF32<'a, [f32; 4]>),
+}
+
 /// Morph targets.
 #[derive(Clone, Debug)]
 pub struct ReadMorphTargets<'a, 's, F>
@@ -0,0 +1,255 @@
+use std::borrow::Cow;
+use std::{fmt, mem};
+
+use crate::mesh::{Primitive, Semantic};
+use crate::Buffer;
+
+/// Represents a failure to read interleaved vertex data as a struct.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// The primitive has no attribute with the given semantic.
+    MissingAttribute(Semantic),
+
+    /// The attribute uses sparse storage and cannot be viewed in place.
+    Sparse(Semantic),
+
+    /// The buffer data for the attribute is unavailable or too short.
+    MissingData(Semantic),
+
+    /// The attribute is stored in a different buffer view to the first attribute
+    /// of the layout.
+    View(Semantic),
+
+    /// The attribute has a different number of elements to the first attribute
+    /// of the layout.
+    Count(Semantic),
+
+    /// The attribute does not fit within the struct at the given offset.
+    Layout(Semantic),
+
+    /// The attribute offset does not match the struct layout.
+    Offset {
+        /// The attribute semantic.
+        semantic: Semantic,
+        /// Offset into the buffer view expected by the struct layout.
+        expected: usize,
+        /// Actual offset of the attribute into the buffer view.
+        actual: usize,
+    },
+
+    /// The buffer view stride does not match the size of the struct.
+    Stride {
+        /// Size of the struct in bytes.
+        expected: usize,
+        /// Actual stride of the buffer view in bytes.
+        actual: usize,
+    },
+}
+
+/// Reads the attributes given by `layout` as a slice of `V`.
+///
+/// Each entry of `layout` pairs an attribute semantic with the byte offset of the
+/// corresponding field within `V`.
+pub(crate) fn read<'a, 's, V, F>(
+    primitive: &Primitive<'a>,
+    get_buffer_data: F,
+    layout: &[(Semantic, usize)],
+) -> Result<Cow<'s, [V]>, Error>
+where
+    V: bytemuck::AnyBitPattern,
+    F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let size = mem::size_of::<V>();
+    let mut first = None;
+    let mut extent = 0;
+    for &(ref semantic, offset) in layout {
+        let accessor = primitive
+            .get(semantic)
+            .ok_or_else(|| Error::MissingAttribute(semantic.clone()))?;
+        if accessor.sparse().is_some() {
+            return Err(Error::Sparse(semantic.clone()));
+        }
+        let view = accessor
+            .view()
+            .ok_or_else(|| Error::MissingData(semantic.clone()))?;
+        if offset + accessor.size() > size {
+            return Err(Error::Layout(semantic.clone()));
+        }
+        extent = extent.max(offset + accessor.size());
+        match first {
+            None => {
+                if accessor.offset() < offset {
+                    return Err(Error::Offset {
+                        semantic: semantic.clone(),
+                        expected: offset,
+                        actual: accessor.offset(),
+                    });
+                }
+                let stride = view.stride().unwrap_or_else(|| accessor.size());
+                if stride != size {
+                    return Err(Error::Stride { expected: size, actual: stride });
+                }
+                let base = accessor.offset() - offset;
+                first = Some((view, base, accessor.count(), semantic.clone()));
+            },
+            Some((ref first_view, base, count, _)) => {
+                if view.index() != first_view.index() {
+                    return Err(Error::View(semantic.clone()));
+                }
+                if accessor.offset() != base + offset {
+                    return Err(Error::Offset {
+                        semantic: semantic.clone(),
+                        expected: base + offset,
+                        actual: accessor.offset(),
+                    });
+                }
+                if accessor.count() != count {
+                    return Err(Error::Count(semantic.clone()));
+                }
+            },
+        }
+    }
+
+    let (view, base, count, semantic) = match first {
+        Some(first) => first,
+        None => return Ok(Cow::Borrowed(&[])),
+    };
+    if count == 0 {
+        return Ok(Cow::Borrowed(&[]));
+    }
+    let missing = || Error::MissingData(semantic.clone());
+    let data = get_buffer_data(view.buffer()).ok_or_else(missing)?;
+    let start = view.offset().checked_add(base).ok_or_else(missing)?;
+    let end = view.offset().saturating_add(view.length()).min(data.len());
+    if crate::accessor::end_of(start, size, count, extent).filter(|&x| x <= end).is_none() {
+        return Err(missing());
+    }
+    let bytes = &data[start..end];
+
+    if bytes.len() >= count * size {
+        if let Ok(slice) = bytemuck::try_cast_slice(&bytes[..count * size]) {
+            return Ok(Cow::Borrowed(slice));
+        }
+    }
+
+    // The data is misaligned or the padding of the last element lies outside
+    // the buffer view.
+    let mut vertices = Vec::with_capacity(count);
+    let mut element = vec![0; size];
+    for i in 0..count {
+        let chunk = &bytes[i * size..bytes.len().min((i + 1) * size)];
+        element[..chunk.len()].copy_from_slice(chunk);
+        for byte in &mut element[chunk.len()..] {
+            *byte = 0;
+        }
+        vertices.push(bytemuck::pod_read_unaligned(&element));
+    }
+    Ok(Cow::Owned(vertices))
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingAttribute(ref semantic) => write!(f, "missing attribute {:?}", semantic),
+            Error::Sparse(ref semantic) => write!(f, "attribute {:?} is sparse", semantic),
+            Error::MissingData(ref semantic) => {
+                write!(f, "buffer data for attribute {:?} is unavailable", semantic)
+            },
+            Error::View(ref semantic) => {
+                write!(f, "attribute {:?} is not interleaved with the others", semantic)
+            },
+            Error::Count(ref semantic) => write!(f, "attribute {:?} has a mismatched count", semantic),
+            Error::Layout(ref semantic) => write!(f, "attribute {:?} does not fit in the struct", semantic),
+            Error::Offset { ref semantic, expected, actual } => write!(
+                f,
+                "attribute {:?} is at offset {} but the struct layout expects {}",
+                semantic, actual, expected,
+            ),
+            Error::Stride { expected, actual } => write!(
+                f,
+                "buffer view stride is {} but the struct size is {}",
+                actual, expected,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use crate::mesh::Semantic;
+    use crate::pack::Packer;
+    use crate::Document;
+    use json::validation::Checked::Valid;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Vertex {
+        position: [f32; 3],
+        color: [f32; 3],
+    }
+
+    unsafe impl bytemuck::Zeroable for Vertex {}
+    unsafe impl bytemuck::Pod for Vertex {}
+
+    fn interleaved() -> (Document, Vec<u8>, Vec<Vertex>) {
+        let vertices = vec![
+            Vertex { position: [0.0, 0.5, 0.0], color: [1.0, 0.0, 0.0] },
+            Vertex { position: [-0.5, -0.5, 0.0], color: [0.0, 1.0, 0.0] },
+            Vertex { position: [0.5, -0.5, 0.0], color: [0.0, 0.0, 1.0] },
+        ];
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let view = packer.push_view(&mut root, bytemuck::cast_slice(&vertices), Some(24), None);
+        let mut attributes = json::OrderedMap::new();
+        for &(ref semantic, offset) in &[(Semantic::Positions, 0), (Semantic::Colors(0), 12)] {
+            let mut accessor: json::Accessor = json::deserialize::from_str(
+                r#"{"componentType": 5126, "type": "VEC3", "count": 3}"#,
+            ).unwrap();
+            accessor.buffer_view = Some(view);
+            accessor.byte_offset = offset;
+            attributes.insert(Valid(semantic.clone()), root.push(accessor));
+        }
+        root.meshes.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: vec![json::mesh::Primitive {
+                attributes,
+                extensions: None,
+                extras: Default::default(),
+                indices: None,
+                material: None,
+                mode: Valid(json::mesh::Mode::Triangles),
+                targets: None,
+            }],
+            weights: None,
+        });
+        let buffer = packer.finish(&mut root);
+        (Document::from_json_without_validation(root), buffer, vertices)
+    }
+
+    #[test]
+    fn read_interleaved_vertices() {
+        let (document, buffer, vertices) = interleaved();
+        let mesh = document.meshes().next().unwrap();
+        let primitive = mesh.primitives().next().unwrap();
+        let reader = primitive.reader(|_| Some(&buffer[..]));
+        let layout = [(Semantic::Positions, 0), (Semantic::Colors(0), 12)];
+        let read = reader.read_pod::<Vertex>(&layout).unwrap();
+        assert_eq!(&read[..], &vertices[..]);
+
+        let layout = [(Semantic::Positions, 0), (Semantic::Colors(0), 16)];
+        assert_eq!(
+            reader.read_pod::<Vertex>(&layout).unwrap_err(),
+            Error::Layout(Semantic::Colors(0)),
+        );
+        assert_eq!(
+            reader.read_pod::<[f32; 3]>(&[(Semantic::Positions, 0)]).unwrap_err(),
+            Error::Stride { expected: 12, actual: 24 },
+        );
+    }
+}
@@ -0,0 +1,139 @@
+/// Returns `a - b`.
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Returns the component of `v` perpendicular to the unit vector `n`,
+/// normalized, or `None` if there is none.
+fn orthonormalize(v: [f32; 3], n: [f32; 3]) -> Option<[f32; 3]> {
+    let d = dot(v, n);
+    let v = [v[0] - n[0] * d, v[1] - n[1] * d, v[2] - n[2] * d];
+    let length = dot(v, v).sqrt();
+    if length > 1e-12 && length.is_finite() {
+        Some([v[0] / length, v[1] / length, v[2] / length])
+    } else {
+        None
+    }
+}
+
+/// Computes a tangent of each vertex from its position, normal, and texture
+/// co-ordinates and the triangles referring to it.
+///
+/// The tangent of each triangle is projected onto the tangent plane of each of
+/// its corners, normalized, and accumulated weighted by the angle of the
+/// corner, which is the weighting MikkTSpace uses. The tangent handedness is
+/// the sign of the accumulated bitangent along `cross(normal, tangent)`.
+/// Texture co-ordinates have their origin at the top left, so the bitangent
+/// points towards decreasing V, the up direction of a glTF normal map.
+///
+/// This is not MikkTSpace: vertices are not split where the tangent space is
+/// discontinuous, so each vertex has a single tangent and handedness. Where
+/// the triangles sharing a vertex disagree, such as across a UV seam or the
+/// mirror line of mirrored UVs, their tangents are averaged, and a vertex
+/// whose tangents cancel out is treated as having no usable triangle. Such
+/// vertices are given the X axis, or failing that the Y axis, projected onto
+/// the plane perpendicular to their normal, with positive handedness unless
+/// the accumulated bitangent says otherwise. Normal maps baked against
+/// MikkTSpace tangents will not match at these seams.
+pub(crate) fn generate(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    tex_coords: &[[f32; 2]],
+    triangles: impl Iterator<Item = [u32; 3]>,
+) -> Vec<[f32; 4]> {
+    let n = positions.len().min(normals.len()).min(tex_coords.len());
+    let mut tangents = vec![[0.0f32; 3]; n];
+    let mut bitangents = vec![[0.0f32; 3]; n];
+    for triangle in triangles {
+        let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+        if a >= n || b >= n || c >= n {
+            continue;
+        }
+        let (e1, e2) = (sub(positions[b], positions[a]), sub(positions[c], positions[a]));
+        let (u1, v1) = (tex_coords[b][0] - tex_coords[a][0], tex_coords[a][1] - tex_coords[b][1]);
+        let (u2, v2) = (tex_coords[c][0] - tex_coords[a][0], tex_coords[a][1] - tex_coords[c][1]);
+        let r = u1 * v2 - u2 * v1;
+        if r.abs() < 1e-12 {
+            continue;
+        }
+        let tangent = [
+            (e1[0] * v2 - e2[0] * v1) / r,
+            (e1[1] * v2 - e2[1] * v1) / r,
+            (e1[2] * v2 - e2[2] * v1) / r,
+        ];
+        let bitangent = [
+            (e2[0] * u1 - e1[0] * u2) / r,
+            (e2[1] * u1 - e1[1] * u2) / r,
+            (e2[2] * u1 - e1[2] * u2) / r,
+        ];
+        for &(corner, next, previous) in [(a, b, c), (b, c, a), (c, a, b)].iter() {
+            let to_next = orthonormalize(sub(positions[next], positions[corner]), normals[corner]);
+            let to_previous =
+                orthonormalize(sub(positions[previous], positions[corner]), normals[corner]);
+            let angle = match (to_next, to_previous) {
+                (Some(x), Some(y)) => dot(x, y).clamp(-1.0, 1.0).acos(),
+                _ => continue,
+            };
+            if let Some(t) = orthonormalize(tangent, normals[corner]) {
+                for i in 0..3 {
+                    tangents[corner][i] += t[i] * angle;
+                    bitangents[corner][i] += bitangent[i] * angle;
+                }
+            }
+        }
+    }
+
+    (0..n)
+        .map(|index| {
+            let normal = normals[index];
+            let tangent = orthonormalize(tangents[index], normal)
+                .or_else(|| orthonormalize([1.0, 0.0, 0.0], normal))
+                .or_else(|| orthonormalize([0.0, 1.0, 0.0], normal))
+                .unwrap_or([1.0, 0.0, 0.0]);
+            let w = if dot(cross(normal, tangent), bitangents[index]) < 0.0 { -1.0 } else { 1.0 };
+            [tangent[0], tangent[1], tangent[2], w]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate;
+
+    #[test]
+    fn mirrored_uv_seam_is_not_split() {
+        // Two unit quads meeting at x = 0, with U mirrored across the seam
+        // and the seam vertices 1 and 2 shared by both quads.
+        let positions = [
+            [-1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [-1.0, 1.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [1.0, 1.0, 0.0],
+        ];
+        let normals = [[0.0, 0.0, 1.0]; 6];
+        let tex_coords = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0], [0.0, 1.0], [0.0, 0.0]];
+        let triangles = vec![[0, 1, 2], [0, 2, 3], [1, 4, 2], [4, 5, 2]];
+        let tangents = generate(&positions, &normals, &tex_coords, triangles.into_iter());
+
+        // Away from the seam, each side has its own tangent and handedness.
+        assert_eq!(tangents[0], [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(tangents[3], [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(tangents[4], [-1.0, 0.0, 0.0, -1.0]);
+        assert_eq!(tangents[5], [-1.0, 0.0, 0.0, -1.0]);
+
+        // MikkTSpace would split the seam vertices; here the opposing tangents
+        // cancel out and the fallback tangent is used for both sides.
+        assert_eq!(tangents[1], [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(tangents[2], [1.0, 0.0, 0.0, 1.0]);
+    }
+}
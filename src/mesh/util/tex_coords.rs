@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::accessor::util::Floats;
 use crate::Normalize;
 
 use super::ReadTexCoords;
@@ -56,6 +57,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(ref mut i)  => i.next().map(A::cast_u8),
             ReadTexCoords::U16(ref mut i) => i.next().map(A::cast_u16),
             ReadTexCoords::F32(ref mut i) => i.next().map(A::cast_f32),
+            ReadTexCoords::Quantized(ref mut i) => i.next().map(A::cast_f32),
         }
     }
 
@@ -65,6 +67,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(ref mut i)  => i.nth(x).map(A::cast_u8),
             ReadTexCoords::U16(ref mut i) => i.nth(x).map(A::cast_u16),
             ReadTexCoords::F32(ref mut i) => i.nth(x).map(A::cast_f32),
+            ReadTexCoords::Quantized(ref mut i) => i.nth(x).map(A::cast_f32),
         }
     }
 
@@ -73,6 +76,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(i)  => i.last().map(A::cast_u8),
             ReadTexCoords::U16(i) => i.last().map(A::cast_u16),
             ReadTexCoords::F32(i) => i.last().map(A::cast_f32),
+            ReadTexCoords::Quantized(i) => i.last().map(A::cast_f32),
         }
     }
 
@@ -86,6 +90,7 @@ impl<'a, A: Cast> Iterator for CastingIter<'a, A> {
             ReadTexCoords::U8(ref i)  => i.size_hint(),
             ReadTexCoords::U16(ref i) => i.size_hint(),
             ReadTexCoords::F32(ref i) => i.size_hint(),
+            ReadTexCoords::Quantized(ref i) => i.size_hint(),
         }
     }
 }
@@ -126,11 +131,11 @@ impl Cast for F32 {
     type Output = [f32; 2];
 
     fn cast_u8(x: [u8; 2]) -> Self::Output {
-        x.normalize()
+        Floats::from_u8(x)
     }
 
     fn cast_u16(x: [u16; 2]) -> Self::Output {
-        x.normalize()
+        Floats::from_u16(x)
     }
 
     fn cast_f32(x: [f32; 2]) -> Self::Output {
@@ -0,0 +1,146 @@
+/// Adapters for iterators over XYZ vectors, such as `Reader::read_positions`.
+///
+/// The adapters compose without collecting intermediate results.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::mesh::util::vectors::Vec3Adapters;
+///
+/// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+/// let node = document.nodes().find(|node| node.mesh().is_some()).unwrap();
+/// let primitive = node.mesh().unwrap().primitives().next().unwrap();
+/// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+/// let positions: Vec<[f64; 3]> = reader
+///     .read_positions()
+///     .unwrap()
+///     .transformed(node.transform().matrix())
+///     .to_f64()
+///     .collect();
+/// assert_eq!(positions.len(), 24);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+pub trait Vec3Adapters: Iterator<Item = [f32; 3]> + Sized {
+    /// Transforms each vector as a point by a column-major matrix, such as
+    /// `scene::Transform::matrix`.
+    ///
+    /// The bottom row of the matrix is ignored. To transform directions such as
+    /// normals, pass a matrix without translation, for example the inverse
+    /// transpose of the upper 3x3 of the point matrix.
+    fn transformed(self, matrix: [[f32; 4]; 4]) -> Transformed<Self> {
+        Transformed { iter: self, matrix }
+    }
+
+    /// Scales each vector to unit length, leaving zero vectors unchanged.
+    fn normalized(self) -> Normalized<Self> {
+        Normalized { iter: self }
+    }
+
+    /// Converts each vector to double precision.
+    fn to_f64(self) -> ToF64<Self> {
+        ToF64 { iter: self }
+    }
+}
+
+impl<I: Iterator<Item = [f32; 3]>> Vec3Adapters for I {}
+
+/// Iterator returned by `Vec3Adapters::transformed`.
+#[derive(Clone, Debug)]
+pub struct Transformed<I> {
+    /// The untransformed vectors.
+    iter: I,
+
+    /// The column-major transform matrix.
+    matrix: [[f32; 4]; 4],
+}
+
+/// Iterator returned by `Vec3Adapters::normalized`.
+#[derive(Clone, Debug)]
+pub struct Normalized<I> {
+    /// The vectors before normalization.
+    iter: I,
+}
+
+/// Iterator returned by `Vec3Adapters::to_f64`.
+#[derive(Clone, Debug)]
+pub struct ToF64<I> {
+    /// The single precision vectors.
+    iter: I,
+}
+
+impl<I: Iterator<Item = [f32; 3]>> Iterator for Transformed<I> {
+    type Item = [f32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let m = &self.matrix;
+        self.iter.next().map(|[x, y, z]| {
+            [
+                m[0][0] * x + m[1][0] * y + m[2][0] * z + m[3][0],
+                m[0][1] * x + m[1][1] * y + m[2][1] * z + m[3][1],
+                m[0][2] * x + m[1][2] * y + m[2][2] * z + m[3][2],
+            ]
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator<Item = [f32; 3]>> Iterator for Normalized<I> {
+    type Item = [f32; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|[x, y, z]| {
+            let length = (x * x + y * y + z * z).sqrt();
+            if length > 0.0 {
+                [x / length, y / length, z / length]
+            } else {
+                [x, y, z]
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: Iterator<Item = [f32; 3]>> Iterator for ToF64<I> {
+    type Item = [f64; 3];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|[x, y, z]| [x as f64, y as f64, z as f64])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator<Item = [f32; 3]>> ExactSizeIterator for Transformed<I> {}
+impl<I: ExactSizeIterator<Item = [f32; 3]>> ExactSizeIterator for Normalized<I> {}
+impl<I: ExactSizeIterator<Item = [f32; 3]>> ExactSizeIterator for ToF64<I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::Vec3Adapters;
+
+    #[test]
+    fn compose_vector_adapters() {
+        let vectors = [[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 3.0, 4.0]];
+        let translation = [
+            [2.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0, 0.0],
+            [1.0, 2.0, 3.0, 1.0],
+        ];
+        let transformed = vectors.iter().copied().transformed(translation);
+        assert_eq!(transformed.len(), 3);
+        let points: Vec<_> = transformed.to_f64().collect();
+        assert_eq!(points, [[3.0, 2.0, 3.0], [1.0, 2.0, 3.0], [1.0, 8.0, 11.0]]);
+        let directions: Vec<_> = vectors.iter().copied().normalized().collect();
+        assert_eq!(directions, [[1.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.6, 0.8]]);
+    }
+}
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::accessor::util::Floats;
 use crate::Normalize;
 
 use super::ReadWeights;
@@ -126,11 +127,11 @@ impl Cast for F32 {
     type Output = [f32; 4];
 
     fn cast_u8(x: [u8; 4]) -> Self::Output {
-        x.normalize()
+        Floats::from_u8(x)
     }
 
     fn cast_u16(x: [u16; 4]) -> Self::Output {
-        x.normalize()
+        Floats::from_u16(x)
     }
 
     fn cast_f32(x: [f32; 4]) -> Self::Output {
@@ -0,0 +1,439 @@
+use json::extensions::buffer::{MeshoptFilter, MeshoptMode};
+
+/// Header byte of version 0 of the attribute codec.
+const ATTRIBUTES_HEADER: u8 = 0xa0;
+
+/// Header byte of version 1 of the triangle index codec.
+const TRIANGLES_HEADER: u8 = 0xe1;
+
+/// Header byte of version 1 of the index sequence codec.
+const INDICES_HEADER: u8 = 0xd1;
+
+/// Decodes the meshopt compressed data of a buffer view into `count` elements
+/// of `stride` bytes each.
+///
+/// Returns `None` if the data is malformed or the stride does not suit the
+/// mode and filter.
+pub(crate) fn decode(
+    mode: MeshoptMode,
+    filter: MeshoptFilter,
+    count: usize,
+    stride: usize,
+    data: &[u8],
+) -> Option<Vec<u8>> {
+    match mode {
+        MeshoptMode::Attributes => {
+            let mut elements = decode_attributes(count, stride, data)?;
+            match filter {
+                MeshoptFilter::None => {},
+                MeshoptFilter::Octahedral => octahedral(&mut elements, stride)?,
+                MeshoptFilter::Quaternion => quaternion(&mut elements, stride)?,
+                MeshoptFilter::Exponential => exponential(&mut elements, stride)?,
+            }
+            Some(elements)
+        },
+        MeshoptMode::Triangles if filter == MeshoptFilter::None => {
+            write_indices(&decode_triangles(count, data)?, stride)
+        },
+        MeshoptMode::Indices if filter == MeshoptFilter::None => {
+            write_indices(&decode_indices(count, data)?, stride)
+        },
+        _ => None,
+    }
+}
+
+/// Reverses the zigzag encoding of a byte delta.
+fn unzigzag8(v: u8) -> u8 {
+    (v >> 1) ^ (v & 1).wrapping_neg()
+}
+
+/// Reverses the zigzag encoding of an index delta.
+fn unzigzag32(v: u32) -> u32 {
+    (v >> 1) ^ (v & 1).wrapping_neg()
+}
+
+/// Reads a variable length integer of up to five bytes.
+fn read_vbyte(data: &mut &[u8]) -> Option<u32> {
+    let mut result = 0;
+    let mut shift = 0;
+    loop {
+        let (&byte, rest) = data.split_first()?;
+        *data = rest;
+        result |= u32::from(byte & 127) << shift;
+        if byte < 128 || shift == 28 {
+            return Some(result);
+        }
+        shift += 7;
+    }
+}
+
+/// Decodes a group of 16 bytes packed with `bits` as the base two logarithm of
+/// the bits per byte, returning the remaining data.
+fn decode_group<'a>(data: &'a [u8], group: &mut [u8], bits: u8) -> Option<&'a [u8]> {
+    match bits {
+        0 => {
+            group.iter_mut().for_each(|x| *x = 0);
+            Some(data)
+        },
+        3 => {
+            group.copy_from_slice(data.get(..16)?);
+            Some(&data[16..])
+        },
+        _ => {
+            let width = 1 << bits;
+            let escape = (1 << width) - 1;
+            let mut extra = 2 * width;
+            let packed = data.get(..extra)?;
+            for (i, value) in group.iter_mut().enumerate() {
+                // Values are packed from the most significant bits down.
+                let bit = i * width;
+                let encoded = (packed[bit / 8] >> (8 - width - bit % 8)) & escape;
+                *value = if encoded == escape {
+                    extra += 1;
+                    *data.get(extra - 1)?
+                } else {
+                    encoded
+                };
+            }
+            Some(&data[extra..])
+        },
+    }
+}
+
+/// Decodes one byte of every element of a block, returning the remaining data.
+fn decode_bytes<'a>(data: &'a [u8], buffer: &mut [u8]) -> Option<&'a [u8]> {
+    let groups = buffer.len() / 16;
+    let header_size = groups.div_ceil(4);
+    let header = data.get(..header_size)?;
+    let mut data = &data[header_size..];
+    for (index, group) in buffer.chunks_exact_mut(16).enumerate() {
+        let bits = (header[index / 4] >> ((index % 4) * 2)) & 3;
+        data = decode_group(data, group, bits)?;
+    }
+    Some(data)
+}
+
+/// Decodes data compressed with the attribute codec.
+fn decode_attributes(count: usize, stride: usize, data: &[u8]) -> Option<Vec<u8>> {
+    if stride == 0 || stride > 256 || stride & 3 != 0 {
+        return None;
+    }
+    let (&header, data) = data.split_first()?;
+    let tail = stride.max(32);
+    if header != ATTRIBUTES_HEADER || data.len() < tail {
+        return None;
+    }
+    // The tail holds the first element, which the deltas of the first
+    // element are relative to.
+    let mut last = data[data.len() - stride..].to_vec();
+    let mut data = &data[..data.len() - tail];
+    let block = ((8192 / stride) & !15).min(256);
+    let mut buffer = [0; 256];
+    let mut elements = vec![0; count.checked_mul(stride)?];
+    for chunk in elements.chunks_mut(block * stride) {
+        let block_count = chunk.len() / stride;
+        let aligned = (block_count + 15) & !15;
+        for k in 0..stride {
+            data = decode_bytes(data, &mut buffer[..aligned])?;
+            let mut value = last[k];
+            for (i, delta) in buffer[..block_count].iter().enumerate() {
+                value = value.wrapping_add(unzigzag8(*delta));
+                chunk[i * stride + k] = value;
+            }
+            last[k] = value;
+        }
+    }
+    if data.is_empty() {
+        Some(elements)
+    } else {
+        None
+    }
+}
+
+/// Decodes a triangle list compressed with the triangle index codec.
+fn decode_triangles(count: usize, data: &[u8]) -> Option<Vec<u32>> {
+    if count / 3 * 3 != count || data.len() < 1 + count / 3 + 16 || data[0] != TRIANGLES_HEADER {
+        return None;
+    }
+    let codes = &data[1..1 + count / 3];
+    let (mut data, codeaux_table) = data[1 + count / 3..].split_at(data.len() - 17 - count / 3);
+    let mut edges = [[0u32; 2]; 16];
+    let mut vertices = [0u32; 16];
+    let (mut edge_offset, mut vertex_offset) = (0usize, 0usize);
+    let (mut next, mut last) = (0u32, 0u32);
+    let mut indices = Vec::with_capacity(count);
+
+    // The codec keeps a FIFO of the most recent edges and one of the most
+    // recent vertices, which the triangles refer back to.
+    let edge = |offset: usize, age: usize, edges: &[[u32; 2]; 16]| {
+        edges[offset.wrapping_sub(age) & 15]
+    };
+    let vertex = |offset: usize, age: usize, vertices: &[u32; 16]| {
+        vertices[offset.wrapping_sub(age) & 15]
+    };
+    macro_rules! push_edge {
+        ($a:expr, $b:expr) => {{
+            edges[edge_offset] = [$a, $b];
+            edge_offset = (edge_offset + 1) & 15;
+        }};
+    }
+    macro_rules! push_vertex {
+        ($v:expr) => {{
+            vertices[vertex_offset] = $v;
+            vertex_offset = (vertex_offset + 1) & 15;
+        }};
+    }
+
+    for &code in codes {
+        if code < 0xf0 {
+            let [a, b] = edge(edge_offset, 1 + (code >> 4) as usize, &edges);
+            let fec = (code & 15) as usize;
+            let c = match fec {
+                0 => {
+                    next += 1;
+                    next - 1
+                },
+                // Vertices just before or after the last free vertex.
+                13 | 14 => last.wrapping_add(if fec == 13 { u32::MAX } else { 1 }),
+                15 => last.wrapping_add(unzigzag32(read_vbyte(&mut data)?)),
+                _ => vertex(vertex_offset, 1 + fec, &vertices),
+            };
+            if fec >= 13 {
+                last = c;
+            }
+            indices.extend_from_slice(&[a, b, c]);
+            if fec == 0 || fec >= 13 {
+                push_vertex!(c);
+            }
+            push_edge!(c, b);
+            push_edge!(a, c);
+        } else {
+            let (fea, codeaux) = if code < 0xfe {
+                (0, codeaux_table[(code & 15) as usize])
+            } else {
+                let (&codeaux, rest) = data.split_first()?;
+                data = rest;
+                if codeaux == 0 {
+                    next = 0;
+                }
+                (if code == 0xfe { 0 } else { 15 }, codeaux)
+            };
+            let (feb, fec) = ((codeaux >> 4) as usize, (codeaux & 15) as usize);
+            let mut fresh = |fe: usize| match fe {
+                0 => {
+                    next += 1;
+                    Some(next - 1)
+                },
+                15 => None,
+                _ => Some(vertex(vertex_offset, fe, &vertices)),
+            };
+            // Fresh vertices are numbered before any free index is read.
+            let (a, b, c) = (fresh(fea), fresh(feb), fresh(fec));
+            let mut free = |x: Option<u32>| match x {
+                Some(x) => Some(x),
+                None => {
+                    last = last.wrapping_add(unzigzag32(read_vbyte(&mut data)?));
+                    Some(last)
+                },
+            };
+            let (a, b, c) = (free(a)?, free(b)?, free(c)?);
+            indices.extend_from_slice(&[a, b, c]);
+            push_vertex!(a);
+            if feb == 0 || feb == 15 {
+                push_vertex!(b);
+            }
+            if fec == 0 || fec == 15 {
+                push_vertex!(c);
+            }
+            push_edge!(b, a);
+            push_edge!(c, b);
+            push_edge!(a, c);
+        }
+    }
+    if data.is_empty() {
+        Some(indices)
+    } else {
+        None
+    }
+}
+
+/// Decodes indices compressed with the index sequence codec.
+fn decode_indices(count: usize, data: &[u8]) -> Option<Vec<u32>> {
+    if data.len() < 1 + count + 4 || data[0] != INDICES_HEADER {
+        return None;
+    }
+    let mut data = &data[1..data.len() - 4];
+    let mut last = [0u32; 2];
+    let mut indices = Vec::with_capacity(count);
+    for _ in 0..count {
+        let v = read_vbyte(&mut data)?;
+        // The lowest bit selects which of two baselines the delta applies to.
+        let baseline = &mut last[(v & 1) as usize];
+        *baseline = baseline.wrapping_add(unzigzag32(v >> 1));
+        indices.push(*baseline);
+    }
+    if data.is_empty() {
+        Some(indices)
+    } else {
+        None
+    }
+}
+
+/// Writes decoded indices as little endian integers of `stride` bytes.
+fn write_indices(indices: &[u32], stride: usize) -> Option<Vec<u8>> {
+    match stride {
+        2 => Some(indices.iter().flat_map(|&x| (x as u16).to_le_bytes()).collect()),
+        4 => Some(indices.iter().flat_map(|&x| x.to_le_bytes()).collect()),
+        _ => None,
+    }
+}
+
+/// Reconstructs unit vectors from octahedral encoding, leaving the fourth
+/// component unchanged.
+fn octahedral(elements: &mut [u8], stride: usize) -> Option<()> {
+    let decode = |x: f32, y: f32, one: f32, max: f32| {
+        let z = one - x.abs() - y.abs();
+        // Points on the lower hemisphere are folded over the diagonals.
+        let t = (-z).max(0.0);
+        let x = if x >= 0.0 { x - t } else { x + t };
+        let y = if y >= 0.0 { y - t } else { y + t };
+        let scale = max / (x * x + y * y + z * z).sqrt();
+        [(x * scale).round(), (y * scale).round(), (z * scale).round()]
+    };
+    match stride {
+        4 => {
+            for element in elements.chunks_exact_mut(4) {
+                let [x, y, one] = [0, 1, 2].map(|i| f32::from(element[i] as i8));
+                for (byte, value) in element.iter_mut().zip(&decode(x, y, one, 127.0)) {
+                    *byte = *value as i8 as u8;
+                }
+            }
+        },
+        8 => {
+            for element in elements.chunks_exact_mut(8) {
+                let [x, y, one] = [0, 1, 2].map(|i| f32::from(read_i16(element, i)));
+                for (i, value) in decode(x, y, one, 32767.0).iter().enumerate() {
+                    write_i16(element, i, *value as i16);
+                }
+            }
+        },
+        _ => return None,
+    }
+    Some(())
+}
+
+/// Reconstructs unit quaternions from their three smallest components.
+fn quaternion(elements: &mut [u8], stride: usize) -> Option<()> {
+    if stride != 8 {
+        return None;
+    }
+    for element in elements.chunks_exact_mut(8) {
+        let [x, y, z, packed] = [0, 1, 2, 3].map(|i| read_i16(element, i));
+        // The fourth component holds the index of the largest component in its
+        // lowest two bits and the quantization scale in the rest.
+        let scale = std::f32::consts::FRAC_1_SQRT_2 / f32::from(packed | 3);
+        let [x, y, z] = [x, y, z].map(|x| f32::from(x) * scale);
+        let w = (1.0 - x * x - y * y - z * z).max(0.0).sqrt();
+        let largest = (packed & 3) as usize;
+        for (i, value) in [w, x, y, z].iter().enumerate() {
+            write_i16(element, (largest + i) & 3, (value * 32767.0).round() as i16);
+        }
+    }
+    Some(())
+}
+
+/// Reconstructs floats from a signed 24 bit mantissa and 8 bit exponent.
+fn exponential(elements: &mut [u8], stride: usize) -> Option<()> {
+    if stride & 3 != 0 {
+        return None;
+    }
+    for value in elements.chunks_exact_mut(4) {
+        let v = i32::from_le_bytes([value[0], value[1], value[2], value[3]]);
+        let mantissa = (v << 8) >> 8;
+        let exponent = v >> 24;
+        let x = mantissa as f32 * 2f32.powi(exponent);
+        value.copy_from_slice(&x.to_le_bytes());
+    }
+    Some(())
+}
+
+/// Reads the little endian `i16` at `index` of an element.
+fn read_i16(element: &[u8], index: usize) -> i16 {
+    i16::from_le_bytes([element[2 * index], element[2 * index + 1]])
+}
+
+/// Writes a little endian `i16` at `index` of an element.
+fn write_i16(element: &mut [u8], index: usize, value: i16) {
+    element[2 * index..2 * index + 2].copy_from_slice(&value.to_le_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::decode;
+    use json::extensions::buffer::{MeshoptFilter, MeshoptMode};
+
+    /// The elements `[1, 2, 3, 4]` and `[3, 2, 1, 4]`, with the bytes of the
+    /// elements packed with 2 bits, as zeros, with 4 bits, and unpacked.
+    const ATTRIBUTES: &[u8] = &[
+        0xa0,
+        0x01, 0x30, 0, 0, 0, 4,
+        0x00,
+        0x02, 0x03, 0, 0, 0, 0, 0, 0, 0,
+        0x03, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4,
+    ];
+
+    #[test]
+    fn decode_meshopt_modes() {
+        let none = MeshoptFilter::None;
+        let elements = decode(MeshoptMode::Attributes, none, 2, 4, ATTRIBUTES).unwrap();
+        assert_eq!(elements, [1, 2, 3, 4, 3, 2, 1, 4]);
+        let truncated = &ATTRIBUTES[..ATTRIBUTES.len() - 1];
+        assert!(decode(MeshoptMode::Attributes, none, 2, 4, truncated).is_none());
+        assert!(decode(MeshoptMode::Attributes, none, 2, 4, &ATTRIBUTES[1..]).is_none());
+
+        // A fresh triangle, one sharing its last edge, and one with a free index.
+        let mut triangles = vec![0xe1, 0xf0, 0x00, 0x0f, 14];
+        triangles.extend_from_slice(&[0; 16]);
+        let indices = decode(MeshoptMode::Triangles, none, 9, 2, &triangles).unwrap();
+        let expected: Vec<u8> = [0u16, 1, 2, 0, 2, 3, 0, 3, 7]
+            .iter()
+            .flat_map(|x| x.to_le_bytes())
+            .collect();
+        assert_eq!(indices, expected);
+
+        let sequence = [0xd1, 20, 6, 41, 0, 0, 0, 0];
+        let indices = decode(MeshoptMode::Indices, none, 3, 4, &sequence).unwrap();
+        assert_eq!(indices, [5, 0, 0, 0, 3, 0, 0, 0, 10, 0, 0, 0]);
+        assert!(decode(MeshoptMode::Indices, none, 3, 3, &sequence).is_none());
+    }
+
+    #[test]
+    fn decode_meshopt_filters() {
+        // Encodes a single element, which is its own baseline, so that every
+        // byte group holds zero deltas.
+        let attributes = |element: &[u8]| {
+            let mut data = vec![0xa0];
+            data.extend(element.iter().map(|_| 0x00));
+            data.extend_from_slice(&[0; 32][element.len()..]);
+            data.extend_from_slice(element);
+            data
+        };
+
+        let octahedral = attributes(&[127, 0, 127, 9]);
+        let filter = MeshoptFilter::Octahedral;
+        let decoded = decode(MeshoptMode::Attributes, filter, 1, 4, &octahedral);
+        assert_eq!(decoded.unwrap(), [127, 0, 0, 9]);
+
+        let quaternion = attributes(&[0, 0, 0, 0, 0, 0, 0xff, 0x7f]);
+        let filter = MeshoptFilter::Quaternion;
+        let decoded = decode(MeshoptMode::Attributes, filter, 1, 8, &quaternion);
+        assert_eq!(decoded.unwrap(), [0, 0, 0, 0, 0, 0, 0xff, 0x7f]);
+
+        let exponential = attributes(&((-1i32 << 24) | 3).to_le_bytes());
+        let filter = MeshoptFilter::Exponential;
+        let decoded = decode(MeshoptMode::Attributes, filter, 1, 4, &exponential);
+        assert_eq!(decoded.unwrap(), 1.5f32.to_le_bytes());
+    }
+}
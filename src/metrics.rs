@@ -0,0 +1,102 @@
+//! Callbacks for collecting import metrics.
+//!
+//! Services that convert or ingest many assets can install a [`Metrics`]
+//! implementation once, for example to update Prometheus counters, rather than
+//! measuring around every call into the crate.
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! #[derive(Default)]
+//! struct Counters {
+//!     bytes_read: AtomicUsize,
+//! }
+//!
+//! impl gltf::metrics::Metrics for Counters {
+//!     fn bytes_read(&self, bytes: usize) {
+//!         self.bytes_read.fetch_add(bytes, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! gltf::metrics::install(Counters::default());
+//! ```
+//!
+//! [`Metrics`]: trait.Metrics.html
+
+use std::sync::{Arc, RwLock};
+
+use crate::json;
+
+/// Receives events from the library as documents are loaded and exported.
+///
+/// Every method has an empty default implementation so that implementors only
+/// need to handle the events they are interested in. Methods may be called from
+/// multiple threads at once.
+pub trait Metrics {
+    /// Called when `bytes` bytes of glTF, binary glTF, buffer, or image data
+    /// have been read from a slice, reader, file, or data URI.
+    fn bytes_read(&self, _bytes: usize) {}
+
+    /// Called when an image has been decoded from `bytes` bytes of encoded data.
+    fn image_decoded(&self, _bytes: usize) {}
+
+    /// Called for each error found when a document is validated.
+    ///
+    /// `error.code()` provides a stable label for grouping errors.
+    fn validation_error(&self, _path: &json::Path, _error: json::validation::Error) {}
+
+    /// Called for each warning found when a document is exported with
+    /// `binary::Glb::from_document`, such as a node that is not reachable from
+    /// any scene.
+    fn export_warning(&self, _path: &json::Path, _error: json::validation::Error) {}
+}
+
+/// Allows the installed receiver to be shared with the code reading its counters.
+impl<M: Metrics + ?Sized> Metrics for Arc<M> {
+    fn bytes_read(&self, bytes: usize) {
+        (**self).bytes_read(bytes)
+    }
+
+    fn image_decoded(&self, bytes: usize) {
+        (**self).image_decoded(bytes)
+    }
+
+    fn validation_error(&self, path: &json::Path, error: json::validation::Error) {
+        (**self).validation_error(path, error)
+    }
+
+    fn export_warning(&self, path: &json::Path, error: json::validation::Error) {
+        (**self).export_warning(path, error)
+    }
+}
+
+lazy_static! {
+    static ref METRICS: RwLock<Option<Arc<dyn Metrics + Send + Sync>>> = RwLock::new(None);
+}
+
+/// Installs `metrics` as the receiver of events, replacing any previous one.
+pub fn install<M>(metrics: M)
+where
+    M: Metrics + Send + Sync + 'static,
+{
+    *METRICS.write().unwrap_or_else(|err| err.into_inner()) = Some(Arc::new(metrics));
+}
+
+/// Removes the installed receiver of events, if any.
+pub fn uninstall() {
+    *METRICS.write().unwrap_or_else(|err| err.into_inner()) = None;
+}
+
+/// Calls `f` with the installed receiver, if any.
+pub(crate) fn report<F>(f: F)
+where
+    F: FnOnce(&dyn Metrics),
+{
+    let metrics = METRICS
+        .read()
+        .unwrap_or_else(|err| err.into_inner())
+        .clone();
+    if let Some(metrics) = metrics {
+        f(&*metrics);
+    }
+}
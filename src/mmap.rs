@@ -0,0 +1,302 @@
+use std::ops::Range;
+use std::path::Path;
+use std::sync::Arc;
+use std::{fmt, fs, io, ops};
+
+use crate::import::Scheme;
+use crate::{binary, buffer, image, Document, Error, Result};
+
+/// Return type of `import_mmap`.
+type Import = (Document, Vec<Data>, Vec<Data>);
+
+/// Bindings to the POSIX memory mapping functions, which `std` links against.
+///
+/// The constant values were checked against `<sys/mman.h>` on Linux and macOS,
+/// where they agree.
+#[cfg(all(unix, target_pointer_width = "64"))]
+mod sys {
+    use std::os::raw::{c_int, c_void};
+
+    pub const PROT_READ: c_int = 1;
+    pub const MAP_PRIVATE: c_int = 2;
+    pub const MAP_FAILED: *mut c_void = usize::MAX as *mut c_void;
+
+    extern "C" {
+        pub fn mmap(
+            addr: *mut c_void,
+            len: usize,
+            prot: c_int,
+            flags: c_int,
+            fd: c_int,
+            offset: i64,
+        ) -> *mut c_void;
+
+        pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    }
+}
+
+/// A read-only mapping of a whole file.
+#[cfg(all(unix, target_pointer_width = "64"))]
+struct Map {
+    /// The first mapped byte.
+    ptr: *const u8,
+
+    /// The number of mapped bytes.
+    len: usize,
+}
+
+// The mapping is never written to, so it may be shared between threads.
+#[cfg(all(unix, target_pointer_width = "64"))]
+unsafe impl Send for Map {}
+#[cfg(all(unix, target_pointer_width = "64"))]
+unsafe impl Sync for Map {}
+
+#[cfg(all(unix, target_pointer_width = "64"))]
+impl Drop for Map {
+    fn drop(&mut self) {
+        unsafe {
+            sys::munmap(self.ptr as *mut _, self.len);
+        }
+    }
+}
+
+/// The contents of a file, or of a data URI.
+enum Bytes {
+    /// The file is memory-mapped.
+    #[cfg(all(unix, target_pointer_width = "64"))]
+    Mapped(Map),
+
+    /// The bytes were read or decoded into memory.
+    Owned(Vec<u8>),
+}
+
+impl Bytes {
+    /// Memory-maps a file, falling back to reading it where mapping is not
+    /// supported.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be truncated or written to while the mapping is alive.
+    unsafe fn open(path: &Path) -> Result<Self> {
+        let file = fs::File::open(path)?;
+        let len = file.metadata()?.len() as usize;
+        #[cfg(all(unix, target_pointer_width = "64"))]
+        {
+            use std::os::unix::io::AsRawFd;
+            // Empty mappings are rejected by `mmap`.
+            if len > 0 {
+                let ptr = sys::mmap(
+                    std::ptr::null_mut(),
+                    len,
+                    sys::PROT_READ,
+                    sys::MAP_PRIVATE,
+                    file.as_raw_fd(),
+                    0,
+                );
+                if ptr == sys::MAP_FAILED {
+                    return Err(Error::Io(io::Error::last_os_error()));
+                }
+                return Ok(Bytes::Mapped(Map { ptr: ptr as *const u8, len }));
+            }
+        }
+        let mut data = Vec::with_capacity(len);
+        io::Read::read_to_end(&mut &file, &mut data)?;
+        Ok(Bytes::Owned(data))
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        match *self {
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            Bytes::Mapped(ref map) => unsafe { std::slice::from_raw_parts(map.ptr, map.len) },
+            Bytes::Owned(ref data) => data,
+        }
+    }
+}
+
+/// Bytes of a file imported by `import_mmap`, or of a part of one.
+///
+/// Files are memory-mapped on 64-bit Unix platforms and read into memory
+/// elsewhere. A mapping is shared by every `Data` referring to it and unmapped
+/// once the last of them is dropped. Data URIs are decoded into memory.
+#[derive(Clone)]
+pub struct Data {
+    /// The contents of the whole file.
+    bytes: Arc<Bytes>,
+
+    /// The range of `bytes` referred to.
+    range: Range<usize>,
+}
+
+impl Data {
+    fn new(bytes: Bytes) -> Self {
+        let range = 0..bytes.as_slice().len();
+        Data { bytes: Arc::new(bytes), range }
+    }
+
+    /// Returns a part of the data, sharing the same file contents.
+    fn slice(&self, range: Range<usize>) -> Self {
+        assert!(range.start <= range.end && range.end <= self.range.len());
+        let start = self.range.start + range.start;
+        let end = self.range.start + range.end;
+        Data { bytes: self.bytes.clone(), range: start..end }
+    }
+
+    /// Returns the bytes for writing, first copying them into memory unless
+    /// they are already in memory and not shared with other `Data`.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    fn to_mut(&mut self) -> &mut [u8] {
+        let unique = match *self.bytes {
+            Bytes::Owned(_) => Arc::get_mut(&mut self.bytes).is_some(),
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            Bytes::Mapped(_) => false,
+        };
+        if !unique {
+            *self = Data::new(Bytes::Owned(self.to_vec()));
+        }
+        match Arc::get_mut(&mut self.bytes) {
+            Some(Bytes::Owned(data)) => &mut data[self.range.clone()],
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns whether the bytes are borrowed from a memory-mapped file.
+    pub fn is_mapped(&self) -> bool {
+        match *self.bytes {
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            Bytes::Mapped(_) => true,
+            Bytes::Owned(_) => false,
+        }
+    }
+}
+
+impl ops::Deref for Data {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        &self.bytes.as_slice()[self.range.clone()]
+    }
+}
+
+impl AsRef<[u8]> for Data {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl fmt::Debug for Data {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Data")
+            .field("len", &self.len())
+            .field("mapped", &self.is_mapped())
+            .finish()
+    }
+}
+
+/// Reads the data referenced by a URI relative to `base`.
+///
+/// # Safety
+///
+/// See `Bytes::open`.
+unsafe fn read(base: &Path, uri: &str) -> Result<Data> {
+    let bytes = match Scheme::parse(uri) {
+        Scheme::Data(_, base64) => Bytes::Owned(base64::decode(base64).map_err(Error::Base64)?),
+        Scheme::File(path) => Bytes::open(Path::new(path))?,
+        Scheme::Relative => Bytes::open(&base.join(uri))?,
+        Scheme::Unsupported => return Err(Error::UnsupportedScheme),
+    };
+    Ok(Data::new(bytes))
+}
+
+/// Import some glTF 2.0 from the file system, memory-mapping the `.glb`, `.bin`,
+/// and image files it consists of rather than copying them into memory.
+///
+/// Buffer data is the whole `.bin` file or the `BIN` chunk of binary glTF, and
+/// is not padded to a multiple of four bytes as by `import`. Meshopt fallback
+/// buffers are decoded into memory, as by `import`. Image data is the
+/// encoded image, left for the caller to decode.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// // Safety: the example files are not modified while the test runs.
+/// let (document, buffers, images) = unsafe { gltf::import_mmap("examples/Box.glb")? };
+/// let mesh = document.meshes().next().unwrap();
+/// let primitive = mesh.primitives().next().unwrap();
+/// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+/// assert_eq!(reader.read_positions().unwrap().len(), 24);
+/// # assert!(images.is_empty());
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+///
+/// # Safety
+///
+/// The `.glb`, `.bin`, and image files must not be truncated or written to,
+/// by this or any other process, while any of the returned data is alive.
+/// Truncating a mapped file makes reading the data raise `SIGBUS`, and writing
+/// to it changes the contents of a slice that is assumed to be immutable.
+pub unsafe fn import_mmap<P>(path: P) -> Result<Import>
+where
+    P: AsRef<Path>,
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or_else(|| Path::new("./"));
+    let file = Data::new(Bytes::open(path)?);
+    let (json, mut blob): (json::Root, Option<Data>);
+    if file.starts_with(b"glTF") {
+        let glb = binary::Glb::from_slice(&file)?;
+        json = json::deserialize::from_slice(&glb.json)?;
+        blob = glb.bin.map(|bin| {
+            let start = bin.as_ptr() as usize - file.as_ptr() as usize;
+            file.slice(start..start + bin.len())
+        });
+    } else {
+        json = json::deserialize::from_slice(&file)?;
+        blob = None;
+    }
+    let document = Document::from_json(json)?;
+
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if buffer.is_meshopt_fallback() {
+                buffers.push(Data::new(Bytes::Owned(vec![0; buffer.length()])));
+                continue;
+            }
+        }
+        let data = match buffer.source() {
+            buffer::Source::Uri(uri) => read(base, uri)?,
+            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob)?,
+        };
+        if data.len() < buffer.length() {
+            return Err(Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            });
+        }
+        buffers.push(data);
+    }
+    #[cfg(feature = "EXT_meshopt_compression")]
+    for view in document.views() {
+        let decoded = crate::import::decode_meshopt_view(&view, |index| &buffers[index][..])?;
+        if let Some(decoded) = decoded {
+            let begin = view.offset();
+            let data = buffers[view.buffer().index()].to_mut();
+            data[begin..begin + decoded.len()].copy_from_slice(&decoded);
+        }
+    }
+
+    let mut images = Vec::new();
+    for image in document.images() {
+        let data = match image.source() {
+            image::Source::Uri { uri, .. } => read(base, uri)?,
+            image::Source::View { view, .. } => {
+                let begin = view.offset();
+                buffers[view.buffer().index()].slice(begin..begin + view.length())
+            },
+        };
+        images.push(data);
+    }
+    Ok((document, buffers, images))
+}
@@ -0,0 +1,2252 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt;
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::mesh::Semantic;
+use json::validation::Checked::Valid;
+
+use crate::accessor::Usage;
+use crate::math::slerp;
+use crate::pack::{component_size, Component};
+
+/// Returns the number of components in a single element.
+pub(crate) fn dimensions(type_: Type) -> usize {
+    match type_ {
+        Type::Scalar => 1,
+        Type::Vec2 => 2,
+        Type::Vec3 => 3,
+        Type::Vec4 | Type::Mat2 => 4,
+        Type::Mat3 => 9,
+        Type::Mat4 => 16,
+    }
+}
+
+/// Reads the raw component values of a non-sparse accessor.
+///
+/// Returns `None` if the accessor is sparse, has no buffer view, is a matrix with
+/// padded columns, or refers to data outside of `buffers`.
+pub(crate) fn read_components(
+    root: &json::Root,
+    buffers: &[Vec<u8>],
+    accessor: &json::Accessor,
+) -> Option<Vec<f64>> {
+    if accessor.sparse.is_some() {
+        return None;
+    }
+    let component_type = match accessor.component_type {
+        Valid(GenericComponentType(component_type)) => component_type,
+        _ => return None,
+    };
+    let type_ = match accessor.type_ {
+        Valid(type_) => type_,
+        _ => return None,
+    };
+    let size = component_size(component_type);
+    if size < 4 && (type_ == Type::Mat2 && size == 1 || type_ == Type::Mat3) {
+        return None;
+    }
+    let view = root.buffer_views.get(accessor.buffer_view?.value())?;
+    let data = buffers.get(view.buffer.value())?;
+    let n = dimensions(type_);
+    let stride = view.byte_stride.map_or(n * size, |stride| stride as usize);
+    let view_start = view.byte_offset.unwrap_or(0) as usize;
+    let start = view_start.checked_add(accessor.byte_offset as usize)?;
+    let count = accessor.count as usize;
+    if count == 0 {
+        return Some(Vec::new());
+    }
+    let end = crate::accessor::end_of(start, stride, count, n * size)?;
+    if end > data.len() || end > view.byte_offset.unwrap_or(0) as usize + view.byte_length as usize {
+        return None;
+    }
+
+    let mut components = Vec::with_capacity(count * n);
+    for i in 0..count {
+        for j in 0..n {
+            let offset = start + i * stride + j * size;
+            let bytes = &data[offset..offset + size];
+            components.push(match component_type {
+                ComponentType::I8 => bytes[0] as i8 as f64,
+                ComponentType::U8 => bytes[0] as f64,
+                ComponentType::I16 => i16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+                ComponentType::U16 => u16::from_le_bytes([bytes[0], bytes[1]]) as f64,
+                ComponentType::U32 => {
+                    u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64
+                },
+                ComponentType::F32 => {
+                    f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f64
+                },
+            });
+        }
+    }
+    Some(components)
+}
+
+/// Rewrites the data of an accessor with the given component values, appending
+/// a new buffer view to the buffer the accessor previously referred to.
+///
+/// The values must already be representable in `component_type`. The previous
+/// buffer view is left in place.
+pub(crate) fn write_components(
+    root: &mut json::Root,
+    buffers: &mut [Vec<u8>],
+    index: json::Index<json::Accessor>,
+    components: &[f64],
+    component_type: ComponentType,
+    target: Option<Target>,
+) {
+    let accessor = &root.accessors[index.value()];
+    let old_view = &root.buffer_views[accessor.buffer_view.unwrap().value()];
+    let buffer = old_view.buffer;
+    let n = match accessor.type_ {
+        Valid(type_) => dimensions(type_),
+        _ => unreachable!(),
+    };
+    let size = component_size(component_type);
+
+    // Elements of vertex attributes must be aligned to four bytes.
+    let element_size = n * size;
+    let stride = match target {
+        Some(Target::ArrayBuffer) => (element_size + 3) & !3,
+        _ => element_size,
+    };
+
+    let mut bytes = Vec::with_capacity(components.len() / n * stride);
+    for element in components.chunks(n) {
+        for &x in element {
+            match component_type {
+                ComponentType::I8 => (x as i8).write(&mut bytes),
+                ComponentType::U8 => (x as u8).write(&mut bytes),
+                ComponentType::I16 => (x as i16).write(&mut bytes),
+                ComponentType::U16 => (x as u16).write(&mut bytes),
+                ComponentType::U32 => (x as u32).write(&mut bytes),
+                ComponentType::F32 => (x as f32).write(&mut bytes),
+            }
+        }
+        bytes.resize(bytes.len() + stride - element_size, 0);
+    }
+
+    let data = &mut buffers[buffer.value()];
+    data.resize((data.len() + 3) & !3, 0);
+    let byte_offset = data.len() as u32;
+    data.extend_from_slice(&bytes);
+    root.buffers[buffer.value()].byte_length = data.len() as u32;
+
+    let view = root.push(json::buffer::View {
+        buffer,
+        byte_length: bytes.len() as u32,
+        byte_offset: Some(byte_offset),
+        byte_stride: if stride != element_size { Some(stride as u32) } else { None },
+        #[cfg(feature = "names")]
+        name: None,
+        target: target.map(Valid),
+        extensions: None,
+        extras: Default::default(),
+    });
+
+    let accessor = &mut root.accessors[index.value()];
+    accessor.buffer_view = Some(view);
+    accessor.byte_offset = 0;
+    accessor.component_type = Valid(GenericComponentType(component_type));
+    if accessor.min.is_some() || accessor.max.is_some() {
+        let (min, max) = bounds(components, n, component_type);
+        accessor.min = Some(min);
+        accessor.max = Some(max);
+    }
+}
+
+/// Computes the per-component bounds of the given component values.
+fn bounds(
+    components: &[f64],
+    n: usize,
+    component_type: ComponentType,
+) -> (json::Value, json::Value) {
+    let mut min = vec![f64::INFINITY; n];
+    let mut max = vec![f64::NEG_INFINITY; n];
+    for element in components.chunks(n) {
+        for (j, &x) in element.iter().enumerate() {
+            min[j] = min[j].min(x);
+            max[j] = max[j].max(x);
+        }
+    }
+    let to_json = |values: Vec<f64>| {
+        json::Value::from(values.into_iter().map(|x| match component_type {
+            ComponentType::F32 => crate::pack::f32_to_json(x as f32),
+            _ => json::Value::from(x as i64),
+        }).collect::<Vec<_>>())
+    };
+    (to_json(min), to_json(max))
+}
+
+/// Decodes a raw component value of a normalized accessor to a float.
+fn denormalize(x: f64, component_type: ComponentType) -> f64 {
+    match component_type {
+        ComponentType::I8 => (x / 127.0).max(-1.0),
+        ComponentType::U8 => x / 255.0,
+        ComponentType::I16 => (x / 32_767.0).max(-1.0),
+        ComponentType::U16 => x / 65_535.0,
+        ComponentType::U32 | ComponentType::F32 => x,
+    }
+}
+
+/// Encodes a float as a raw component value of the given type, rounding and
+/// clamping to the representable range.
+fn encode(x: f64, component_type: ComponentType, normalized: bool) -> f64 {
+    let (min, max) = match component_type {
+        ComponentType::I8 => (-127.0, 127.0),
+        ComponentType::U8 => (0.0, 255.0),
+        ComponentType::I16 => (-32_767.0, 32_767.0),
+        ComponentType::U16 => (0.0, 65_535.0),
+        ComponentType::U32 => (0.0, 4_294_967_295.0),
+        ComponentType::F32 => return x,
+    };
+    let x = if normalized { x * max } else { x };
+    x.round().max(min).min(max)
+}
+
+/// Returns `true` if a vertex attribute of the given type requires the
+/// `KHR_mesh_quantization` extension.
+fn requires_quantization(
+    semantic: &json::mesh::Semantic,
+    component_type: ComponentType,
+    normalized: bool,
+) -> bool {
+    use json::mesh::Semantic::*;
+    match *semantic {
+        Positions | Normals | Tangents => component_type != ComponentType::F32,
+        TexCoords(_) => match component_type {
+            ComponentType::F32 => false,
+            ComponentType::U8 | ComponentType::U16 => !normalized,
+            _ => true,
+        },
+        _ => false,
+    }
+}
+
+/// Converts the data of an accessor to a different component type, rewriting
+/// its binary data and metadata together.
+///
+/// Values are decoded to floats, honouring the `normalized` flag of the existing
+/// accessor, and then encoded into `component_type`. Integer encodings are
+/// rounded and clamped to the representable range, which allows for example
+/// float texture co-ordinates to be quantized to normalized `UNSIGNED_SHORT`.
+///
+/// The accessor keeps its index, so every primitive referring to it sees the
+/// converted data. If a primitive then uses a vertex attribute encoding that is
+/// only permitted by `KHR_mesh_quantization`, the extension is added to the used
+/// and required extensions of `root`.
+///
+/// `buffers` holds the contents of each buffer in `root`; converted data is
+/// appended as described in [`narrow_component_types`]. Returns `false`, leaving
+/// `root` unmodified, if the accessor data cannot be read or if `normalized` is
+/// requested with a `FLOAT` or `UNSIGNED_INT` component type.
+///
+/// [`narrow_component_types`]: fn.narrow_component_types.html
+pub fn convert_accessor(
+    root: &mut json::Root,
+    buffers: &mut [Vec<u8>],
+    index: json::Index<json::Accessor>,
+    component_type: ComponentType,
+    normalized: bool,
+) -> bool {
+    if normalized && (component_type == ComponentType::F32 || component_type == ComponentType::U32) {
+        return false;
+    }
+    let accessor = match root.accessors.get(index.value()) {
+        Some(accessor) => accessor,
+        None => return false,
+    };
+    let current = match accessor.component_type {
+        Valid(GenericComponentType(component_type)) => component_type,
+        _ => return false,
+    };
+    let mut components = match read_components(root, buffers, accessor) {
+        Some(components) => components,
+        None => return false,
+    };
+    for x in &mut components {
+        if accessor.normalized {
+            *x = denormalize(*x, current);
+        }
+        *x = encode(*x, component_type, normalized);
+    }
+    let target = accessor.buffer_view
+        .and_then(|view| root.buffer_views[view.value()].target)
+        .and_then(|target| match target {
+            Valid(target) => Some(target),
+            _ => None,
+        });
+
+    write_components(root, buffers, index, &components, component_type, target);
+    root.accessors[index.value()].normalized = normalized;
+
+    let quantized = root.meshes.iter()
+        .flat_map(|mesh| mesh.primitives.iter())
+        .flat_map(|primitive| {
+            let targets = primitive.targets.iter().flatten().flat_map(|target| {
+                vec![
+                    (json::mesh::Semantic::Positions, target.positions),
+                    (json::mesh::Semantic::Normals, target.normals),
+                    (json::mesh::Semantic::Tangents, target.tangents),
+                ]
+            });
+            primitive.attributes.iter()
+                .filter_map(|(semantic, accessor)| match semantic {
+                    Valid(semantic) => Some((semantic.clone(), Some(*accessor))),
+                    _ => None,
+                })
+                .chain(targets)
+        })
+        .any(|(semantic, accessor)| {
+            accessor.map(|accessor| accessor.value()) == Some(index.value())
+                && requires_quantization(&semantic, component_type, normalized)
+        });
+    if quantized {
+        let name = "KHR_mesh_quantization".to_string();
+        if !root.extensions_used.contains(&name) {
+            root.extensions_used.push(name.clone());
+        }
+        if !root.extensions_required.contains(&name) {
+            root.extensions_required.push(name);
+        }
+    }
+    true
+}
+
+/// Narrows the component types of index and integer vertex attribute accessors
+/// to the smallest type that represents every value losslessly, returning the
+/// number of accessors rewritten.
+///
+/// Index data is narrowed from `UNSIGNED_INT` to `UNSIGNED_SHORT` or
+/// `UNSIGNED_BYTE` while avoiding the primitive restart value of the new type.
+/// Non-normalized unsigned integer vertex attributes, such as `JOINTS_0`, are
+/// narrowed similarly. Sparse accessors are left untouched.
+///
+/// `buffers` holds the contents of each buffer in `root`. Rewritten data is
+/// appended to the buffer it was read from; the buffer views previously used
+/// are left in place and may be removed by repacking the buffers afterwards.
+pub fn narrow_component_types(root: &mut json::Root, buffers: &mut [Vec<u8>]) -> usize {
+    let mut candidates = Vec::new();
+    for mesh in &root.meshes {
+        for primitive in &mesh.primitives {
+            if let Some(indices) = primitive.indices {
+                candidates.push((indices, Target::ElementArrayBuffer));
+            }
+            for accessor in primitive.attributes.values() {
+                candidates.push((*accessor, Target::ArrayBuffer));
+            }
+        }
+    }
+    candidates.sort_by_key(|&(index, _)| index.value());
+    candidates.dedup_by_key(|&mut (index, _)| index.value());
+
+    let mut count = 0;
+    for (index, target) in candidates {
+        let accessor = match root.accessors.get(index.value()) {
+            Some(accessor) => accessor,
+            None => continue,
+        };
+        let current = match accessor.component_type {
+            Valid(GenericComponentType(ComponentType::U16)) => ComponentType::U16,
+            Valid(GenericComponentType(ComponentType::U32)) => ComponentType::U32,
+            _ => continue,
+        };
+        if accessor.normalized {
+            continue;
+        }
+        let components = match read_components(root, buffers, accessor) {
+            Some(components) => components,
+            None => continue,
+        };
+        let max = components.iter().cloned().fold(0.0, f64::max);
+        // Index data must not contain the primitive restart value.
+        let reserved = if target == Target::ElementArrayBuffer { 1.0 } else { 0.0 };
+        let narrowed = if max + reserved <= 255.0 {
+            ComponentType::U8
+        } else if max + reserved <= 65_535.0 {
+            ComponentType::U16
+        } else {
+            continue;
+        };
+        if component_size(narrowed) >= component_size(current) {
+            continue;
+        }
+        write_components(root, buffers, index, &components, narrowed, Some(target));
+        count += 1;
+    }
+    count
+}
+
+/// Reads the component values of an accessor as floats, decoding normalized
+/// integer components.
+fn read_floats(root: &json::Root, buffers: &[Vec<u8>], index: usize) -> Option<Vec<f64>> {
+    let accessor = root.accessors.get(index)?;
+    let mut components = read_components(root, buffers, accessor)?;
+    if let (true, Valid(GenericComponentType(component_type))) =
+        (accessor.normalized, accessor.component_type)
+    {
+        components.iter_mut().for_each(|x| *x = denormalize(*x, component_type));
+    }
+    Some(components)
+}
+
+/// Collapses the joint influences of primitives with more than one set of
+/// `JOINTS_n` and `WEIGHTS_n` attributes into `JOINTS_0` and `WEIGHTS_0`,
+/// returning the number of primitives rewritten.
+///
+/// The four largest weights of each vertex are kept and renormalized to sum to
+/// one, for renderers limited to four influences per vertex. The joints and
+/// weights are written to new `UNSIGNED_SHORT` and `FLOAT` accessors, the other
+/// sets are removed from the primitive, and primitives whose joints or weights
+/// cannot be read are left untouched.
+///
+/// `buffers` holds the contents of each buffer in `root`; new data is appended
+/// to the buffer of the previous `JOINTS_0` data.
+pub fn collapse_influences(root: &mut json::Root, buffers: &mut [Vec<u8>]) -> usize {
+    let mut count = 0;
+    for m in 0..root.meshes.len() {
+        for p in 0..root.meshes[m].primitives.len() {
+            let attributes = &root.meshes[m].primitives[p].attributes;
+            let get = |semantic| attributes.get(&Valid(semantic)).map(|x| x.value());
+            let sets: Vec<_> = (0..)
+                .map(|set| Some((get(Semantic::Joints(set))?, get(Semantic::Weights(set))?)))
+                .take_while(Option::is_some)
+                .flatten()
+                .collect();
+            if sets.len() < 2 {
+                continue;
+            }
+            let data = sets
+                .iter()
+                .map(|&(joints, weights)| {
+                    let joints = read_floats(root, buffers, joints)?;
+                    Some((joints, read_floats(root, buffers, weights)?))
+                })
+                .collect::<Option<Vec<_>>>();
+            let data = match data {
+                Some(data) => data,
+                None => continue,
+            };
+            let len = data[0].0.len();
+            if len % 4 != 0 || data.iter().any(|(j, w)| j.len() != len || w.len() != len) {
+                continue;
+            }
+
+            let mut joints = Vec::with_capacity(len);
+            let mut weights = Vec::with_capacity(len);
+            for vertex in 0..len / 4 {
+                let mut influences: Vec<(f64, f64)> = data
+                    .iter()
+                    .flat_map(|(j, w)| (4 * vertex..4 * vertex + 4).map(move |i| (j[i], w[i])))
+                    .collect();
+                // The sort is stable, so equal weights keep the order of their sets.
+                influences.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal));
+                influences.truncate(4);
+                let sum: f64 = influences.iter().map(|&(_, weight)| weight).sum();
+                for (joint, weight) in influences {
+                    let weight = if sum > 0.0 { weight / sum } else { 0.0 };
+                    joints.push(if weight > 0.0 { joint } else { 0.0 });
+                    weights.push(weight);
+                }
+            }
+
+            let mut push = |template: usize, components: &[f64], component_type| {
+                let mut accessor = root.accessors[template].clone();
+                accessor.normalized = false;
+                accessor.min = None;
+                accessor.max = None;
+                let index = root.push(accessor);
+                let target = Some(Target::ArrayBuffer);
+                write_components(root, buffers, index, components, component_type, target);
+                index
+            };
+            let joints = push(sets[0].0, &joints, ComponentType::U16);
+            let weights = push(sets[0].1, &weights, ComponentType::F32);
+            let attributes = &mut root.meshes[m].primitives[p].attributes;
+            attributes.retain(|semantic, _| match semantic {
+                Valid(Semantic::Joints(set)) | Valid(Semantic::Weights(set)) => *set == 0,
+                _ => true,
+            });
+            attributes.insert(Valid(Semantic::Joints(0)), joints);
+            attributes.insert(Valid(Semantic::Weights(0)), weights);
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Rewrites every referenced buffer view contiguously into a single buffer,
+/// returning the contents of the new buffer.
+///
+/// Buffer views are aligned to four bytes. Views not referenced by any accessor,
+/// sparse accessor, image, or Draco compressed primitive are discarded, as are
+/// the regions of the original buffers not covered by any view. All buffers in
+/// `root` are replaced by one buffer without a URI, making the result suitable
+/// for binary glTF export.
+///
+/// `buffers` holds the contents of each buffer in `root`. Meshopt compressed
+/// buffer views are written decompressed, so `buffers` must hold the decoded
+/// data, as returned by `gltf::import`.
+///
+/// # Panics
+///
+/// Panics if a buffer view refers to data outside of `buffers`.
+pub fn repack_buffers(root: &mut json::Root, buffers: &[Vec<u8>]) -> Vec<u8> {
+    let order: Vec<_> = referenced_views(root)
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| **x)
+        .map(|(i, _)| i)
+        .collect();
+    repack_views(root, buffers, &order)
+}
+
+/// Returns whether each buffer view is referenced by an accessor, sparse
+/// accessor, image, or Draco compressed primitive.
+fn referenced_views(root: &json::Root) -> Vec<bool> {
+    let mut referenced = vec![false; root.buffer_views.len()];
+    let mut mark = |view: json::Index<json::buffer::View>| {
+        if let Some(flag) = referenced.get_mut(view.value()) {
+            *flag = true;
+        }
+    };
+    for accessor in &root.accessors {
+        if let Some(view) = accessor.buffer_view {
+            mark(view);
+        }
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            mark(sparse.indices.buffer_view);
+            mark(sparse.values.buffer_view);
+        }
+    }
+    for image in &root.images {
+        if let Some(view) = image.buffer_view {
+            mark(view);
+        }
+    }
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    for primitive in root.meshes.iter().flat_map(|mesh| mesh.primitives.iter()) {
+        let extensions = primitive.extensions.as_ref();
+        if let Some(draco) = extensions.and_then(|x| x.khr_draco_mesh_compression.as_ref()) {
+            mark(draco.buffer_view);
+        }
+    }
+    referenced
+}
+
+/// Rewrites the buffer views listed in `order`, which must include every
+/// referenced view exactly once, contiguously into a single buffer in that
+/// order, discarding every other view.
+fn repack_views(root: &mut json::Root, buffers: &[Vec<u8>], order: &[usize]) -> Vec<u8> {
+    let buffer = json::Index::new(0);
+    let mut data = Vec::new();
+    let mut remap = vec![None; root.buffer_views.len()];
+    let mut old: Vec<_> = root.buffer_views.drain(..).map(Some).collect();
+    let mut views = Vec::new();
+    for &i in order {
+        let mut view = old[i].take().unwrap();
+        let start = view.byte_offset.unwrap_or(0) as usize;
+        let end = start + view.byte_length as usize;
+        data.resize((data.len() + 3) & !3, 0);
+        view.byte_offset = Some(data.len() as u32);
+        data.extend_from_slice(&buffers[view.buffer.value()][start..end]);
+        view.buffer = buffer;
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if let Some(extensions) = view.extensions.as_mut() {
+                extensions.ext_meshopt_compression = None;
+            }
+        }
+        remap[i] = Some(json::Index::new(views.len() as u32));
+        views.push(view);
+    }
+    data.resize((data.len() + 3) & !3, 0);
+    root.buffer_views = views;
+
+    let remap = |view: json::Index<json::buffer::View>| remap[view.value()].unwrap();
+    for accessor in &mut root.accessors {
+        accessor.buffer_view = accessor.buffer_view.map(remap);
+        if let Some(sparse) = accessor.sparse.as_mut() {
+            sparse.indices.buffer_view = remap(sparse.indices.buffer_view);
+            sparse.values.buffer_view = remap(sparse.values.buffer_view);
+        }
+    }
+    for image in &mut root.images {
+        image.buffer_view = image.buffer_view.map(remap);
+    }
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    for primitive in root.meshes.iter_mut().flat_map(|mesh| mesh.primitives.iter_mut()) {
+        let extensions = primitive.extensions.as_mut();
+        if let Some(draco) = extensions.and_then(|x| x.khr_draco_mesh_compression.as_mut()) {
+            draco.buffer_view = remap(draco.buffer_view);
+        }
+    }
+
+    root.buffers.clear();
+    if !root.buffer_views.is_empty() {
+        root.buffers.push(json::Buffer {
+            byte_length: data.len() as u32,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+    }
+    data
+}
+
+/// The position of a mesh in a buffer repacked by `repack_buffers_progressive`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StreamEntry {
+    /// The index of the mesh.
+    pub mesh: usize,
+
+    /// The `MSFT_lod` level of the mesh, where `0` is the highest detail. Meshes
+    /// without levels of detail are at level `0`.
+    pub level: usize,
+
+    /// The number of bytes from the start of the buffer that must be available
+    /// before every buffer view of the mesh is complete.
+    pub end: usize,
+}
+
+/// Returns the buffer views holding the geometry of a mesh, sorted and without
+/// duplicates.
+fn mesh_views(root: &json::Root, mesh: &json::Mesh) -> Vec<usize> {
+    let mut views = Vec::new();
+    for primitive in &mesh.primitives {
+        let targets = primitive.targets.iter().flatten().flat_map(|target| {
+            let others = target.others.values().copied();
+            target.positions.into_iter().chain(target.normals).chain(target.tangents).chain(others)
+        });
+        let attributes = primitive.attributes.values().copied();
+        let accessors = attributes.chain(primitive.indices).chain(targets);
+        for accessor in accessors.filter_map(|index| root.accessors.get(index.value())) {
+            views.extend(accessor.buffer_view.map(|view| view.value()));
+            if let Some(sparse) = accessor.sparse.as_ref() {
+                views.push(sparse.indices.buffer_view.value());
+                views.push(sparse.values.buffer_view.value());
+            }
+        }
+    }
+    #[cfg(feature = "KHR_draco_mesh_compression")]
+    for primitive in &mesh.primitives {
+        let extensions = primitive.extensions.as_ref();
+        if let Some(draco) = extensions.and_then(|x| x.khr_draco_mesh_compression.as_ref()) {
+            views.push(draco.buffer_view.value());
+        }
+    }
+    views.sort_unstable();
+    views.dedup();
+    views.retain(|&view| view < root.buffer_views.len());
+    views
+}
+
+/// Returns the `MSFT_lod` level of each mesh along with its distance from the
+/// lowest level of detail of its node, which is `0` for meshes without levels.
+fn mesh_levels(root: &json::Root) -> Vec<(usize, usize)> {
+    let mut levels = vec![(0, usize::MAX); root.meshes.len()];
+    for node in &root.nodes {
+        let ids = node
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.others.get("MSFT_lod"))
+            .and_then(|lod| lod.get("ids"))
+            .and_then(|ids| ids.as_array());
+        let mut chain = vec![node.mesh];
+        for id in ids.into_iter().flatten().filter_map(|id| id.as_u64()) {
+            chain.push(root.nodes.get(id as usize).and_then(|node| node.mesh));
+        }
+        let coarsest = chain.len() - 1;
+        for (level, mesh) in chain.into_iter().enumerate() {
+            let entry = mesh.and_then(|mesh| levels.get_mut(mesh.value()));
+            if let Some(entry) = entry {
+                if coarsest - level < entry.1 {
+                    *entry = (level, coarsest - level);
+                }
+            }
+        }
+    }
+    // Meshes not instantiated by any node count as the lowest level of detail.
+    levels
+        .into_iter()
+        .map(|(level, rank)| (level, if rank == usize::MAX { 0 } else { rank }))
+        .collect()
+}
+
+/// Rewrites every referenced buffer view into a single buffer as
+/// `repack_buffers` does, ordering the data for progressive rendering while
+/// binary glTF is downloaded.
+///
+/// The geometry of meshes comes first, ordered from the lowest `MSFT_lod` level
+/// of detail up to the highest and, within the same level, from the smallest
+/// mesh to the largest. Buffer views not used by any mesh, such as images,
+/// skins, and animations, follow in their original order. Returns the new
+/// buffer along with a manifest listing when each mesh becomes complete, in
+/// buffer order.
+///
+/// # Panics
+///
+/// Panics if a buffer view refers to data outside of `buffers`.
+pub fn repack_buffers_progressive(
+    root: &mut json::Root,
+    buffers: &[Vec<u8>],
+) -> (Vec<u8>, Vec<StreamEntry>) {
+    let referenced = referenced_views(root);
+    let levels = mesh_levels(root);
+    let mut meshes: Vec<_> = root
+        .meshes
+        .iter()
+        .enumerate()
+        .map(|(index, mesh)| {
+            let views = mesh_views(root, mesh);
+            let size: usize =
+                views.iter().map(|&view| root.buffer_views[view].byte_length as usize).sum();
+            (levels[index].1, size, index, views)
+        })
+        .collect();
+    meshes.sort();
+
+    // The position of each placed view in the new buffer.
+    let mut placed = vec![None; referenced.len()];
+    let mut order = Vec::new();
+    let mut last_views = Vec::new();
+    for (_, _, _, views) in &meshes {
+        for &view in views {
+            if placed[view].is_none() {
+                placed[view] = Some(order.len());
+                order.push(view);
+            }
+        }
+        last_views.push(views.iter().filter_map(|&view| placed[view]).max());
+    }
+    for (view, _) in referenced.iter().enumerate().filter(|(_, x)| **x) {
+        if placed[view].is_none() {
+            order.push(view);
+        }
+    }
+
+    let data = repack_views(root, buffers, &order);
+    let manifest = meshes
+        .iter()
+        .zip(last_views)
+        .map(|(&(_, _, index, _), last)| {
+            let end = last.map_or(0, |last| {
+                let view = &root.buffer_views[last];
+                view.byte_offset.unwrap_or(0) as usize + view.byte_length as usize
+            });
+            StreamEntry { mesh: index, level: levels[index].0, end }
+        })
+        .collect();
+    (data, manifest)
+}
+
+/// Reads the keyframe times of an animation input accessor.
+fn keyframe_times(root: &json::Root, buffers: &[Vec<u8>], accessor: &json::Accessor) -> Option<Vec<f64>> {
+    match (accessor.type_, accessor.component_type) {
+        (Valid(Type::Scalar), Valid(GenericComponentType(ComponentType::F32))) => {
+            read_components(root, buffers, accessor)
+        },
+        _ => None,
+    }
+}
+
+/// Returns `true` if the declared single-component bound matches `x`.
+fn bound_matches(bound: &Option<json::Value>, x: f64) -> bool {
+    match bound.as_ref().and_then(|bound| bound.get(0)).and_then(json::Value::as_f64) {
+        Some(bound) => bound as f32 == x as f32,
+        None => false,
+    }
+}
+
+/// Checks the keyframe times of every animation input accessor against the
+/// data in `buffers`.
+///
+/// Times must be non-negative and strictly increasing, and the declared `min`
+/// and `max` must match the first and last times. Each offending input accessor
+/// is reported once, at the first sampler using it; mismatched bounds are
+/// reported at the accessor itself. Accessors whose data cannot be read are
+/// skipped.
+pub fn validate_keyframe_times(
+    root: &json::Root,
+    buffers: &[Vec<u8>],
+) -> Vec<(json::Path, json::validation::Error)> {
+    use json::validation::Error;
+    let mut errors = Vec::new();
+    let mut visited = vec![false; root.accessors.len()];
+    for (a, animation) in root.animations.iter().enumerate() {
+        for (s, sampler) in animation.samplers.iter().enumerate() {
+            let index = sampler.input.value();
+            match visited.get_mut(index) {
+                Some(visited) if !*visited => *visited = true,
+                _ => continue,
+            }
+            let accessor = &root.accessors[index];
+            let times = match keyframe_times(root, buffers, accessor) {
+                Some(times) => times,
+                None => continue,
+            };
+            let ordered = times.iter().all(|&t| t >= 0.0 && t.is_finite())
+                && times.windows(2).all(|pair| pair[0] < pair[1]);
+            if !ordered {
+                let path = json::Path::new()
+                    .field("animations")
+                    .index(a)
+                    .field("samplers")
+                    .index(s)
+                    .field("input");
+                errors.push((path, Error::Invalid));
+            }
+            if let (Some(&first), Some(&last)) = (times.first(), times.last()) {
+                let path = || json::Path::new().field("accessors").index(index);
+                if !bound_matches(&accessor.min, first) {
+                    errors.push((path().field("min"), Error::Invalid));
+                }
+                if !bound_matches(&accessor.max, last) {
+                    errors.push((path().field("max"), Error::Invalid));
+                }
+            }
+        }
+    }
+    errors
+}
+
+/// Repairs the keyframe times of every animation input accessor, returning the
+/// number of input accessors rewritten.
+///
+/// Keyframes with negative or non-finite times are dropped, the remainder are
+/// sorted by time, and of several keyframes with equal times only the first is
+/// kept. The output accessors of every sampler sharing the input are reordered
+/// to match, and the `min` and `max` of the input are set from the repaired
+/// times. Inputs are skipped if any related output cannot be read, does not
+/// hold a whole number of values per keyframe, or is shared with a sampler
+/// using a different input.
+///
+/// `buffers` holds the contents of each buffer in `root`; rewritten data is
+/// appended as described in [`narrow_component_types`].
+///
+/// [`narrow_component_types`]: fn.narrow_component_types.html
+pub fn repair_keyframe_times(root: &mut json::Root, buffers: &mut [Vec<u8>]) -> usize {
+    let samplers: Vec<(usize, usize)> = root.animations.iter()
+        .flat_map(|animation| animation.samplers.iter())
+        .map(|sampler| (sampler.input.value(), sampler.output.value()))
+        .collect();
+    let mut inputs: Vec<usize> = samplers.iter().map(|&(input, _)| input).collect();
+    inputs.sort_unstable();
+    inputs.dedup();
+
+    let mut count = 0;
+    'inputs: for input in inputs {
+        let accessor = match root.accessors.get(input) {
+            Some(accessor) => accessor,
+            None => continue,
+        };
+        let times = match keyframe_times(root, buffers, accessor) {
+            Some(times) if !times.is_empty() => times,
+            _ => continue,
+        };
+        let mut outputs: Vec<usize> = samplers.iter()
+            .filter(|&&(other, _)| other == input)
+            .map(|&(_, output)| output)
+            .collect();
+        outputs.sort_unstable();
+        outputs.dedup();
+        let mut output_data = Vec::with_capacity(outputs.len());
+        for &output in &outputs {
+            if samplers.iter().any(|&(other, o)| o == output && other != input) {
+                continue 'inputs;
+            }
+            let components = match root.accessors.get(output)
+                .and_then(|accessor| read_components(root, buffers, accessor))
+            {
+                Some(components) => components,
+                None => continue 'inputs,
+            };
+            let per_keyframe = components.len() / times.len();
+            if per_keyframe == 0 || per_keyframe * times.len() != components.len() {
+                continue 'inputs;
+            }
+            output_data.push((output, components, per_keyframe));
+        }
+
+        let mut order: Vec<usize> = (0..times.len())
+            .filter(|&i| times[i] >= 0.0 && times[i].is_finite())
+            .collect();
+        order.sort_by(|&i, &j| times[i].partial_cmp(&times[j]).unwrap());
+        order.dedup_by(|&mut i, &mut j| times[i] == times[j]);
+        let (first, last) = match (order.first(), order.last()) {
+            (Some(&first), Some(&last)) => (times[first], times[last]),
+            _ => continue,
+        };
+
+        if order.len() != times.len() || order.iter().enumerate().any(|(i, &j)| i != j) {
+            let repaired: Vec<f64> = order.iter().map(|&i| times[i]).collect();
+            let index = json::Index::new(input as u32);
+            write_components(root, buffers, index, &repaired, ComponentType::F32, None);
+            root.accessors[input].count = repaired.len() as u32;
+            for (output, components, per_keyframe) in output_data {
+                let reordered: Vec<f64> = order.iter()
+                    .flat_map(|&i| components[i * per_keyframe..(i + 1) * per_keyframe].iter().cloned())
+                    .collect();
+                let accessor = &root.accessors[output];
+                let component_type = match accessor.component_type {
+                    Valid(GenericComponentType(component_type)) => component_type,
+                    _ => unreachable!(),
+                };
+                let n = match accessor.type_ {
+                    Valid(type_) => dimensions(type_),
+                    _ => unreachable!(),
+                };
+                let index = json::Index::new(output as u32);
+                write_components(root, buffers, index, &reordered, component_type, None);
+                root.accessors[output].count = (reordered.len() / n) as u32;
+            }
+        } else if bound_matches(&accessor.min, first) && bound_matches(&accessor.max, last) {
+            continue;
+        }
+
+        let accessor = &mut root.accessors[input];
+        accessor.min = Some(json::Value::from(vec![crate::pack::f32_to_json(first as f32)]));
+        accessor.max = Some(json::Value::from(vec![crate::pack::f32_to_json(last as f32)]));
+        count += 1;
+    }
+    count
+}
+
+/// Options for `reduce_keyframes`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ReductionOptions {
+    /// The greatest difference allowed between any original and reconstructed
+    /// component value.
+    ///
+    /// Rotations are compared after choosing the nearer of each reconstructed
+    /// quaternion and its negation.
+    pub tolerance: f64,
+
+    /// Whether samplers whose values are constant within `tolerance` are
+    /// converted to `STEP` interpolation.
+    pub constant_to_step: bool,
+}
+
+impl Default for ReductionOptions {
+    fn default() -> Self {
+        Self { tolerance: 1e-4, constant_to_step: true }
+    }
+}
+
+/// Returns `true` if the reconstructed values match the original values within
+/// `tolerance`, comparing quaternions up to sign if `rotation` is set.
+fn within(original: &[f64], reconstructed: &[f64], rotation: bool, tolerance: f64) -> bool {
+    let matches = |sign: f64| {
+        original.iter().zip(reconstructed).all(|(a, b)| (a - sign * b).abs() <= tolerance)
+    };
+    matches(1.0) || (rotation && matches(-1.0))
+}
+
+/// Returns the indices of the keyframes needed to reproduce every keyframe within
+/// `tolerance`.
+///
+/// The first and last keyframes are always kept so that the duration of the
+/// animation is unchanged.
+fn essential_keyframes(
+    times: &[f64],
+    values: &[f64],
+    per_keyframe: usize,
+    interpolation: json::animation::Interpolation,
+    rotation: bool,
+    tolerance: f64,
+) -> Vec<usize> {
+    let value = |i: usize| &values[i * per_keyframe..(i + 1) * per_keyframe];
+    // Returns `true` if keyframe `k` is reproduced from keyframes `a` and `b`.
+    let fits = |a: usize, b: usize, k: usize| {
+        if interpolation == json::animation::Interpolation::Step {
+            return within(value(k), value(a), rotation, tolerance);
+        }
+        let t = (times[k] - times[a]) / (times[b] - times[a]);
+        let reconstructed: Vec<f64> = if rotation {
+            value(a).chunks(4)
+                .zip(value(b).chunks(4))
+                .flat_map(|(a, b)| slerp(a, b, t).to_vec())
+                .collect()
+        } else {
+            value(a).iter().zip(value(b)).map(|(a, b)| a + t * (b - a)).collect()
+        };
+        within(value(k), &reconstructed, rotation, tolerance)
+    };
+    let mut keep = vec![0];
+    let mut anchor = 0;
+    for end in 2..times.len() {
+        if !(anchor + 1..end).all(|k| fits(anchor, end, k)) {
+            anchor = end - 1;
+            keep.push(anchor);
+        }
+    }
+    if times.len() > 1 {
+        keep.push(times.len() - 1);
+    }
+    keep
+}
+
+/// Returns an accessor that may be rewritten in place of `index`, copying it if
+/// it has other references.
+fn exclusive_accessor(
+    root: &mut json::Root,
+    references: &mut [usize],
+    index: json::Index<json::Accessor>,
+) -> json::Index<json::Accessor> {
+    if references[index.value()] <= 1 {
+        return index;
+    }
+    references[index.value()] -= 1;
+    let copy = root.accessors[index.value()].clone();
+    root.push(copy)
+}
+
+/// Removes redundant keyframes from every `LINEAR` and `STEP` animation sampler,
+/// returning the number of samplers rewritten.
+///
+/// A keyframe is redundant if interpolating between its neighbouring retained
+/// keyframes reproduces it within `options.tolerance`. Samplers of rotation
+/// channels are interpolated spherically. Samplers whose values are constant are
+/// reduced to their first and last keyframes, and converted to `STEP`
+/// interpolation if `options.constant_to_step` is set.
+///
+/// Only samplers with `FLOAT` inputs and outputs and strictly increasing times
+/// are reduced. Accessors referenced elsewhere are copied rather than rewritten.
+/// `buffers` holds the contents of each buffer in `root`; rewritten data is
+/// appended as described in [`narrow_component_types`].
+///
+/// [`narrow_component_types`]: fn.narrow_component_types.html
+pub fn reduce_keyframes(
+    root: &mut json::Root,
+    buffers: &mut [Vec<u8>],
+    options: ReductionOptions,
+) -> usize {
+    use json::animation::{Interpolation, Property};
+
+    let mut references: Vec<usize> = crate::accessor::usage::usages(root)
+        .iter()
+        .map(|usages| {
+            usages.iter()
+                .filter(|usage| !matches!(usage, Usage::AnimationInput | Usage::AnimationOutput))
+                .count()
+        })
+        .collect();
+    for sampler in root.animations.iter().flat_map(|animation| animation.samplers.iter()) {
+        for accessor in &[sampler.input, sampler.output] {
+            if let Some(references) = references.get_mut(accessor.value()) {
+                *references += 1;
+            }
+        }
+    }
+
+    let mut count = 0;
+    for a in 0..root.animations.len() {
+        for s in 0..root.animations[a].samplers.len() {
+            let animation = &root.animations[a];
+            let sampler = &animation.samplers[s];
+            let interpolation = match sampler.interpolation {
+                Valid(Interpolation::Linear) => Interpolation::Linear,
+                Valid(Interpolation::Step) => Interpolation::Step,
+                _ => continue,
+            };
+            let rotation = animation.channels.iter().any(|channel| {
+                channel.sampler.value() == s && channel.target.path == Valid(Property::Rotation)
+            });
+            let (input, output) = (sampler.input, sampler.output);
+            let times = match root.accessors.get(input.value())
+                .and_then(|accessor| keyframe_times(root, buffers, accessor))
+            {
+                Some(times) if !times.is_empty() => times,
+                _ => continue,
+            };
+            if !times.windows(2).all(|pair| pair[0] < pair[1]) {
+                continue;
+            }
+            let values = match root.accessors.get(output.value()) {
+                Some(accessor) => match accessor.component_type {
+                    Valid(GenericComponentType(ComponentType::F32)) => {
+                        read_components(root, buffers, accessor)
+                    },
+                    _ => None,
+                },
+                None => None,
+            };
+            let values = match values {
+                Some(values) => values,
+                None => continue,
+            };
+            let per_keyframe = values.len() / times.len();
+            if per_keyframe == 0
+                || per_keyframe * times.len() != values.len()
+                || (rotation && per_keyframe % 4 != 0)
+            {
+                continue;
+            }
+
+            let tolerance = options.tolerance;
+            let first = &values[..per_keyframe];
+            let constant = values.chunks(per_keyframe)
+                .all(|value| within(value, first, rotation, tolerance));
+            let keep = if constant {
+                let mut keep = vec![0];
+                if times.len() > 1 {
+                    keep.push(times.len() - 1);
+                }
+                keep
+            } else {
+                essential_keyframes(
+                    &times,
+                    &values,
+                    per_keyframe,
+                    interpolation,
+                    rotation,
+                    tolerance,
+                )
+            };
+            let step = constant
+                && options.constant_to_step
+                && interpolation == Interpolation::Linear;
+            if keep.len() == times.len() && !step {
+                continue;
+            }
+
+            let reduced_times: Vec<f64> = keep.iter().map(|&k| times[k]).collect();
+            let reduced_values: Vec<f64> = keep.iter()
+                .flat_map(|&k| values[k * per_keyframe..(k + 1) * per_keyframe].iter().copied())
+                .collect();
+            let input = exclusive_accessor(root, &mut references, input);
+            write_components(root, buffers, input, &reduced_times, ComponentType::F32, None);
+            let accessor = &mut root.accessors[input.value()];
+            accessor.count = reduced_times.len() as u32;
+            let (min, max) = (reduced_times[0], reduced_times[reduced_times.len() - 1]);
+            accessor.min = Some(json::Value::from(vec![crate::pack::f32_to_json(min as f32)]));
+            accessor.max = Some(json::Value::from(vec![crate::pack::f32_to_json(max as f32)]));
+            let output = exclusive_accessor(root, &mut references, output);
+            write_components(root, buffers, output, &reduced_values, ComponentType::F32, None);
+            let n = match root.accessors[output.value()].type_ {
+                Valid(type_) => dimensions(type_),
+                _ => unreachable!(),
+            };
+            root.accessors[output.value()].count = (reduced_values.len() / n) as u32;
+
+            let sampler = &mut root.animations[a].samplers[s];
+            sampler.input = input;
+            sampler.output = output;
+            if step {
+                sampler.interpolation = Valid(Interpolation::Step);
+            }
+            count += 1;
+        }
+    }
+    count
+}
+
+/// The repair made to a node by `repair_node_transforms`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransformRepair {
+    /// The matrix was decomposed into translation, rotation, and scale, which
+    /// replaced the previous TRS properties.
+    Decomposed,
+
+    /// The TRS properties were dropped, keeping the matrix, because the matrix
+    /// has shear or projection and cannot be decomposed.
+    DroppedTrs,
+}
+
+/// Returns the TRS properties equivalent to a column-major matrix, or `None` if
+/// the matrix cannot be decomposed.
+fn decompose(m: [f32; 16]) -> Option<([f32; 3], [f32; 4], [f32; 3])> {
+    use crate::scene::Transform;
+    if m[3] != 0.0 || m[7] != 0.0 || m[11] != 0.0 || m[15] != 1.0 {
+        return None;
+    }
+    let original = [
+        [m[0], m[1], m[2], m[3]],
+        [m[4], m[5], m[6], m[7]],
+        [m[8], m[9], m[10], m[11]],
+        [m[12], m[13], m[14], m[15]],
+    ];
+    let (translation, rotation, scale) = Transform::Matrix { matrix: original }.decomposed();
+    let recomposed = Transform::Decomposed { translation, rotation, scale }.matrix();
+    let tolerance = 1e-4 * original.iter().flatten().fold(1.0f32, |max, x| max.max(x.abs()));
+    let matches = original.iter()
+        .flatten()
+        .zip(recomposed.iter().flatten())
+        .all(|(a, b)| (a - b).abs() <= tolerance);
+    if matches {
+        Some((translation, rotation, scale))
+    } else {
+        None
+    }
+}
+
+/// Repairs nodes that specify both a `matrix` and TRS properties, returning the
+/// nodes rewritten and how.
+///
+/// The matrix takes precedence, as it does in `scene::Node::transform`. It is
+/// decomposed into TRS properties where possible, which replace the previous
+/// ones; otherwise the TRS properties are dropped.
+pub fn repair_node_transforms(
+    root: &mut json::Root,
+) -> Vec<(json::Index<json::Node>, TransformRepair)> {
+    let mut repairs = Vec::new();
+    for (index, node) in root.nodes.iter_mut().enumerate() {
+        let matrix = match node.matrix {
+            Some(matrix) => matrix,
+            None => continue,
+        };
+        if node.translation.is_none() && node.rotation.is_none() && node.scale.is_none() {
+            continue;
+        }
+        let repair = match decompose(matrix) {
+            Some((translation, rotation, scale)) => {
+                node.matrix = None;
+                node.translation = Some(translation);
+                node.rotation = Some(json::scene::UnitQuaternion(rotation));
+                node.scale = Some(scale);
+                TransformRepair::Decomposed
+            },
+            None => {
+                node.translation = None;
+                node.rotation = None;
+                node.scale = None;
+                TransformRepair::DroppedTrs
+            },
+        };
+        repairs.push((json::Index::new(index as u32), repair));
+    }
+    repairs
+}
+
+/// Returns the core texture references of a material.
+#[cfg(feature = "import")]
+fn material_textures(material: &mut json::Material) -> Vec<&mut json::Index<json::Texture>> {
+    let pbr = &mut material.pbr_metallic_roughness;
+    let mut textures = Vec::new();
+    textures.extend(pbr.base_color_texture.as_mut().map(|info| &mut info.index));
+    textures.extend(pbr.metallic_roughness_texture.as_mut().map(|info| &mut info.index));
+    textures.extend(material.normal_texture.as_mut().map(|texture| &mut texture.index));
+    textures.extend(material.occlusion_texture.as_mut().map(|texture| &mut texture.index));
+    textures.extend(material.emissive_texture.as_mut().map(|info| &mut info.index));
+    textures
+}
+
+/// Creates lower-resolution texture variants of a material and binds them to
+/// `MSFT_lod` material levels, returning the level materials from highest to
+/// lowest detail.
+///
+/// One level is created for each entry of `budgets`, which gives the maximum
+/// uncompressed texture memory of that level in bytes. The images of each level
+/// are downsampled from those of the previous level at least once, and then
+/// repeatedly until their total memory fits the budget or every image is a single
+/// pixel. Level materials are copies of `material` referencing the downsampled
+/// textures; images are encoded as PNG into a new buffer appended to `buffers`.
+///
+/// `buffers` holds the contents of each buffer in `root` and `images` holds the
+/// decoded data of each image, as returned by `gltf::import`. Textures whose
+/// image has no data are shared with `material`. Any existing `MSFT_lod`
+/// extension of `material` is replaced. To keep texture and geometry detail
+/// consistent, bind the level materials to the primitives of the mesh levels of
+/// matching detail.
+///
+/// # Panics
+///
+/// Panics if `material` is out of bounds.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn texture_lods(
+    root: &mut json::Root,
+    buffers: &mut Vec<Vec<u8>>,
+    images: &[crate::image::Data],
+    material: json::Index<json::Material>,
+    budgets: &[usize],
+) -> Result<Vec<json::Index<json::Material>>, crate::Error> {
+    let mut base = root.materials[material.value()].clone();
+    if let Some(extensions) = base.extensions.as_mut() {
+        extensions.others.remove("MSFT_lod");
+    }
+
+    // The distinct images of the material, downsampled to the current level.
+    let mut sources: Vec<(usize, crate::image::Data)> = Vec::new();
+    for texture in material_textures(&mut base.clone()) {
+        // KTX2 and undecoded images cannot be downsampled, so only the core source is used.
+        let source = match root.textures.get(texture.value()).and_then(|x| x.source) {
+            Some(source) => source.value(),
+            None => continue,
+        };
+        let data = images.get(source).filter(|x| !x.format.is_encoded());
+        if let Some(data) = data {
+            if !sources.iter().any(|&(other, _)| other == source) {
+                sources.push((source, data.clone()));
+            }
+        }
+    }
+
+    let mut packer = crate::pack::Packer::new(root);
+    let mut levels = Vec::new();
+    for &budget in budgets {
+        while sources.iter().any(|(_, data)| data.width > 1 || data.height > 1) {
+            for (_, data) in &mut sources {
+                *data = data.downsample();
+            }
+            if sources.iter().map(|(_, data)| data.pixels.len()).sum::<usize>() <= budget {
+                break;
+            }
+        }
+        let mut copies = Vec::new();
+        for (source, data) in &sources {
+            let png = data.to_png()?;
+            let view = packer.push_view(root, &png, None, None);
+            let image = root.push(json::Image {
+                buffer_view: Some(view),
+                mime_type: Some(json::image::MimeType("image/png".to_string())),
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+                extensions: None,
+                extras: Default::default(),
+            });
+            copies.push((*source, image));
+        }
+
+        let mut level = base.clone();
+        let mut textures: Vec<(usize, json::Index<json::Texture>)> = Vec::new();
+        for texture in material_textures(&mut level) {
+            if let Some(&(_, copy)) = textures.iter().find(|&&(original, _)| original == texture.value()) {
+                *texture = copy;
+                continue;
+            }
+            let mut json = match root.textures.get(texture.value()) {
+                Some(json) => json.clone(),
+                None => continue,
+            };
+            let source = json.source.map(|x| x.value());
+            let image = match copies.iter().find(|&&(x, _)| Some(x) == source) {
+                Some(&(_, image)) => image,
+                None => continue,
+            };
+            json.source = Some(image);
+            // The images of the extensions would take precedence over the
+            // downsampled copy.
+            #[cfg(feature = "KHR_texture_basisu")]
+            {
+                if let Some(extensions) = json.extensions.as_mut() {
+                    extensions.texture_basisu = None;
+                }
+            }
+            #[cfg(feature = "EXT_texture_webp")]
+            {
+                if let Some(extensions) = json.extensions.as_mut() {
+                    extensions.texture_webp = None;
+                }
+            }
+            let copy = root.push(json);
+            textures.push((texture.value(), copy));
+            *texture = copy;
+        }
+        levels.push(root.push(level));
+    }
+    buffers.push(packer.finish(root));
+
+    let ids: Vec<json::Value> = levels.iter().map(|level| json::Value::from(level.value())).collect();
+    let lod = json::Value::Object(vec![("ids".to_string(), json::Value::from(ids))].into_iter().collect());
+    root.materials[material.value()]
+        .extensions
+        .get_or_insert_with(Default::default)
+        .others
+        .insert("MSFT_lod".to_string(), lod);
+    let name = "MSFT_lod".to_string();
+    if !root.extensions_used.contains(&name) {
+        root.extensions_used.push(name);
+    }
+    Ok(levels)
+}
+
+/// The key in the `extras` of an image under which `convert_normal_maps`
+/// records the convention of a normal map.
+#[cfg(all(feature = "import", feature = "extras"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "import", feature = "extras"))))]
+pub const NORMAL_CONVENTION_EXTRAS_KEY: &str = "normalConvention";
+
+/// The direction of the green channel of a tangent space normal map.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NormalConvention {
+    /// Green points up the texture, towards the first row of the image. This
+    /// is the convention required by glTF.
+    OpenGl,
+
+    /// Green points down the texture, as expected by DirectX based tools.
+    DirectX,
+}
+
+#[cfg(feature = "import")]
+impl NormalConvention {
+    /// Returns the name recorded under `NORMAL_CONVENTION_EXTRAS_KEY`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            NormalConvention::OpenGl => "OpenGL",
+            NormalConvention::DirectX => "DirectX",
+        }
+    }
+}
+
+/// Guesses the convention of a tangent space normal map from its pixels.
+///
+/// The normals of a map baked from a height field are the gradient of that
+/// field, so the change of the red channel down the image and the change of
+/// the green channel across the image are correlated, with opposite signs in
+/// the two conventions. Returns `None` if the image has fewer than two
+/// channels or the correlation is too weak to tell, as for flat maps or maps
+/// that are not derived from a height field.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn detect_normal_convention(data: &crate::image::Data) -> Option<NormalConvention> {
+    use crate::image::Format;
+    let max = match data.format {
+        Format::R8 | Format::R16 => return None,
+        format if format.is_encoded() => return None,
+        Format::R16G16 | Format::R16G16B16 | Format::R16G16B16A16 => f64::from(u16::MAX),
+        _ => f64::from(u8::MAX),
+    };
+    let value = |x: u32, y: u32, channel: usize| f64::from(data.read(x, y, channel)) / max;
+    let (mut sum, mut magnitude) = (0.0, 0.0);
+    for y in 1..data.height.saturating_sub(1) {
+        for x in 1..data.width.saturating_sub(1) {
+            let red = value(x, y + 1, 0) - value(x, y - 1, 0);
+            let green = value(x + 1, y, 1) - value(x - 1, y, 1);
+            sum += red * green;
+            magnitude += (red * green).abs();
+        }
+    }
+    // Noise correlates in either direction; require a clear majority.
+    if magnitude == 0.0 || sum.abs() < 0.25 * magnitude {
+        None
+    } else if sum < 0.0 {
+        Some(NormalConvention::OpenGl)
+    } else {
+        Some(NormalConvention::DirectX)
+    }
+}
+
+/// Returns the convention recorded in the `extras` of an image, if any.
+#[cfg(all(feature = "import", feature = "extras"))]
+fn recorded_normal_convention(image: &json::Image) -> Option<NormalConvention> {
+    match json::extras::get(&image.extras, NORMAL_CONVENTION_EXTRAS_KEY)?.as_str()? {
+        "OpenGL" => Some(NormalConvention::OpenGl),
+        "DirectX" => Some(NormalConvention::DirectX),
+        _ => None,
+    }
+}
+
+/// Converts the normal maps of every material to the given convention,
+/// returning the number of images whose green channel was flipped.
+///
+/// The convention of each image is taken from its `extras`, then guessed with
+/// `detect_normal_convention`, and otherwise assumed to be OpenGL as required
+/// by glTF. Flipped images are updated in `images` and re-encoded as PNG into a
+/// new buffer appended to `buffers`, replacing their source in `root`. The
+/// convention of every normal map image is recorded under the
+/// `"normalConvention"` key of its `extras`; any other data in `extras` is
+/// preserved.
+///
+/// Convert to `NormalConvention::OpenGl` after importing assets authored for
+/// DirectX, and to `NormalConvention::DirectX` before handing an asset to DirectX
+/// based tools. The normal scale of materials is left unchanged: it scales X and
+/// Y together, so it cannot express a flip of Y alone, and it applies equally in
+/// either convention. Images without data in `images`, such as GPU-compressed
+/// images, are neither flipped nor recorded.
+///
+/// `images` holds the decoded data of each image in `root`, as returned by
+/// `gltf::import`.
+#[cfg(all(feature = "import", feature = "extras"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "import", feature = "extras"))))]
+pub fn convert_normal_maps(
+    root: &mut json::Root,
+    buffers: &mut Vec<Vec<u8>>,
+    images: &mut [crate::image::Data],
+    convention: NormalConvention,
+) -> Result<usize, crate::Error> {
+    let mut sources: Vec<usize> = root
+        .materials
+        .iter()
+        .filter_map(|material| material.normal_texture.as_ref())
+        .filter_map(|normal| root.textures.get(normal.index.value()))
+        .filter_map(|texture| texture.source.map(|source| source.value()))
+        .filter(|&source| source < images.len() && source < root.images.len())
+        .filter(|&source| !images[source].format.is_encoded())
+        .collect();
+    sources.sort_unstable();
+    sources.dedup();
+
+    // Everything that may fail is done before `root` and `images` change, so an
+    // error leaves them untouched.
+    let mut extras = Vec::with_capacity(sources.len());
+    let mut flips = Vec::new();
+    for source in sources {
+        let recorded = recorded_normal_convention(&root.images[source]);
+        let current = recorded
+            .or_else(|| detect_normal_convention(&images[source]))
+            .unwrap_or(NormalConvention::OpenGl);
+        if current != convention {
+            let mut image = images[source].clone();
+            image.flip_green();
+            let png = image.to_png()?;
+            flips.push((source, image, png));
+        }
+        let mut recorded = root.images[source].extras.clone();
+        let name = Some(json::Value::from(convention.as_str()));
+        json::extras::set(&mut recorded, NORMAL_CONVENTION_EXTRAS_KEY, name)?;
+        extras.push((source, recorded));
+    }
+
+    let flipped = flips.len();
+    if flipped > 0 {
+        let mut packer = crate::pack::Packer::new(root);
+        for (source, image, png) in flips {
+            let view = packer.push_view(root, &png, None, None);
+            let json = &mut root.images[source];
+            json.buffer_view = Some(view);
+            json.mime_type = Some(json::image::MimeType("image/png".to_string()));
+            json.uri = None;
+            images[source] = image;
+        }
+        buffers.push(packer.finish(root));
+    }
+    for (source, recorded) in extras {
+        root.images[source].extras = recorded;
+    }
+    Ok(flipped)
+}
+
+/// Images larger than this many bytes are flagged by `lint`.
+pub const LARGE_IMAGE_SIZE: usize = 1 << 20;
+
+/// A wasteful encoding found by `lint`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Lint {
+    /// Float texture co-ordinates lie within `[0, 1]` and may be stored as
+    /// normalized unsigned shorts with `convert_accessor`.
+    UnormTexCoords,
+
+    /// 32-bit indices may be narrowed to the given component type with
+    /// `narrow_component_types`.
+    WideIndices(ComponentType),
+
+    /// Float normals may be quantized to normalized bytes with
+    /// `convert_accessor`, which requires the `KHR_mesh_quantization` extension.
+    UnquantizedNormals,
+
+    /// An image larger than `LARGE_IMAGE_SIZE` is not GPU-compressed and may be
+    /// converted to KTX2 with Basis Universal compression.
+    UncompressedImage,
+}
+
+/// An actionable suggestion returned by `lint`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    /// The accessor or image concerned.
+    pub path: json::Path,
+
+    /// The wasteful encoding found.
+    pub lint: Lint,
+
+    /// The estimated number of bytes saved by following the suggestion, if it
+    /// can be estimated.
+    pub estimated_savings: Option<usize>,
+}
+
+/// Returns the encoded size of an image stored in a buffer view or data URI.
+fn image_size(root: &json::Root, image: &json::Image) -> Option<usize> {
+    if let Some(view) = image.buffer_view {
+        return root.buffer_views.get(view.value()).map(|view| view.byte_length as usize);
+    }
+    let uri = image.uri.as_ref()?;
+    if !uri.starts_with("data:") {
+        return None;
+    }
+    let (_, data) = uri.split_at(uri.find(',')? + 1);
+    Some(data.len() / 4 * 3)
+}
+
+/// Finds wasteful encodings of accessor and image data.
+///
+/// Accessors are classified with `accessor::usage::usages`, and only accessors
+/// with a single kind of use are considered, so that following a suggestion does
+/// not break another use of the same data. `buffers` holds the contents of each
+/// buffer in `root`; accessors whose data cannot be read are skipped.
+pub fn lint(root: &json::Root, buffers: &[Vec<u8>]) -> Vec<Suggestion> {
+    let mut suggestions = Vec::new();
+    let usages = crate::accessor::usage::usages(root);
+    for (index, (accessor, usage)) in root.accessors.iter().zip(&usages).enumerate() {
+        let component_type = match accessor.component_type {
+            Valid(GenericComponentType(component_type)) => component_type,
+            _ => continue,
+        };
+        let type_ = match accessor.type_ {
+            Valid(type_) => type_,
+            _ => continue,
+        };
+        let float = component_type == ComponentType::F32;
+        let only = |f: &dyn Fn(&Usage) -> bool| !usage.is_empty() && usage.iter().all(f);
+        let count = accessor.count as usize;
+        let found = if float && type_ == Type::Vec2
+            && only(&|usage| matches!(usage, Usage::Attribute(Semantic::TexCoords(_))))
+        {
+            let components = match read_components(root, buffers, accessor) {
+                Some(components) => components,
+                None => continue,
+            };
+            if !components.iter().all(|&x| (0.0..=1.0).contains(&x)) {
+                continue;
+            }
+            (Lint::UnormTexCoords, count * 4)
+        } else if float && type_ == Type::Vec3
+            && only(&|usage| *usage == Usage::Attribute(Semantic::Normals))
+        {
+            // Byte normals are padded to four bytes per vertex.
+            (Lint::UnquantizedNormals, count * 8)
+        } else if component_type == ComponentType::U32 && only(&|usage| *usage == Usage::Indices) {
+            let components = match read_components(root, buffers, accessor) {
+                Some(components) => components,
+                None => continue,
+            };
+            // Index data must not contain the primitive restart value.
+            let max = components.iter().copied().fold(0.0, f64::max);
+            if max < 255.0 {
+                (Lint::WideIndices(ComponentType::U8), count * 3)
+            } else if max < 65_535.0 {
+                (Lint::WideIndices(ComponentType::U16), count * 2)
+            } else {
+                continue;
+            }
+        } else {
+            continue;
+        };
+        suggestions.push(Suggestion {
+            path: json::Path::new().field("accessors").index(index),
+            lint: found.0,
+            estimated_savings: Some(found.1),
+        });
+    }
+    for (index, image) in root.images.iter().enumerate() {
+        let ktx2 = image.mime_type.as_ref().map(|mime_type| mime_type.0 == "image/ktx2");
+        if ktx2 == Some(true) {
+            continue;
+        }
+        match image_size(root, image) {
+            Some(size) if size > LARGE_IMAGE_SIZE => suggestions.push(Suggestion {
+                path: json::Path::new().field("images").index(index),
+                lint: Lint::UncompressedImage,
+                estimated_savings: None,
+            }),
+            _ => {},
+        }
+    }
+    suggestions
+}
+
+/// Statistics of the component values of an accessor, as computed by
+/// `accessor_statistics`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Statistics {
+    /// The smallest finite value of each component, or `None` for components
+    /// without finite values.
+    pub min: Vec<Option<f64>>,
+
+    /// The largest finite value of each component, or `None` for components
+    /// without finite values.
+    pub max: Vec<Option<f64>>,
+
+    /// The number of NaN component values.
+    pub nan_count: usize,
+
+    /// The number of infinite component values.
+    pub infinite_count: usize,
+
+    /// The number of bitwise distinct elements.
+    pub unique_count: usize,
+
+    /// For float accessors, the number of finite component values whose
+    /// mantissa needs each number of explicit bits, from 0 to 23; empty for
+    /// integer accessors.
+    ///
+    /// Values needing few bits are represented exactly by narrower encodings.
+    pub precision: Vec<usize>,
+}
+
+/// Computes statistics of the component values of an accessor, for deciding
+/// how it may be quantized and for checking the quality of its data.
+///
+/// Values are read as stored; normalized integer components are not decoded.
+/// Returns `None` if the accessor data cannot be read, as for `lint`, which
+/// includes sparse accessors.
+pub fn accessor_statistics(
+    root: &json::Root,
+    buffers: &[Vec<u8>],
+    accessor: json::Index<json::Accessor>,
+) -> Option<Statistics> {
+    let accessor = root.accessors.get(accessor.value())?;
+    let n = match accessor.type_ {
+        Valid(type_) => dimensions(type_),
+        _ => return None,
+    };
+    let float = matches!(accessor.component_type, Valid(GenericComponentType(ComponentType::F32)));
+    let components = read_components(root, buffers, accessor)?;
+    let mut statistics = Statistics {
+        min: vec![None; n],
+        max: vec![None; n],
+        precision: if float { vec![0; 24] } else { Vec::new() },
+        ..Default::default()
+    };
+    for (i, &x) in components.iter().enumerate() {
+        if x.is_nan() {
+            statistics.nan_count += 1;
+            continue;
+        }
+        if x.is_infinite() {
+            statistics.infinite_count += 1;
+            continue;
+        }
+        let min = &mut statistics.min[i % n];
+        *min = Some(min.map_or(x, |min| min.min(x)));
+        let max = &mut statistics.max[i % n];
+        *max = Some(max.map_or(x, |max| max.max(x)));
+        if float {
+            let mantissa = (x as f32).to_bits() & 0x7f_ffff;
+            let bits = if mantissa == 0 { 0 } else { 23 - mantissa.trailing_zeros() as usize };
+            statistics.precision[bits] += 1;
+        }
+    }
+    let unique: HashSet<Vec<u64>> = components
+        .chunks(n)
+        .map(|element| element.iter().map(|x| x.to_bits()).collect())
+        .collect();
+    statistics.unique_count = unique.len();
+    Some(statistics)
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Lint::UnormTexCoords => {
+                write!(f, "float texture co-ordinates may be stored as normalized unsigned shorts")
+            },
+            Lint::WideIndices(component_type) => {
+                write!(f, "32-bit indices may be narrowed to {:?}", component_type)
+            },
+            Lint::UnquantizedNormals => {
+                write!(f, "float normals may be quantized with KHR_mesh_quantization")
+            },
+            Lint::UncompressedImage => {
+                write!(f, "large image may be GPU-compressed with KTX2 and Basis Universal")
+            },
+        }
+    }
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.lint)?;
+        if let Some(savings) = self.estimated_savings {
+            write!(f, ", saving about {} bytes", savings)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use json::accessor::{ComponentType, GenericComponentType};
+    use json::validation::Checked::Valid;
+    use crate::pack::Packer;
+
+    fn primitive(
+        indices: json::Index<json::Accessor>,
+        joints: json::Index<json::Accessor>,
+    ) -> json::mesh::Primitive {
+        json::mesh::Primitive {
+            attributes: vec![(Valid(json::mesh::Semantic::Joints(0)), joints)].into_iter().collect(),
+            extensions: None,
+            extras: Default::default(),
+            indices: Some(indices),
+            material: None,
+            mode: Valid(json::mesh::Mode::Triangles),
+            targets: None,
+        }
+    }
+
+    #[test]
+    fn narrow_indices_and_joints() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let indices = packer.push_accessor(&mut root, &[0u32, 1, 254, 2, 1, 0], None);
+        let joints = packer.push_accessor(&mut root, &[[0u16, 1, 2, 3], [255, 0, 0, 0]], None);
+        let wide = packer.push_accessor(&mut root, &[0u32, 255], None);
+        let buffer = packer.finish(&mut root);
+        root.meshes.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: vec![primitive(indices, joints), primitive(wide, joints)],
+            weights: None,
+        });
+
+        let mut buffers = vec![buffer];
+        assert_eq!(super::narrow_component_types(&mut root, &mut buffers), 3);
+
+        let component_type = |index: json::Index<json::Accessor>| {
+            match root.accessors[index.value()].component_type {
+                Valid(GenericComponentType(component_type)) => component_type,
+                _ => unreachable!(),
+            }
+        };
+        assert_eq!(component_type(indices), ComponentType::U8);
+        assert_eq!(component_type(joints), ComponentType::U8);
+        assert_eq!(component_type(wide), ComponentType::U16);
+
+        let values = super::read_components(&root, &buffers, &root.accessors[indices.value()]);
+        assert_eq!(values.unwrap(), [0.0, 1.0, 254.0, 2.0, 1.0, 0.0]);
+        assert_eq!(root.accessors[indices.value()].max, Some(json::Value::from(vec![254])));
+        assert_eq!(root.buffers[0].byte_length as usize, buffers[0].len());
+    }
+
+    #[cfg(feature = "utils")]
+    #[test]
+    fn collapse_joint_influences() {
+        use json::mesh::Semantic::{Joints, Weights};
+
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let joints_0 = packer.push_accessor(&mut root, &[[0u16, 1, 2, 3]], None);
+        let weights_0 = packer.push_accessor(&mut root, &[[0.3f32, 0.05, 0.2, 0.05]], None);
+        let joints_1 = packer.push_accessor(&mut root, &[[4u16, 5, 0, 0]], None);
+        let weights_1 = packer.push_accessor(&mut root, &[[0.1f32, 0.3, 0.0, 0.0]], None);
+        let buffer = packer.finish(&mut root);
+        let mut primitive = primitive(joints_0, joints_0);
+        primitive.indices = None;
+        primitive.attributes.insert(Valid(Weights(0)), weights_0);
+        primitive.attributes.insert(Valid(Joints(1)), joints_1);
+        primitive.attributes.insert(Valid(Weights(1)), weights_1);
+        root.meshes.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: vec![primitive],
+            weights: None,
+        });
+
+        let mut buffers = vec![buffer];
+        let influences = |root: &json::Root, buffers: &[Vec<u8>]| {
+            let document = crate::Document::from_json_without_validation(root.clone());
+            let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+            let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+            let influences = reader.read_influences().unwrap().next().unwrap();
+            (primitive.influence_sets(), influences)
+        };
+        let (sets, before) = influences(&root, &buffers);
+        assert_eq!((sets, before.len()), (2, 6));
+        assert_eq!(super::collapse_influences(&mut root, &mut buffers), 1);
+        let (sets, after) = influences(&root, &buffers);
+        assert_eq!(sets, 1);
+        let joints: Vec<_> = after.iter().map(|&(joint, _)| joint).collect();
+        assert_eq!(joints, [0, 5, 2, 4]);
+        assert_relative_eq!(after[0].1, 0.3 / 0.9);
+        assert_relative_eq!(after.iter().map(|x| x.1).sum::<f32>(), 1.0);
+        assert_eq!(super::collapse_influences(&mut root, &mut buffers), 0);
+    }
+
+    #[test]
+    fn repack_merges_buffers() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let unused = packer.push_view(&mut root, &[1, 2, 3], None, None);
+        let a = packer.push_accessor(&mut root, &[1u16, 2, 3], None);
+        let first = packer.finish(&mut root);
+        let mut packer = Packer::new(&mut root);
+        let b = packer.push_accessor(&mut root, &[4.0f32, 5.0], None);
+        let second = packer.finish(&mut root);
+        assert_eq!(unused.value(), 0);
+
+        let data = super::repack_buffers(&mut root, &[first, second]);
+        assert_eq!(root.buffers.len(), 1);
+        assert_eq!(root.buffer_views.len(), 2);
+        assert_eq!(data.len(), 16);
+        assert_eq!(root.buffers[0].byte_length, 16);
+
+        let buffers = [data];
+        let read = |index: json::Index<json::Accessor>| {
+            super::read_components(&root, &buffers, &root.accessors[index.value()]).unwrap()
+        };
+        assert_eq!(read(a), [1.0, 2.0, 3.0]);
+        assert_eq!(read(b), [4.0, 5.0]);
+    }
+
+    #[test]
+    fn repack_for_progressive_streaming() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let image_view = packer.push_view(&mut root, &[9; 8], None, None);
+        let push_mesh = |root: &mut json::Root, indices, joints| {
+            root.push(json::Mesh {
+                extensions: None,
+                extras: Default::default(),
+                #[cfg(feature = "names")]
+                name: None,
+                primitives: vec![primitive(indices, joints)],
+                weights: None,
+            })
+        };
+        let indices = packer.push_accessor(&mut root, &[0u32, 1, 2, 2, 1, 0], None);
+        let joints = packer.push_accessor(&mut root, &[[0u16, 1, 2, 3], [3, 2, 1, 0]], None);
+        push_mesh(&mut root, indices, joints);
+        let small = packer.push_accessor(&mut root, &[0u8, 0, 0], None);
+        let joints = packer.push_accessor(&mut root, &[[0u8; 4]], None);
+        push_mesh(&mut root, small, joints);
+        let coarse = packer.push_accessor(&mut root, &[0u16, 0, 0], None);
+        let joints = packer.push_accessor(&mut root, &[[1u16; 4]], None);
+        push_mesh(&mut root, coarse, joints);
+        let buffer = packer.finish(&mut root);
+        let image = format!(r#"{{"bufferView": {}, "mimeType": "image/png"}}"#, image_view.value());
+        root.images.push(json::deserialize::from_str(&image).unwrap());
+        let lod = r#"{"mesh": 0, "extensions": {"MSFT_lod": {"ids": [1]}}}"#;
+        root.nodes.push(json::deserialize::from_str(lod).unwrap());
+        root.nodes.push(json::deserialize::from_str(r#"{"mesh": 2}"#).unwrap());
+
+        let (data, manifest) = super::repack_buffers_progressive(&mut root, &[buffer]);
+        let meshes: Vec<_> = manifest.iter().map(|entry| (entry.mesh, entry.level)).collect();
+        assert_eq!(meshes, [(1, 0), (2, 1), (0, 0)]);
+        assert!(manifest.windows(2).all(|pair| pair[0].end < pair[1].end));
+        let image_view = root.images[0].buffer_view.unwrap().value();
+        assert_eq!(image_view, root.buffer_views.len() - 1);
+        assert_eq!(root.buffer_views[image_view].byte_offset, Some(manifest[2].end as u32));
+
+        let buffers = [data];
+        let read = |index: json::Index<json::Accessor>| {
+            super::read_components(&root, &buffers, &root.accessors[index.value()]).unwrap()
+        };
+        assert_eq!(read(indices), [0.0, 1.0, 2.0, 2.0, 1.0, 0.0]);
+        assert_eq!(read(joints), [1.0; 4]);
+    }
+
+    #[test]
+    fn convert_texcoords_to_unorm16() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let uvs = packer.push_accessor(&mut root, &[[0.0f32, 1.0], [0.5, 0.25]], None);
+        let buffer = packer.finish(&mut root);
+        let mut buffers = vec![buffer];
+
+        assert!(super::convert_accessor(&mut root, &mut buffers, uvs, ComponentType::U16, true));
+        let accessor = &root.accessors[uvs.value()];
+        assert!(accessor.normalized);
+        let values = super::read_components(&root, &buffers, accessor).unwrap();
+        assert_eq!(values, [0.0, 65_535.0, 32_768.0, 16_384.0]);
+        assert!(root.extensions_required.is_empty());
+
+        assert!(super::convert_accessor(&mut root, &mut buffers, uvs, ComponentType::F32, false));
+        let values = super::read_components(&root, &buffers, &root.accessors[uvs.value()]).unwrap();
+        assert!((values[2] - 0.5).abs() < 1e-4);
+        assert!(!super::convert_accessor(&mut root, &mut buffers, uvs, ComponentType::F32, true));
+    }
+
+    #[test]
+    fn repair_unordered_keyframes() {
+        let mut root = json::Root::default();
+        let node = root.push::<json::Node>(json::deserialize::from_str("{}").unwrap());
+        let mut packer = Packer::new(&mut root);
+        let input = packer.push_accessor(&mut root, &[0.0f32, 1.0, 0.5, 0.5, -1.0], None);
+        let values = [[0.0f32; 3], [1.0, 0.0, 0.0], [0.5, 0.0, 0.0], [9.0; 3], [9.0; 3]];
+        let output = packer.push_accessor(&mut root, &values, None);
+        let mut animation: json::Animation = json::deserialize::from_str(&format!(
+            r#"{{
+                "channels": [{{"sampler": 0, "target": {{"node": {}, "path": "translation"}}}}],
+                "samplers": [{{"input": {}, "output": {}}}]
+            }}"#,
+            node.value(),
+            input.value(),
+            output.value(),
+        )).unwrap();
+        animation.samplers.push(animation.samplers[0].clone());
+        root.push(animation);
+        let mut buffers = vec![packer.finish(&mut root)];
+
+        let path = |s: &str| json::Path(s.to_string());
+        let invalid = json::validation::Error::Invalid;
+        assert_eq!(super::validate_keyframe_times(&root, &buffers), [
+            (path("animations[0].samplers[0].input"), invalid),
+            (path("accessors[0].min"), invalid),
+            (path("accessors[0].max"), invalid),
+        ]);
+        assert!(crate::Document::from_json(root.clone()).is_err());
+
+        assert_eq!(super::repair_keyframe_times(&mut root, &mut buffers), 1);
+        assert!(super::validate_keyframe_times(&root, &buffers).is_empty());
+        let times = super::read_components(&root, &buffers, &root.accessors[input.value()]);
+        assert_eq!(times.unwrap(), [0.0, 0.5, 1.0]);
+        let values = super::read_components(&root, &buffers, &root.accessors[output.value()]);
+        assert_eq!(values.unwrap(), [0.0, 0.0, 0.0, 0.5, 0.0, 0.0, 1.0, 0.0, 0.0]);
+        assert_eq!(root.accessors[output.value()].count, 3);
+        crate::Document::from_json(root.clone()).unwrap();
+        assert_eq!(super::repair_keyframe_times(&mut root, &mut buffers), 0);
+    }
+
+    #[test]
+    fn lint_wasteful_encodings() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let indices = packer.push_accessor(&mut root, &[0u32, 1, 2], None);
+        let tex_coords = packer.push_accessor(&mut root, &[[0.0f32, 1.0], [0.5, 0.25], [1.0, 0.0]], None);
+        let normals = packer.push_accessor(&mut root, &[[0.0f32, 0.0, 1.0]; 3], None);
+        let wrapped = packer.push_accessor(&mut root, &[[0.0f32, 2.0]; 3], None);
+        let image = packer.push_view(&mut root, &vec![0; super::LARGE_IMAGE_SIZE + 1], None, None);
+        let buffer = packer.finish(&mut root);
+        root.images.push(json::Image {
+            buffer_view: Some(image),
+            mime_type: Some(json::image::MimeType("image/png".to_string())),
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        let mut primitive = primitive(indices, normals);
+        primitive.attributes = vec![
+            (Valid(json::mesh::Semantic::TexCoords(0)), tex_coords),
+            (Valid(json::mesh::Semantic::TexCoords(1)), wrapped),
+            (Valid(json::mesh::Semantic::Normals), normals),
+        ].into_iter().collect();
+        root.meshes.push(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: None,
+            primitives: vec![primitive],
+            weights: None,
+        });
+
+        let suggestions: Vec<_> = super::lint(&root, &[buffer])
+            .into_iter()
+            .map(|suggestion| (suggestion.path.to_string(), suggestion.lint, suggestion.estimated_savings))
+            .collect();
+        assert_eq!(
+            suggestions,
+            vec![
+                ("accessors[0]".to_string(), super::Lint::WideIndices(ComponentType::U8), Some(9)),
+                ("accessors[1]".to_string(), super::Lint::UnormTexCoords, Some(12)),
+                ("accessors[2]".to_string(), super::Lint::UnquantizedNormals, Some(24)),
+                ("images[0]".to_string(), super::Lint::UncompressedImage, None),
+            ],
+        );
+    }
+
+    #[cfg(feature = "import")]
+    #[test]
+    fn generate_texture_lods() {
+        use crate::image::{Data, Format};
+
+        let odd = Data { pixels: vec![0, 10, 20, 30, 40, 50, 60, 70, 80], format: Format::R8, width: 3, height: 3, premultiplied: false };
+        let half = odd.downsample();
+        assert_eq!((half.width, half.height), (2, 2));
+        assert_eq!(half.pixels, [20, 35, 65, 80]);
+
+        let mut root = json::Root::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "images": [{"uri": "a.png"}],
+            "textures": [{"source": 0}],
+            "materials": [{
+                "pbrMetallicRoughness": {"baseColorTexture": {"index": 0}},
+                "emissiveTexture": {"index": 0}
+            }]
+        }"#).unwrap();
+        let images = [Data { pixels: vec![255; 64], format: Format::R8G8B8A8, width: 4, height: 4, premultiplied: false }];
+        let mut buffers = Vec::new();
+        let levels = super::texture_lods(&mut root, &mut buffers, &images, json::Index::new(0), &[16, 8]).unwrap();
+
+        assert_eq!(levels.iter().map(|level| level.value()).collect::<Vec<_>>(), [1, 2]);
+        let lod = &root.materials[0].extensions.as_ref().unwrap().others["MSFT_lod"];
+        assert_eq!(lod["ids"], json::Value::from(vec![1, 2]));
+        assert_eq!(root.extensions_used, ["MSFT_lod"]);
+        assert_eq!((root.images.len(), root.textures.len()), (3, 3));
+        let level = &root.materials[2];
+        let texture = level.pbr_metallic_roughness.base_color_texture.as_ref().unwrap().index;
+        assert_eq!(texture.value(), level.emissive_texture.as_ref().unwrap().index.value());
+        assert_eq!(root.textures[texture.value()].source.unwrap().value(), 2);
+        assert_eq!(root.buffers[0].byte_length as usize, buffers[0].len());
+        crate::Document::from_json(root).unwrap();
+    }
+
+    #[cfg(all(feature = "import", feature = "extras"))]
+    #[test]
+    fn convert_normal_map_conventions() {
+        use super::NormalConvention;
+        use crate::image::{Data, Format};
+
+        // A bump in the OpenGL convention, with green pointing up the image.
+        let mut pixels = Vec::new();
+        for y in 0..16 {
+            for x in 0..16 {
+                let (dx, dy) = (x as f32 - 7.5, y as f32 - 7.5);
+                let height = (-(dx * dx + dy * dy) / 16.0).exp();
+                let normal = [dx / 8.0 * height, -dy / 8.0 * height, 1.0];
+                let length = normal.iter().map(|x| x * x).sum::<f32>().sqrt();
+                let encode = |x: &f32| ((x / length * 0.5 + 0.5) * 255.0).round() as u8;
+                pixels.extend(normal.iter().map(encode));
+            }
+        }
+        let format = Format::R8G8B8;
+        let bump = Data { pixels, format, width: 16, height: 16, premultiplied: false };
+        assert_eq!(super::detect_normal_convention(&bump), Some(NormalConvention::OpenGl));
+        let mut flipped = bump.clone();
+        flipped.flip_green();
+        assert_eq!(flipped.pixels[1], 255 - bump.pixels[1]);
+        assert_eq!(super::detect_normal_convention(&flipped), Some(NormalConvention::DirectX));
+        let pixels = [128, 128, 255].repeat(64);
+        let flat = Data { pixels, format, width: 8, height: 8, premultiplied: false };
+        assert_eq!(super::detect_normal_convention(&flat), None);
+
+        let mut root = json::Root::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "images": [
+                {"uri": "normal.png", "extras": {"author": "someone"}},
+                {"uri": "color.png"}
+            ],
+            "textures": [{"source": 0}, {"source": 1}],
+            "materials": [{
+                "pbrMetallicRoughness": {"baseColorTexture": {"index": 1}},
+                "normalTexture": {"index": 0, "scale": 0.5}
+            }]
+        }"#).unwrap();
+        let mut images = vec![flipped, flat];
+        let mut buffers = Vec::new();
+        let mut convert = |root: &mut json::Root, images: &mut Vec<Data>, convention| {
+            super::convert_normal_maps(root, &mut buffers, images, convention).unwrap()
+        };
+        assert_eq!(convert(&mut root, &mut images, NormalConvention::OpenGl), 1);
+        assert_eq!(images[0].pixels, bump.pixels);
+        assert_eq!(root.images[0].uri, None);
+        let extras = root.images[0].extras.as_ref().unwrap().get();
+        assert_eq!(extras, r#"{"author":"someone","normalConvention":"OpenGL"}"#);
+        assert!(root.images[1].extras.is_none());
+        assert_eq!(root.materials[0].normal_texture.as_ref().unwrap().scale, 0.5);
+
+        // The recorded convention takes precedence over detection.
+        assert_eq!(convert(&mut root, &mut images, NormalConvention::DirectX), 1);
+        assert_eq!(convert(&mut root, &mut images, NormalConvention::DirectX), 0);
+        assert_eq!(root.buffers.len(), 2);
+        assert_eq!(buffers.len(), 2);
+        assert_eq!(root.buffers[1].byte_length as usize, buffers[1].len());
+        crate::Document::from_json(root).unwrap();
+    }
+
+    #[test]
+    fn reduce_redundant_keyframes() {
+        use json::animation::Interpolation;
+
+        let mut root = json::Root::default();
+        let node = root.push::<json::Node>(json::deserialize::from_str("{}").unwrap());
+        let other = root.push::<json::Node>(json::deserialize::from_str("{}").unwrap());
+        let mut packer = Packer::new(&mut root);
+        let mut builder = crate::animation::AnimationBuilder::new();
+        let times = [0.0, 1.0, 2.0];
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        let (sin, cos) = (std::f32::consts::FRAC_PI_8.sin(), std::f32::consts::FRAC_PI_8.cos());
+        let line = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [2.0, 0.0, 0.0]];
+        let bend = [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [2.0, 0.0, 0.0]];
+        let rotations = [[0.0, 0.0, 0.0, 1.0], [0.0, 0.0, sin, cos], [0.0, 0.0, half, half]];
+        builder.channel_translation(&mut root, &mut packer, node, &times, &line).unwrap();
+        builder.channel_rotation(&mut root, &mut packer, node, &times, &rotations).unwrap();
+        builder.channel_scale(&mut root, &mut packer, node, &times, &[[1.0; 3]; 3]).unwrap();
+        builder.channel_translation(&mut root, &mut packer, other, &times, &bend).unwrap();
+        let animation = builder.build(&mut root);
+        let mut buffers = vec![packer.finish(&mut root)];
+
+        let options = super::ReductionOptions { tolerance: 1e-5, ..Default::default() };
+        assert_eq!(super::reduce_keyframes(&mut root, &mut buffers, options), 3);
+
+        let samplers = &root.animations[animation.value()].samplers;
+        let count = |accessor: json::Index<json::Accessor>| root.accessors[accessor.value()].count;
+        let counts: Vec<_> = samplers.iter()
+            .map(|sampler| (count(sampler.input), count(sampler.output)))
+            .collect();
+        assert_eq!(counts, [(2, 2), (2, 2), (2, 2), (3, 3)]);
+        assert_eq!(samplers[1].interpolation, Valid(Interpolation::Linear));
+        assert_eq!(samplers[2].interpolation, Valid(Interpolation::Step));
+        assert_ne!(samplers[0].input.value(), samplers[3].input.value());
+        let rotations = &root.accessors[samplers[1].output.value()];
+        let values = super::read_components(&root, &buffers, rotations).unwrap();
+        assert_eq!(values[4..], [0.0, 0.0, half as f64, half as f64]);
+        assert!(super::validate_keyframe_times(&root, &buffers).is_empty());
+        crate::Document::from_json(root).unwrap();
+    }
+
+    #[test]
+    fn repair_matrix_and_trs() {
+        use super::TransformRepair;
+
+        let mut root = json::Root::default();
+        let translated = [
+            1.0, 0.0, 0.0, 0.0, 0.0, 2.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 3.0, 4.0, 5.0, 1.0,
+        ];
+        let sheared = [
+            1.0, 0.0, 0.0, 0.0, 1.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+        ];
+        for &(matrix, translation) in &[(translated, true), (sheared, true), (sheared, false)] {
+            let mut node: json::Node = json::deserialize::from_str("{}").unwrap();
+            node.matrix = Some(matrix);
+            if translation {
+                node.translation = Some([9.0, 9.0, 9.0]);
+            }
+            root.push(node);
+        }
+        // The conflicting nodes are only a warning, so the asset still loads.
+        let document = crate::Document::from_json(root.clone()).unwrap();
+        let warnings = document.validate_full();
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().all(|x| x.error == json::validation::Error::MatrixAndTrs));
+
+        let repairs: Vec<_> = super::repair_node_transforms(&mut root)
+            .into_iter()
+            .map(|(index, repair)| (index.value(), repair))
+            .collect();
+        assert_eq!(repairs, [(0, TransformRepair::Decomposed), (1, TransformRepair::DroppedTrs)]);
+        let nodes = &root.nodes;
+        assert_eq!(nodes[0].matrix, None);
+        assert_eq!(nodes[0].translation, Some([3.0, 4.0, 5.0]));
+        assert_eq!(nodes[0].scale, Some([1.0, 2.0, 1.0]));
+        assert_eq!((nodes[1].matrix, nodes[1].translation), (Some(sheared), None));
+        assert_eq!((nodes[2].matrix, nodes[2].translation), (Some(sheared), None));
+        crate::Document::from_json(root).unwrap();
+    }
+
+    #[test]
+    fn compute_accessor_statistics() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let nan = f32::NAN;
+        let items = [[0.5, 1.0, nan], [0.5, f32::INFINITY, 0.1], [0.5, 1.0, nan]];
+        let floats = packer.push_accessor(&mut root, &items, None);
+        let integers = packer.push_accessor(&mut root, &[3u16, 1, 3], None);
+        let buffers = vec![packer.finish(&mut root)];
+
+        let statistics = super::accessor_statistics(&root, &buffers, floats).unwrap();
+        assert_eq!(statistics.min, [Some(0.5), Some(1.0), Some(0.1f32 as f64)]);
+        assert_eq!(statistics.max, statistics.min);
+        assert_eq!((statistics.nan_count, statistics.infinite_count), (2, 1));
+        assert_eq!(statistics.unique_count, 2);
+        assert_eq!((statistics.precision[0], statistics.precision[23]), (5, 1));
+        assert_eq!(statistics.precision.iter().sum::<usize>(), 6);
+
+        let statistics = super::accessor_statistics(&root, &buffers, integers).unwrap();
+        assert_eq!((statistics.min, statistics.max), (vec![Some(1.0)], vec![Some(3.0)]));
+        assert_eq!(statistics.unique_count, 2);
+        assert!(statistics.precision.is_empty());
+    }
+}
@@ -0,0 +1,656 @@
+use std::collections::BTreeMap;
+
+use json::accessor::{ComponentType, GenericComponentType, IndexComponentType, Type};
+use json::buffer::Target;
+use json::validation::Checked::Valid;
+
+/// A component data type that can be written to a buffer.
+pub trait Component: Copy + Default + PartialEq + PartialOrd {
+    /// The corresponding accessor component type.
+    const COMPONENT_TYPE: ComponentType;
+
+    /// Appends the little-endian representation of this component to `out`.
+    fn write(self, out: &mut Vec<u8>);
+
+    /// Reads a component from the start of its little-endian representation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than the component.
+    fn read(bytes: &[u8]) -> Self;
+
+    /// Returns this component as a JSON number, as used by accessor bounds.
+    fn to_json(self) -> json::Value;
+}
+
+/// An accessor element that can be written to a buffer.
+pub trait Element: Copy + Default + PartialEq {
+    /// The data type of each component of this element.
+    type Component: Component;
+
+    /// The corresponding accessor type.
+    const TYPE: Type;
+
+    /// Calls `f` with each component of this element in order.
+    fn visit<F: FnMut(Self::Component)>(&self, f: F);
+
+    /// Calls `f` with a mutable reference to each component of this element in
+    /// order.
+    fn visit_mut<F: FnMut(&mut Self::Component)>(&mut self, f: F);
+}
+
+/// Accumulates binary data into a single buffer, creating the buffer views and
+/// accessors that describe it.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf::json;
+/// let mut root = json::Root::default();
+/// let mut packer = gltf::pack::Packer::new(&mut root);
+/// let positions = packer.push_accessor(
+///     &mut root,
+///     &[[0.0f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+///     Some(json::buffer::Target::ArrayBuffer),
+/// );
+/// let bin = packer.finish(&mut root);
+/// assert_eq!(bin.len(), 36);
+/// assert_eq!(root.accessors[positions.value()].count, 3);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Packer {
+    /// The buffer being written to.
+    buffer: json::Index<json::Buffer>,
+
+    /// The buffer contents written so far.
+    data: Vec<u8>,
+}
+
+impl Component for i8 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::I8;
+    fn read(bytes: &[u8]) -> Self {
+        i8::from_le_bytes([bytes[0]])
+    }
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+    fn to_json(self) -> json::Value {
+        json::Value::from(self)
+    }
+}
+
+impl Component for u8 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::U8;
+    fn read(bytes: &[u8]) -> Self {
+        bytes[0]
+    }
+    fn write(self, out: &mut Vec<u8>) {
+        out.push(self);
+    }
+    fn to_json(self) -> json::Value {
+        json::Value::from(self)
+    }
+}
+
+impl Component for i16 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::I16;
+    fn read(bytes: &[u8]) -> Self {
+        i16::from_le_bytes([bytes[0], bytes[1]])
+    }
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+    fn to_json(self) -> json::Value {
+        json::Value::from(self)
+    }
+}
+
+impl Component for u16 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::U16;
+    fn read(bytes: &[u8]) -> Self {
+        u16::from_le_bytes([bytes[0], bytes[1]])
+    }
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+    fn to_json(self) -> json::Value {
+        json::Value::from(self)
+    }
+}
+
+impl Component for u32 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::U32;
+    fn read(bytes: &[u8]) -> Self {
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+    fn to_json(self) -> json::Value {
+        json::Value::from(self)
+    }
+}
+
+impl Component for f32 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::F32;
+    fn read(bytes: &[u8]) -> Self {
+        f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+    fn write(self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.to_le_bytes());
+    }
+    fn to_json(self) -> json::Value {
+        f32_to_json(self)
+    }
+}
+
+/// Converts an `f32` to a JSON number using its shortest decimal representation.
+///
+/// Widening to `f64` directly yields numbers such as `0.10000000149011612`, which
+/// are noisy and may not parse back to the same value.
+pub(crate) fn f32_to_json(x: f32) -> json::Value {
+    json::Value::from(x.to_string().parse::<f64>().unwrap_or(f64::NAN))
+}
+
+impl<C: Component> Element for C {
+    type Component = C;
+    const TYPE: Type = Type::Scalar;
+    fn visit<F: FnMut(C)>(&self, mut f: F) {
+        f(*self)
+    }
+    fn visit_mut<F: FnMut(&mut C)>(&mut self, mut f: F) {
+        f(self)
+    }
+}
+
+impl<C: Component> Element for [C; 2] {
+    type Component = C;
+    const TYPE: Type = Type::Vec2;
+    fn visit<F: FnMut(C)>(&self, f: F) {
+        self.iter().cloned().for_each(f)
+    }
+    fn visit_mut<F: FnMut(&mut C)>(&mut self, f: F) {
+        self.iter_mut().for_each(f)
+    }
+}
+
+impl<C: Component> Element for [C; 3] {
+    type Component = C;
+    const TYPE: Type = Type::Vec3;
+    fn visit<F: FnMut(C)>(&self, f: F) {
+        self.iter().cloned().for_each(f)
+    }
+    fn visit_mut<F: FnMut(&mut C)>(&mut self, f: F) {
+        self.iter_mut().for_each(f)
+    }
+}
+
+impl<C: Component> Element for [C; 4] {
+    type Component = C;
+    const TYPE: Type = Type::Vec4;
+    fn visit<F: FnMut(C)>(&self, f: F) {
+        self.iter().cloned().for_each(f)
+    }
+    fn visit_mut<F: FnMut(&mut C)>(&mut self, f: F) {
+        self.iter_mut().for_each(f)
+    }
+}
+
+impl<C: Component> Element for [[C; 4]; 4] {
+    type Component = C;
+    const TYPE: Type = Type::Mat4;
+    fn visit<F: FnMut(C)>(&self, f: F) {
+        self.iter().flat_map(|column| column.iter().cloned()).for_each(f)
+    }
+    fn visit_mut<F: FnMut(&mut C)>(&mut self, f: F) {
+        self.iter_mut().flat_map(|column| column.iter_mut()).for_each(f)
+    }
+}
+
+/// Returns the size of a single component in bytes.
+pub(crate) fn component_size(component_type: ComponentType) -> usize {
+    match component_type {
+        ComponentType::I8 | ComponentType::U8 => 1,
+        ComponentType::I16 | ComponentType::U16 => 2,
+        ComponentType::U32 | ComponentType::F32 => 4,
+    }
+}
+
+/// Returns the size of a single element in bytes.
+pub(crate) fn element_size<T: Element>() -> usize {
+    let mut n = 0;
+    T::default().visit(|_| n += 1);
+    n * component_size(T::Component::COMPONENT_TYPE)
+}
+
+/// Computes the per-component `min` and `max` bounds of `items` as JSON arrays.
+pub(crate) fn bounds<T: Element>(items: &[T]) -> (Option<json::Value>, Option<json::Value>) {
+    let first = match items.first() {
+        Some(first) => first,
+        None => return (None, None),
+    };
+    let mut min = Vec::new();
+    first.visit(|x| min.push(x));
+    let mut max = min.clone();
+    for item in &items[1..] {
+        let mut i = 0;
+        item.visit(|x| {
+            if x < min[i] {
+                min[i] = x;
+            }
+            if x > max[i] {
+                max[i] = x;
+            }
+            i += 1;
+        });
+    }
+    let min = min.into_iter().map(Component::to_json).collect::<Vec<_>>();
+    let max = max.into_iter().map(Component::to_json).collect::<Vec<_>>();
+    (Some(json::Value::from(min)), Some(json::Value::from(max)))
+}
+
+impl Packer {
+    /// Creates a packer that writes to a new buffer appended to `root`.
+    pub fn new(root: &mut json::Root) -> Self {
+        let buffer = root.push(json::Buffer {
+            byte_length: 0,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        Self {
+            buffer,
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the buffer being written to.
+    pub fn buffer(&self) -> json::Index<json::Buffer> {
+        self.buffer
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if no data has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Appends raw bytes as a new buffer view, returning its index.
+    ///
+    /// The view is aligned to a multiple of four bytes.
+    pub fn push_view(
+        &mut self,
+        root: &mut json::Root,
+        bytes: &[u8],
+        byte_stride: Option<u32>,
+        target: Option<Target>,
+    ) -> json::Index<json::buffer::View> {
+        self.data.resize((self.data.len() + 3) & !3, 0);
+        let byte_offset = self.data.len() as u32;
+        self.data.extend_from_slice(bytes);
+        root.push(json::buffer::View {
+            buffer: self.buffer,
+            byte_length: bytes.len() as u32,
+            byte_offset: Some(byte_offset),
+            byte_stride,
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Valid),
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+
+    /// Appends `items` as a tightly packed buffer view and returns the index of
+    /// a new accessor describing it.
+    ///
+    /// The accessor `min` and `max` bounds are always provided.
+    pub fn push_accessor<T: Element>(
+        &mut self,
+        root: &mut json::Root,
+        items: &[T],
+        target: Option<Target>,
+    ) -> json::Index<json::Accessor> {
+        let mut bytes = Vec::with_capacity(items.len() * element_size::<T>());
+        for item in items {
+            item.visit(|x| x.write(&mut bytes));
+        }
+        let view = self.push_view(root, &bytes, None, target);
+        let (min, max) = bounds(items);
+        root.push(accessor::<T>(Some(view), items.len(), min, max, None))
+    }
+
+    /// Appends `values` as an accessor, using sparse storage when this is smaller
+    /// than writing the values in full.
+    ///
+    /// `base` pairs an existing accessor with the data it contains. Only the
+    /// elements of `values` that differ from `base` are stored, and the new
+    /// accessor is initialized from the buffer view of the base accessor. When
+    /// `base` is `None`, elements are compared against zero and the new accessor
+    /// has no buffer view, which suits morph target displacements. An accessor
+    /// written in full keeps the `normalized` flag and buffer view target of
+    /// the base accessor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is provided with a different number of elements to
+    /// `values`, or if the base accessor is sparse, has no buffer view, or does
+    /// not hold elements of type `T`.
+    pub fn push_sparse_accessor<T: Element>(
+        &mut self,
+        root: &mut json::Root,
+        base: Option<(json::Index<json::Accessor>, &[T])>,
+        values: &[T],
+    ) -> json::Index<json::Accessor> {
+        if let Some((base, base_values)) = base {
+            let base = &root.accessors[base.value()];
+            assert!(base.sparse.is_none() && base.buffer_view.is_some());
+            assert_eq!(base.count as usize, base_values.len());
+            assert_eq!(base_values.len(), values.len());
+            let component_type = match base.component_type {
+                Valid(GenericComponentType(component_type)) => Some(component_type),
+                _ => None,
+            };
+            assert!(base.type_ == Valid(T::TYPE));
+            assert!(component_type == Some(T::Component::COMPONENT_TYPE));
+        }
+        let initial = |i: usize| base.map_or_else(T::default, |(_, data)| data[i]);
+        let indices: Vec<usize> = (0..values.len())
+            .filter(|&i| values[i] != initial(i))
+            .collect();
+
+        let index_size = component_size(sparse_index_type(values.len()));
+        let sparse_size = ((indices.len() * index_size + 3) & !3)
+            + indices.len() * element_size::<T>();
+        let dense_size = values.len() * element_size::<T>();
+        if indices.is_empty() || sparse_size >= dense_size {
+            if let (true, Some((base, _))) = (indices.is_empty(), base) {
+                let copy = root.accessors[base.value()].clone();
+                return root.push(copy);
+            }
+            let (normalized, target) = match base {
+                Some((base, _)) => {
+                    let base = &root.accessors[base.value()];
+                    let view = base.buffer_view.map(|view| &root.buffer_views[view.value()]);
+                    let target = match view.and_then(|view| view.target) {
+                        Some(Valid(target)) => Some(target),
+                        _ => None,
+                    };
+                    (base.normalized, target)
+                },
+                None => (false, None),
+            };
+            let accessor = self.push_accessor(root, values, target);
+            root.accessors[accessor.value()].normalized = normalized;
+            return accessor;
+        }
+
+        let entries: Vec<_> = indices.iter().map(|&i| (i, values[i])).collect();
+        let sparse = self.push_sparse(root, values.len(), &entries);
+        let (min, max) = bounds(values);
+        let mut accessor = accessor::<T>(None, values.len(), min, max, Some(sparse));
+        if let Some((base, _)) = base {
+            let base = &root.accessors[base.value()];
+            accessor.buffer_view = base.buffer_view;
+            accessor.byte_offset = base.byte_offset;
+            accessor.normalized = base.normalized;
+        }
+        root.push(accessor)
+    }
+
+    /// Appends a new accessor that applies `patch` to the data of an existing
+    /// accessor, storing only the patched elements as sparse substitutions on
+    /// top of the buffer view of the base accessor.
+    ///
+    /// This suits small edits, such as moving a few vertices of a variant,
+    /// without duplicating the base data. `base` pairs the existing accessor
+    /// with the data it contains, from which the bounds of the patched data
+    /// are computed. `patch` pairs element indices with their new values; when
+    /// an index appears more than once, the last value is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the base accessor is sparse, has no buffer view, or does not
+    /// hold elements of type `T`, if its data has a different number of
+    /// elements to its count, or if an index of `patch` is out of bounds.
+    pub fn push_accessor_patch<T: Element>(
+        &mut self,
+        root: &mut json::Root,
+        base: (json::Index<json::Accessor>, &[T]),
+        patch: &[(usize, T)],
+    ) -> json::Index<json::Accessor> {
+        let (base, base_values) = base;
+        let mut accessor = root.accessors[base.value()].clone();
+        assert!(accessor.sparse.is_none() && accessor.buffer_view.is_some());
+        assert_eq!(accessor.count as usize, base_values.len());
+        let component_type = match accessor.component_type {
+            Valid(GenericComponentType(component_type)) => Some(component_type),
+            _ => None,
+        };
+        assert!(accessor.type_ == Valid(T::TYPE));
+        assert!(component_type == Some(T::Component::COMPONENT_TYPE));
+        let entries: BTreeMap<usize, T> = patch
+            .iter()
+            .map(|&(i, value)| {
+                assert!(i < base_values.len(), "patch index {} out of bounds", i);
+                (i, value)
+            })
+            .collect();
+        let entries: Vec<_> = entries.into_iter().collect();
+
+        if accessor.min.is_some() || accessor.max.is_some() {
+            let mut values = base_values.to_vec();
+            for &(i, value) in &entries {
+                values[i] = value;
+            }
+            let (min, max) = bounds(&values);
+            accessor.min = min;
+            accessor.max = max;
+        }
+        if !entries.is_empty() {
+            accessor.sparse = Some(self.push_sparse(root, base_values.len(), &entries));
+        }
+        #[cfg(feature = "names")]
+        {
+            accessor.name = None;
+        }
+        root.push(accessor)
+    }
+
+    /// Appends the sparse indices and values of `entries`, which are sorted by
+    /// index, for an accessor of `count` elements.
+    fn push_sparse<T: Element>(
+        &mut self,
+        root: &mut json::Root,
+        count: usize,
+        entries: &[(usize, T)],
+    ) -> json::accessor::sparse::Sparse {
+        let index_type = sparse_index_type(count);
+        let index_size = component_size(index_type);
+        let mut index_bytes = Vec::with_capacity(entries.len() * index_size);
+        let mut value_bytes = Vec::with_capacity(entries.len() * element_size::<T>());
+        for &(i, value) in entries {
+            match index_type {
+                ComponentType::U8 => (i as u8).write(&mut index_bytes),
+                ComponentType::U16 => (i as u16).write(&mut index_bytes),
+                _ => (i as u32).write(&mut index_bytes),
+            }
+            value.visit(|x| x.write(&mut value_bytes));
+        }
+        let index_view = self.push_view(root, &index_bytes, None, None);
+        let value_view = self.push_view(root, &value_bytes, None, None);
+        json::accessor::sparse::Sparse {
+            count: entries.len() as u32,
+            indices: json::accessor::sparse::Indices {
+                buffer_view: index_view,
+                byte_offset: 0,
+                component_type: Valid(IndexComponentType(index_type)),
+                extensions: None,
+                extras: Default::default(),
+            },
+            values: json::accessor::sparse::Values {
+                buffer_view: value_view,
+                byte_offset: 0,
+                extensions: None,
+                extras: Default::default(),
+            },
+            extensions: None,
+            extras: Default::default(),
+        }
+    }
+
+    /// Pads the buffer to a multiple of four bytes, records its final length in
+    /// `root`, and returns its contents.
+    pub fn finish(mut self, root: &mut json::Root) -> Vec<u8> {
+        self.data.resize((self.data.len() + 3) & !3, 0);
+        root.buffers[self.buffer.value()].byte_length = self.data.len() as u32;
+        self.data
+    }
+}
+
+/// Returns the smallest component type of the sparse indices of an accessor
+/// with `count` elements.
+fn sparse_index_type(count: usize) -> ComponentType {
+    match count {
+        0..=0xFF => ComponentType::U8,
+        0x100..=0xFFFF => ComponentType::U16,
+        _ => ComponentType::U32,
+    }
+}
+
+/// Constructs an accessor of element type `T`.
+fn accessor<T: Element>(
+    buffer_view: Option<json::Index<json::buffer::View>>,
+    count: usize,
+    min: Option<json::Value>,
+    max: Option<json::Value>,
+    sparse: Option<json::accessor::sparse::Sparse>,
+) -> json::Accessor {
+    json::Accessor {
+        buffer_view,
+        byte_offset: 0,
+        count: count as u32,
+        component_type: Valid(GenericComponentType(T::Component::COMPONENT_TYPE)),
+        extensions: None,
+        extras: Default::default(),
+        type_: Valid(T::TYPE),
+        min,
+        max,
+        #[cfg(feature = "names")]
+        name: None,
+        normalized: false,
+        sparse,
+    }
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use super::Packer;
+    use json::buffer::Target;
+    use json::validation::Checked::Valid;
+    use crate::accessor::Iter;
+    use crate::Document;
+
+    #[test]
+    fn sparse_accessor_roundtrip() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let mut displacements = vec![[0.0f32; 3]; 300];
+        displacements[7] = [1.0, 2.0, 3.0];
+        displacements[299] = [-1.0, 0.0, 0.5];
+        let accessor = packer.push_sparse_accessor(&mut root, None, &displacements);
+        let bin = packer.finish(&mut root);
+
+        let json = &root.accessors[accessor.value()];
+        assert!(json.buffer_view.is_none());
+        assert_eq!(json.sparse.as_ref().unwrap().count, 2);
+        assert_eq!(json.min.as_ref().unwrap(), &json::Value::from(vec![-1.0f32, 0.0, 0.0]));
+        assert!(bin.len() < 300 * 12);
+
+        let document = Document::from_json(root).unwrap();
+        let accessor = document.accessors().nth(accessor.value()).unwrap();
+        let mut iter = Iter::<[f32; 3]>::new(accessor, |_| Some(&bin[..])).unwrap();
+        for expected in &displacements {
+            assert_eq!(iter.next().as_ref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn sparse_accessor_falls_back_to_dense() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let base = packer.push_accessor(&mut root, &[1u16, 2, 3, 4], None);
+        let values = [5u16, 6, 7, 8];
+        let accessor = packer.push_sparse_accessor(&mut root, Some((base, &[1, 2, 3, 4])), &values);
+        let json = &root.accessors[accessor.value()];
+        assert!(json.sparse.is_none());
+        assert_eq!(json.max.as_ref().unwrap(), &json::Value::from(vec![8u16]));
+    }
+
+    #[test]
+    fn sparse_accessor_fallback_keeps_normalized() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let base_values = [[0u8, 0, 0, 255], [255, 0, 0, 255]];
+        let base = packer.push_accessor(&mut root, &base_values, Some(Target::ArrayBuffer));
+        root.accessors[base.value()].normalized = true;
+        let values = [[0u8, 255, 0, 255], [0, 0, 255, 255]];
+        let accessor = packer.push_sparse_accessor(&mut root, Some((base, &base_values)), &values);
+        let json = &root.accessors[accessor.value()];
+        assert!(json.sparse.is_none() && json.normalized);
+        let view = &root.buffer_views[json.buffer_view.unwrap().value()];
+        assert_eq!(view.target, Some(Valid(Target::ArrayBuffer)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn sparse_accessor_rejects_sparse_base() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let base_values = [1u16, 2, 3, 4];
+        let base = packer.push_accessor(&mut root, &base_values, None);
+        let base = packer.push_accessor_patch(&mut root, (base, &base_values), &[(0, 9)]);
+        packer.push_sparse_accessor(&mut root, Some((base, &[9u16, 2, 3, 4])), &[9, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn sparse_accessor_rejects_mismatched_base_type() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let base = packer.push_accessor(&mut root, &[1u16, 2, 3, 4], None);
+        packer.push_sparse_accessor(&mut root, Some((base, &[1u32, 2, 3, 4])), &[1u32, 2, 3, 5]);
+    }
+
+    #[test]
+    fn accessor_patch_shares_base_view() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let positions: Vec<_> = (0..300).map(|i| [i as f32, 0.0, 0.0]).collect();
+        let base = packer.push_accessor(&mut root, &positions, None);
+        let patch = [(299, [0.0, 5.0, 0.0]), (3, [1.0, 1.0, 1.0]), (299, [-1.0, 2.0, 0.0])];
+        let patched = packer.push_accessor_patch(&mut root, (base, &positions), &patch);
+        let bin = packer.finish(&mut root);
+        assert!(bin.len() < 2 * 300 * 12);
+
+        let json = &root.accessors[patched.value()];
+        let view = |accessor: &json::Accessor| accessor.buffer_view.map(|x| x.value());
+        assert_eq!(view(json), view(&root.accessors[base.value()]));
+        assert_eq!(json.sparse.as_ref().unwrap().count, 2);
+        assert_eq!(json.min.as_ref().unwrap(), &json::Value::from(vec![-1.0f32, 0.0, 0.0]));
+        assert_eq!(json.max.as_ref().unwrap(), &json::Value::from(vec![298.0f32, 2.0, 1.0]));
+
+        let document = Document::from_json(root).unwrap();
+        let accessor = document.accessors().nth(patched.value()).unwrap();
+        let values: Vec<_> = Iter::<[f32; 3]>::new(accessor, |_| Some(&bin[..])).unwrap().collect();
+        assert_eq!(values[2], [2.0, 0.0, 0.0]);
+        assert_eq!(values[3], [1.0, 1.0, 1.0]);
+        assert_eq!(values[299], [-1.0, 2.0, 0.0]);
+    }
+}
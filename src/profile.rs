@@ -0,0 +1,225 @@
+use json::accessor::{ComponentType, GenericComponentType};
+use json::mesh::Semantic;
+use json::validation::Checked::Valid;
+use json::Path;
+
+use crate::Document;
+
+/// The hardware limits of a target platform, against which `Profile::check`
+/// flags the parts of an asset that would not render as authored.
+///
+/// The presets are conservative choices for commonly deployed devices rather
+/// than the guaranteed minima of the graphics APIs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// The maximum number of joints of a skin.
+    pub max_joints: usize,
+
+    /// The maximum number of joint influences per vertex, four for each set of
+    /// `JOINTS_n` and `WEIGHTS_n` attributes.
+    pub max_weights_per_vertex: usize,
+
+    /// The maximum number of morph targets of a primitive.
+    pub max_morph_targets: usize,
+
+    /// The maximum width and height of an image in pixels.
+    pub max_texture_size: u32,
+
+    /// Whether 8-bit indices are supported.
+    pub u8_indices: bool,
+
+    /// Whether 32-bit indices are supported.
+    pub u32_indices: bool,
+}
+
+/// A limit of a `Profile`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Limit {
+    /// `Profile::max_joints`.
+    Joints,
+
+    /// `Profile::max_weights_per_vertex`.
+    WeightsPerVertex,
+
+    /// `Profile::max_morph_targets`.
+    MorphTargets,
+
+    /// `Profile::max_texture_size`.
+    TextureSize,
+
+    /// `Profile::u8_indices` and `Profile::u32_indices`.
+    IndexWidth,
+}
+
+/// A part of an asset exceeding a limit of a `Profile`, as returned by
+/// `Profile::check`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Violation {
+    /// The skin, primitive, or image concerned.
+    pub path: Path,
+
+    /// The limit exceeded.
+    pub limit: Limit,
+
+    /// The value exceeding the limit: the number of joints, weights per vertex,
+    /// or morph targets, the larger image dimension, or the index width in
+    /// bytes.
+    pub value: usize,
+}
+
+impl Profile {
+    /// WebGL 2 in desktop and mobile browsers.
+    pub const WEBGL2: Profile = Profile {
+        max_joints: 64,
+        max_weights_per_vertex: 4,
+        max_morph_targets: 8,
+        max_texture_size: 4096,
+        u8_indices: true,
+        u32_indices: true,
+    };
+
+    /// OpenGL ES 3.0 on mobile devices.
+    pub const MOBILE_GLES: Profile = Profile {
+        max_joints: 64,
+        max_weights_per_vertex: 4,
+        max_morph_targets: 4,
+        max_texture_size: 2048,
+        u8_indices: true,
+        u32_indices: true,
+    };
+
+    /// Vulkan on desktop GPUs, where 8-bit indices require the
+    /// `VK_EXT_index_type_uint8` extension.
+    pub const DESKTOP_VULKAN: Profile = Profile {
+        max_joints: 256,
+        max_weights_per_vertex: 8,
+        max_morph_targets: 64,
+        max_texture_size: 16384,
+        u8_indices: false,
+        u32_indices: true,
+    };
+
+    /// Returns the skins and primitives of a document that exceed the limits
+    /// of the profile.
+    ///
+    /// Image dimensions are not part of the JSON, so images are checked by
+    /// `check_images` instead.
+    pub fn check(&self, document: &Document) -> Vec<Violation> {
+        let root = &document.0;
+        let mut violations = Vec::new();
+        let mut flag = |path: Path, limit: Limit, value: usize, max: usize| {
+            if value > max {
+                violations.push(Violation { path, limit, value });
+            }
+        };
+        for (index, skin) in root.skins.iter().enumerate() {
+            let path = Path::new().field("skins").index(index).field("joints");
+            flag(path, Limit::Joints, skin.joints.len(), self.max_joints);
+        }
+        for (mesh_index, mesh) in root.meshes.iter().enumerate() {
+            for (index, primitive) in mesh.primitives.iter().enumerate() {
+                let path = || {
+                    Path::new().field("meshes").index(mesh_index).field("primitives").index(index)
+                };
+                let sets = primitive
+                    .attributes
+                    .keys()
+                    .filter_map(|semantic| match semantic {
+                        Valid(Semantic::Joints(set)) => Some(*set as usize + 1),
+                        _ => None,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                let max = self.max_weights_per_vertex;
+                flag(path().field("attributes"), Limit::WeightsPerVertex, 4 * sets, max);
+                let targets = primitive.targets.as_ref().map_or(0, Vec::len);
+                flag(path().field("targets"), Limit::MorphTargets, targets, self.max_morph_targets);
+                // Unsupported index widths are flagged by allowing no width at all.
+                let indices = primitive.indices.and_then(|x| root.accessors.get(x.value()));
+                let (width, supported) = match indices.map(|x| x.component_type) {
+                    Some(Valid(GenericComponentType(ComponentType::U8))) => (1, self.u8_indices),
+                    Some(Valid(GenericComponentType(ComponentType::U32))) => (4, self.u32_indices),
+                    _ => continue,
+                };
+                let max = if supported { width } else { 0 };
+                flag(path().field("indices"), Limit::IndexWidth, width, max);
+            }
+        }
+        violations
+    }
+
+    /// Returns the images exceeding `max_texture_size`, given the image data
+    /// in the order of `Document::images`, as returned by `gltf::import`.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn check_images(&self, images: &[crate::image::Data]) -> Vec<Violation> {
+        images
+            .iter()
+            .enumerate()
+            .filter(|(_, data)| data.width.max(data.height) > self.max_texture_size)
+            .map(|(index, data)| Violation {
+                path: Path::new().field("images").index(index),
+                limit: Limit::TextureSize,
+                value: data.width.max(data.height) as usize,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Limit, Profile};
+    use crate::Document;
+
+    #[test]
+    fn check_platform_limits() {
+        let joints: Vec<_> = (0..100).map(|x| x.to_string()).collect();
+        let json = json::Root::from_str(&format!(r#"{{
+            "asset": {{"version": "2.0"}},
+            "accessors": [
+                {{"componentType": 5126, "type": "VEC3", "count": 0}},
+                {{"componentType": 5121, "type": "SCALAR", "count": 0}}
+            ],
+            "meshes": [{{"primitives": [{{
+                "attributes": {{"POSITION": 0, "JOINTS_0": 0, "JOINTS_1": 0}},
+                "indices": 1,
+                "targets": [{{}}, {{}}, {{}}, {{}}, {{}}, {{}}]
+            }}]}}],
+            "skins": [{{"joints": [{}]}}]
+        }}"#, joints.join(","))).unwrap();
+        let document = Document::from_json_without_validation(json);
+
+        let limits = |profile: Profile| -> Vec<_> {
+            let violations = profile.check(&document).into_iter();
+            violations.map(|x| (x.path.to_string(), x.limit, x.value)).collect()
+        };
+        assert_eq!(limits(Profile::WEBGL2), [
+            ("skins[0].joints".to_string(), Limit::Joints, 100),
+            ("meshes[0].primitives[0].attributes".to_string(), Limit::WeightsPerVertex, 8),
+        ]);
+        assert_eq!(limits(Profile::MOBILE_GLES).len(), 3);
+        assert_eq!(limits(Profile::DESKTOP_VULKAN), [
+            ("meshes[0].primitives[0].indices".to_string(), Limit::IndexWidth, 1),
+        ]);
+    }
+
+    #[cfg(feature = "import")]
+    #[test]
+    fn check_texture_sizes() {
+        use crate::image::{Data, Format};
+
+        let image = |width, height| Data {
+            pixels: vec![0; (width * height) as usize],
+            format: Format::R8,
+            width,
+            height,
+            premultiplied: false,
+        };
+        let images = [image(4096, 1), image(1, 3000)];
+        assert!(Profile::WEBGL2.check_images(&images).is_empty());
+        let violations = Profile::MOBILE_GLES.check_images(&images);
+        assert_eq!(violations.len(), 2);
+        assert_eq!((violations[1].limit, violations[1].value), (Limit::TextureSize, 3000));
+        assert_eq!(violations[1].path.as_str(), "images[1]");
+    }
+}
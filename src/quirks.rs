@@ -0,0 +1,300 @@
+use std::collections::{BTreeSet, HashSet};
+
+use json::accessor::{ComponentType, GenericComponentType};
+use json::buffer::Target;
+use json::mesh::Semantic;
+use json::validation::Checked::Valid;
+use json::{Index, Path};
+
+use crate::optimize::{dimensions, read_components, write_components};
+use crate::pack::component_size;
+
+/// A known exporter bug with a targeted workaround.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Quirk {
+    /// Buffer views with a `byteStride` smaller than the elements of the
+    /// accessors they hold, which are tightly packed in fact.
+    ///
+    /// The workaround removes the stride.
+    ByteStride,
+
+    /// `TEXCOORD_n` attributes written with the V axis pointing up, as in
+    /// OpenGL, rather than down from the top left corner of the image.
+    ///
+    /// The workaround replaces V with `1 - V` for float and normalized
+    /// unsigned coordinates and negates the V displacements of morph targets.
+    FlippedTexCoords,
+}
+
+/// A rule of a `Quirks` database, applying a quirk to the assets whose
+/// `asset.generator` starts with the given string.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    /// The prefix of the generator string.
+    pub generator: String,
+
+    /// The quirk of the generator.
+    pub quirk: Quirk,
+}
+
+/// A workaround applied by `Quirks::apply`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Applied {
+    /// The quirk worked around.
+    pub quirk: Quirk,
+
+    /// The buffer view or accessor that was changed.
+    pub path: Path,
+}
+
+/// An opt-in database of generator quirks.
+///
+/// The database starts out empty; rules are added for the exporters an
+/// application needs to handle, and each quirk may be disabled regardless of
+/// the rules matching an asset.
+///
+/// ```
+/// use gltf::quirks::{Quirk, Quirks};
+///
+/// let mut quirks = Quirks::new();
+/// quirks.add_rule("Example Exporter 1.", Quirk::FlippedTexCoords);
+/// quirks.set_enabled(Quirk::ByteStride, false);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Quirks {
+    /// The rules of the database in insertion order.
+    rules: Vec<Rule>,
+
+    /// The quirks that are never applied.
+    disabled: HashSet<Quirk>,
+}
+
+impl Quirks {
+    /// Creates an empty database.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule applying `quirk` to the assets whose generator starts
+    /// with `generator`.
+    pub fn add_rule(&mut self, generator: &str, quirk: Quirk) {
+        self.rules.push(Rule { generator: generator.to_string(), quirk });
+    }
+
+    /// Returns the rules of the database.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Enables or disables a quirk. Every quirk is enabled initially.
+    pub fn set_enabled(&mut self, quirk: Quirk, enabled: bool) {
+        if enabled {
+            self.disabled.remove(&quirk);
+        } else {
+            self.disabled.insert(quirk);
+        }
+    }
+
+    /// Returns `true` if the quirk is enabled.
+    pub fn is_enabled(&self, quirk: Quirk) -> bool {
+        !self.disabled.contains(&quirk)
+    }
+
+    /// Returns the enabled quirks whose rules match the generator of an asset,
+    /// in order and without duplicates.
+    pub fn detect(&self, root: &json::Root) -> Vec<Quirk> {
+        let generator = match root.asset.generator.as_ref() {
+            Some(generator) => generator,
+            None => return Vec::new(),
+        };
+        let matching = self
+            .rules
+            .iter()
+            .filter(|rule| generator.starts_with(&rule.generator))
+            .map(|rule| rule.quirk)
+            .filter(|quirk| self.is_enabled(*quirk));
+        matching.collect::<BTreeSet<_>>().into_iter().collect()
+    }
+
+    /// Applies the workarounds of the detected quirks to an asset, returning
+    /// each part of the asset that was changed.
+    ///
+    /// Rewritten accessors are given new buffer views, appended to their
+    /// buffers; the previous buffer views are left in place, so
+    /// `optimize::repack_buffers` may be used to drop them.
+    pub fn apply(&self, root: &mut json::Root, buffers: &mut [Vec<u8>]) -> Vec<Applied> {
+        let mut applied = Vec::new();
+        for quirk in self.detect(root) {
+            let paths = match quirk {
+                Quirk::ByteStride => fix_byte_strides(root),
+                Quirk::FlippedTexCoords => flip_tex_coords(root, buffers),
+            };
+            applied.extend(paths.into_iter().map(|path| Applied { quirk, path }));
+        }
+        applied
+    }
+}
+
+/// Removes the strides of the buffer views that are smaller than the elements
+/// of their accessors.
+fn fix_byte_strides(root: &mut json::Root) -> Vec<Path> {
+    let mut element_sizes = vec![0; root.buffer_views.len()];
+    for accessor in &root.accessors {
+        let size = match (accessor.component_type, accessor.type_) {
+            (Valid(GenericComponentType(component_type)), Valid(type_)) => {
+                component_size(component_type) * dimensions(type_)
+            },
+            _ => continue,
+        };
+        let view = accessor.buffer_view.map(|x| x.value());
+        if let Some(max) = view.and_then(|x| element_sizes.get_mut(x)) {
+            *max = size.max(*max);
+        }
+    }
+    let mut paths = Vec::new();
+    for (index, view) in root.buffer_views.iter_mut().enumerate() {
+        let element_size = element_sizes[index];
+        if view.byte_stride.filter(|&stride| (stride as usize) < element_size).is_some() {
+            view.byte_stride = None;
+            paths.push(Path::new().field("bufferViews").index(index));
+        }
+    }
+    paths
+}
+
+/// Flips the V axis of the texture coordinates of every primitive.
+///
+/// Sparse accessors and non-normalized integer coordinates are left as is.
+fn flip_tex_coords(root: &mut json::Root, buffers: &mut [Vec<u8>]) -> Vec<Path> {
+    let mut coordinates = BTreeSet::new();
+    let mut displacements = BTreeSet::new();
+    for primitive in root.meshes.iter().flat_map(|mesh| mesh.primitives.iter()) {
+        for (semantic, index) in &primitive.attributes {
+            if let Valid(Semantic::TexCoords(_)) = semantic {
+                coordinates.insert(index.value());
+            }
+        }
+        for target in primitive.targets.iter().flatten() {
+            for (semantic, index) in &target.others {
+                if let Valid(Semantic::TexCoords(_)) = semantic {
+                    displacements.insert(index.value());
+                }
+            }
+        }
+    }
+
+    let mut paths = Vec::new();
+    for (indices, displacement) in [(coordinates, false), (displacements, true)].iter() {
+        for &index in indices {
+            let accessor = match root.accessors.get(index) {
+                Some(accessor) => accessor,
+                None => continue,
+            };
+            let component_type = match accessor.component_type {
+                Valid(GenericComponentType(component_type)) => component_type,
+                _ => continue,
+            };
+            let one = match (component_type, accessor.normalized) {
+                (ComponentType::F32, _) => 1.0,
+                (ComponentType::U8, true) => 255.0,
+                (ComponentType::U16, true) => 65_535.0,
+                _ if *displacement => 0.0,
+                _ => continue,
+            };
+            let mut components = match read_components(root, buffers, accessor) {
+                Some(components) => components,
+                None => continue,
+            };
+            for v in components.iter_mut().skip(1).step_by(2) {
+                *v = if *displacement { -*v } else { one - *v };
+            }
+            let index = Index::new(index as u32);
+            let target = Some(Target::ArrayBuffer);
+            write_components(root, buffers, index, &components, component_type, target);
+            paths.push(Path::new().field("accessors").index(index.value()));
+        }
+    }
+    paths
+}
+
+/// Return type of `import`, besides the workarounds applied.
+#[cfg(feature = "import")]
+type Import = (crate::Document, Vec<crate::buffer::Data>, Vec<crate::image::Data>);
+
+/// Import some glTF 2.0 from the file system, applying the workarounds of the
+/// quirks detected by `quirks` before the document is validated.
+///
+/// Returns the same data as `gltf::import`, followed by the workarounds that
+/// were applied.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub fn import<P>(path: P, quirks: &Quirks) -> crate::Result<(Import, Vec<Applied>)>
+where
+    P: AsRef<std::path::Path>,
+{
+    use crate::{buffer, import, Document, Error, Gltf};
+
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or_else(|| std::path::Path::new("./"));
+    let bytes = std::fs::read(path).map_err(Error::Io)?;
+    let Gltf { document, blob } = Gltf::from_slice_without_validation(&bytes)?;
+    let buffers = import::import_buffer_data(&document, Some(base), blob)?;
+    let mut root = document.into_json();
+    let mut buffers: Vec<_> = buffers.into_iter().map(|data| data.0).collect();
+    let applied = quirks.apply(&mut root, &mut buffers);
+    let document = Document::from_json(root)?;
+    let buffers: Vec<_> = buffers.into_iter().map(buffer::Data).collect();
+    let images = import::import_image_data(&document, Some(base), &buffers)?;
+    Ok(((document, buffers, images), applied))
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use super::{Quirk, Quirks};
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn apply_generator_quirks() {
+        let mut root = json::Root::default();
+        root.asset.generator = Some("Example Exporter 1.2".to_string());
+        let mut packer = Packer::new(&mut root);
+        let uvs = [[0.25f32, 0.0], [0.5, 0.75]];
+        let uv = packer.push_accessor(&mut root, &uvs, None);
+        let positions = [[0.0f32; 3], [1.0, 0.0, 0.0]];
+        let position = packer.push_accessor(&mut root, &positions, None);
+        let mut buffers = vec![packer.finish(&mut root)];
+        let view = root.accessors[position.value()].buffer_view.unwrap().value();
+        root.buffer_views[view].byte_stride = Some(8);
+        let primitive = format!(
+            r#"{{"primitives": [{{"attributes": {{"POSITION": {}, "TEXCOORD_0": {}}}}}]}}"#,
+            position.value(),
+            uv.value(),
+        );
+        root.push::<json::Mesh>(json::deserialize::from_str(&primitive).unwrap());
+
+        let mut quirks = Quirks::new();
+        quirks.add_rule("Other Exporter", Quirk::ByteStride);
+        assert!(quirks.detect(&root).is_empty());
+        quirks.add_rule("Example Exporter 1.", Quirk::FlippedTexCoords);
+        quirks.add_rule("Example Exporter", Quirk::ByteStride);
+        quirks.add_rule("Example", Quirk::ByteStride);
+        assert_eq!(quirks.detect(&root), [Quirk::ByteStride, Quirk::FlippedTexCoords]);
+        quirks.set_enabled(Quirk::FlippedTexCoords, false);
+        assert_eq!(quirks.detect(&root), [Quirk::ByteStride]);
+        quirks.set_enabled(Quirk::FlippedTexCoords, true);
+
+        let applied = quirks.apply(&mut root, &mut buffers);
+        let paths: Vec<_> = applied.iter().map(|x| (x.quirk, x.path.to_string())).collect();
+        assert_eq!(paths, [
+            (Quirk::ByteStride, format!("bufferViews[{}]", view)),
+            (Quirk::FlippedTexCoords, format!("accessors[{}]", uv.value())),
+        ]);
+        let document = Document::from_json(root).unwrap();
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        let uvs: Vec<_> = reader.read_tex_coords(0).unwrap().into_f32().collect();
+        assert_eq!(uvs, [[0.25, 1.0], [0.5, 0.25]]);
+        assert_eq!(reader.read_positions().unwrap().nth(1), Some([1.0, 0.0, 0.0]));
+    }
+}
@@ -1,6 +1,109 @@
 use std::slice;
 
-use crate::{Document, Node};
+use crate::math::Matrix4;
+use crate::{Camera, Document, Node};
+
+/// An `Iterator` that visits every node in a scene in pre-order, yielding the
+/// depth of each node, the index of its parent, and the node itself.
+///
+/// Root nodes have depth zero and no parent. Every node is visited before its
+/// children, and siblings in the order they are listed. A node that is its own
+/// ancestor is skipped along with its descendants, so the traversal terminates
+/// even if the node hierarchy contains cycles.
+#[derive(Clone, Debug)]
+pub struct Preorder<'a> {
+    /// Nodes yet to be visited, paired with their depth in the hierarchy.
+    stack: Vec<(Node<'a>, usize)>,
+
+    /// Indices of the ancestors of the next node to be visited.
+    path: Vec<usize>,
+}
+
+impl<'a> Preorder<'a> {
+    /// Constructs a `Preorder` over the given root nodes.
+    pub(crate) fn new<I: IntoIterator<Item = Node<'a>>>(roots: I) -> Self {
+        let mut stack: Vec<_> = roots.into_iter().map(|node| (node, 0)).collect();
+        stack.reverse();
+        Self { stack, path: Vec::new() }
+    }
+}
+
+impl<'a> Iterator for Preorder<'a> {
+    type Item = (usize, Option<usize>, Node<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (node, depth) = self.stack.pop()?;
+            self.path.truncate(depth);
+            if self.path.contains(&node.index()) {
+                continue;
+            }
+            let parent = self.path.last().cloned();
+            self.path.push(node.index());
+            let mut children: Vec<_> = node.children().map(|child| (child, depth + 1)).collect();
+            children.reverse();
+            self.stack.extend(children);
+            return Some((depth, parent, node));
+        }
+    }
+}
+
+/// Depth-first traversal of a scene that tracks the world transform of each node.
+#[derive(Clone, Debug)]
+pub(crate) struct Walk<'a> {
+    /// The underlying pre-order traversal.
+    preorder: Preorder<'a>,
+
+    /// World transforms of the ancestors of the next node to be visited.
+    worlds: Vec<Matrix4>,
+}
+
+impl<'a> Walk<'a> {
+    /// Constructs a `Walk` over the given root nodes.
+    pub(crate) fn new<I: IntoIterator<Item = Node<'a>>>(roots: I) -> Self {
+        Self { preorder: Preorder::new(roots), worlds: Vec::new() }
+    }
+}
+
+impl<'a> Iterator for Walk<'a> {
+    type Item = (Node<'a>, Matrix4);
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, _, node) = self.preorder.next()?;
+        self.worlds.truncate(depth);
+        let local = Matrix4::from_array(node.transform().matrix());
+        let world = match self.worlds.last() {
+            Some(&parent) => parent * local,
+            None => local,
+        };
+        self.worlds.push(world);
+        Some((node, world))
+    }
+}
+
+/// An `Iterator` that visits every node in a scene depth-first, along with its
+/// world transform.
+#[derive(Clone, Debug)]
+pub struct Flatten<'a> {
+    /// The internal node traversal.
+    pub(crate) walk: Walk<'a>,
+}
+
+/// An `Iterator` that visits every camera in a scene along with its world
+/// transform.
+#[derive(Clone, Debug)]
+pub struct Cameras<'a> {
+    /// The internal node traversal.
+    pub(crate) walk: Walk<'a>,
+}
+
+/// An `Iterator` that visits every light in a scene along with its world
+/// transform.
+#[cfg(feature = "KHR_lights_punctual")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
+#[derive(Clone, Debug)]
+pub struct Lights<'a> {
+    /// The internal node traversal.
+    pub(crate) walk: Walk<'a>,
+}
 
 /// An `Iterator` that visits the nodes in a scene.
 #[derive(Clone, Debug)]
@@ -58,3 +161,29 @@ impl<'a> Iterator for Children<'a> {
         self.iter.nth(n).map(|index| self.document.nodes().nth(index.value()).unwrap())
     }
 }
+
+impl<'a> Iterator for Flatten<'a> {
+    type Item = (Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.walk.next().map(|(node, world)| (node, world.as_array()))
+    }
+}
+
+impl<'a> Iterator for Cameras<'a> {
+    type Item = (Camera<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.walk
+            .by_ref()
+            .find_map(|(node, world)| node.camera().map(|camera| (camera, world.as_array())))
+    }
+}
+
+#[cfg(feature = "KHR_lights_punctual")]
+impl<'a> Iterator for Lights<'a> {
+    type Item = (crate::khr_lights_punctual::Light<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.walk
+            .by_ref()
+            .find_map(|(node, world)| node.light().map(|light| (light, world.as_array())))
+    }
+}
@@ -27,6 +27,26 @@ pub enum Transform {
 }
 
 impl Transform {
+    /// Returns the transform of a JSON node.
+    pub(crate) fn from_json(json: &json::scene::Node) -> Self {
+        if let Some(m) = json.matrix {
+            Transform::Matrix {
+                matrix: [
+                    [m[0], m[1], m[2], m[3]],
+                    [m[4], m[5], m[6], m[7]],
+                    [m[8], m[9], m[10], m[11]],
+                    [m[12], m[13], m[14], m[15]],
+                ],
+            }
+        } else {
+            Transform::Decomposed {
+                translation: json.translation.unwrap_or([0.0, 0.0, 0.0]),
+                rotation: json.rotation.unwrap_or_default().0,
+                scale: json.scale.unwrap_or([1.0, 1.0, 1.0]),
+            }
+        }
+    }
+
     /// Returns the matrix representation of this transform.
     ///
     /// If the transform is `Decomposed`, then the matrix is generated with the
@@ -90,11 +110,20 @@ pub struct Node<'a> {
     json: &'a json::scene::Node,
 }
 
+/// A node being edited, as returned by `Document::node_mut`.
+#[derive(Debug)]
+pub struct NodeMut<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a mut json::scene::Node,
+}
+
 /// The root nodes of a scene.
 #[derive(Clone, Debug)]
 pub struct Scene<'a> {
     /// The parent `Document` struct.
-    #[allow(dead_code)]
     document: &'a Document,
 
     /// The corresponding JSON index.
@@ -104,6 +133,51 @@ pub struct Scene<'a> {
     json: &'a json::scene::Scene,
 }
 
+impl<'a> NodeMut<'a> {
+    /// Constructs a `NodeMut`.
+    pub(crate) fn new(index: usize, json: &'a mut json::scene::Node) -> Self {
+        Self { index, json }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Sets the local transform of the node.
+    ///
+    /// A `Matrix` transform is stored as the `matrix` property and a
+    /// `Decomposed` transform as the TRS properties, removing the other.
+    pub fn set_transform(&mut self, transform: Transform) {
+        let json = &mut *self.json;
+        match transform {
+            Transform::Matrix { matrix } => {
+                let mut flat = [0.0; 16];
+                for (column, values) in matrix.iter().enumerate() {
+                    flat[4 * column..4 * column + 4].copy_from_slice(values);
+                }
+                json.matrix = Some(flat);
+                json.translation = None;
+                json.rotation = None;
+                json.scale = None;
+            },
+            Transform::Decomposed { translation, rotation, scale } => {
+                json.matrix = None;
+                json.translation = Some(translation);
+                json.rotation = Some(json::scene::UnitQuaternion(rotation));
+                json.scale = Some(scale);
+            },
+        }
+    }
+
+    /// Sets the user-defined name of the node.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn set_name(&mut self, name: Option<&str>) {
+        self.json.name = name.map(str::to_string);
+    }
+}
+
 impl<'a> Node<'a> {
     /// Constructs a `Node`.
     pub(crate) fn new(
@@ -130,6 +204,15 @@ impl<'a> Node<'a> {
         })
     }
 
+    /// Returns the parent of the node, or `None` if the node is a root.
+    ///
+    /// The parent of every node is computed on the first call and cached by
+    /// the document.
+    pub fn parent(&self) -> Option<Node<'a>> {
+        let parent = crate::cache::parents(self.document)[self.index]?;
+        self.document.nodes().nth(parent)
+    }
+
     /// Returns an `Iterator` that visits the node's children.
     pub fn children(&self) -> iter::Children<'a> {
         iter::Children {
@@ -143,6 +226,32 @@ impl<'a> Node<'a> {
         &self.json.extras
     }
 
+    /// Returns the tags recorded under the `"tags"` key of `extras`.
+    ///
+    /// See `json::extras::Taggable` for tagging nodes on export.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn tags(&self) -> Vec<String> {
+        json::extras::Taggable::tags(self.json)
+    }
+
+    /// Returns whether the node carries the given tag.
+    #[cfg(feature = "extras")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extras")))]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        json::extras::Taggable::has_tag(self.json, tag)
+    }
+
+    /// Returns the instance attributes of this node as defined by the
+    /// `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+    pub fn instancing(&self) -> Option<crate::ext_mesh_gpu_instancing::Instancing<'a>> {
+        let extensions = self.json.extensions.as_ref()?;
+        let instancing = extensions.ext_mesh_gpu_instancing.as_ref()?;
+        Some(crate::ext_mesh_gpu_instancing::Instancing::new(self.document, instancing))
+    }
+
     /// Returns the light at this node as defined by the `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -174,25 +283,7 @@ impl<'a> Node<'a> {
 
     /// Returns the node's transform.
     pub fn transform(&self) -> Transform {
-        if let Some(m) = self.json.matrix {
-            Transform::Matrix {
-                matrix: [
-                    [m[0], m[1], m[2], m[3]],
-                    [m[4], m[5], m[6], m[7]],
-                    [m[8], m[9], m[10], m[11]],
-                    [m[12], m[13], m[14], m[15]],
-                ],
-            }
-        } else {
-            Transform::Decomposed {
-                translation: self.json.translation
-                    .unwrap_or_else(|| [0.0, 0.0, 0.0]),
-                rotation: self.json.rotation
-                    .unwrap_or_else(json::scene::UnitQuaternion::default).0,
-                scale: self.json.scale
-                    .unwrap_or_else(|| [1.0, 1.0, 1.0]),
-            }
-        }
+        Transform::from_json(self.json)
     }
 
     /// Returns the skin referenced by this node.
@@ -238,6 +329,78 @@ impl<'a> Scene<'a> {
         self.json.name.as_ref().map(String::as_str)
     }
 
+    /// Returns an `Iterator` that visits every node of the scene in pre-order,
+    /// along with its depth and the index of its parent.
+    ///
+    /// This is the traversal underlying `flatten`, `cameras`, `lights`, and
+    /// `draw_order`. Nodes instantiated more than once are visited once per
+    /// instance.
+    pub fn iter_preorder(&self) -> iter::Preorder<'a> {
+        iter::Preorder::new(self.nodes())
+    }
+
+    /// Returns an `Iterator` that visits every node of the scene depth-first,
+    /// parents before children, along with its column-major world transform.
+    ///
+    /// Nodes are visited in the same order as by `iter_preorder`. A node that is
+    /// its own ancestor is skipped along with its descendants, so that
+    /// hierarchies containing cycles are visited in finite time.
+    pub fn flatten(&self) -> iter::Flatten<'a> {
+        iter::Flatten {
+            walk: iter::Walk::new(self.nodes()),
+        }
+    }
+
+    /// Returns the world space bounds of every mesh instantiated in the scene,
+    /// or `None` if the scene contains no positions.
+    ///
+    /// The bounds of each mesh, as given by `Mesh::bounding_box`, are
+    /// transformed by the world transform of its node before being combined.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn bounding_box<'s, F>(&self, get_buffer_data: F) -> Option<crate::mesh::BoundingBox>
+    where
+        F: Clone + Fn(crate::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        iter::Walk::new(self.nodes())
+            .filter_map(|(node, world)| {
+                let bounds = node.mesh()?.bounding_box(get_buffer_data.clone())?;
+                Some(bounds.transformed(world.as_array()))
+            })
+            .fold(None, |bounds: Option<crate::mesh::BoundingBox>, x| {
+                Some(bounds.map_or(x.clone(), |bounds| bounds.union(&x)))
+            })
+    }
+
+    /// Returns an `Iterator` that visits every camera instantiated in the scene
+    /// along with the world transform of the node it is attached to.
+    pub fn cameras(&self) -> iter::Cameras<'a> {
+        iter::Cameras {
+            walk: iter::Walk::new(self.nodes()),
+        }
+    }
+
+    /// Returns an `Iterator` that visits every light instantiated in the scene
+    /// along with the world transform of the node it is attached to.
+    #[cfg(feature = "KHR_lights_punctual")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
+    pub fn lights(&self) -> iter::Lights<'a> {
+        iter::Lights {
+            walk: iter::Walk::new(self.nodes()),
+        }
+    }
+
+    /// Returns every primitive instantiated in the scene in a suggested drawing
+    /// order for a camera with the given world transform.
+    ///
+    /// Opaque primitives are ordered front-to-back, followed by masked primitives
+    /// front-to-back, followed by blended primitives back-to-front, each by the
+    /// centre of their `POSITION` bounds. See `draw::report` for finding blended
+    /// primitives that this order may not suit.
+    pub fn draw_order(&self, camera: [[f32; 4]; 4]) -> Vec<crate::draw::Draw<'a>> {
+        crate::draw::order(self, camera)
+    }
+
     /// Returns an `Iterator` that visits each root node of the scene.
     pub fn nodes(&self) -> iter::Nodes<'a> {
         iter::Nodes {
@@ -287,6 +450,52 @@ mod tests {
         test_decompose(translation, rotation, scale);
     }
 
+    #[test]
+    fn flatten_scene() {
+        let root = json::Root::from_str(r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [0] }],
+            "nodes": [
+                { "children": [1, 2], "scale": [2.0, 1.0, 1.0] },
+                { "children": [0], "translation": [1.0, 0.0, 0.0] },
+                { "matrix": [1, 0, 0, 0, 0, 1, 0, 0, 0, 0, 1, 0, 0, 0, 3, 1] }
+            ]
+        }"#).unwrap();
+        let document = crate::Document::from_json_without_validation(root);
+        let scene = document.scenes().next().unwrap();
+        let nodes: Vec<_> = scene
+            .flatten()
+            .map(|(node, world)| (node.index(), world[3]))
+            .collect();
+        assert_eq!(nodes, [
+            (0, [0.0, 0.0, 0.0, 1.0]),
+            (1, [2.0, 0.0, 0.0, 1.0]),
+            (2, [0.0, 0.0, 3.0, 1.0]),
+        ]);
+    }
+
+    #[test]
+    fn iterate_scene_preorder() {
+        let root = json::Root::from_str(r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [{ "nodes": [3, 0] }],
+            "nodes": [{ "children": [1, 2] }, { "children": [4] }, {}, {}, { "children": [0] }]
+        }"#).unwrap();
+        let document = crate::Document::from_json_without_validation(root);
+        let scene = document.scenes().next().unwrap();
+        let nodes: Vec<_> = scene
+            .iter_preorder()
+            .map(|(depth, parent, node)| (depth, parent, node.index()))
+            .collect();
+        assert_eq!(nodes, [
+            (0, None, 3),
+            (0, None, 0),
+            (1, Some(0), 1),
+            (2, Some(1), 4),
+            (1, Some(0), 2),
+        ]);
+    }
+
     #[test]
     fn decompose_identity() {
         let translation = [0.0, 0.0, 0.0];
@@ -582,4 +791,62 @@ mod tests {
         let scale = [10.0, 0.1, -0.1];
         test_decompose_scale(scale);
     }
+
+    #[test]
+    fn scene_cameras_world_transform() {
+        let gltf = crate::Gltf::from_slice(br#"{
+            "asset": { "version": "2.0" },
+            "cameras": [
+                { "type": "perspective", "perspective": { "yfov": 1.0, "znear": 0.1 } }
+            ],
+            "nodes": [
+                { "translation": [1.0, 2.0, 3.0], "children": [1] },
+                { "translation": [0.0, 0.0, 5.0], "scale": [2.0, 2.0, 2.0], "camera": 0 }
+            ],
+            "scenes": [{ "nodes": [0] }]
+        }"#).unwrap();
+        let scene = gltf.scenes().next().unwrap();
+        let cameras: Vec<_> = scene.cameras().collect();
+        assert_eq!(cameras.len(), 1);
+        let (camera, world) = &cameras[0];
+        assert_eq!(camera.index(), 0);
+        assert_eq!(world[3], [1.0, 2.0, 8.0, 1.0]);
+        assert_eq!(world[0], [2.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn edit_through_wrappers() {
+        use crate::material::AlphaMode;
+        use crate::Document;
+
+        let mut document = Document::from_json(json::Root::from_str(r#"{
+            "asset": {"version": "2.0"},
+            "materials": [{}],
+            "nodes": [{"children": [1], "translation": [1.0, 2.0, 3.0]}, {}]
+        }"#).unwrap()).unwrap();
+        assert_eq!(document.nodes().nth(1).unwrap().parent().unwrap().index(), 0);
+
+        let matrix = [[2.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0; 4]];
+        let mut node = document.node_mut(0).unwrap();
+        node.set_transform(Transform::Matrix { matrix });
+        let mut material = document.material_mut(0).unwrap();
+        material.set_base_color_factor([1.0, 0.0, 0.0, 1.0]);
+        material.set_alpha_mode(AlphaMode::Mask);
+        material.set_alpha_cutoff(Some(0.25));
+        assert!(document.node_mut(2).is_none());
+
+        let node = document.nodes().next().unwrap();
+        assert_eq!(node.transform().matrix(), matrix);
+        let material = document.materials().next().unwrap();
+        assert_eq!(material.pbr_metallic_roughness().base_color_factor(), [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(material.alpha_mode(), AlphaMode::Mask);
+        assert_eq!(material.alpha_cutoff(), Some(0.25));
+
+        // Edits through the JSON are seen by the cached lookups.
+        document.json_mut().nodes[0].children = None;
+        assert!(document.nodes().nth(1).unwrap().parent().is_none());
+        let json = json::serialize::to_string(&document.into_json()).unwrap();
+        assert!(json.contains(r#""matrix":[2.0,0.0,0.0,0.0,0.0,1.0"#));
+        assert!(!json.contains("translation"));
+    }
 }
@@ -0,0 +1,457 @@
+use json::{texture, Index};
+
+use crate::{optimize, Document, Node};
+
+/// The kinds of objects removed by `trim` when they are unused.
+#[derive(Clone, Copy)]
+enum Kind {
+    Accessor,
+    Camera,
+    Image,
+    Material,
+    Mesh,
+    Sampler,
+    Skin,
+    Texture,
+}
+
+/// Returns the new index of each object of a list from which the objects
+/// that are not kept are removed.
+fn new_indices(kept: &[bool]) -> Vec<Option<usize>> {
+    let mut next = 0;
+    kept.iter()
+        .map(|&kept| {
+            next += kept as usize;
+            if kept {
+                Some(next - 1)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Removes the objects of a list that are not kept.
+fn retain<T>(objects: &mut Vec<T>, kept: &[bool]) {
+    let mut kept = kept.iter();
+    objects.retain(|_| *kept.next().unwrap_or(&true));
+}
+
+/// Splits a JSON pointer such as `/materials/0/alphaCutoff` into the
+/// collection, index, and remainder it refers to.
+#[cfg(feature = "KHR_animation_pointer")]
+fn split_pointer(pointer: &str) -> Option<(&str, usize, &str)> {
+    let (collection, rest) = pointer.strip_prefix('/')?.split_once('/')?;
+    let (index, rest) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+    Some((collection, index.parse().ok()?, rest))
+}
+
+/// Passes a reference to `f`, replacing it with the index returned.
+fn visit<T>(index: &mut Index<T>, kind: Kind, f: &mut dyn FnMut(Kind, usize) -> usize) {
+    *index = Index::new(f(kind, index.value()) as u32);
+}
+
+/// Passes the texture reference of a texture info to `f`.
+fn visit_info(info: &mut Option<texture::Info>, f: &mut dyn FnMut(Kind, usize) -> usize) {
+    if let Some(info) = info.as_mut() {
+        visit(&mut info.index, Kind::Texture, f);
+    }
+}
+
+/// Passes every reference to an object of a `Kind` to `f`, replacing each with
+/// the index returned.
+fn visit_references(root: &mut json::Root, f: &mut dyn FnMut(Kind, usize) -> usize) {
+    for node in &mut root.nodes {
+        node.camera.iter_mut().for_each(|x| visit(x, Kind::Camera, f));
+        node.mesh.iter_mut().for_each(|x| visit(x, Kind::Mesh, f));
+        node.skin.iter_mut().for_each(|x| visit(x, Kind::Skin, f));
+        #[cfg(feature = "EXT_mesh_gpu_instancing")]
+        {
+            let extensions = node.extensions.as_mut();
+            if let Some(x) = extensions.and_then(|x| x.ext_mesh_gpu_instancing.as_mut()) {
+                x.attributes.values_mut().for_each(|x| visit(x, Kind::Accessor, f));
+            }
+        }
+    }
+    for primitive in root.meshes.iter_mut().flat_map(|mesh| mesh.primitives.iter_mut()) {
+        primitive.attributes.values_mut().for_each(|x| visit(x, Kind::Accessor, f));
+        primitive.indices.iter_mut().for_each(|x| visit(x, Kind::Accessor, f));
+        primitive.material.iter_mut().for_each(|x| visit(x, Kind::Material, f));
+        for target in primitive.targets.iter_mut().flatten() {
+            let accessors = target.positions.iter_mut().chain(target.normals.iter_mut());
+            let accessors = accessors.chain(target.tangents.iter_mut());
+            let accessors = accessors.chain(target.others.values_mut());
+            accessors.for_each(|x| visit(x, Kind::Accessor, f));
+        }
+        #[cfg(feature = "KHR_materials_variants")]
+        {
+            let extensions = primitive.extensions.as_mut();
+            if let Some(variants) = extensions.and_then(|x| x.khr_materials_variants.as_mut()) {
+                for mapping in &mut variants.mappings {
+                    visit(&mut mapping.material, Kind::Material, f);
+                }
+            }
+        }
+    }
+    for skin in &mut root.skins {
+        skin.inverse_bind_matrices.iter_mut().for_each(|x| visit(x, Kind::Accessor, f));
+    }
+    for animation in &mut root.animations {
+        for sampler in &mut animation.samplers {
+            visit(&mut sampler.input, Kind::Accessor, f);
+            visit(&mut sampler.output, Kind::Accessor, f);
+        }
+        #[cfg(feature = "KHR_animation_pointer")]
+        for channel in &mut animation.channels {
+            let extensions = channel.target.extensions.as_mut();
+            let pointer = match extensions.and_then(|x| x.khr_animation_pointer.as_mut()) {
+                Some(x) => &mut x.pointer,
+                None => continue,
+            };
+            let (collection, index, rest) = match split_pointer(pointer) {
+                Some(x) => x,
+                None => continue,
+            };
+            let kind = match collection {
+                "cameras" => Kind::Camera,
+                "materials" => Kind::Material,
+                "meshes" => Kind::Mesh,
+                _ => continue,
+            };
+            *pointer = format!("/{}/{}{}", collection, f(kind, index), rest);
+        }
+    }
+    for material in &mut root.materials {
+        let pbr = &mut material.pbr_metallic_roughness;
+        visit_info(&mut pbr.base_color_texture, f);
+        visit_info(&mut pbr.metallic_roughness_texture, f);
+        visit_info(&mut material.emissive_texture, f);
+        if let Some(normal) = material.normal_texture.as_mut() {
+            visit(&mut normal.index, Kind::Texture, f);
+        }
+        if let Some(occlusion) = material.occlusion_texture.as_mut() {
+            visit(&mut occlusion.index, Kind::Texture, f);
+        }
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        {
+            let extension = material.extensions.as_mut();
+            if let Some(x) = extension.and_then(|x| x.pbr_specular_glossiness.as_mut()) {
+                visit_info(&mut x.diffuse_texture, f);
+                visit_info(&mut x.specular_glossiness_texture, f);
+            }
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        {
+            let extension = material.extensions.as_mut();
+            if let Some(x) = extension.and_then(|x| x.transmission.as_mut()) {
+                visit_info(&mut x.transmission_texture, f);
+            }
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        {
+            let extension = material.extensions.as_mut();
+            if let Some(x) = extension.and_then(|x| x.volume.as_mut()) {
+                visit_info(&mut x.thickness_texture, f);
+            }
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        {
+            let extension = material.extensions.as_mut();
+            if let Some(x) = extension.and_then(|x| x.specular.as_mut()) {
+                visit_info(&mut x.specular_texture, f);
+                visit_info(&mut x.specular_color_texture, f);
+            }
+        }
+    }
+    for texture in &mut root.textures {
+        texture.source.iter_mut().for_each(|x| visit(x, Kind::Image, f));
+        texture.sampler.iter_mut().for_each(|x| visit(x, Kind::Sampler, f));
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            let extensions = texture.extensions.as_mut();
+            if let Some(basisu) = extensions.and_then(|x| x.texture_basisu.as_mut()) {
+                visit(&mut basisu.source, Kind::Image, f);
+            }
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            let extensions = texture.extensions.as_mut();
+            if let Some(webp) = extensions.and_then(|x| x.texture_webp.as_mut()) {
+                visit(&mut webp.source, Kind::Image, f);
+            }
+        }
+    }
+}
+
+/// Removes the objects of each `Kind` that are not referenced, directly or
+/// through other objects, by the nodes and animations.
+fn trim(root: &mut json::Root) {
+    loop {
+        let lengths = [
+            root.accessors.len(),
+            root.cameras.len(),
+            root.images.len(),
+            root.materials.len(),
+            root.meshes.len(),
+            root.samplers.len(),
+            root.skins.len(),
+            root.textures.len(),
+        ];
+        let mut referenced: Vec<_> = lengths.iter().map(|&n| vec![false; n]).collect();
+        visit_references(root, &mut |kind, index| {
+            if let Some(flag) = referenced[kind as usize].get_mut(index) {
+                *flag = true;
+            }
+            index
+        });
+        // Objects referenced only by removed objects are found in the next pass.
+        if referenced.iter().flatten().all(|x| *x) {
+            return;
+        }
+
+        retain(&mut root.accessors, &referenced[Kind::Accessor as usize]);
+        retain(&mut root.cameras, &referenced[Kind::Camera as usize]);
+        retain(&mut root.images, &referenced[Kind::Image as usize]);
+        retain(&mut root.materials, &referenced[Kind::Material as usize]);
+        retain(&mut root.meshes, &referenced[Kind::Mesh as usize]);
+        retain(&mut root.samplers, &referenced[Kind::Sampler as usize]);
+        retain(&mut root.skins, &referenced[Kind::Skin as usize]);
+        retain(&mut root.textures, &referenced[Kind::Texture as usize]);
+        let remap: Vec<_> = referenced.iter().map(|x| new_indices(x)).collect();
+        visit_references(root, &mut |kind, index| {
+            remap[kind as usize].get(index).copied().flatten().unwrap_or(index)
+        });
+    }
+}
+
+/// Retargets an animation channel to the exported nodes, returning `false` if
+/// the node it targets is not exported.
+fn retarget(
+    channel: &mut json::animation::Channel,
+    remap: &dyn Fn(Index<json::Node>) -> Option<Index<json::Node>>,
+) -> bool {
+    if let Some(node) = channel.target.node {
+        match remap(node) {
+            Some(node) => channel.target.node = Some(node),
+            None => return false,
+        }
+    }
+    #[cfg(feature = "KHR_animation_pointer")]
+    {
+        let extensions = channel.target.extensions.as_mut();
+        if let Some(x) = extensions.and_then(|x| x.khr_animation_pointer.as_mut()) {
+            if let Some(("nodes", index, rest)) = split_pointer(&x.pointer) {
+                match remap(Index::new(index as u32)) {
+                    Some(node) => x.pointer = format!("/nodes/{}{}", node.value(), rest),
+                    None => return false,
+                }
+            }
+        }
+    }
+    true
+}
+
+/// Constructs a standalone document from the nodes of a document that are
+/// kept by a predicate, such as a visibility flag stored in their extras.
+///
+/// A node is exported if `keep` returns `true` for it and each of its
+/// ancestors, so hiding a node hides its subtree. The joints and skeletons of
+/// the skins of exported nodes are exported as well, along with their
+/// ancestors, but without meshes, cameras, skins, weights, or extensions
+/// unless they were kept themselves. Nodes keep their local transforms.
+///
+/// Scenes keep their exported root nodes, and scenes without any are removed.
+/// Animation channels targeting nodes that are not exported are removed, along
+/// with the samplers and animations left unused. Accessors, cameras, images,
+/// materials, meshes, samplers, skins, and textures that are no longer
+/// referenced are removed, and the remaining buffer views are repacked into a
+/// single buffer by `optimize::repack_buffers`, whose contents are returned
+/// with the new JSON.
+///
+/// `buffers` holds the contents of each buffer of the document, with meshopt
+/// compressed buffer views decoded, as returned by `gltf::import`.
+///
+/// # Panics
+///
+/// Panics if a buffer view refers to data outside of `buffers`.
+pub fn select<F>(document: &Document, buffers: &[Vec<u8>], mut keep: F) -> (json::Root, Vec<u8>)
+where
+    F: FnMut(Node) -> bool,
+{
+    let parents = crate::cache::parents(document);
+    let kept: Vec<_> = document.nodes().map(&mut keep).collect();
+    let count = kept.len();
+    // Nodes that are part of a cycle are never exported.
+    let exported: Vec<_> = (0..count)
+        .map(|mut index| {
+            for _ in 0..count {
+                if !kept[index] {
+                    return false;
+                }
+                match parents[index] {
+                    Some(parent) => index = parent,
+                    None => return true,
+                }
+            }
+            false
+        })
+        .collect();
+
+    let mut root = document.0.clone();
+    let mut required = exported.clone();
+    let mut stack = Vec::new();
+    for node in root.nodes.iter().zip(&exported).filter(|(_, x)| **x).map(|(node, _)| node) {
+        if let Some(skin) = node.skin.and_then(|x| root.skins.get(x.value())) {
+            let joints = skin.joints.iter().chain(skin.skeleton.iter());
+            stack.extend(joints.map(|x| x.value()));
+        }
+    }
+    while let Some(index) = stack.pop() {
+        match required.get_mut(index) {
+            Some(flag) if !*flag => *flag = true,
+            _ => continue,
+        }
+        stack.extend(parents[index]);
+    }
+
+    let new_nodes = new_indices(&required);
+    let remap = |index: Index<json::Node>| {
+        let index = new_nodes.get(index.value()).copied().flatten()?;
+        Some(Index::new(index as u32))
+    };
+    for (node, exported) in root.nodes.iter_mut().zip(exported) {
+        if !exported {
+            node.camera = None;
+            node.mesh = None;
+            node.skin = None;
+            node.weights = None;
+            node.extensions = None;
+        }
+        let children = node.children.take().into_iter().flatten();
+        let children: Vec<_> = children.filter_map(remap).collect();
+        node.children = Some(children).filter(|x| !x.is_empty());
+    }
+    retain(&mut root.nodes, &required);
+    for skin in &mut root.skins {
+        skin.joints = skin.joints.iter().copied().filter_map(remap).collect();
+        skin.skeleton = skin.skeleton.and_then(remap);
+    }
+
+    for scene in &mut root.scenes {
+        scene.nodes = scene.nodes.iter().copied().filter_map(remap).collect();
+    }
+    let scenes: Vec<_> = root.scenes.iter().map(|x| !x.nodes.is_empty()).collect();
+    let new_scenes = new_indices(&scenes);
+    retain(&mut root.scenes, &scenes);
+    let scene = root.scene.and_then(|x| new_scenes.get(x.value()).copied().flatten());
+    root.scene = scene.map(|x| Index::new(x as u32));
+
+    for animation in &mut root.animations {
+        animation.channels.retain_mut(|channel| retarget(channel, &remap));
+        let mut used = vec![false; animation.samplers.len()];
+        for channel in &animation.channels {
+            if let Some(flag) = used.get_mut(channel.sampler.value()) {
+                *flag = true;
+            }
+        }
+        let new_samplers = new_indices(&used);
+        for channel in &mut animation.channels {
+            let index = new_samplers.get(channel.sampler.value()).copied().flatten();
+            channel.sampler = Index::new(index.unwrap_or(channel.sampler.value()) as u32);
+        }
+        retain(&mut animation.samplers, &used);
+    }
+    root.animations.retain(|animation| !animation.channels.is_empty());
+
+    trim(&mut root);
+    let data = optimize::repack_buffers(&mut root, buffers);
+    (root, data)
+}
+
+/// Constructs a standalone document from one scene of a document, as `select`
+/// does for the nodes of the scene.
+///
+/// Other scenes are removed unless they share root nodes with the scene.
+pub fn select_scene(
+    document: &Document,
+    buffers: &[Vec<u8>],
+    scene: usize,
+) -> (json::Root, Vec<u8>) {
+    let roots: Vec<_> = document.0.scenes.get(scene).map_or(Vec::new(), |scene| {
+        scene.nodes.iter().map(|x| x.value()).collect()
+    });
+    select(document, buffers, |node| node.parent().is_some() || roots.contains(&node.index()))
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use super::{select, select_scene};
+    use crate::pack::Packer;
+    use crate::Document;
+
+    #[test]
+    fn select_visible_nodes() {
+        let mut root = json::Root::default();
+        let mut packer = Packer::new(&mut root);
+        let shown = packer.push_accessor(&mut root, &[[0.0f32; 3]; 3], None);
+        let hidden = packer.push_accessor(&mut root, &[[1.0f32; 3]; 4], None);
+        let times = packer.push_accessor(&mut root, &[0.0f32, 1.0], None);
+        let translations = packer.push_accessor(&mut root, &[[0.0f32; 3]; 2], None);
+        let buffers = vec![packer.finish(&mut root)];
+        let meshes = format!(
+            r#"[
+                {{"primitives": [{{"attributes": {{"POSITION": {}}}}}]}},
+                {{"primitives": [{{"attributes": {{"POSITION": {}}}, "material": 0}}]}}
+            ]"#,
+            shown.value(),
+            hidden.value(),
+        );
+        root.meshes = json::deserialize::from_str(&meshes).unwrap();
+        root.materials = json::deserialize::from_str("[{}]").unwrap();
+        root.skins = json::deserialize::from_str(r#"[{"joints": [4]}]"#).unwrap();
+        root.nodes = json::deserialize::from_str(r#"[
+            {"children": [1, 2]},
+            {"mesh": 0, "skin": 0},
+            {"mesh": 1},
+            {"children": [4], "mesh": 1},
+            {"translation": [0.0, 1.0, 0.0]}
+        ]"#).unwrap();
+        root.scenes = json::deserialize::from_str(r#"[{"nodes": [0]}, {"nodes": [3]}]"#).unwrap();
+        root.scene = Some(json::Index::new(1));
+        let animations = format!(
+            r#"[{{
+                "channels": [{{"sampler": 0, "target": {{"node": 2, "path": "translation"}}}}],
+                "samplers": [{{"input": {}, "output": {}}}]
+            }}]"#,
+            times.value(),
+            translations.value(),
+        );
+        root.animations = json::deserialize::from_str(&animations).unwrap();
+        let document = Document::from_json(root).unwrap();
+
+        let (root, data) = select(&document, &buffers, |node| node.index() == 1);
+        assert!(root.nodes.is_empty() && root.scenes.is_empty() && data.is_empty());
+
+        let (root, data) = select(&document, &buffers, |node| ![2, 3].contains(&node.index()));
+        let values = |x: &[json::Index<json::Node>]| -> Vec<_> {
+            x.iter().map(|x| x.value()).collect()
+        };
+        assert_eq!(root.nodes.len(), 4);
+        assert_eq!(values(root.nodes[0].children.as_ref().unwrap()), [1]);
+        assert!(root.nodes[2].mesh.is_none());
+        assert_eq!(values(root.nodes[2].children.as_ref().unwrap()), [3]);
+        assert_eq!(root.nodes[3].translation, Some([0.0, 1.0, 0.0]));
+        assert_eq!(values(&root.skins[0].joints), [3]);
+        assert_eq!(values(&root.scenes[0].nodes), [0]);
+        assert_eq!(values(&root.scenes[1].nodes), [2]);
+        assert_eq!(root.scene.map(|x| x.value()), Some(1));
+        assert!(root.animations.is_empty() && root.materials.is_empty());
+        assert_eq!((root.meshes.len(), root.accessors.len(), data.len()), (1, 1, 36));
+
+        let (root, _) = select_scene(&document, &buffers, 1);
+        assert_eq!(root.scene.map(|x| x.value()), Some(0));
+        assert_eq!((root.scenes.len(), root.nodes.len(), root.skins.len()), (1, 2, 0));
+        assert!(root.animations.is_empty());
+        assert_eq!((root.materials.len(), root.accessors.len()), (1, 1));
+    }
+}
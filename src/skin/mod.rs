@@ -1,3 +1,7 @@
+use crate::cache::node_parents;
+use crate::math::Matrix4;
+use crate::pack::Packer;
+use crate::scene::Transform;
 use crate::{Accessor, Document, Node};
 
 #[cfg(feature = "utils")]
@@ -107,3 +111,232 @@ impl<'a> Skin<'a> {
         })
     }
 }
+
+/// Returns the world transform of a node, or `None` if the node does not exist
+/// or is part of a cycle.
+fn world(root: &json::Root, parents: &[Option<usize>], mut index: usize) -> Option<Matrix4> {
+    let mut world = Matrix4::from_array(Transform::from_json(root.nodes.get(index)?).matrix());
+    let mut depth = 0;
+    while let Some(parent) = parents[index] {
+        depth += 1;
+        if depth > root.nodes.len() {
+            return None;
+        }
+        let local = Transform::from_json(&root.nodes[parent]).matrix();
+        world = Matrix4::from_array(local) * world;
+        index = parent;
+    }
+    Some(world)
+}
+
+/// Returns the world transform of every node of the hierarchy, for recording
+/// the pose of the joints before an edit to be passed to `rebind`.
+///
+/// Returns `None` if the hierarchy has a cycle.
+pub fn world_transforms(root: &json::Root) -> Option<Vec<[[f32; 4]; 4]>> {
+    let parents = node_parents(root);
+    (0..root.nodes.len())
+        .map(|index| world(root, &parents, index).map(|x| x.as_array()))
+        .collect()
+}
+
+/// Computes the inverse-bind matrices of a skin from the current transforms of
+/// its joints, writing them to a new accessor through `packer`.
+///
+/// Each matrix is the inverse of the world transform of the corresponding joint,
+/// so the current node hierarchy is taken to be the bind pose. The new accessor
+/// is assigned to the skin and its index returned.
+///
+/// Returns `None`, leaving `root` unmodified, if the skin or a joint does not
+/// exist, if a joint is part of a cycle in the node hierarchy, or if the world
+/// transform of a joint cannot be inverted.
+///
+/// # Examples
+///
+/// ```
+/// # use gltf::json;
+/// let mut root = json::Root::default();
+/// let mut joint: json::Node = json::deserialize::from_str("{}").unwrap();
+/// joint.translation = Some([0.0, 2.0, 0.0]);
+/// let joint = root.push(joint);
+/// let mut skin: json::Skin = json::deserialize::from_str("{\"joints\": []}").unwrap();
+/// skin.joints.push(joint);
+/// let skin = root.push(skin);
+///
+/// let mut packer = gltf::pack::Packer::new(&mut root);
+/// gltf::skin::push_inverse_bind_matrices(&mut root, &mut packer, skin).unwrap();
+/// assert_eq!(root.accessors[0].count, 1);
+/// ```
+pub fn push_inverse_bind_matrices(
+    root: &mut json::Root,
+    packer: &mut Packer,
+    skin: json::Index<json::Skin>,
+) -> Option<json::Index<json::Accessor>> {
+    let parents = node_parents(root);
+    let joints = &root.skins.get(skin.value())?.joints;
+    let mut matrices = Vec::with_capacity(joints.len());
+    for joint in joints {
+        matrices.push(world(root, &parents, joint.value())?.invert()?.as_array());
+    }
+
+    let accessor = packer.push_accessor(root, &matrices, None);
+    root.skins[skin.value()].inverse_bind_matrices = Some(accessor);
+    Some(accessor)
+}
+
+/// Recomputes the inverse-bind matrices of a skin after the node hierarchy was
+/// edited, such as by reparenting nodes or baking transforms, so that the skin
+/// deforms its meshes as it did before the edit.
+///
+/// `previous` holds the world transform of every node before the edit, as
+/// returned by `world_transforms`. Each joint keeps the skinning matrix it had
+/// in that pose: the new inverse-bind matrix is the inverse of its current
+/// world transform, times its previous world transform, times its previous
+/// inverse-bind matrix, read from `buffers`. The matrices are written to a new
+/// accessor through `packer`, which is assigned to the skin and returned.
+///
+/// Returns `None`, leaving `root` unmodified, if the skin or a joint does not
+/// exist or has no previous transform, if a joint is part of a cycle, if the
+/// world transform of a joint cannot be inverted, or if the previous
+/// inverse-bind matrices cannot be read.
+pub fn rebind(
+    root: &mut json::Root,
+    buffers: &[Vec<u8>],
+    packer: &mut Packer,
+    skin: json::Index<json::Skin>,
+    previous: &[[[f32; 4]; 4]],
+) -> Option<json::Index<json::Accessor>> {
+    let parents = node_parents(root);
+    let json = root.skins.get(skin.value())?;
+    let inverse_bind_matrices = match json.inverse_bind_matrices {
+        Some(index) => {
+            let accessor = root.accessors.get(index.value())?;
+            let components = crate::optimize::read_components(root, buffers, accessor)?;
+            if components.len() != 16 * json.joints.len() {
+                return None;
+            }
+            Some(components)
+        },
+        None => None,
+    };
+    let mut matrices = Vec::with_capacity(json.joints.len());
+    for (i, joint) in json.joints.iter().enumerate() {
+        let current = world(root, &parents, joint.value())?;
+        let mut matrix = Matrix4::from_array(*previous.get(joint.value())?);
+        if let Some(components) = inverse_bind_matrices.as_ref() {
+            let mut columns = [[0.0; 4]; 4];
+            for (j, x) in components[16 * i..16 * (i + 1)].iter().enumerate() {
+                columns[j / 4][j % 4] = *x as f32;
+            }
+            matrix = matrix * Matrix4::from_array(columns);
+        }
+        matrices.push((current.invert()? * matrix).as_array());
+    }
+
+    let accessor = packer.push_accessor(root, &matrices, None);
+    root.skins[skin.value()].inverse_bind_matrices = Some(accessor);
+    Some(accessor)
+}
+
+#[cfg(all(test, feature = "utils"))]
+mod tests {
+    use crate::pack::Packer;
+
+    #[test]
+    fn inverse_bind_matrices_from_hierarchy() {
+        let mut root = json::Root::default();
+        let mut parent: json::Node = json::deserialize::from_str(r#"{"children": [1]}"#).unwrap();
+        parent.translation = Some([1.0, 0.0, 0.0]);
+        parent.scale = Some([2.0, 2.0, 2.0]);
+        root.push(parent);
+        let mut child: json::Node = json::deserialize::from_str("{}").unwrap();
+        child.translation = Some([0.0, 3.0, 0.0]);
+        let child = root.push(child);
+        let mut skin: json::Skin = json::deserialize::from_str(r#"{"joints": []}"#).unwrap();
+        skin.joints = vec![json::Index::new(0), child];
+        let skin = root.push(skin);
+
+        let mut packer = Packer::new(&mut root);
+        let accessor = super::push_inverse_bind_matrices(&mut root, &mut packer, skin).unwrap();
+        let bin = packer.finish(&mut root);
+        assert_eq!(root.skins[skin.value()].inverse_bind_matrices.unwrap().value(), accessor.value());
+
+        let document = crate::Document::from_json(root).unwrap();
+        let skin = document.skins().next().unwrap();
+        let matrices: Vec<_> = skin.reader(|_| Some(&bin[..]))
+            .read_inverse_bind_matrices()
+            .unwrap()
+            .collect();
+        assert_eq!(matrices[0], [
+            [0.5, 0.0, 0.0, 0.0],
+            [0.0, 0.5, 0.0, 0.0],
+            [0.0, 0.0, 0.5, 0.0],
+            [-0.5, 0.0, 0.0, 1.0],
+        ]);
+        // The child joint is at (1, 6, 0) in world space.
+        assert_eq!(matrices[1][3], [-0.5, -3.0, 0.0, 1.0]);
+
+        let identity = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+        let mut globals = vec![identity; document.nodes().len()];
+        let reader = skin.reader(|_| Some(&bin[..]));
+        let joints = super::util::joint_matrices(&skin, &reader, &globals).unwrap();
+        assert_eq!(joints, matrices);
+        // A joint in its bind pose does not displace vertices.
+        globals[0] = [
+            [2.0, 0.0, 0.0, 0.0],
+            [0.0, 2.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0, 0.0],
+            [1.0, 0.0, 0.0, 1.0],
+        ];
+        let joints = super::util::joint_matrices(&skin, &reader, &globals).unwrap();
+        assert_eq!(joints[0], identity);
+        assert_eq!(super::util::joint_matrices(&skin, &reader, &globals[..1]), None);
+    }
+
+    #[test]
+    fn rebind_after_reparenting() {
+        let mut root = json::Root::default();
+        let mut parent: json::Node = json::deserialize::from_str(r#"{"children": [1]}"#).unwrap();
+        parent.translation = Some([1.0, 0.0, 0.0]);
+        parent.rotation = Some(json::scene::UnitQuaternion([0.0, 0.0, 0.6, 0.8]));
+        root.push(parent);
+        let mut child: json::Node = json::deserialize::from_str("{}").unwrap();
+        child.translation = Some([0.0, 3.0, 0.0]);
+        let child = root.push(child);
+        let mut skin: json::Skin = json::deserialize::from_str(r#"{"joints": []}"#).unwrap();
+        skin.joints = vec![json::Index::new(0), child];
+        let skin = root.push(skin);
+        let mut packer = Packer::new(&mut root);
+        root.nodes[1].scale = Some([2.0, 2.0, 2.0]);
+        super::push_inverse_bind_matrices(&mut root, &mut packer, skin).unwrap();
+        root.nodes[1].scale = None;
+        let mut buffers = vec![packer.finish(&mut root)];
+
+        let joint_matrices = |root: &json::Root, buffers: &[Vec<u8>]| {
+            let globals = super::world_transforms(root).unwrap();
+            let document = crate::Document::from_json(root.clone()).unwrap();
+            let skin = document.skins().next().unwrap();
+            let reader = skin.reader(|buffer| Some(&buffers[buffer.index()][..]));
+            super::util::joint_matrices(&skin, &reader, &globals).unwrap()
+        };
+        let expected = joint_matrices(&root, &buffers);
+
+        // Moving the child to the top of the hierarchy changes its world transform.
+        let previous = super::world_transforms(&root).unwrap();
+        root.nodes[0].children = None;
+        let mut packer = Packer::new(&mut root);
+        super::rebind(&mut root, &buffers, &mut packer, skin, &previous).unwrap();
+        buffers.push(packer.finish(&mut root));
+        let actual = joint_matrices(&root, &buffers);
+        for (a, b) in actual.iter().flatten().flatten().zip(expected.iter().flatten().flatten()) {
+            assert!((a - b).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+        let mut packer = Packer::new(&mut root);
+        assert!(super::rebind(&mut root, &buffers, &mut packer, skin, &[]).is_none());
+    }
+}
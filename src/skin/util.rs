@@ -1,4 +1,5 @@
 use crate::accessor;
+use crate::math::Matrix4;
 
 use crate::{Buffer, Skin};
 
@@ -27,3 +28,40 @@ where
             .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
     }
 }
+
+/// Computes the skinning matrix of each joint of a skin, in the order of
+/// `Skin::joints`.
+///
+/// Each matrix is the global transform of the joint multiplied by its inverse
+/// bind matrix, transforming vertices from the bind pose to the current pose in
+/// world space. To skin in the space of the mesh node instead, multiply each
+/// matrix by the inverse of the global transform of the mesh node.
+///
+/// `node_global_transforms` holds the column-major global transform of every
+/// node, indexed by node index. Joints default to identity inverse bind
+/// matrices when the skin has none.
+///
+/// Returns `None` if the inverse bind matrices cannot be read, if there are
+/// fewer of them than joints, or if a joint has no global transform.
+pub fn joint_matrices<'a, 's, F>(
+    skin: &Skin<'a>,
+    reader: &Reader<'a, 's, F>,
+    node_global_transforms: &[[[f32; 4]; 4]],
+) -> Option<Vec<[[f32; 4]; 4]>>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    let mut inverse_bind_matrices = match skin.inverse_bind_matrices() {
+        Some(_) => Some(reader.read_inverse_bind_matrices()?),
+        None => None,
+    };
+    skin.joints()
+        .map(|joint| {
+            let global = Matrix4::from_array(*node_global_transforms.get(joint.index())?);
+            match inverse_bind_matrices.as_mut() {
+                Some(iter) => Some((global * Matrix4::from_array(iter.next()?)).as_array()),
+                None => Some(global.as_array()),
+            }
+        })
+        .collect()
+}
@@ -89,6 +89,17 @@ impl<'a> Sampler<'a> {
         self.json.name.as_ref().map(String::as_str)
     }
 
+    /// Returns the filtering and wrapping modes of the sampler as plain data.
+    pub fn state(&self) -> SamplerState {
+        SamplerState {
+            sampler: self.index,
+            mag_filter: self.mag_filter(),
+            min_filter: self.min_filter(),
+            wrap_s: self.wrap_s(),
+            wrap_t: self.wrap_t(),
+        }
+    }
+
     /// `s` wrapping mode.
     pub fn wrap_s(&self) -> WrappingMode {
         self.json.wrap_s.unwrap()
@@ -139,8 +150,21 @@ impl<'a> Texture<'a> {
     }
 
     /// Returns the image used by this texture.
+    ///
+    /// With the `KHR_texture_basisu` or `EXT_texture_webp` features, this is
+    /// the KTX2 or WebP image of the extension when one is present.
     pub fn source(&self) -> image::Image<'a> {
-        self.document.images().nth(self.json.source.value() as usize).unwrap()
+        let index = self.json.image().unwrap();
+        self.document.images().nth(index.value()).unwrap()
+    }
+
+    /// Returns the image of the core `source` property, which clients without
+    /// support for `KHR_texture_basisu` or `EXT_texture_webp` use instead of
+    /// the image of the extension.
+    #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp"))))]
+    pub fn fallback_source(&self) -> Option<image::Image<'a>> {
+        self.json.source.map(|index| self.document.images().nth(index.value()).unwrap())
     }
 
     /// Optional application specific data.
@@ -180,6 +204,16 @@ impl<'a> Info<'a> {
             .map(|x| TextureTransform::new(x))
     }
 
+    /// Returns the texture co-ordinate set and transform to sample the texture
+    /// with, taking `KHR_texture_transform` into account when enabled.
+    pub fn resolved_uv_pipeline(&self) -> UvPipeline {
+        #[cfg(feature = "KHR_texture_transform")]
+        let transform = self.texture_transform().map(|x| (x.tex_coord(), x.matrix()));
+        #[cfg(not(feature = "KHR_texture_transform"))]
+        let transform = None;
+        UvPipeline::new(self.tex_coord(), transform)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -192,6 +226,57 @@ impl<'a> AsRef<Texture<'a>> for Info<'a> {
     }
 }
 
+/// The texture co-ordinate handling of a texture binding, as returned by
+/// `Info::resolved_uv_pipeline`.
+///
+/// A shader samples the texture at `matrix * vec3(uv, 1.0)`, where `uv` is read
+/// from the `TEXCOORD_n` attribute with `n = tex_coord`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UvPipeline {
+    /// The set index of the `TEXCOORD` attribute to sample with.
+    pub tex_coord: u32,
+
+    /// The column-major 3x3 matrix transforming homogeneous UV co-ordinates.
+    pub matrix: [[f32; 3]; 3],
+}
+
+impl UvPipeline {
+    /// Combines the `texCoord` of a texture binding with the optional
+    /// `texCoord` override and matrix of its texture transform.
+    pub(crate) fn new(tex_coord: u32, transform: Option<(Option<u32>, [[f32; 3]; 3])>) -> Self {
+        match transform {
+            Some((tex_coord_override, matrix)) => UvPipeline {
+                tex_coord: tex_coord_override.unwrap_or(tex_coord),
+                matrix,
+            },
+            None => UvPipeline {
+                tex_coord,
+                matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            },
+        }
+    }
+}
+
+/// The filtering and wrapping modes of a sampler, as returned by
+/// `Sampler::state`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SamplerState {
+    /// The index of the sampler, or `None` for the default sampler.
+    pub sampler: Option<usize>,
+
+    /// The magnification filter, or `None` to let the renderer choose.
+    pub mag_filter: Option<MagFilter>,
+
+    /// The minification filter, or `None` to let the renderer choose.
+    pub min_filter: Option<MinFilter>,
+
+    /// The `s` wrapping mode.
+    pub wrap_s: WrappingMode,
+
+    /// The `t` wrapping mode.
+    pub wrap_t: WrappingMode,
+}
+
 /// Many techniques can be used to optimize resource usage for a 3d scene.
 /// Chief among them is the ability to minimize the number of textures the GPU must load.
 /// To achieve this, many engines encourage packing many objects' low-resolution textures into a single large texture atlas.
@@ -234,6 +319,15 @@ impl<'a> TextureTransform<'a> {
         self.json.tex_coord
     }
 
+    /// Returns the column-major 3x3 matrix `translation * rotation * scale`
+    /// applied to homogeneous UV co-ordinates.
+    pub fn matrix(&self) -> [[f32; 3]; 3] {
+        let [x, y] = self.offset();
+        let [sx, sy] = self.scale();
+        let (sin, cos) = self.rotation().sin_cos();
+        [[cos * sx, -sin * sx, 0.0], [sin * sy, cos * sy, 0.0], [x, y, 1.0]]
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
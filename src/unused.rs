@@ -0,0 +1,207 @@
+use json::validation::{Diagnostic, Error};
+use json::{texture, Index, Path};
+
+use crate::accessor::usage;
+
+/// Marks the object with the given index as referenced, ignoring indices that
+/// are out of bounds.
+fn mark<T>(referenced: &mut [bool], index: Index<T>) {
+    if let Some(flag) = referenced.get_mut(index.value()) {
+        *flag = true;
+    }
+}
+
+/// Returns the indices of the nodes that are not the child of another node.
+#[cfg(feature = "import")]
+pub(crate) fn root_nodes(root: &json::Root) -> Vec<Index<json::Node>> {
+    let mut is_child = vec![false; root.nodes.len()];
+    for child in root.nodes.iter().flat_map(|node| node.children.iter().flatten()) {
+        mark(&mut is_child, *child);
+    }
+    (0..root.nodes.len())
+        .filter(|&index| !is_child[index])
+        .map(|index| Index::new(index as u32))
+        .collect()
+}
+
+/// Returns a warning for each node that is not reachable from the root nodes
+/// of any scene.
+///
+/// Nothing is reported for documents without scenes, whose nodes are meant to
+/// be instantiated by the application.
+pub(crate) fn unreachable(root: &json::Root) -> Vec<Diagnostic> {
+    if root.scenes.is_empty() {
+        return Vec::new();
+    }
+    let mut reachable = vec![false; root.nodes.len()];
+    let mut stack: Vec<_> = root.scenes.iter().flat_map(|scene| scene.nodes.iter()).collect();
+    while let Some(index) = stack.pop() {
+        match reachable.get_mut(index.value()) {
+            // Nodes already visited are skipped, so cycles terminate.
+            Some(flag) if !*flag => *flag = true,
+            _ => continue,
+        }
+        stack.extend(root.nodes[index.value()].children.iter().flatten());
+    }
+    reachable
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| !**x)
+        .map(|(index, _)| Diagnostic {
+            path: Path::new().field("nodes").index(index),
+            error: Error::Unreachable,
+        })
+        .collect()
+}
+
+/// Returns a warning for each object that is not referenced by another object,
+/// followed by a warning for each node that is not reachable from a scene.
+///
+/// Unreferenced accessors, buffers, buffer views, cameras, images, materials,
+/// meshes, samplers, skins, and textures are reported. Unreferenced nodes are
+/// reported by `unreachable` only, since root nodes of documents without scenes
+/// are commonly instantiated by the application.
+pub(crate) fn report(root: &json::Root) -> Vec<Diagnostic> {
+    let mut buffers = vec![false; root.buffers.len()];
+    let mut views = vec![false; root.buffer_views.len()];
+    let mut cameras = vec![false; root.cameras.len()];
+    let mut images = vec![false; root.images.len()];
+    let mut materials = vec![false; root.materials.len()];
+    let mut meshes = vec![false; root.meshes.len()];
+    let mut samplers = vec![false; root.samplers.len()];
+    let mut skins = vec![false; root.skins.len()];
+    let mut textures = vec![false; root.textures.len()];
+
+    let accessors: Vec<_> = usage::usages(root).iter().map(|x| !x.is_empty()).collect();
+    for accessor in &root.accessors {
+        accessor.buffer_view.into_iter().for_each(|view| mark(&mut views, view));
+        if let Some(sparse) = accessor.sparse.as_ref() {
+            mark(&mut views, sparse.indices.buffer_view);
+            mark(&mut views, sparse.values.buffer_view);
+        }
+    }
+    for image in &root.images {
+        image.buffer_view.into_iter().for_each(|view| mark(&mut views, view));
+    }
+    for view in &root.buffer_views {
+        mark(&mut buffers, view.buffer);
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            let extensions = view.extensions.as_ref();
+            if let Some(meshopt) = extensions.and_then(|x| x.ext_meshopt_compression.as_ref()) {
+                mark(&mut buffers, meshopt.buffer);
+            }
+        }
+    }
+    for node in &root.nodes {
+        node.camera.into_iter().for_each(|camera| mark(&mut cameras, camera));
+        node.mesh.into_iter().for_each(|mesh| mark(&mut meshes, mesh));
+        node.skin.into_iter().for_each(|skin| mark(&mut skins, skin));
+    }
+    for primitive in root.meshes.iter().flat_map(|mesh| mesh.primitives.iter()) {
+        primitive.material.into_iter().for_each(|material| mark(&mut materials, material));
+        #[cfg(feature = "KHR_materials_variants")]
+        {
+            let extensions = primitive.extensions.as_ref();
+            if let Some(variants) = extensions.and_then(|x| x.khr_materials_variants.as_ref()) {
+                for mapping in &variants.mappings {
+                    mark(&mut materials, mapping.material);
+                }
+            }
+        }
+        #[cfg(feature = "KHR_draco_mesh_compression")]
+        {
+            let extensions = primitive.extensions.as_ref();
+            if let Some(draco) = extensions.and_then(|x| x.khr_draco_mesh_compression.as_ref()) {
+                mark(&mut views, draco.buffer_view);
+            }
+        }
+    }
+    for material in &root.materials {
+        let mut mark_info = |info: &Option<texture::Info>| {
+            info.iter().for_each(|info| mark(&mut textures, info.index));
+        };
+        let pbr = &material.pbr_metallic_roughness;
+        mark_info(&pbr.base_color_texture);
+        mark_info(&pbr.metallic_roughness_texture);
+        mark_info(&material.emissive_texture);
+        #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+        {
+            let extension = material.extensions.as_ref();
+            if let Some(x) = extension.and_then(|x| x.pbr_specular_glossiness.as_ref()) {
+                mark_info(&x.diffuse_texture);
+                mark_info(&x.specular_glossiness_texture);
+            }
+        }
+        #[cfg(feature = "KHR_materials_transmission")]
+        {
+            let extension = material.extensions.as_ref();
+            if let Some(x) = extension.and_then(|x| x.transmission.as_ref()) {
+                mark_info(&x.transmission_texture);
+            }
+        }
+        #[cfg(feature = "KHR_materials_volume")]
+        {
+            let extension = material.extensions.as_ref();
+            if let Some(x) = extension.and_then(|x| x.volume.as_ref()) {
+                mark_info(&x.thickness_texture);
+            }
+        }
+        #[cfg(feature = "KHR_materials_specular")]
+        {
+            let extension = material.extensions.as_ref();
+            if let Some(x) = extension.and_then(|x| x.specular.as_ref()) {
+                mark_info(&x.specular_texture);
+                mark_info(&x.specular_color_texture);
+            }
+        }
+        if let Some(normal) = material.normal_texture.as_ref() {
+            mark(&mut textures, normal.index);
+        }
+        if let Some(occlusion) = material.occlusion_texture.as_ref() {
+            mark(&mut textures, occlusion.index);
+        }
+    }
+    for texture in &root.textures {
+        texture.source.into_iter().for_each(|image| mark(&mut images, image));
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            let extensions = texture.extensions.as_ref();
+            if let Some(basisu) = extensions.and_then(|x| x.texture_basisu.as_ref()) {
+                mark(&mut images, basisu.source);
+            }
+        }
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            let extensions = texture.extensions.as_ref();
+            if let Some(webp) = extensions.and_then(|x| x.texture_webp.as_ref()) {
+                mark(&mut images, webp.source);
+            }
+        }
+        texture.sampler.into_iter().for_each(|sampler| mark(&mut samplers, sampler));
+    }
+
+    let kinds = [
+        ("accessors", accessors),
+        ("buffers", buffers),
+        ("bufferViews", views),
+        ("cameras", cameras),
+        ("images", images),
+        ("materials", materials),
+        ("meshes", meshes),
+        ("samplers", samplers),
+        ("skins", skins),
+        ("textures", textures),
+    ];
+    let mut warnings = Vec::new();
+    for (field, referenced) in kinds.iter() {
+        for (index, _) in referenced.iter().enumerate().filter(|(_, x)| !**x) {
+            warnings.push(Diagnostic {
+                path: Path::new().field(field).index(index),
+                error: Error::Unused,
+            });
+        }
+    }
+    warnings.extend(unreachable(root));
+    warnings
+}
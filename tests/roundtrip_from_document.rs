@@ -0,0 +1,80 @@
+//! Checks that binary glTF reconstructed from an imported document holds the
+//! same buffer and image data.
+
+#![cfg(all(feature = "import", feature = "utils"))]
+
+use gltf::binary::{Glb, Options};
+use gltf::image::{Data, Format};
+
+#[test]
+fn roundtrip_from_document() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::deserialize::from_str(r#"{"uri": "pixels.png"}"#).unwrap());
+    let document = gltf::Document::from_json(json).unwrap();
+    let images = vec![Data {
+        pixels: vec![0, 64, 128, 255, 192, 255, 32, 255],
+        format: Format::B8G8R8A8,
+        width: 2,
+        height: 1,
+        premultiplied: false,
+    }];
+
+    let glb = Glb::from_document(&document, &buffers, &images, Options::default()).unwrap();
+    let bytes = glb.to_vec().unwrap();
+    assert_eq!(glb.header.length as usize, bytes.len());
+
+    let (document, new_buffers, new_images) = gltf::import_slice(&bytes).unwrap();
+    assert_eq!(document.buffers().len(), 1);
+    assert!(document.images().all(|image| match image.source() {
+        gltf::image::Source::View { mime_type, .. } => mime_type == "image/png",
+        _ => false,
+    }));
+    assert_eq!(new_images[0].format, Format::R8G8B8A8);
+    assert_eq!(new_images[0].pixels, vec![128, 64, 0, 255, 32, 255, 192, 255]);
+
+    let read = |buffers: &[gltf::buffer::Data], document: &gltf::Document| -> Vec<[f32; 3]> {
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        reader.read_positions().unwrap().collect()
+    };
+    let (original, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    assert_eq!(read(&new_buffers, &document), read(&buffers, &original));
+
+    let options = Options { embed_images: false, ..Default::default() };
+    let glb = Glb::from_document(&original, &buffers, &[], options).unwrap();
+    assert!(glb.bin.is_some());
+}
+
+#[test]
+fn export_default_scene() {
+    use gltf::json::validation::Error;
+
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [{ "children": [1] }, {}, {}]
+    }"#).unwrap();
+    let document = gltf::Document::from_json(json).unwrap();
+    let options = Options { create_scene: true, ..Default::default() };
+    let glb = Glb::from_document(&document, &[], &[], options).unwrap();
+    let exported = gltf::Gltf::from_slice(&glb.to_vec().unwrap()).unwrap();
+    let scene = exported.default_scene().unwrap();
+    let roots: Vec<_> = scene.nodes().map(|node| node.index()).collect();
+    assert_eq!(roots, [0, 2]);
+    assert!(exported.validate_full().is_empty());
+
+    let mut json = document.into_json();
+    json.scenes.push(gltf::json::deserialize::from_str(r#"{"nodes": [2]}"#).unwrap());
+    let document = gltf::Document::from_json(json).unwrap();
+    let pointers: Vec<_> = document.validate_full().iter().map(|x| x.pointer()).collect();
+    assert_eq!(pointers, ["/nodes/0", "/nodes/1"]);
+    assert_eq!(document.validate_full()[0].error, Error::Unreachable);
+
+    let mut json = document.into_json();
+    json.scene = Some(gltf::json::Index::new(1));
+    let document = gltf::Document::from_json_without_validation(json);
+    match Glb::from_document(&document, &[], &[], Options::default()) {
+        Err(gltf::Error::Validation(errors)) => assert_eq!(errors[0].1, Error::IndexOutOfBounds),
+        _ => panic!("expected a validation error"),
+    }
+}
@@ -0,0 +1,159 @@
+//! Property-style roundtrip test.
+//!
+//! Generates pseudo-random valid documents with the export helpers, then checks
+//! that serializing and parsing them again, as standard and binary glTF, yields
+//! the same document and that the document passes validation.
+
+use std::borrow::Cow;
+
+use gltf::json;
+use gltf::pack::Packer;
+use json::camera::CameraBuilder;
+use json::validation::Checked::Valid;
+
+const NR_CASES: u64 = 256;
+
+/// Minimal xorshift generator, seeded per test case so failures are reproducible.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Rng(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn float(&mut self) -> f32 {
+        (self.next() % 2001) as f32 / 100.0 - 10.0
+    }
+
+    fn chance(&mut self) -> bool {
+        self.next() & 1 == 0
+    }
+}
+
+fn generate(rng: &mut Rng) -> (json::Root, Vec<u8>) {
+    let mut root = json::Root::default();
+    let mut packer = Packer::new(&mut root);
+
+    for _ in 0..rng.below(4) {
+        let count = 1 + rng.below(32);
+        let positions: Vec<[f32; 3]> = (0..count)
+            .map(|_| [rng.float(), rng.float(), rng.float()])
+            .collect();
+        let positions = packer.push_accessor(
+            &mut root,
+            &positions,
+            Some(json::buffer::Target::ArrayBuffer),
+        );
+        let mut primitive: json::mesh::Primitive =
+            json::deserialize::from_str(r#"{"attributes": {}}"#).unwrap();
+        primitive.attributes.insert(Valid(json::mesh::Semantic::Positions), positions);
+        if rng.chance() {
+            let indices: Vec<u16> = (0..3 * (1 + rng.below(16)))
+                .map(|_| rng.below(count) as u16)
+                .collect();
+            primitive.indices = Some(packer.push_accessor(
+                &mut root,
+                &indices,
+                Some(json::buffer::Target::ElementArrayBuffer),
+            ));
+        }
+        if rng.chance() {
+            let displacements: Vec<[f32; 3]> = (0..count)
+                .map(|_| if rng.below(4) == 0 { [rng.float(), 0.0, 0.0] } else { [0.0; 3] })
+                .collect();
+            let mut target: json::mesh::MorphTarget =
+                json::deserialize::from_str("{}").unwrap();
+            target.positions = Some(packer.push_sparse_accessor(&mut root, None, &displacements));
+            primitive.targets = Some(vec![target]);
+        }
+        let mut mesh: json::Mesh =
+            json::deserialize::from_str(r#"{"primitives": []}"#).unwrap();
+        mesh.primitives.push(primitive);
+        root.push(mesh);
+    }
+
+    let nr_nodes = rng.below(8);
+    for i in 0..nr_nodes {
+        let mut node: json::Node = json::deserialize::from_str("{}").unwrap();
+        if rng.chance() {
+            node.translation = Some([rng.float(), rng.float(), rng.float()]);
+        }
+        if rng.chance() {
+            node.scale = Some([rng.float(), rng.float(), rng.float()]);
+        }
+        if !root.meshes.is_empty() && rng.chance() {
+            node.mesh = Some(json::Index::new(rng.below(root.meshes.len()) as u32));
+        }
+        // Only refer to later nodes so that the hierarchy remains acyclic.
+        if i + 1 < nr_nodes && rng.chance() {
+            node.children = Some(vec![json::Index::new((i + 1) as u32)]);
+        }
+        root.push(node);
+    }
+
+    for _ in 0..rng.below(3) {
+        let scene: json::Scene = json::deserialize::from_str(r#"{"nodes": []}"#).unwrap();
+        let scene = root.push(scene);
+        let camera = if rng.chance() {
+            CameraBuilder::perspective(0.1 + rng.below(30) as f32 / 10.0, 0.01, None)
+        } else {
+            CameraBuilder::orthographic(1.0 + rng.below(10) as f32, 1.0, 0.0, 100.0)
+        };
+        camera
+            .translation([rng.float(), rng.float(), rng.float()])
+            .scene(scene)
+            .build(&mut root)
+            .unwrap();
+    }
+    if !root.scenes.is_empty() {
+        root.scene = Some(json::Index::new(0));
+    }
+
+    let bin = packer.finish(&mut root);
+    (root, bin)
+}
+
+fn check(seed: u64) {
+    let mut rng = Rng::new(seed);
+    let (root, bin) = generate(&mut rng);
+    let original = root.to_string().unwrap();
+
+    // Standard glTF.
+    let document = gltf::Gltf::from_slice(original.as_bytes())
+        .unwrap_or_else(|err| panic!("seed {}: {}", seed, err));
+    assert_eq!(document.document.into_json().to_string().unwrap(), original, "seed {}", seed);
+
+    // Binary glTF.
+    let glb = gltf::binary::Glb {
+        header: gltf::binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: 0,
+        },
+        json: Cow::Borrowed(original.as_bytes()),
+        bin: Some(Cow::Borrowed(&bin)),
+    };
+    let bytes = glb.to_vec().unwrap();
+    let gltf = gltf::Gltf::from_slice(&bytes)
+        .unwrap_or_else(|err| panic!("seed {}: {}", seed, err));
+    assert_eq!(gltf.blob.as_ref().map(Vec::len), Some(bin.len()), "seed {}", seed);
+    assert_eq!(gltf.document.into_json().to_string().unwrap(), original, "seed {}", seed);
+}
+
+#[test]
+fn roundtrip_generated() {
+    for seed in 0..NR_CASES {
+        check(seed);
+    }
+}
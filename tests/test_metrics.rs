@@ -0,0 +1,62 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::{fs, io};
+
+use gltf::json;
+
+#[derive(Default)]
+struct Counters {
+    bytes_read: AtomicUsize,
+    images_decoded: AtomicUsize,
+    validation_errors: Mutex<Vec<&'static str>>,
+}
+
+impl gltf::metrics::Metrics for Counters {
+    fn bytes_read(&self, bytes: usize) {
+        self.bytes_read.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    fn image_decoded(&self, _bytes: usize) {
+        self.images_decoded.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn validation_error(&self, _path: &json::Path, error: json::validation::Error) {
+        self.validation_errors.lock().unwrap().push(error.code());
+    }
+}
+
+// Metrics are global, so every check runs in a single test.
+#[test]
+fn test_metrics() {
+    let counters = Arc::new(Counters::default());
+    gltf::metrics::install(counters.clone());
+
+    let glb = fs::read("examples/Box.glb").unwrap();
+    gltf::Gltf::from_slice(&glb).unwrap();
+    assert_eq!(counters.bytes_read.swap(0, Ordering::Relaxed), glb.len());
+
+    let file = fs::File::open("examples/Box.glb").unwrap();
+    gltf::Gltf::from_reader(io::BufReader::new(file)).unwrap();
+    assert_eq!(counters.bytes_read.swap(0, Ordering::Relaxed), glb.len());
+
+    #[cfg(feature = "import")]
+    {
+        let bin = fs::metadata("examples/Box0.bin").unwrap().len() as usize;
+        let gltf = fs::metadata("examples/Box.gltf").unwrap().len() as usize;
+        gltf::import("examples/Box.gltf").unwrap();
+        assert_eq!(counters.bytes_read.swap(0, Ordering::Relaxed), gltf + bin);
+        assert_eq!(counters.images_decoded.load(Ordering::Relaxed), 0);
+    }
+
+    let invalid = br#"{"asset": {"version": "2.0"}, "scene": 1, "scenes": [{"nodes": [0]}]}"#;
+    assert!(gltf::Gltf::from_slice(invalid).is_err());
+    assert_eq!(counters.bytes_read.swap(0, Ordering::Relaxed), invalid.len());
+    assert_eq!(
+        *counters.validation_errors.lock().unwrap(),
+        ["INDEX_OUT_OF_BOUNDS", "INDEX_OUT_OF_BOUNDS"],
+    );
+
+    gltf::metrics::uninstall();
+    gltf::Gltf::from_slice(&glb).unwrap();
+    assert_eq!(counters.bytes_read.load(Ordering::Relaxed), 0);
+}
@@ -18,3 +18,836 @@ fn test_accessor_bounds() {
 }
 
 
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_mesh_and_scene_bounds() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get = |buffer: gltf::Buffer| Some(&*buffers[buffer.index()]);
+    let expected = Some(Bounds { min: [-0.5; 3], max: [0.5; 3] });
+    assert_eq!(document.meshes().next().unwrap().bounding_box(get), expected);
+    assert_eq!(document.scenes().next().unwrap().bounding_box(get), expected);
+
+    // Without declared bounds the positions are scanned.
+    let mut json = document.into_json();
+    json.accessors[2].min = None;
+    json.accessors[2].max = Some(gltf::json::Value::from(vec![0.0]));
+    json.nodes[0].matrix.as_mut().unwrap()[12] = 2.0;
+    let document = gltf::Document::from_json_without_validation(json);
+    assert_eq!(document.meshes().next().unwrap().bounding_box(get), expected);
+    let translated = Bounds { min: [1.5, -0.5, -0.5], max: [2.5, 0.5, 0.5] };
+    assert_eq!(document.scenes().next().unwrap().bounding_box(get), Some(translated));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_thumbnail_roundtrip() {
+    let mut json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0", "extras": { "author": "someone" } },
+        "images": [{ "uri": "preview.png" }]
+    }"#).unwrap();
+    json.set_thumbnail(gltf::json::Index::new(0)).unwrap();
+    let document = gltf::Document::from_json(json).unwrap();
+    assert_eq!(document.thumbnail().map(|image| image.index()), Some(0));
+    let json = document.into_json();
+    let extras = json.asset.extras.as_ref().unwrap().get();
+    assert!(extras.contains("\"author\":\"someone\""));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_tags_roundtrip() {
+    use gltf::json::extras::Taggable;
+
+    let mut json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "meshes": [{ "primitives": [] }],
+        "nodes": [{ "mesh": 0, "extras": { "tool": "exporter" } }, {}, { "extras": [] }]
+    }"#).unwrap();
+    assert!(json.nodes[0].add_tag("collider").unwrap());
+    assert!(json.nodes[0].add_tag("lod0").unwrap());
+    assert!(json.nodes[1].add_tag("collider").unwrap());
+    assert!(json.nodes[1].remove_tag("collider").unwrap());
+    assert!(!json.nodes[1].remove_tag("collider").unwrap());
+    assert!(json.nodes[1].extras.is_none());
+    assert!(json.nodes[2].add_tag("collider").is_err());
+    json.meshes[0].add_tag("static").unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let tagged: Vec<_> = document.tagged_nodes("collider").iter().map(|x| x.index()).collect();
+    assert_eq!(tagged, [0]);
+    assert_eq!(document.nodes().next().unwrap().tags(), ["collider", "lod0"]);
+    assert_eq!(document.tagged_meshes("static").len(), 1);
+    assert!(document.tagged_meshes("collider").is_empty());
+    let json = document.into_json();
+    let extras = json.nodes[0].extras.as_ref().unwrap().get();
+    assert!(extras.contains("\"tool\":\"exporter\""));
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn test_morph_target_names_roundtrip() {
+    let mut json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "accessors": [{ "componentType": 5126, "type": "VEC3", "count": 0 }],
+        "meshes": [{
+            "primitives": [{ "attributes": {}, "targets": [{ "POSITION": 0 }, { "POSITION": 0 }] }],
+            "extras": { "tool": "exporter" }
+        }]
+    }"#).unwrap();
+    assert_eq!(json.meshes[0].target_names(), None);
+    json.meshes[0].set_target_names(vec!["smile", "blink"]).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let mesh = document.meshes().next().unwrap();
+    assert_eq!(mesh.target_names(), Some(vec!["smile".to_string(), "blink".to_string()]));
+    let primitive = mesh.primitives().next().unwrap();
+    let names: Vec<_> = primitive.morph_targets().map(|target| target.name()).collect();
+    assert_eq!(names, vec![Some("smile".to_string()), Some("blink".to_string())]);
+    assert_eq!(primitive.morph_targets().last().map(|target| target.index()), Some(1));
+    let extras = mesh.extras().as_ref().unwrap().get();
+    assert!(extras.contains("\"tool\":\"exporter\""));
+}
+
+#[test]
+fn test_resolved_render_state() {
+    use gltf::material::AlphaMode;
+    use gltf::mesh::RenderState;
+
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "materials": [{ "alphaMode": "MASK", "doubleSided": true }, { "alphaMode": "BLEND", "alphaCutoff": 0.2 }],
+        "meshes": [{
+            "primitives": [{ "attributes": {}, "material": 0 }, { "attributes": {}, "material": 1 }, { "attributes": {} }]
+        }]
+    }"#).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let states: Vec<_> = document
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .map(|primitive| primitive.resolved_render_state())
+        .collect();
+    assert_eq!(states[0], RenderState { alpha_mode: AlphaMode::Mask, alpha_cutoff: Some(0.5), double_sided: true });
+    assert!(!states[0].cull_back_faces());
+    assert_eq!(states[1].alpha_cutoff, None);
+    assert!(states[1].blend() && !states[1].depth_write());
+    assert_eq!(states[2], RenderState::default());
+}
+
+#[test]
+fn test_vertex_buffer_layout() {
+    use gltf::accessor::{DataType, Dimensions};
+    use gltf::mesh::{Repack, Semantic, VertexBinding, VertexFormat};
+
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 96 }],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 8, "byteLength": 48, "byteStride": 16 },
+            { "buffer": 0, "byteOffset": 56, "byteLength": 40 }
+        ],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3" },
+            {
+                "bufferView": 0, "byteOffset": 12, "count": 3, "type": "VEC4",
+                "componentType": 5121, "normalized": true
+            },
+            {
+                "bufferView": 1, "count": 3, "type": "VEC3",
+                "componentType": 5121, "normalized": true
+            },
+            {
+                "bufferView": 1, "byteOffset": 10, "count": 3, "type": "VEC2",
+                "componentType": 5123
+            },
+            { "componentType": 5126, "count": 3, "type": "VEC2" },
+            {
+                "bufferView": 1, "componentType": 5126, "count": 3, "type": "SCALAR",
+                "sparse": {
+                    "count": 1,
+                    "indices": { "bufferView": 1, "componentType": 5121 },
+                    "values": { "bufferView": 1 }
+                }
+            }
+        ],
+        "meshes": [{
+            "primitives": [{
+                "attributes": {
+                    "POSITION": 0, "COLOR_0": 1, "COLOR_1": 2, "TEXCOORD_0": 3, "TEXCOORD_1": 4,
+                    "WEIGHTS_0": 5
+                }
+            }]
+        }]
+    }"#).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let layout = primitive.vertex_buffer_layout();
+    let get = |semantic| layout.iter().find(|(x, _)| *x == semantic).unwrap().1;
+    let format = VertexFormat {
+        data_type: DataType::F32,
+        dimensions: Dimensions::Vec3,
+        normalized: false,
+    };
+    let binding = VertexBinding { buffer: 0, offset: 8, stride: 16, format };
+    assert_eq!(get(Semantic::Positions), Ok(binding));
+    assert_eq!(get(Semantic::Colors(0)).unwrap().offset, 20);
+    assert_eq!(get(Semantic::Colors(1)), Err(Repack::Format));
+    assert_eq!(get(Semantic::TexCoords(0)), Err(Repack::Unaligned));
+    assert_eq!(get(Semantic::TexCoords(1)), Err(Repack::Missing));
+    assert_eq!(get(Semantic::Weights(0)), Err(Repack::Sparse));
+}
+
+#[test]
+fn test_webgpu_formats() {
+    use gltf::accessor::{DataType, Dimensions};
+    use gltf::mesh::{webgpu_index_format, VertexFormat};
+
+    let format = |data_type, dimensions, normalized| {
+        VertexFormat { data_type, dimensions, normalized }.webgpu_name()
+    };
+    assert_eq!(format(DataType::F32, Dimensions::Vec3, false), Some("float32x3"));
+    assert_eq!(format(DataType::U8, Dimensions::Vec4, true), Some("unorm8x4"));
+    assert_eq!(format(DataType::I16, Dimensions::Vec2, true), Some("snorm16x2"));
+    assert_eq!(format(DataType::U16, Dimensions::Vec4, false), Some("uint16x4"));
+    assert_eq!(format(DataType::U8, Dimensions::Vec3, true), None);
+    assert_eq!(format(DataType::F32, Dimensions::Mat4, false), None);
+    assert_eq!(webgpu_index_format(DataType::U32), Some("uint32"));
+    assert_eq!(webgpu_index_format(DataType::U8), None);
+}
+
+#[cfg(feature = "wgpu-types")]
+#[test]
+fn test_wgpu_formats() {
+    use gltf::mesh::UnsupportedFormat;
+    use wgpu_types::{IndexFormat, VertexFormat};
+
+    let mut root = gltf::json::Root::default();
+    let mut packer = gltf::pack::Packer::new(&mut root);
+    let positions = packer.push_accessor(&mut root, &[[0.0f32, 0.0, 0.0]], None);
+    let colors = packer.push_accessor(&mut root, &[[0u8, 0, 0]], None);
+    let indices = packer.push_accessor(&mut root, &[0u16], None);
+    let small_indices = packer.push_accessor(&mut root, &[0u8], None);
+    root.accessors[colors.value()].normalized = true;
+    packer.finish(&mut root);
+    let document = gltf::Document::from_json(root).unwrap();
+    let accessor = |index: gltf::json::Index<gltf::json::Accessor>| {
+        document.accessors().nth(index.value()).unwrap()
+    };
+
+    assert_eq!(accessor(positions).wgpu_vertex_format(), Ok(VertexFormat::Float32x3));
+    assert_eq!(accessor(indices).wgpu_index_format(), Ok(IndexFormat::Uint16));
+    assert_eq!(accessor(indices).wgpu_vertex_format(), Err(UnsupportedFormat {
+        accessor: indices.value(),
+        format: gltf::mesh::VertexFormat {
+            data_type: gltf::accessor::DataType::U16,
+            dimensions: gltf::accessor::Dimensions::Scalar,
+            normalized: false,
+        },
+    }));
+    let error = accessor(colors).wgpu_vertex_format().unwrap_err();
+    assert_eq!(error.to_string(), "accessor 1: no wgpu format for normalized U8 Vec3 elements");
+    assert_eq!(accessor(small_indices).wgpu_index_format().unwrap_err().accessor, 3);
+}
+
+#[test]
+fn test_component_nodes() {
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "nodes": [
+            { "camera": 0 },
+            { "children": [2, 3], "translation": [1.0, 0.0, 0.0] },
+            { "mesh": 0, "translation": [0.0, 1.0, 0.0] },
+            { "camera": 0 }
+        ],
+        "skins": [{ "joints": [1, 2] }]
+    }"#).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let visit = |iter: gltf::iter::ComponentNodes| -> Vec<_> {
+        iter.map(|(node, world)| (node.index(), world[3])).collect()
+    };
+    assert_eq!(visit(document.mesh_nodes()), [(2, [1.0, 1.0, 0.0, 1.0])]);
+    let cameras = [(0, [0.0, 0.0, 0.0, 1.0]), (3, [1.0, 0.0, 0.0, 1.0])];
+    assert_eq!(visit(document.camera_nodes()), cameras);
+    let joints = [(1, [1.0, 0.0, 0.0, 1.0]), (2, [1.0, 1.0, 0.0, 1.0])];
+    assert_eq!(visit(document.joint_nodes()), joints);
+}
+
+#[test]
+fn test_validate_full() {
+    use gltf::json::validation::{Error, Severity};
+
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": { "version": "2.0" },
+        "buffers": [{ "byteLength": 12 }, { "byteLength": 4 }],
+        "bufferViews": [{ "buffer": 0, "byteLength": 12 }],
+        "accessors": [
+            { "bufferView": 0, "componentType": 5126, "count": 1, "type": "VEC3" },
+            { "bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR" }
+        ],
+        "materials": [{}],
+        "meshes": [{ "primitives": [{ "attributes": { "POSITION": 0 } }] }],
+        "nodes": [{ "mesh": 0 }]
+    }"#).unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+    let diagnostics = document.validate_full();
+    let pointers: Vec<_> = diagnostics.iter().map(|x| x.pointer()).collect();
+    assert_eq!(pointers, [
+        "/meshes/0/primitives/0/attributes/POSITION/min",
+        "/meshes/0/primitives/0/attributes/POSITION/max",
+        "/accessors/1",
+        "/buffers/1",
+        "/materials/0",
+    ]);
+    assert_eq!(diagnostics[1].error, Error::Missing);
+    assert_eq!(diagnostics[1].severity(), Severity::Error);
+    assert_eq!(diagnostics[2].error, Error::Unused);
+    assert_eq!(diagnostics[2].severity(), Severity::Warning);
+}
+
+#[test]
+fn test_read_asset_stops_after_asset() {
+    let data = br#"{
+        "extensionsUsed": ["EXT_example"],
+        "asset": { "version": "2.0", "generator": "exporter", "minVersion": "2.0" },
+        "nodes": [ This is never parsed.
+    "#;
+    let asset = gltf::read_asset(&data[..]).unwrap();
+    assert_eq!(asset.generator.as_deref(), Some("exporter"));
+    assert_eq!(asset.min_version.as_deref(), Some("2.0"));
+
+    let glb = std::fs::read("examples/Box.glb").unwrap();
+    assert_eq!(gltf::read_asset(&glb[..]).unwrap().version, "2.0");
+    assert!(gltf::read_asset(&br#"{"nodes": []}"#[..]).is_err());
+}
+
+#[test]
+fn test_from_slice_with_limits() {
+    use gltf::json::limits::{Error, Limits};
+
+    let glb = std::fs::read("examples/Box.glb").unwrap();
+    assert!(gltf::Gltf::from_slice_with_limits(&glb, &Limits::default()).is_ok());
+    let limits = Limits { max_depth: 1, ..Limits::default() };
+    match gltf::Gltf::from_slice_with_limits(&glb, &limits) {
+        Err(gltf::Error::Limit(Error::Depth { .. })) => {},
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_normal_and_occlusion_texture_transform() {
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}],
+        "textures": [{"source": 0}],
+        "materials": [{
+            "normalTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"offset": [0.5, 0], "texCoord": 1}}
+            },
+            "occlusionTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"rotation": 1.5, "scale": [2, 2]}}
+            }
+        }]
+    }"#).unwrap();
+    assert_eq!(json.materials[0].tex_coord_sets(), [0, 1]);
+
+    let document = gltf::Document::from_json(json).unwrap();
+    let material = document.materials().next().unwrap();
+    let normal = material.normal_texture().unwrap().texture_transform().unwrap();
+    assert_eq!(normal.offset(), [0.5, 0.0]);
+    assert_eq!(normal.tex_coord(), Some(1));
+    let occlusion = material.occlusion_texture().unwrap().texture_transform().unwrap();
+    assert_eq!(occlusion.rotation(), 1.5);
+    assert_eq!(occlusion.scale(), [2.0, 2.0]);
+}
+
+#[cfg(all(
+    feature = "KHR_materials_volume",
+    feature = "KHR_materials_emissive_strength",
+    feature = "KHR_materials_specular"
+))]
+#[test]
+fn test_material_extensions() {
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}],
+        "textures": [{"source": 0}],
+        "materials": [{
+            "extensions": {
+                "KHR_materials_volume": {
+                    "thicknessFactor": 0.5,
+                    "thicknessTexture": {"index": 0, "texCoord": 1},
+                    "attenuationColor": [0.5, 0.25, 1.0]
+                },
+                "KHR_materials_emissive_strength": {"emissiveStrength": 4.0},
+                "KHR_materials_specular": {
+                    "specularColorFactor": [0.5, 0.5, 0.5],
+                    "specularColorTexture": {"index": 0}
+                }
+            }
+        }, {}]
+    }"#).unwrap();
+    assert_eq!(json.materials[0].tex_coord_sets(), [0, 1]);
+    let document = gltf::Document::from_json(json).unwrap();
+    let material = document.materials().next().unwrap();
+
+    let volume = material.volume().unwrap();
+    assert_eq!(volume.thickness_factor(), 0.5);
+    assert_eq!(volume.thickness_texture().unwrap().tex_coord(), 1);
+    assert_eq!(volume.attenuation_distance(), f32::INFINITY);
+    assert_eq!(volume.attenuation_color(), [0.5, 0.25, 1.0]);
+    assert_eq!(material.emissive_strength(), Some(4.0));
+    let specular = material.specular().unwrap();
+    assert_eq!(specular.specular_factor(), 1.0);
+    assert!(specular.specular_texture().is_none());
+    assert_eq!(specular.specular_color_factor(), [0.5, 0.5, 0.5]);
+    assert_eq!(specular.specular_color_texture().unwrap().texture().index(), 0);
+
+    let default = document.materials().nth(1).unwrap();
+    assert!(default.volume().is_none());
+    assert!(default.emissive_strength().is_none());
+    assert!(default.specular().is_none());
+    let diagnostics = document.validate_full();
+    assert!(!diagnostics.iter().any(|x| x.path.as_str().starts_with("textures")));
+}
+
+#[cfg(all(feature = "import", feature = "KHR_materials_variants"))]
+#[test]
+fn test_material_variants() {
+    use gltf::json::deserialize::from_str;
+
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut json = document.into_json();
+    json.extensions_used.push("KHR_materials_variants".to_string());
+    json.extensions.get_or_insert_with(Default::default).khr_materials_variants = from_str(r#"{
+        "variants": [{"name": "red"}, {"name": "blue"}, {"name": "chrome"}]
+    }"#).unwrap();
+    let material = json.materials[0].clone();
+    json.materials.extend(vec![material; 2]);
+    let primitive = json.meshes[0].primitives[0].clone();
+    json.meshes[0].primitives.push(primitive);
+    json.meshes[0].primitives[0].extensions = from_str(r#"{
+        "KHR_materials_variants": {
+            "mappings": [
+                {"material": 1, "variants": [1]},
+                {"material": 2, "variants": [0, 2]}
+            ]
+        }
+    }"#).unwrap();
+    let document = gltf::Document::from_json(json).unwrap();
+    let names: Vec<_> = document.variants().unwrap().map(|variant| variant.name()).collect();
+    assert_eq!(names, ["red", "blue", "chrome"]);
+
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let materials: Vec<_> = (0..4)
+        .map(|variant| primitive.material_for_variant(variant).index())
+        .collect();
+    assert_eq!(materials, [Some(2), Some(1), Some(2), Some(0)]);
+    let plain = mesh.primitives().nth(1).unwrap();
+    assert_eq!(plain.material_for_variant(1).index(), Some(0));
+
+    let unused = document.validate_full();
+    assert!(!unused.iter().any(|x| x.path.as_str().starts_with("materials")));
+
+    let mut json = document.into_json();
+    json.meshes[0].primitives[0]
+        .extensions.as_mut().unwrap()
+        .khr_materials_variants.as_mut().unwrap()
+        .mappings[0].variants[0] = gltf::json::Index::new(3);
+    assert!(gltf::Document::from_json(json).is_err());
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_resolved_uv_pipeline() {
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "images": [{"uri": "a.png"}],
+        "textures": [{"source": 0}],
+        "materials": [{
+            "pbrMetallicRoughness": {
+                "baseColorTexture": {
+                    "index": 0,
+                    "texCoord": 1,
+                    "extensions": {
+                        "KHR_texture_transform": {
+                            "offset": [0.5, 0.25],
+                            "rotation": 1.5707964,
+                            "scale": [2, 3],
+                            "texCoord": 2
+                        }
+                    }
+                }
+            },
+            "emissiveTexture": {"index": 0, "texCoord": 1}
+        }]
+    }"#).unwrap();
+    let document = gltf::Document::from_json(json).unwrap();
+    let material = document.materials().next().unwrap();
+
+    let emissive = material.emissive_texture().unwrap().resolved_uv_pipeline();
+    assert_eq!(emissive.tex_coord, 1);
+    assert_eq!(emissive.matrix, [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+
+    let base_color = material.pbr_metallic_roughness().base_color_texture().unwrap();
+    let pipeline = base_color.resolved_uv_pipeline();
+    assert_eq!(pipeline.tex_coord, 2);
+    let m = pipeline.matrix;
+    // The UV (1, 0) is scaled to (2, 0), rotated to (0, -2), and offset.
+    let uv = [m[0][0] + m[2][0], m[0][1] + m[2][1]];
+    assert!((uv[0] - 0.5).abs() < 1e-6 && (uv[1] + 1.75).abs() < 1e-6, "{:?}", uv);
+    // The UV (0, 1) is scaled to (0, 3), rotated to (3, 0), and offset.
+    let uv = [m[1][0] + m[2][0], m[1][1] + m[2][1]];
+    assert!((uv[0] - 3.5).abs() < 1e-6 && (uv[1] - 0.25).abs() < 1e-6, "{:?}", uv);
+}
+
+#[cfg(feature = "KHR_draco_mesh_compression")]
+#[test]
+fn test_draco_mesh_compression() {
+    use gltf::Semantic;
+
+    let json = gltf::json::Root::from_str(r#"{
+        "asset": {"version": "2.0"},
+        "extensionsUsed": ["KHR_draco_mesh_compression"],
+        "extensionsRequired": ["KHR_draco_mesh_compression"],
+        "buffers": [{"byteLength": 64}],
+        "bufferViews": [{"buffer": 0, "byteLength": 64}],
+        "accessors": [
+            {"componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 0]},
+            {"componentType": 5123, "count": 3, "type": "SCALAR"}
+        ],
+        "meshes": [{"primitives": [{
+            "attributes": {"POSITION": 0},
+            "indices": 1,
+            "extensions": {
+                "KHR_draco_mesh_compression": {"bufferView": 0, "attributes": {"POSITION": 2}}
+            }
+        }]}]
+    }"#).unwrap();
+    let document = gltf::Document::from_json(json).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let draco = primitive.draco_mesh_compression().unwrap();
+    assert_eq!(draco.view().length(), 64);
+    assert_eq!(draco.attribute_id(&Semantic::Positions), Some(2));
+    assert_eq!(draco.attribute_id(&Semantic::Normals), None);
+    assert_eq!(draco.attributes().collect::<Vec<_>>(), [(Semantic::Positions, 2)]);
+    assert!(document.validate_full().iter().all(|x| x.pointer() != "/bufferViews/0"));
+
+    let mut json = document.into_json();
+    gltf::optimize::repack_buffers(&mut json, &[vec![0; 64]]);
+    assert_eq!(json.buffer_views.len(), 1);
+}
+
+#[test]
+fn test_streaming_glb_reader() {
+    use gltf::binary::{Glb, Reader};
+    use std::io::Cursor;
+
+    let data = std::fs::read("examples/Box.glb").unwrap();
+    let glb = Glb::from_slice(&data).unwrap();
+    let mut reader = Reader::new(Cursor::new(&data)).unwrap();
+    assert_eq!(reader.header().length, glb.header.length);
+    assert_eq!(reader.read_json().unwrap(), &glb.json[..]);
+    let bin = glb.bin.unwrap();
+    assert_eq!(reader.bin_length(), Some(bin.len()));
+    assert_eq!(reader.read_bin_range(4, 8).unwrap(), &bin[4..12]);
+    assert!(reader.read_bin_range(bin.len() - 4, 8).is_err());
+
+    match Reader::new(Cursor::new(&data[..data.len() - 1])) {
+        Err(gltf::Error::Binary(gltf::binary::Error::Length { .. })) => {},
+        result => panic!("unexpected result: {:?}", result.map(|_| ())),
+    }
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_import_with_resolver() {
+    let mut requested = Vec::new();
+    let mut resolver = |uri: &str| {
+        requested.push(uri.to_string());
+        Ok(std::fs::read(std::path::Path::new("examples").join(uri))?)
+    };
+    let (document, buffers, _) = gltf::import_with_resolver(&mut resolver, "Box.gltf").unwrap();
+    assert_eq!(requested, ["Box.gltf", "Box0.bin"]);
+    let (_, expected, _) = gltf::import("examples/Box.gltf").unwrap();
+    assert_eq!(document.buffers().count(), 1);
+    assert_eq!(buffers[0].0, expected[0].0);
+}
+
+#[test]
+fn test_inspect_glb() {
+    use gltf::binary::{inspect, Glb};
+    use std::io::Cursor;
+
+    let data = std::fs::read("examples/Box.glb").unwrap();
+    let glb = Glb::from_slice(&data).unwrap();
+    let inspection = inspect(Cursor::new(&data)).unwrap();
+    let types: Vec<_> = inspection.chunks.iter().map(|chunk| chunk.ty).collect();
+    assert_eq!(types, [*b"JSON", *b"BIN\0"]);
+    let json = inspection.json().unwrap();
+    assert_eq!(json.offset, 20);
+    assert_eq!(&data[json.offset as usize..json.end() as usize], &glb.json[..]);
+    let bin = inspection.bin().unwrap();
+    assert_eq!(bin.length as usize, glb.bin.unwrap().len());
+    assert_eq!(bin.offset, json.end() + 8);
+    assert!(!inspection.is_truncated());
+
+    let truncated = &data[..bin.offset as usize + 4];
+    let inspection = inspect(Cursor::new(truncated)).unwrap();
+    assert!(inspection.is_truncated());
+    assert_eq!(inspection.bin(), Some(bin));
+    assert!(inspection.bin().unwrap().end() > inspection.length);
+    let inspection = inspect(Cursor::new(&data[..bin.offset as usize - 4])).unwrap();
+    assert_eq!(inspection.chunks.len(), 1);
+}
+
+#[test]
+fn test_glb_bin_ranges() {
+    use gltf::binary::inspect;
+    use std::io::Cursor;
+
+    let data = std::fs::read("examples/Box.glb").unwrap();
+    let inspection = inspect(Cursor::new(&data)).unwrap();
+    let json = inspection.json().unwrap();
+    let gltf = gltf::Gltf::from_slice(&data[json.offset as usize..json.end() as usize]).unwrap();
+    let bin = inspection.bin().unwrap();
+    let offset = bin.offset;
+    let ranges = inspection.bin_ranges(gltf.views());
+    let length: usize = gltf.views().map(|view| view.length()).sum();
+    assert_eq!(ranges, vec![offset..offset + length as u64]);
+
+    let meshes = inspection.mesh_ranges(gltf.meshes());
+    assert_eq!(meshes, ranges);
+    let scene = gltf.default_scene().unwrap();
+    assert_eq!(inspection.scene_ranges(&scene), ranges);
+    let view = gltf.views().nth(1).unwrap();
+    let start = offset + view.offset() as u64;
+    let end = start + view.length() as u64;
+    assert_eq!(inspection.bin_ranges(Some(view)), vec![start..end]);
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_import_mmap() {
+    for path in &["examples/Box.glb", "examples/Box.gltf"] {
+        let (document, buffers, images) = gltf::import(path).unwrap();
+        let (mapped_document, mapped_buffers, mapped_images) =
+            unsafe { gltf::import_mmap(path) }.unwrap();
+        assert_eq!(mapped_document.buffers().len(), document.buffers().len());
+        assert_eq!(mapped_buffers.len(), buffers.len());
+        assert_eq!(mapped_images.len(), images.len());
+        for (mapped, buffer) in mapped_buffers.iter().zip(&buffers) {
+            assert_eq!(&buffer[..mapped.len()], &mapped[..]);
+            assert_eq!(mapped.is_mapped(), cfg!(all(unix, target_pointer_width = "64")));
+        }
+    }
+}
+
+#[cfg(all(feature = "mmap", feature = "EXT_meshopt_compression"))]
+#[test]
+fn test_import_mmap_meshopt_fallback() {
+    // The elements `[1, 2, 3, 4]` and `[3, 2, 1, 4]` with raw byte groups.
+    let mut compressed = vec![0xa0];
+    for deltas in [[0, 4], [0, 0], [0, 3], [0, 0]].iter() {
+        compressed.push(0x03);
+        compressed.extend_from_slice(deltas);
+        compressed.extend_from_slice(&[0; 14]);
+    }
+    compressed.extend_from_slice(&[0; 28]);
+    compressed.extend_from_slice(&[1, 2, 3, 4]);
+    let json = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "extensionsUsed": ["EXT_meshopt_compression"],
+            "buffers": [
+                {{"byteLength": {0}, "uri": "compressed.bin"}},
+                {{"byteLength": 8, "extensions": {{"EXT_meshopt_compression": {{"fallback": true}}}}}}
+            ],
+            "bufferViews": [{{
+                "buffer": 1,
+                "byteLength": 8,
+                "byteStride": 4,
+                "extensions": {{
+                    "EXT_meshopt_compression": {{
+                        "buffer": 0,
+                        "byteLength": {0},
+                        "byteStride": 4,
+                        "count": 2,
+                        "mode": "ATTRIBUTES"
+                    }}
+                }}
+            }}]
+        }}"#,
+        compressed.len(),
+    );
+    let directory = std::env::temp_dir().join(format!("gltf-mmap-meshopt-{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(directory.join("compressed.bin"), &compressed).unwrap();
+    std::fs::write(directory.join("model.gltf"), json).unwrap();
+
+    let result = unsafe { gltf::import_mmap(directory.join("model.gltf")) };
+    std::fs::remove_dir_all(&directory).unwrap();
+    let (_, buffers, _) = result.unwrap();
+    assert_eq!(&buffers[0][..], &compressed[..]);
+    assert_eq!(&buffers[1][..], &[1, 2, 3, 4, 3, 2, 1, 4]);
+    assert!(!buffers[1].is_mapped());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_compare_documents() {
+    use gltf::compare::{compare, Difference, Kind, Tolerances};
+    use gltf::json::Path;
+    use gltf::Semantic;
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let tolerances = Tolerances::default();
+    assert!(compare(&document, &buffers, &document, &buffers, &tolerances).is_empty());
+
+    let mut json = document.clone().into_json();
+    json.nodes[0].matrix.as_mut().unwrap()[13] += 1.0;
+    let red = json.materials[0].pbr_metallic_roughness.base_color_factor.0[0];
+    json.materials[0].pbr_metallic_roughness.base_color_factor.0[0] = 0.0;
+    json.materials[0].double_sided = true;
+    let modified = gltf::Document::from_json(json).unwrap();
+    let positions = document.meshes().next().unwrap()
+        .primitives().next().unwrap()
+        .get(&Semantic::Positions).unwrap();
+    let view = positions.view().unwrap();
+    let offset = view.offset() + positions.offset();
+    let mut modified_buffers = buffers.clone();
+    let bytes = &mut modified_buffers[view.buffer().index()].0[offset..offset + 4];
+    let x = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) + 0.25;
+    bytes.copy_from_slice(&x.to_le_bytes());
+
+    let differences = compare(&document, &buffers, &modified, &modified_buffers, &tolerances);
+    let paths: Vec<_> = differences.iter().map(|x| x.path.as_str()).collect();
+    assert_eq!(paths, [
+        "meshes[0].primitives[0].attributes[\"POSITION\"]",
+        "materials[0].pbrMetallicRoughness.baseColorFactor",
+        "materials[0].doubleSided",
+        "nodes[0].matrix",
+    ]);
+    assert_eq!(differences[0].kind, Kind::Deviation(0.25));
+    assert_eq!(differences[1].kind, Kind::Deviation(red));
+    assert_eq!(differences[2].kind, Kind::Mismatch);
+    assert_eq!(differences[3], Difference {
+        path: Path("nodes[0].matrix".into()),
+        kind: Kind::Deviation(1.0),
+    });
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_diff_report() {
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    assert_eq!(gltf::diff_report(&document, &document), "no differences\n");
+
+    let mut json = document.clone().into_json();
+    json.nodes[1].rotation = Some(gltf::json::scene::UnitQuaternion([0.0, 0.0, 1.0, 0.0]));
+    json.nodes[1].scale = Some([2.0, 1.0, 1.0]);
+    json.materials[0].double_sided = true;
+    let node = json.nodes[1].clone();
+    json.nodes.push(node);
+    let modified = gltf::Document::from_json(json).unwrap();
+    let report = gltf::diff_report(&document, &modified);
+    let lines: Vec<_> = report.lines().collect();
+    assert_eq!(lines[0], "nodes: 2 -> 3");
+    assert_eq!(lines[1], "+ node 2");
+    assert_eq!(lines[2], "~ node 1: rotation [0.0, 0.0, 0.0, 1.0] -> [0.0, 0.0, 1.0, 0.0] \
+        (delta 180.000 degrees)");
+    assert_eq!(lines[3], "~ node 1: scale [1.0, 1.0, 1.0] -> [2.0, 1.0, 1.0] \
+        (delta [1.0, 0.0, 0.0])");
+    assert!(lines[4].ends_with(": doubleSided false -> true"));
+    assert_eq!(lines.len(), 5);
+    assert!(gltf::diff_report(&modified, &document).contains("- node 2"));
+}
+
+#[cfg(all(feature = "import", feature = "KHR_texture_basisu"))]
+#[test]
+fn test_texture_basisu() {
+    use gltf::json::deserialize::from_str;
+
+    // The header of a 4x2 KTX2 file without any levels.
+    let uri = "data:image/ktx2;base64,q0tUWCAyMLsNChoKAAAAAAEAAAAEAAAAAgAAAAAAAAAAAAAAAQAAAAAAAAA=";
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut json = document.into_json();
+    json.extensions_used.push("KHR_texture_basisu".to_string());
+    json.images.push(from_str(&format!(r#"{{"uri": "{}"}}"#, uri)).unwrap());
+    json.textures.push(from_str(r#"{
+        "extensions": {"KHR_texture_basisu": {"source": 0}}
+    }"#).unwrap());
+    let document = gltf::Document::from_json(json.clone()).unwrap();
+    let texture = document.textures().next().unwrap();
+    assert_eq!(texture.source().index(), 0);
+    assert!(texture.fallback_source().is_none());
+
+    let images = gltf::import::import_image_data(&document, None, &[]).unwrap();
+    assert_eq!(images[0].format, gltf::image::Format::Ktx2);
+    assert_eq!((images[0].width, images[0].height), (4, 2));
+    assert_eq!(images[0].pixels.len(), 44);
+
+    json.textures[0].extensions = None;
+    let errors = gltf::Document::from_json(json).unwrap_err();
+    assert!(errors.to_string().contains("textures[0].source"));
+}
+
+#[cfg(all(feature = "import", feature = "EXT_texture_webp"))]
+#[test]
+fn test_texture_webp() {
+    use gltf::json::deserialize::from_str;
+
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut json = document.into_json();
+    json.extensions_used.push("EXT_texture_webp".to_string());
+    json.images.push(from_str(r#"{"uri": "box.webp"}"#).unwrap());
+    json.textures.push(from_str(r#"{
+        "extensions": {"EXT_texture_webp": {"source": 0}}
+    }"#).unwrap());
+    let document = gltf::Document::from_json(json).unwrap();
+    let texture = document.textures().next().unwrap();
+    assert_eq!(texture.source().index(), 0);
+    assert!(texture.fallback_source().is_none());
+    assert!(document.validate_full().iter().all(|x| !x.path.as_str().starts_with("images")));
+
+    let base = std::path::Path::new("tests");
+    let images = gltf::import::import_image_data(&document, Some(base), &[]).unwrap();
+    assert_eq!(images[0].format, gltf::image::Format::R8G8B8);
+    assert_eq!((images[0].width, images[0].height), (16, 16));
+    let pixels = &images[0].pixels;
+    assert!(pixels.chunks_exact(3).any(|x| x[0] != x[1] || x[1] != x[2]));
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_material_binding_table() {
+    use gltf::json::deserialize::from_str;
+    use gltf::texture::{MagFilter, WrappingMode};
+
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(from_str(r#"{"uri": "normal.png"}"#).unwrap());
+    json.samplers.push(from_str(r#"{"magFilter": 9728, "wrapS": 33071}"#).unwrap());
+    json.textures.push(from_str(r#"{"source": 0, "sampler": 0}"#).unwrap());
+    json.materials[0].normal_texture = from_str(r#"{"index": 0, "scale": 0.5}"#).unwrap();
+    json.materials[0].emissive_factor = from_str("[1.0, 0.5, 0.0]").unwrap();
+    let document = gltf::Document::from_json_without_validation(json);
+
+    let table = document.material_binding_table();
+    assert_eq!(table.len(), 1);
+    let binding = &table[0];
+    assert_eq!(binding.material, Some(0));
+    assert_eq!(binding.emissive_factor, [1.0, 0.5, 0.0]);
+    assert_eq!(binding.render_state, Default::default());
+    assert!(binding.base_color_texture.is_none());
+    let normal = binding.normal_texture.unwrap();
+    assert_eq!((normal.texture, normal.image, normal.scale), (0, 0, 0.5));
+    assert_eq!(normal.sampler.sampler, Some(0));
+    assert_eq!(normal.sampler.mag_filter, Some(MagFilter::Nearest));
+    assert_eq!(normal.sampler.wrap_s, WrappingMode::ClampToEdge);
+    assert_eq!(normal.sampler.wrap_t, WrappingMode::Repeat);
+    assert_eq!(normal.uv.tex_coord, 0);
+
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(&primitive.material().binding(), binding);
+}